@@ -33,6 +33,22 @@ TWData* _Nonnull TWEthereumAbiDecodeContractCall(enum TWCoinType coin, TWData* _
 TW_EXPORT_STATIC_METHOD
 TWData* _Nonnull TWEthereumAbiDecodeParams(enum TWCoinType coin, TWData* _Nonnull input);
 
+/// Decode an event log according to a given smart contract ABI.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.EventLogDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.EventLogDecodingOutput` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumAbiDecodeEventLog(enum TWCoinType coin, TWData* _Nonnull input);
+
+/// Decode revert data as `Error(string)`, `Panic(uint256)` or a custom error of a given smart contract ABI.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.RevertDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.RevertDecodingOutput` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumAbiDecodeRevert(enum TWCoinType coin, TWData* _Nonnull input);
+
 /// /// Decodes an Eth ABI value according to a given type.
 ///
 /// \param coin EVM-compatible coin type.
@@ -1,6 +1,5 @@
-use crate::address::Address;
 use crate::transaction_builder;
-use std::str::FromStr;
+use crate::transaction_signers::TransactionSigners;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
@@ -21,17 +20,15 @@ impl Compiler {
     fn preimage_hashes_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let signers = TransactionSigners::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
-        let sender = Address::from_str(&input.sender)
-            .into_tw()
-            .context("Invalid sender address")?;
-        let signed_tx = builder
-            .sender(sender.inner())
+        let raw_txn = builder
+            .sender(signers.sender().address())
             .sequence_number(input.sequence_number as u64)
-            .build()?
-            .pre_image()?;
+            .build()?;
+        let pre_image = signers.pre_image(&raw_txn)?;
         Ok(CompilerProto::PreSigningOutput {
-            data: signed_tx.into(),
+            data: pre_image.into(),
             ..CompilerProto::PreSigningOutput::default()
         })
     }
@@ -51,20 +48,13 @@ impl Compiler {
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let signers = TransactionSigners::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
-        let sender = Address::from_str(&input.sender)?;
-        let signature = signatures
-            .first()
-            .or_tw_err(SigningErrorType::Error_signatures_count)?;
-        let public_key = public_keys
-            .first()
-            .or_tw_err(SigningErrorType::Error_signatures_count)?;
-
-        let signed_tx = builder
-            .sender(sender.inner())
+        let raw_txn = builder
+            .sender(signers.sender().address())
             .sequence_number(input.sequence_number as u64)
-            .build()?
-            .compile(signature.to_vec(), public_key.to_vec())?;
+            .build()?;
+        let signed_tx = signers.compile(raw_txn, signatures, public_keys)?;
         Ok(Proto::SigningOutput {
             raw_txn: signed_tx.raw_txn_bytes().clone().into(),
             encoded: signed_tx.encoded().clone().into(),
@@ -5,3 +5,4 @@
 pub const GAS_UNIT_PRICE: u64 = 100;
 pub const MAX_GAS_AMOUNT: u64 = 100_000_000;
 pub const APTOS_SALT: &[u8] = b"APTOS::RawTransaction";
+pub const APTOS_RAW_TRANSACTION_WITH_DATA_SALT: &[u8] = b"APTOS::RawTransactionWithData";
@@ -13,7 +13,10 @@ pub mod nft;
 pub mod compiler;
 pub mod liquid_staking;
 pub mod modules;
+pub mod move_value;
+pub mod multi_ed25519;
 pub mod signer;
 pub mod transaction;
 pub mod transaction_builder;
 pub mod transaction_payload;
+pub mod transaction_signers;
@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::from_account_error;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::transaction_argument::TransactionArgument;
+use move_core_types::u256::U256;
+use serde::ser::SerializeSeq;
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::{bcs, hex};
+use tw_memory::Data;
+use tw_proto::Aptos::Proto;
+use tw_proto::Aptos::Proto::mod_FunctionArgument::OneOfvalue as ArgumentValue;
+
+/// Typed Move value that is passed as an entry function or a script argument.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MoveValue {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    U256(U256),
+    Bool(bool),
+    Address(AccountAddress),
+    U8Vector(Data),
+    Vector(Vec<MoveValue>),
+    String(String),
+    Option(Option<Box<MoveValue>>),
+    Object(AccountAddress),
+}
+
+impl MoveValue {
+    /// Returns the `bcs` encoded value.
+    pub fn to_bcs(&self) -> SigningResult<Data> {
+        bcs::encode(self)
+            .into_tw()
+            .context("Error serializing a Move value")
+    }
+
+    /// Returns the JSON representation of the value as expected by the Aptos API.
+    /// Please note that 64-bit and larger integers are represented as strings.
+    pub fn to_json(&self) -> Value {
+        match self {
+            MoveValue::U8(v) => json!(v),
+            MoveValue::U16(v) => json!(v),
+            MoveValue::U32(v) => json!(v),
+            MoveValue::U64(v) => json!(v.to_string()),
+            MoveValue::U128(v) => json!(v.to_string()),
+            MoveValue::U256(v) => json!(v.to_string()),
+            MoveValue::Bool(v) => json!(v),
+            MoveValue::Address(addr) | MoveValue::Object(addr) => json!(addr.to_hex_literal()),
+            MoveValue::U8Vector(bytes) => json!(hex::encode(bytes, true)),
+            MoveValue::Vector(elements) => {
+                let elements: Vec<_> = elements.iter().map(MoveValue::to_json).collect();
+                json!(elements)
+            },
+            MoveValue::String(v) => json!(v),
+            MoveValue::Option(None) => json!({ "vec": [] }),
+            MoveValue::Option(Some(value)) => json!({ "vec": [value.to_json()] }),
+        }
+    }
+
+    /// Converts the value into a script argument.
+    /// Only integers, `bool`, `address` and `vector<u8>` values are allowed.
+    pub fn into_transaction_argument(self) -> SigningResult<TransactionArgument> {
+        match self {
+            MoveValue::U8(v) => Ok(TransactionArgument::U8(v)),
+            MoveValue::U16(v) => Ok(TransactionArgument::U16(v)),
+            MoveValue::U32(v) => Ok(TransactionArgument::U32(v)),
+            MoveValue::U64(v) => Ok(TransactionArgument::U64(v)),
+            MoveValue::U128(v) => Ok(TransactionArgument::U128(v)),
+            MoveValue::U256(v) => Ok(TransactionArgument::U256(v)),
+            MoveValue::Bool(v) => Ok(TransactionArgument::Bool(v)),
+            MoveValue::Address(v) => Ok(TransactionArgument::Address(v)),
+            MoveValue::U8Vector(v) => Ok(TransactionArgument::U8Vector(v)),
+            MoveValue::Vector(_)
+            | MoveValue::String(_)
+            | MoveValue::Option(_)
+            | MoveValue::Object(_) => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Script arguments can be integers, bool, address or vector<u8> only"),
+        }
+    }
+}
+
+impl From<TransactionArgument> for MoveValue {
+    fn from(arg: TransactionArgument) -> Self {
+        match arg {
+            TransactionArgument::U8(v) => MoveValue::U8(v),
+            TransactionArgument::U16(v) => MoveValue::U16(v),
+            TransactionArgument::U32(v) => MoveValue::U32(v),
+            TransactionArgument::U64(v) => MoveValue::U64(v),
+            TransactionArgument::U128(v) => MoveValue::U128(v),
+            TransactionArgument::U256(v) => MoveValue::U256(v),
+            TransactionArgument::Bool(v) => MoveValue::Bool(v),
+            TransactionArgument::Address(v) => MoveValue::Address(v),
+            TransactionArgument::U8Vector(v) => MoveValue::U8Vector(v),
+        }
+    }
+}
+
+impl Serialize for MoveValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MoveValue::U8(v) => serializer.serialize_u8(*v),
+            MoveValue::U16(v) => serializer.serialize_u16(*v),
+            MoveValue::U32(v) => serializer.serialize_u32(*v),
+            MoveValue::U64(v) => serializer.serialize_u64(*v),
+            MoveValue::U128(v) => serializer.serialize_u128(*v),
+            MoveValue::U256(v) => v.serialize(serializer),
+            MoveValue::Bool(v) => serializer.serialize_bool(*v),
+            MoveValue::Address(addr) | MoveValue::Object(addr) => addr.serialize(serializer),
+            MoveValue::U8Vector(bytes) => serializer.serialize_bytes(bytes),
+            MoveValue::Vector(elements) => {
+                let mut seq = serializer.serialize_seq(Some(elements.len()))?;
+                for element in elements {
+                    seq.serialize_element(element)?;
+                }
+                seq.end()
+            },
+            MoveValue::String(v) => serializer.serialize_str(v),
+            MoveValue::Option(None) => serializer.serialize_none(),
+            MoveValue::Option(Some(value)) => serializer.serialize_some(value),
+        }
+    }
+}
+
+impl<'a> TryFrom<&Proto::FunctionArgument<'a>> for MoveValue {
+    type Error = SigningError;
+
+    fn try_from(arg: &Proto::FunctionArgument<'a>) -> SigningResult<Self> {
+        match arg.value {
+            ArgumentValue::u8_pb(v) => u8::try_from(v)
+                .map(MoveValue::U8)
+                .tw_err(|_| SigningErrorType::Error_invalid_params)
+                .context("'u8' argument is out of range"),
+            ArgumentValue::u16(v) => u16::try_from(v)
+                .map(MoveValue::U16)
+                .tw_err(|_| SigningErrorType::Error_invalid_params)
+                .context("'u16' argument is out of range"),
+            ArgumentValue::u32_pb(v) => Ok(MoveValue::U32(v)),
+            ArgumentValue::u64_pb(v) => Ok(MoveValue::U64(v)),
+            ArgumentValue::u128(ref v) => u128::from_str(v)
+                .map(MoveValue::U128)
+                .tw_err(|_| SigningErrorType::Error_invalid_params)
+                .context("Invalid 'u128' argument"),
+            ArgumentValue::u256(ref v) => U256::from_str(v)
+                .map(MoveValue::U256)
+                .tw_err(|_| SigningErrorType::Error_invalid_params)
+                .context("Invalid 'u256' argument"),
+            ArgumentValue::bool_value(v) => Ok(MoveValue::Bool(v)),
+            ArgumentValue::address(ref addr) => parse_address(addr).map(MoveValue::Address),
+            ArgumentValue::u8_vector(ref bytes) => Ok(MoveValue::U8Vector(bytes.to_vec())),
+            ArgumentValue::vector(ref vector) => vector
+                .elements
+                .iter()
+                .map(MoveValue::try_from)
+                .collect::<SigningResult<Vec<_>>>()
+                .map(MoveValue::Vector),
+            ArgumentValue::string_value(ref v) => Ok(MoveValue::String(v.to_string())),
+            ArgumentValue::option(ref option) => match option.elements.as_slice() {
+                [] => Ok(MoveValue::Option(None)),
+                [value] => {
+                    let value = MoveValue::try_from(value)?;
+                    Ok(MoveValue::Option(Some(Box::new(value))))
+                },
+                _ => SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("'Option' argument can contain one element at most"),
+            },
+            ArgumentValue::object(ref addr) => parse_address(addr).map(MoveValue::Object),
+            ArgumentValue::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Function argument value is not set"),
+        }
+    }
+}
+
+fn parse_address(addr: &str) -> SigningResult<AccountAddress> {
+    AccountAddress::from_str(addr)
+        .map_err(from_account_error)
+        .into_tw()
+        .context("Invalid address argument")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    #[test]
+    fn test_move_value_bcs() {
+        let value = MoveValue::Vector(vec![MoveValue::U16(1), MoveValue::U16(256)]);
+        assert_eq!(value.to_bcs().unwrap().to_hex(), "0201000001");
+
+        let value = MoveValue::String("Aptos".to_string());
+        assert_eq!(value.to_bcs().unwrap().to_hex(), "054170746f73");
+
+        let value = MoveValue::Option(None);
+        assert_eq!(value.to_bcs().unwrap().to_hex(), "00");
+
+        let value = MoveValue::Option(Some(Box::new(MoveValue::U64(1000))));
+        assert_eq!(value.to_bcs().unwrap().to_hex(), "01e803000000000000");
+
+        let value =
+            MoveValue::U256(U256::from_str("340282366920938463463374607431768211456").unwrap());
+        assert_eq!(
+            value.to_bcs().unwrap().to_hex(),
+            "0000000000000000000000000000000001000000000000000000000000000000"
+        );
+
+        let value = MoveValue::Object(AccountAddress::ONE);
+        assert_eq!(
+            value.to_bcs().unwrap().to_hex(),
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        );
+    }
+
+    #[test]
+    fn test_move_value_json() {
+        let value = MoveValue::Vector(vec![
+            MoveValue::U8(1),
+            MoveValue::U64(2),
+            MoveValue::U8Vector(vec![0xab, 0xcd]),
+        ]);
+        assert_eq!(value.to_json(), json!([1, "2", "0xabcd"]));
+
+        let value = MoveValue::Option(Some(Box::new(MoveValue::Address(AccountAddress::ONE))));
+        assert_eq!(value.to_json(), json!({ "vec": ["0x1"] }));
+    }
+
+    #[test]
+    fn test_move_value_into_script_argument() {
+        let arg = MoveValue::U128(1).into_transaction_argument().unwrap();
+        assert_eq!(arg, TransactionArgument::U128(1));
+
+        MoveValue::String("Aptos".to_string())
+            .into_transaction_argument()
+            .unwrap_err();
+    }
+}
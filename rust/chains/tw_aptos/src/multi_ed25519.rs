@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! K-of-N multisig Ed25519 account authentication.
+//! https://github.com/aptos-labs/aptos-core/blob/main/crates/aptos-crypto/src/multi_ed25519.rs
+
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::encode;
+use tw_hash::H512;
+use tw_keypair::ed25519::sha512::PublicKey;
+use tw_memory::Data;
+
+/// The maximum number of Ed25519 public keys a MultiEd25519 account can consist of.
+pub const MAX_NUM_OF_KEYS: usize = 32;
+/// The bitmap is 4 bytes long, so it can reference up to [`MAX_NUM_OF_KEYS`] keys.
+pub const BITMAP_NUM_OF_BYTES: usize = 4;
+
+#[derive(Clone)]
+pub struct MultiEd25519PublicKey {
+    public_keys: Vec<PublicKey>,
+    threshold: u8,
+}
+
+impl MultiEd25519PublicKey {
+    pub fn new(public_keys: Vec<PublicKey>, threshold: u8) -> SigningResult<Self> {
+        if public_keys.is_empty() || public_keys.len() > MAX_NUM_OF_KEYS {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("MultiEd25519 must have from 1 to {MAX_NUM_OF_KEYS} public keys")
+            });
+        }
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!(
+                    "MultiEd25519 threshold must be in range [1, {}]",
+                    public_keys.len()
+                )
+            });
+        }
+        Ok(MultiEd25519PublicKey {
+            public_keys,
+            threshold,
+        })
+    }
+
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns whether the given public key is one of the MultiEd25519 keys.
+    pub fn contains(&self, public_key: &PublicKey) -> bool {
+        self.public_keys
+            .iter()
+            .any(|pk| pk.to_bytes() == public_key.to_bytes())
+    }
+
+    /// Combines the signatures made by the MultiEd25519 members into a MultiEd25519 signature.
+    /// The number of signatures must reach the threshold.
+    pub fn combine<I>(&self, signatures: I) -> SigningResult<MultiEd25519Signature>
+    where
+        I: IntoIterator<Item = (PublicKey, H512)>,
+    {
+        let mut signed: Vec<(usize, H512)> = Vec::new();
+        for (public_key, signature) in signatures {
+            let index = self
+                .public_keys
+                .iter()
+                .position(|pk| pk.to_bytes() == public_key.to_bytes())
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .context("Signer public key is not a member of the MultiEd25519 account")?;
+            if signed
+                .iter()
+                .any(|(signed_index, _)| *signed_index == index)
+            {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Each MultiEd25519 member can sign only once");
+            }
+            signed.push((index, signature));
+        }
+
+        if signed.len() < self.threshold as usize {
+            return SigningError::err(SigningErrorType::Error_signatures_count).with_context(
+                || {
+                    format!(
+                        "Expected at least {} MultiEd25519 signatures, found {}",
+                        self.threshold,
+                        signed.len()
+                    )
+                },
+            );
+        }
+        // Signatures must be ordered the same way as the public keys.
+        signed.sort_by_key(|(index, _)| *index);
+
+        let mut bitmap = [0_u8; BITMAP_NUM_OF_BYTES];
+        for (index, _) in signed.iter() {
+            // The most significant bit of the first byte refers to the first public key.
+            bitmap[index / 8] |= 0b1000_0000 >> (index % 8);
+        }
+
+        Ok(MultiEd25519Signature {
+            signatures: signed.into_iter().map(|(_, signature)| signature).collect(),
+            bitmap,
+        })
+    }
+
+    /// Returns `public_key_1 || ... || public_key_n || threshold`.
+    pub fn to_bytes(&self) -> Data {
+        let mut bytes: Data = self
+            .public_keys
+            .iter()
+            .flat_map(|public_key| public_key.as_slice().to_vec())
+            .collect();
+        bytes.push(self.threshold);
+        bytes
+    }
+}
+
+impl Serialize for MultiEd25519PublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+#[derive(Clone)]
+pub struct MultiEd25519Signature {
+    signatures: Vec<H512>,
+    bitmap: [u8; BITMAP_NUM_OF_BYTES],
+}
+
+impl MultiEd25519Signature {
+    /// Returns `signature_1 || ... || signature_k || bitmap`.
+    pub fn to_bytes(&self) -> Data {
+        let mut bytes: Data = self
+            .signatures
+            .iter()
+            .flat_map(|signature| signature.as_slice().to_vec())
+            .collect();
+        bytes.extend_from_slice(&self.bitmap);
+        bytes
+    }
+}
+
+impl Serialize for MultiEd25519Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+/// Returns the JSON representation of a MultiEd25519 signature as expected by the Aptos API.
+pub fn multi_ed25519_json(
+    public_key: &MultiEd25519PublicKey,
+    signature: &MultiEd25519Signature,
+) -> Value {
+    let public_keys: Vec<_> = public_key
+        .public_keys
+        .iter()
+        .map(|public_key| encode(public_key.as_slice(), true))
+        .collect();
+    let signatures: Vec<_> = signature
+        .signatures
+        .iter()
+        .map(|signature| encode(signature.as_slice(), true))
+        .collect();
+    json!({
+        "bitmap": encode(signature.bitmap, true),
+        "public_keys": public_keys,
+        "signatures": signatures,
+        "threshold": public_key.threshold,
+        "type": "multi_ed25519_signature"
+    })
+}
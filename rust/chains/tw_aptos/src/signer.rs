@@ -2,12 +2,10 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::address::Address;
 use crate::transaction_builder;
-use std::str::FromStr;
+use crate::transaction_signers::TransactionSigners;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
-use tw_keypair::ed25519;
 use tw_proto::Aptos::Proto;
 
 pub struct Signer;
@@ -22,16 +20,13 @@ impl Signer {
     fn sign_proto_impl(
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
-        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+        let signers = TransactionSigners::from_proto(&input)?;
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
-        let sender = Address::from_str(&input.sender)
-            .into_tw()
-            .context("Invalid sender address")?;
-        let signed_tx = builder
-            .sender(sender.inner())
+        let raw_txn = builder
+            .sender(signers.sender().address())
             .sequence_number(input.sequence_number as u64)
-            .build()?
-            .sign(key_pair)?;
+            .build()?;
+        let signed_tx = signers.sign(raw_txn)?;
         Ok(Proto::SigningOutput {
             raw_txn: signed_tx.raw_txn_bytes().clone().into(),
             encoded: signed_tx.encoded().clone().into(),
@@ -2,7 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::constants::APTOS_SALT;
+use crate::constants::{APTOS_RAW_TRANSACTION_WITH_DATA_SALT, APTOS_SALT};
+use crate::multi_ed25519::{multi_ed25519_json, MultiEd25519PublicKey, MultiEd25519Signature};
 use crate::transaction_payload::TransactionPayload;
 use move_core_types::account_address::AccountAddress;
 use serde::Serialize;
@@ -23,6 +24,25 @@ pub enum TransactionAuthenticator {
         public_key: Vec<u8>,
         signature: Vec<u8>,
     },
+    /// K-of-N MultiEd25519 signature
+    MultiEd25519 {
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    },
+    /// Multi-agent transaction signed by the sender and secondary signers
+    MultiAgent {
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+    },
+    /// Transaction which gas fee is paid by another account
+    FeePayer {
+        sender: AccountAuthenticator,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_address: AccountAddress,
+        fee_payer_signer: AccountAuthenticator,
+    },
 }
 
 impl From<TransactionAuthenticator> for Proto::TransactionAuthenticator<'_> {
@@ -34,28 +54,125 @@ impl From<TransactionAuthenticator> for Proto::TransactionAuthenticator<'_> {
     }
 }
 
+impl From<AccountAuthenticator> for TransactionAuthenticator {
+    fn from(sender: AccountAuthenticator) -> Self {
+        match sender {
+            AccountAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => TransactionAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            },
+            AccountAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            } => TransactionAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            },
+        }
+    }
+}
+
 impl TransactionAuthenticator {
-    pub fn get_signature(&self) -> Vec<u8> {
+    /// Returns the authenticator of the transaction sender.
+    pub fn sender(&self) -> AccountAuthenticator {
         match self {
             TransactionAuthenticator::Ed25519 {
-                public_key: _public_key,
+                public_key,
+                signature,
+            } => AccountAuthenticator::Ed25519 {
+                public_key: public_key.clone(),
+                signature: signature.clone(),
+            },
+            TransactionAuthenticator::MultiEd25519 {
+                public_key,
                 signature,
-            } => signature.clone(),
+            } => AccountAuthenticator::MultiEd25519 {
+                public_key: public_key.clone(),
+                signature: signature.clone(),
+            },
+            TransactionAuthenticator::MultiAgent { sender, .. }
+            | TransactionAuthenticator::FeePayer { sender, .. } => sender.clone(),
         }
     }
 
+    /// Returns the signature of the transaction sender.
+    pub fn get_signature(&self) -> Vec<u8> {
+        self.sender().get_signature()
+    }
+
+    /// Returns the public key of the transaction sender.
     pub fn get_public_key(&self) -> Vec<u8> {
+        self.sender().get_public_key()
+    }
+
+    pub fn to_json(&self) -> Value {
         match self {
-            TransactionAuthenticator::Ed25519 {
-                public_key,
-                signature: _signature,
-            } => public_key.clone(),
+            TransactionAuthenticator::Ed25519 { .. }
+            | TransactionAuthenticator::MultiEd25519 { .. } => self.sender().to_json(),
+            TransactionAuthenticator::MultiAgent {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            } => {
+                json!({"secondary_signer_addresses": addresses_to_json(secondary_signer_addresses),
+                       "secondary_signers": authenticators_to_json(secondary_signers),
+                       "sender": sender.to_json(),
+                       "type": "multi_agent_signature"})
+            },
+            TransactionAuthenticator::FeePayer {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address,
+                fee_payer_signer,
+            } => {
+                json!({"fee_payer_address": fee_payer_address.to_hex_literal(),
+                       "fee_payer_signer": fee_payer_signer.to_json(),
+                       "secondary_signer_addresses": addresses_to_json(secondary_signer_addresses),
+                       "secondary_signers": authenticators_to_json(secondary_signers),
+                       "sender": sender.to_json(),
+                       "type": "fee_payer_signature"})
+            },
+        }
+    }
+}
+
+/// Authenticator of a single account that signs a transaction.
+#[derive(Clone, Serialize)]
+pub enum AccountAuthenticator {
+    /// Single Ed25519 signature
+    Ed25519 {
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    },
+    /// K-of-N MultiEd25519 signature
+    MultiEd25519 {
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    },
+}
+
+impl AccountAuthenticator {
+    pub fn get_signature(&self) -> Vec<u8> {
+        match self {
+            AccountAuthenticator::Ed25519 { signature, .. } => signature.clone(),
+            AccountAuthenticator::MultiEd25519 { signature, .. } => signature.to_bytes(),
+        }
+    }
+
+    pub fn get_public_key(&self) -> Vec<u8> {
+        match self {
+            AccountAuthenticator::Ed25519 { public_key, .. } => public_key.clone(),
+            AccountAuthenticator::MultiEd25519 { public_key, .. } => public_key.to_bytes(),
         }
     }
 
     pub fn to_json(&self) -> Value {
         match self {
-            TransactionAuthenticator::Ed25519 {
+            AccountAuthenticator::Ed25519 {
                 public_key,
                 signature,
             } => {
@@ -63,10 +180,27 @@ impl TransactionAuthenticator {
                        "signature": encode(signature, true),
                        "type": "ed25519_signature"})
             },
+            AccountAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            } => multi_ed25519_json(public_key, signature),
         }
     }
 }
 
+fn addresses_to_json(addresses: &[AccountAddress]) -> Value {
+    let addresses: Vec<_> = addresses
+        .iter()
+        .map(|address| address.to_hex_literal())
+        .collect();
+    json!(addresses)
+}
+
+fn authenticators_to_json(authenticators: &[AccountAuthenticator]) -> Value {
+    let authenticators: Vec<_> = authenticators.iter().map(|auth| auth.to_json()).collect();
+    json!(authenticators)
+}
+
 /// RawTransaction is the portion of a transaction that a client signs.
 #[derive(Clone, Serialize)]
 pub struct RawTransaction {
@@ -146,11 +280,18 @@ impl RawTransaction {
         signature: Vec<u8>,
         public_key: Vec<u8>,
     ) -> SigningResult<SignedTransaction> {
-        let serialized = self.serialize()?;
         let auth = TransactionAuthenticator::Ed25519 {
             public_key,
             signature,
         };
+        self.compile_with_authenticator(auth)
+    }
+
+    pub fn compile_with_authenticator(
+        &self,
+        auth: TransactionAuthenticator,
+    ) -> SigningResult<SignedTransaction> {
+        let serialized = self.serialize()?;
         let mut encoded = serialized.clone();
         encoded.extend_from_slice(bcs::encode(&auth)?.as_slice());
         Ok(SignedTransaction {
@@ -180,6 +321,32 @@ impl RawTransaction {
     }
 }
 
+/// Transaction data signed by all accounts of a multi-agent or a fee payer transaction,
+/// i.e. by the sender, secondary signers and the fee payer.
+#[derive(Clone, Serialize)]
+pub enum RawTransactionWithData {
+    MultiAgent {
+        raw_txn: RawTransaction,
+        secondary_signer_addresses: Vec<AccountAddress>,
+    },
+    MultiAgentWithFeePayer {
+        raw_txn: RawTransaction,
+        secondary_signer_addresses: Vec<AccountAddress>,
+        fee_payer_address: AccountAddress,
+    },
+}
+
+impl RawTransactionWithData {
+    pub fn pre_image(&self) -> SigningResult<Data> {
+        let serialized = bcs::encode(&self)
+            .into_tw()
+            .context("Error serializing RawTransactionWithData")?;
+        let mut preimage = tw_hash::sha3::sha3_256(APTOS_RAW_TRANSACTION_WITH_DATA_SALT);
+        preimage.extend_from_slice(serialized.as_slice());
+        Ok(preimage)
+    }
+}
+
 /// A transaction that has been signed.
 ///
 /// A `SignedTransaction` is a single transaction that can be atomically executed. Clients submit
@@ -15,7 +15,7 @@ use crate::liquid_staking::{
 use crate::nft::NftOperation;
 use crate::transaction::RawTransaction;
 use crate::transaction_payload::{
-    convert_proto_struct_tag_to_type_tag, EntryFunction, TransactionPayload,
+    convert_proto_struct_tag_to_type_tag, EntryFunction, Script, TransactionPayload,
 };
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::TypeTag;
@@ -147,6 +147,12 @@ impl TransactionFactory {
                     convert_proto_struct_tag_to_type_tag(func)?,
                 )
             },
+            OneOftransaction_payload::entry_function(entry_function) => Ok(factory.payload(
+                TransactionPayload::EntryFunction(EntryFunction::try_from(entry_function)?),
+            )),
+            OneOftransaction_payload::script(script) => {
+                Ok(factory.payload(TransactionPayload::Script(Script::try_from(script)?)))
+            },
             OneOftransaction_payload::None => {
                 let is_blind_sign = !input.any_encoded.is_empty();
                 let v = serde_json::from_str::<Value>(&input.any_encoded)
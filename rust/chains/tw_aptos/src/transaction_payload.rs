@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::move_value::MoveValue;
 use crate::serde_helper::vec_bytes;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
@@ -9,10 +10,11 @@ use move_core_types::parser::parse_transaction_argument;
 use move_core_types::transaction_argument::TransactionArgument;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::borrow::Cow;
 use std::default::Default;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
-use tw_encoding::{bcs, EncodingError, EncodingResult};
+use tw_encoding::{bcs, hex, EncodingError, EncodingResult};
 use tw_memory::Data;
 use tw_proto::Aptos;
 
@@ -99,6 +101,44 @@ impl TryFrom<Value> for EntryFunction {
     }
 }
 
+impl<'a> TryFrom<Aptos::Proto::EntryFunctionMessage<'a>> for EntryFunction {
+    type Error = SigningError;
+
+    fn try_from(value: Aptos::Proto::EntryFunctionMessage<'a>) -> SigningResult<Self> {
+        let tag = StructTag::from_str(&value.function)
+            .map_err(|_| EntryFunctionError::InvalidFunctionName)?;
+        let ty_args = parse_type_arguments(&value.type_arguments)?;
+        let arguments = value
+            .arguments
+            .iter()
+            .map(MoveValue::try_from)
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let args = arguments
+            .iter()
+            .map(MoveValue::to_bcs)
+            .collect::<SigningResult<Vec<_>>>()?;
+        let json_args: Value = arguments.iter().map(MoveValue::to_json).collect();
+
+        Ok(EntryFunction {
+            module: tag.module_id(),
+            function: tag.name,
+            ty_args,
+            args,
+            json_args,
+        })
+    }
+}
+
+fn parse_type_arguments(type_arguments: &[Cow<'_, str>]) -> EntryFunctionResult<Vec<TypeTag>> {
+    type_arguments
+        .iter()
+        .map(|ty_arg| {
+            TypeTag::from_str(ty_arg).map_err(|_| EntryFunctionError::InvalidTypeArguments)
+        })
+        .collect()
+}
+
 fn serialize_argument(arg: &TransactionArgument) -> EncodingResult<Data> {
     match arg {
         TransactionArgument::U8(v) => bcs::encode(v),
@@ -155,9 +195,69 @@ impl EntryFunction {
     }
 }
 
+/// A transaction that executes the Move script bytecode.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Script {
+    #[serde(with = "serde_bytes")]
+    code: Vec<u8>,
+    ty_args: Vec<TypeTag>,
+    args: Vec<TransactionArgument>,
+}
+
+impl Script {
+    pub fn new(code: Vec<u8>, ty_args: Vec<TypeTag>, args: Vec<TransactionArgument>) -> Self {
+        Script {
+            code,
+            ty_args,
+            args,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let type_arguments: Vec<_> = self.ty_args.iter().map(|item| item.to_string()).collect();
+        let arguments: Vec<_> = self
+            .args
+            .iter()
+            .cloned()
+            .map(|arg| MoveValue::from(arg).to_json())
+            .collect();
+
+        json!({
+            "type": "script_payload",
+            "code": {
+                "bytecode": hex::encode(&self.code, true)
+            },
+            "arguments": arguments,
+            "type_arguments": type_arguments
+        })
+    }
+}
+
+impl<'a> TryFrom<Aptos::Proto::ScriptMessage<'a>> for Script {
+    type Error = SigningError;
+
+    fn try_from(value: Aptos::Proto::ScriptMessage<'a>) -> SigningResult<Self> {
+        if value.bytecode.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Script bytecode is empty");
+        }
+
+        let ty_args = parse_type_arguments(&value.type_arguments)?;
+        let args = value
+            .arguments
+            .iter()
+            .map(|arg| MoveValue::try_from(arg)?.into_transaction_argument())
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(Script::new(value.bytecode.to_vec(), ty_args, args))
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TransactionPayload {
-    Script,
+    /// A transaction that executes the Move script bytecode.
+    Script(Script),
+    /// Deprecated. The variant is kept to preserve the `bcs` enum indexes.
     ModuleBundle,
     /// A transaction that executes an existing entry function published on-chain.
     EntryFunction(EntryFunction),
@@ -166,7 +266,7 @@ pub enum TransactionPayload {
 impl TransactionPayload {
     pub fn to_json(&self) -> Value {
         match self {
-            TransactionPayload::Script => Value::default(),
+            TransactionPayload::Script(script) => script.to_json(),
             TransactionPayload::ModuleBundle => Value::default(),
             TransactionPayload::EntryFunction(entry) => entry.to_json(),
         }
@@ -273,4 +373,30 @@ mod tests {
         let serialized = bcs::encode(&tp).unwrap();
         assert_eq!(hex::encode(serialized, false), expected_serialized);
     }
+
+    #[test]
+    fn test_script_payload() {
+        let code = hex::decode("a11ceb0b0600000006010002030206050807070f0d081c200c3c04000000010001010001060c0001050d6170746f735f6163636f756e74087472616e73666572000000000000000000000000000000000000000000000000000000000000000101000000010305000a0002").unwrap();
+        let type_tag = vec![TypeTag::from_str("0x1::aptos_coin::AptosCoin").unwrap()];
+        let args = vec![
+            TransactionArgument::U64(1000),
+            TransactionArgument::Address(AccountAddress::ONE),
+            TransactionArgument::U8Vector(vec![1, 2, 3]),
+            TransactionArgument::Bool(true),
+        ];
+
+        let tp = TransactionPayload::Script(Script::new(code, type_tag, args));
+        let serialized = bcs::encode(&tp).unwrap();
+        assert_eq!(hex::encode(serialized, false), "006ba11ceb0b0600000006010002030206050807070f0d081c200c3c04000000010001010001060c0001050d6170746f735f6163636f756e74087472616e73666572000000000000000000000000000000000000000000000000000000000000000101000000010305000a0002010700000000000000000000000000000000000000000000000000000000000000010a6170746f735f636f696e094170746f73436f696e000401e80300000000000003000000000000000000000000000000000000000000000000000000000000000104030102030501");
+
+        let payload_value: Value = json!({
+            "type": "script_payload",
+            "code": {
+                "bytecode": "0xa11ceb0b0600000006010002030206050807070f0d081c200c3c04000000010001010001060c0001050d6170746f735f6163636f756e74087472616e73666572000000000000000000000000000000000000000000000000000000000000000101000000010305000a0002"
+            },
+            "arguments": ["1000", "0x1", "0x010203", true],
+            "type_arguments": ["0x1::aptos_coin::AptosCoin"]
+        });
+        assert_eq!(tp.to_json(), payload_value);
+    }
 }
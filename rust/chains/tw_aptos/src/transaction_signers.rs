@@ -0,0 +1,316 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::multi_ed25519::MultiEd25519PublicKey;
+use crate::transaction::{
+    AccountAuthenticator, RawTransaction, RawTransactionWithData, SignedTransaction,
+    TransactionAuthenticator,
+};
+use move_core_types::account_address::AccountAddress;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H512;
+use tw_keypair::ed25519::sha512::{KeyPair, PublicKey};
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_memory::Data;
+use tw_proto::Aptos::Proto;
+
+/// Type of the account key.
+pub enum AccountKey {
+    Ed25519,
+    MultiEd25519(MultiEd25519PublicKey),
+}
+
+/// An account that signs the transaction.
+pub struct AccountSigner {
+    address: AccountAddress,
+    key: AccountKey,
+    /// Private keys to sign the transaction with. Not used when compiling the transaction.
+    private_keys: Vec<Data>,
+}
+
+impl AccountSigner {
+    pub fn address(&self) -> AccountAddress {
+        self.address
+    }
+
+    fn from_proto(signer: &Proto::AccountSigner) -> SigningResult<Self> {
+        let address = Address::from_str(&signer.address)
+            .into_tw()
+            .context("Invalid signer address")?;
+        let key = account_key_from_proto(&signer.multi_ed25519_public_key)?;
+        let private_keys = signer.private_keys.iter().map(|key| key.to_vec()).collect();
+        Ok(AccountSigner {
+            address: address.inner(),
+            key,
+            private_keys,
+        })
+    }
+
+    fn sign(&self, msg: &[u8]) -> SigningResult<AccountAuthenticator> {
+        match self.key {
+            AccountKey::Ed25519 => {
+                let [private_key] = self.private_keys.as_slice() else {
+                    return SigningError::err(SigningErrorType::Error_invalid_private_key)
+                        .context("Expected exactly one private key of an Ed25519 account");
+                };
+                let key_pair = KeyPair::try_from(private_key.as_slice())?;
+                let signature = key_pair.private().sign(msg.to_vec())?;
+                Ok(AccountAuthenticator::Ed25519 {
+                    public_key: key_pair.public().as_slice().to_vec(),
+                    signature: signature.to_bytes().into_vec(),
+                })
+            },
+            AccountKey::MultiEd25519(ref public_key) => {
+                let mut signatures = Vec::with_capacity(self.private_keys.len());
+                for private_key in self.private_keys.iter() {
+                    let key_pair = KeyPair::try_from(private_key.as_slice())?;
+                    let signature = key_pair.private().sign(msg.to_vec())?;
+                    signatures.push((key_pair.public().clone(), signature.to_bytes()));
+                }
+                let signature = public_key.combine(signatures)?;
+                Ok(AccountAuthenticator::MultiEd25519 {
+                    public_key: public_key.clone(),
+                    signature,
+                })
+            },
+        }
+    }
+
+    /// Takes the signatures of the account from the beginning of the `signatures` iterator:
+    /// one signature of an Ed25519 account, or exactly `threshold` signatures of a MultiEd25519 account.
+    fn compile<I>(&self, signatures: &mut I) -> SigningResult<AccountAuthenticator>
+    where
+        I: Iterator<Item = (PublicKeyBytes, SignatureBytes)>,
+    {
+        match self.key {
+            AccountKey::Ed25519 => {
+                let (public_key, signature) = signatures
+                    .next()
+                    .or_tw_err(SigningErrorType::Error_signatures_count)
+                    .context("Expected a signature of an Ed25519 account")?;
+                Ok(AccountAuthenticator::Ed25519 {
+                    public_key,
+                    signature,
+                })
+            },
+            AccountKey::MultiEd25519(ref public_key) => {
+                let threshold = public_key.threshold() as usize;
+
+                let mut member_signatures = Vec::with_capacity(threshold);
+                for (member, signature) in signatures.take(threshold) {
+                    let member = PublicKey::try_from(member.as_slice())?;
+                    let signature = H512::try_from(signature.as_slice())
+                        .tw_err(|_| SigningErrorType::Error_invalid_params)
+                        .context("Invalid MultiEd25519 member signature")?;
+                    member_signatures.push((member, signature));
+                }
+                if member_signatures.len() != threshold {
+                    return SigningError::err(SigningErrorType::Error_signatures_count)
+                        .with_context(|| {
+                            format!("Expected {threshold} signatures of a MultiEd25519 account")
+                        });
+                }
+
+                let signature = public_key.combine(member_signatures)?;
+                Ok(AccountAuthenticator::MultiEd25519 {
+                    public_key: public_key.clone(),
+                    signature,
+                })
+            },
+        }
+    }
+}
+
+/// The transaction sender, secondary signers of a multi-agent transaction
+/// and the fee payer of a sponsored transaction.
+pub struct TransactionSigners {
+    sender: AccountSigner,
+    secondary_signers: Vec<AccountSigner>,
+    fee_payer: Option<AccountSigner>,
+}
+
+impl TransactionSigners {
+    pub fn from_proto(input: &Proto::SigningInput) -> SigningResult<Self> {
+        let sender_address = Address::from_str(&input.sender)
+            .into_tw()
+            .context("Invalid sender address")?;
+        let sender_key = account_key_from_proto(&input.multi_ed25519_public_key)?;
+        let sender_private_keys = match sender_key {
+            AccountKey::Ed25519 => vec![input.private_key.to_vec()],
+            AccountKey::MultiEd25519(_) => Some(input.private_key.to_vec())
+                .filter(|key| !key.is_empty())
+                .into_iter()
+                .chain(input.additional_private_keys.iter().map(|key| key.to_vec()))
+                .collect(),
+        };
+        let sender = AccountSigner {
+            address: sender_address.inner(),
+            key: sender_key,
+            private_keys: sender_private_keys,
+        };
+
+        let secondary_signers = input
+            .secondary_signers
+            .iter()
+            .map(AccountSigner::from_proto)
+            .collect::<SigningResult<Vec<_>>>()
+            .context("Invalid secondary signer")?;
+        let fee_payer = input
+            .fee_payer
+            .as_ref()
+            .map(AccountSigner::from_proto)
+            .transpose()
+            .context("Invalid fee payer")?;
+
+        Ok(TransactionSigners {
+            sender,
+            secondary_signers,
+            fee_payer,
+        })
+    }
+
+    pub fn sender(&self) -> &AccountSigner {
+        &self.sender
+    }
+
+    /// Returns the data to be signed by every signer of the transaction.
+    pub fn pre_image(&self, raw_txn: &RawTransaction) -> SigningResult<Data> {
+        let secondary_signer_addresses = self.secondary_signer_addresses();
+        match self.fee_payer {
+            Some(ref fee_payer) => RawTransactionWithData::MultiAgentWithFeePayer {
+                raw_txn: raw_txn.clone(),
+                secondary_signer_addresses,
+                fee_payer_address: fee_payer.address,
+            }
+            .pre_image(),
+            None if !secondary_signer_addresses.is_empty() => RawTransactionWithData::MultiAgent {
+                raw_txn: raw_txn.clone(),
+                secondary_signer_addresses,
+            }
+            .pre_image(),
+            None => raw_txn.pre_image(),
+        }
+    }
+
+    pub fn sign(&self, raw_txn: RawTransaction) -> SigningResult<SignedTransaction> {
+        let msg = self.pre_image(&raw_txn)?;
+
+        let sender = self
+            .sender
+            .sign(&msg)
+            .context("Error signing as the sender")?;
+        let secondary_signers = self
+            .secondary_signers
+            .iter()
+            .map(|signer| signer.sign(&msg))
+            .collect::<SigningResult<Vec<_>>>()
+            .context("Error signing as a secondary signer")?;
+        let fee_payer = self
+            .fee_payer
+            .as_ref()
+            .map(|fee_payer| fee_payer.sign(&msg))
+            .transpose()
+            .context("Error signing as the fee payer")?;
+
+        raw_txn.compile_with_authenticator(self.authenticator(sender, secondary_signers, fee_payer))
+    }
+
+    /// Compiles the transaction with the signatures collected separately.
+    ///
+    /// The signatures must be ordered as the signers: the sender, secondary signers, then the fee payer.
+    /// A MultiEd25519 account takes exactly `threshold` subsequent signatures made by its members.
+    pub fn compile(
+        &self,
+        raw_txn: RawTransaction,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<SignedTransaction> {
+        if signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("The number of signatures and public keys must be equal");
+        }
+        let mut signatures = public_keys.into_iter().zip(signatures);
+
+        let sender = self
+            .sender
+            .compile(&mut signatures)
+            .context("Invalid sender signature")?;
+        let mut secondary_signers = Vec::with_capacity(self.secondary_signers.len());
+        for signer in self.secondary_signers.iter() {
+            let auth = signer
+                .compile(&mut signatures)
+                .context("Invalid secondary signer signature")?;
+            secondary_signers.push(auth);
+        }
+        let fee_payer = match self.fee_payer {
+            Some(ref fee_payer) => Some(
+                fee_payer
+                    .compile(&mut signatures)
+                    .context("Invalid fee payer signature")?,
+            ),
+            None => None,
+        };
+
+        if signatures.next().is_some() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Too many signatures provided");
+        }
+
+        raw_txn.compile_with_authenticator(self.authenticator(sender, secondary_signers, fee_payer))
+    }
+
+    fn authenticator(
+        &self,
+        sender: AccountAuthenticator,
+        secondary_signers: Vec<AccountAuthenticator>,
+        fee_payer_signer: Option<AccountAuthenticator>,
+    ) -> TransactionAuthenticator {
+        let secondary_signer_addresses = self.secondary_signer_addresses();
+        match (&self.fee_payer, fee_payer_signer) {
+            (Some(fee_payer), Some(fee_payer_signer)) => TransactionAuthenticator::FeePayer {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+                fee_payer_address: fee_payer.address,
+                fee_payer_signer,
+            },
+            _ if !secondary_signers.is_empty() => TransactionAuthenticator::MultiAgent {
+                sender,
+                secondary_signer_addresses,
+                secondary_signers,
+            },
+            _ => TransactionAuthenticator::from(sender),
+        }
+    }
+
+    fn secondary_signer_addresses(&self) -> Vec<AccountAddress> {
+        self.secondary_signers
+            .iter()
+            .map(|signer| signer.address)
+            .collect()
+    }
+}
+
+fn account_key_from_proto(
+    multi_ed25519: &Option<Proto::MultiEd25519PublicKey>,
+) -> SigningResult<AccountKey> {
+    let Some(multi_ed25519) = multi_ed25519 else {
+        return Ok(AccountKey::Ed25519);
+    };
+
+    let public_keys = multi_ed25519
+        .public_keys
+        .iter()
+        .map(|public_key| PublicKey::try_from(public_key.as_ref()))
+        .collect::<Result<Vec<_>, _>>()
+        .into_tw()
+        .context("Invalid MultiEd25519 public key")?;
+    let threshold = u8::try_from(multi_ed25519.threshold)
+        .tw_err(|_| SigningErrorType::Error_invalid_params)
+        .context("MultiEd25519 threshold is too large")?;
+    MultiEd25519PublicKey::new(public_keys, threshold).map(AccountKey::MultiEd25519)
+}
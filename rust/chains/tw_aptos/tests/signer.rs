@@ -148,6 +148,7 @@ fn setup_proto_transaction<'a>(
         private_key: private.into(),
         any_encoded: any_encoded.into(),
         transaction_payload: payload,
+        ..SigningInput::default()
     };
 
     input
@@ -2,7 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use tw_coin_entry::error::prelude::SigningResult;
+use crate::modules::tx_builder::script_parser::StandardScriptParser;
+use crate::modules::tx_builder::BitcoinChainInfo;
+use tw_coin_entry::error::prelude::{AddressResult, SigningResult};
 use tw_utxo::address::standard_bitcoin::StandardBitcoinAddress;
 use tw_utxo::context::{AddressPrefixes, UtxoContext};
 use tw_utxo::script::Script;
@@ -25,4 +27,20 @@ impl UtxoContext for StandardBitcoinContext {
             StandardBitcoinAddress::Taproot(taproot) => taproot.to_script_pubkey(),
         }
     }
+
+    fn addr_from_script_pubkey(
+        script_pubkey: &Script,
+        prefixes: AddressPrefixes,
+        hrp: Option<String>,
+    ) -> AddressResult<Option<Self::Address>> {
+        let Ok(script) = StandardScriptParser.parse(script_pubkey) else {
+            return Ok(None);
+        };
+        let chain_info = BitcoinChainInfo {
+            p2pkh_prefix: prefixes.p2pkh_prefix,
+            p2sh_prefix: prefixes.p2sh_prefix,
+            hrp,
+        };
+        script.try_to_address(&chain_info)
+    }
 }
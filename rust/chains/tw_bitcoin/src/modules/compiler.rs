@@ -2,7 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::modules::planner::fee_bump_planner::FeeBumpPlanner;
 use crate::modules::protobuf_builder::ProtobufBuilder;
+use crate::modules::psbt::{create_psbt, psbt_to_proto, update_psbt_partially_signed};
 use crate::modules::psbt_request::PsbtRequest;
 use crate::modules::signing_request::SigningRequestBuilder;
 use std::borrow::Cow;
@@ -23,7 +25,9 @@ use tw_utxo::modules::tx_compiler::TxCompiler;
 use tw_utxo::modules::tx_planner::TxPlanner;
 use tw_utxo::modules::utxo_selector::SelectResult;
 use tw_utxo::signing_mode::SigningMethod;
+use tw_utxo::transaction::standard_transaction::Transaction;
 use tw_utxo::transaction::transaction_interface::TransactionInterface;
+use tw_utxo::transaction::unsigned_transaction::UnsignedTransaction;
 
 pub struct BitcoinCompiler<Context: UtxoContext> {
     _phantom: PhantomData<Context>,
@@ -46,13 +50,23 @@ impl<Context: UtxoContext> BitcoinCompiler<Context> {
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::PreSigningOutput<'static>> {
         let unsigned_tx = match input.transaction {
-            TransactionType::builder(ref tx_builder) => {
+            TransactionType::builder(ref tx_builder)
+            | TransactionType::psbt_create(ref tx_builder) => {
                 let request = SigningRequestBuilder::<Context>::build(coin, &input, tx_builder)?;
                 TxPlanner::plan(request)?.unsigned_tx
             },
-            TransactionType::psbt(ref psbt) => {
+            TransactionType::psbt(ref psbt) | TransactionType::psbt_sign(ref psbt) => {
                 PsbtRequest::<Context>::build(&input, psbt)?.unsigned_tx
             },
+            TransactionType::fee_bump(ref fee_bump) => {
+                let tx_builder = FeeBumpPlanner::<Context>::tx_builder(coin, &input, fee_bump)?;
+                let request = SigningRequestBuilder::<Context>::build(coin, &input, &tx_builder)?;
+                TxPlanner::plan(request)?.unsigned_tx
+            },
+            TransactionType::psbt_combine(_) | TransactionType::psbt_finalize(_) => {
+                return SigningError::err(SigningErrorType::Error_not_supported)
+                    .context("Combining and finalizing PSBTs don't require signing")
+            },
             TransactionType::None => {
                 return SigningError::err(SigningErrorType::Error_invalid_params)
                     .context("Either `TransactionBuilder` or `Psbt` should be set")
@@ -99,6 +113,20 @@ impl<Context: UtxoContext> BitcoinCompiler<Context> {
                 Self::compile_with_tx_builder(coin, &input, tx, signatures)
             },
             TransactionType::psbt(ref psbt) => Self::compile_psbt(coin, &input, psbt, signatures),
+            TransactionType::fee_bump(ref fee_bump) => {
+                let tx_builder = FeeBumpPlanner::<Context>::tx_builder(coin, &input, fee_bump)?;
+                Self::compile_with_tx_builder(coin, &input, &tx_builder, signatures)
+            },
+            TransactionType::psbt_create(ref tx) => {
+                Self::compile_psbt_create(coin, &input, tx, signatures)
+            },
+            TransactionType::psbt_sign(ref psbt) => {
+                Self::compile_psbt_sign(coin, &input, psbt, signatures)
+            },
+            TransactionType::psbt_combine(_) | TransactionType::psbt_finalize(_) => {
+                SigningError::err(SigningErrorType::Error_not_supported)
+                    .context("Combining and finalizing PSBTs don't require signing")
+            },
             TransactionType::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("No transaction type specified"),
         }
@@ -154,6 +182,78 @@ impl<Context: UtxoContext> BitcoinCompiler<Context> {
             ..Proto::SigningOutput::default()
         })
     }
+
+    /// Creates a PSBT from the transaction builder, and adds the given signatures as partial signatures.
+    fn compile_psbt_create(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        tx_builder_input: &Proto::TransactionBuilder,
+        signatures: Vec<SignatureBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let request = SigningRequestBuilder::<Context>::build(coin, input, tx_builder_input)?;
+        let SelectResult { unsigned_tx, plan } = TxPlanner::plan(request)?;
+
+        Self::verify_partial_signatures(&unsigned_tx, &signatures)?;
+        let mut psbt = create_psbt(&unsigned_tx)?;
+        update_psbt_partially_signed(&mut psbt, &unsigned_tx, &signatures)?;
+
+        Ok(Proto::SigningOutput {
+            fee: plan.fee_estimate,
+            psbt: Some(psbt_to_proto(&psbt)?),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    /// Adds the given signatures to the PSBT as partial signatures without finalizing it.
+    fn compile_psbt_sign(
+        _coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        psbt_input: &Proto::Psbt,
+        signatures: Vec<SignatureBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let PsbtRequest {
+            mut psbt,
+            unsigned_tx,
+            ..
+        } = PsbtRequest::<Context>::build(input, psbt_input)?;
+        let fee = unsigned_tx.fee()?;
+
+        Self::verify_partial_signatures(&unsigned_tx, &signatures)?;
+        update_psbt_partially_signed(&mut psbt, &unsigned_tx, &signatures)?;
+
+        Ok(Proto::SigningOutput {
+            fee,
+            psbt: Some(psbt_to_proto(&psbt)?),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    /// Verifies the given signatures, where an empty signature means the sighash is left to other signers.
+    fn verify_partial_signatures(
+        unsigned_tx: &UnsignedTransaction<Transaction>,
+        signatures: &[SignatureBytes],
+    ) -> SigningResult<()> {
+        let TxPreimage { sighashes } = SighashComputer::preimage_tx(unsigned_tx)?;
+        if sighashes.len() != signatures.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count).context(format!(
+                "Expected '{}' signatures, but given '{}'",
+                sighashes.len(),
+                signatures.len()
+            ));
+        }
+
+        let mut sighashes = sighashes.iter().zip(signatures);
+        for utxo in unsigned_tx.input_args() {
+            for (sighash, signature) in sighashes.by_ref().take(utxo.pending_signers().len()) {
+                if signature.is_empty() {
+                    continue;
+                }
+                SighashVerifier::<Transaction>::verify_signature(sighash, utxo, signature)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 pub fn signing_method(s: SigningMethod) -> ProtoSigningMethod {
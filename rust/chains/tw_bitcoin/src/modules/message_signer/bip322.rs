@@ -0,0 +1,456 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! [BIP322](https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki) generic signed messages.
+//! The signer proves the ownership of an address by signing a virtual `to_sign` transaction,
+//! that spends a virtual `to_spend` transaction output locked by the address scriptPubkey.
+
+use crate::context::StandardBitcoinContext;
+use crate::modules::message_signer::MessageAddress;
+use crate::modules::transaction_decoder::BitcoinTransactionDecoder;
+use bitcoin::blockdata::script::Instruction;
+use bitcoin::hashes::Hash;
+use bitcoin::sighash::{Prevouts, SighashCache, TapSighashType};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::hasher::sha256_d;
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_keypair::{ecdsa, schnorr};
+use tw_memory::Data;
+use tw_utxo::encode::encode;
+use tw_utxo::modules::keys_manager::KeysManager;
+use tw_utxo::modules::sighash_computer::{SighashComputer, UtxoSighash};
+use tw_utxo::modules::sighash_verifier::SighashVerifier;
+use tw_utxo::modules::tx_signer::TxSigner;
+use tw_utxo::script::standard_script::opcodes::{OP_PUSHBYTES_0, OP_RETURN};
+use tw_utxo::script::{Script, Witness};
+use tw_utxo::sighash::SighashType;
+use tw_utxo::transaction::standard_transaction::builder::{
+    OutputBuilder, TransactionBuilder, UtxoBuilder,
+};
+use tw_utxo::transaction::standard_transaction::{
+    Transaction, TransactionInput, TransactionOutput,
+};
+use tw_utxo::transaction::transaction_parts::OutPoint;
+use tw_utxo::transaction::unsigned_transaction::UnsignedTransaction;
+use tw_utxo::transaction::UtxoToSign;
+
+const BIP322_TAG: &[u8] = b"BIP0322-signed-message";
+const VIRTUAL_TX_VERSION: u32 = 0;
+const VIRTUAL_TX_SEQUENCE: u32 = 0;
+/// Only `SIGHASH_ALL` (or `SIGHASH_DEFAULT` for Taproot) signatures are accepted.
+const SIGHASH_ALL: u32 = 1;
+const SCHNORR_SIGNATURE_LEN: usize = 64;
+
+pub struct Bip322Message;
+
+impl Bip322Message {
+    /// Returns the encoded `to_sign` transaction and its sighash to be signed by the given public key.
+    pub fn preimage(
+        message: &[u8],
+        address: &MessageAddress,
+        public_key: &[u8],
+    ) -> SigningResult<(Data, H256)> {
+        let unsigned_tx = Self::to_sign_with_public_key(message, address, public_key)?;
+        let sighash = Self::sighash(&unsigned_tx)?;
+        Ok((unsigned_tx.transaction().encode_out(), sighash.sighash))
+    }
+
+    /// Signs the message and returns the witness stack of the `to_sign` transaction.
+    pub fn sign_simple(
+        message: &[u8],
+        address: &MessageAddress,
+        private_key: &[u8],
+        dangerous_use_fixed_schnorr_rng: bool,
+    ) -> SigningResult<Data> {
+        if let MessageAddress::P2PKH(_) = address {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("BIP322 simple signature is not supported for P2PKH addresses. Consider using either full or legacy signature");
+        }
+
+        let signed_tx = Self::sign_to_sign(
+            message,
+            address,
+            private_key,
+            dangerous_use_fixed_schnorr_rng,
+        )?;
+        let witness = signed_tx
+            .inputs
+            .first()
+            .map(|input| encode(&input.witness))
+            .or_tw_err(SigningErrorType::Error_internal)
+            .context("'to_sign' transaction has no inputs")?;
+        Ok(witness)
+    }
+
+    /// Signs the message and returns the whole `to_sign` transaction.
+    pub fn sign_full(
+        message: &[u8],
+        address: &MessageAddress,
+        private_key: &[u8],
+        dangerous_use_fixed_schnorr_rng: bool,
+    ) -> SigningResult<Data> {
+        Self::sign_to_sign(
+            message,
+            address,
+            private_key,
+            dangerous_use_fixed_schnorr_rng,
+        )
+        .map(|signed_tx| signed_tx.encode_out())
+    }
+
+    /// Verifies either a simple or full signature.
+    pub fn verify(message: &[u8], address: &MessageAddress, signature: &[u8]) -> SigningResult<()> {
+        let (script_sig, witness) = match Self::parse_full_signature(message, address, signature) {
+            Some(to_sign) => to_sign?,
+            None => (Script::default(), Self::parse_simple_signature(signature)?),
+        };
+
+        match address {
+            MessageAddress::P2PKH(_) => {
+                Self::check_empty(witness.as_items(), "witness")?;
+                let items = Self::parse_script_sig(&script_sig)?;
+                let [signature, public_key] = items.as_slice() else {
+                    return SigningError::err(SigningErrorType::Error_input_parse)
+                        .context("P2PKH scriptSig must contain a signature and a public key");
+                };
+                let public_key = Self::ecdsa_public_key(public_key)?;
+                let utxo = Self::to_spend_utxo(message, address)?.p2pkh(&public_key)?;
+                Self::verify_ecdsa(address, utxo, &public_key, signature)
+            },
+            MessageAddress::P2WPKH(_) => {
+                Self::check_empty(script_sig.as_slice(), "scriptSig")?;
+                let [signature, public_key] = witness.as_items() else {
+                    return SigningError::err(SigningErrorType::Error_input_parse)
+                        .context("P2WPKH witness must contain a signature and a public key");
+                };
+                let public_key = Self::ecdsa_public_key(public_key.as_slice())?;
+                let utxo = Self::to_spend_utxo(message, address)?.p2wpkh(&public_key)?;
+                Self::verify_ecdsa(address, utxo, &public_key, signature.as_slice())
+            },
+            MessageAddress::P2TR(tweaked_pubkey) => {
+                Self::check_empty(script_sig.as_slice(), "scriptSig")?;
+                let [signature] = witness.as_items() else {
+                    return SigningError::err(SigningErrorType::Error_input_parse)
+                        .context("P2TR key-path witness must contain a signature only");
+                };
+
+                let (input, utxo_args) = Self::to_spend_utxo(message, address)?
+                    .p2tr_key_path_with_tweaked_pubkey(tweaked_pubkey)?;
+                let unsigned_tx = Self::to_sign(input, utxo_args)?;
+                let mut sighash = Self::sighash(&unsigned_tx)?;
+
+                // Either a 64-byte SIGHASH_DEFAULT or a 65-byte SIGHASH_ALL signature.
+                let signature = match signature.as_slice() {
+                    signature if signature.len() == SCHNORR_SIGNATURE_LEN => signature,
+                    [signature @ .., sighash_ty]
+                        if signature.len() == SCHNORR_SIGNATURE_LEN
+                            && *sighash_ty as u32 == SIGHASH_ALL =>
+                    {
+                        sighash.sighash = Self::taproot_sighash_all(&unsigned_tx, address)?;
+                        signature
+                    },
+                    _ => {
+                        return SigningError::err(SigningErrorType::Error_not_supported).context(
+                            "Only SIGHASH_DEFAULT and SIGHASH_ALL Taproot signatures are supported",
+                        )
+                    },
+                };
+
+                SighashVerifier::<Transaction>::verify_schnorr_signature(
+                    &sighash,
+                    tweaked_pubkey.bytes().as_slice(),
+                    signature,
+                )
+            },
+        }
+    }
+
+    /// Returns a BIP340 tagged hash of the message.
+    pub fn message_hash(message: &[u8]) -> H256 {
+        let tag_hash = sha256(BIP322_TAG);
+
+        let mut data = Vec::with_capacity(tag_hash.len() * 2 + message.len());
+        data.extend_from_slice(&tag_hash);
+        data.extend_from_slice(&tag_hash);
+        data.extend_from_slice(message);
+        H256::try_from(sha256(&data).as_slice()).expect("sha256 returns 32 bytes")
+    }
+
+    /// Builds the virtual `to_spend` transaction.
+    pub fn to_spend(message: &[u8], message_challenge: Script) -> Transaction {
+        let mut script_sig = Script::new();
+        script_sig.push(OP_PUSHBYTES_0);
+        script_sig.push_slice(Self::message_hash(message).as_slice());
+
+        Transaction {
+            version: VIRTUAL_TX_VERSION as i32,
+            inputs: vec![TransactionInput {
+                previous_output: OutPoint {
+                    hash: H256::default(),
+                    index: u32::MAX,
+                },
+                sequence: VIRTUAL_TX_SEQUENCE,
+                script_sig,
+                witness: Witness::default(),
+            }],
+            outputs: vec![TransactionOutput {
+                value: 0,
+                script_pubkey: message_challenge,
+            }],
+            locktime: 0,
+        }
+    }
+
+    fn sign_to_sign(
+        message: &[u8],
+        address: &MessageAddress,
+        private_key: &[u8],
+        dangerous_use_fixed_schnorr_rng: bool,
+    ) -> SigningResult<Transaction> {
+        let (unsigned_tx, keys_manager) = Self::to_sign_with_private_key(
+            message,
+            address,
+            private_key,
+            dangerous_use_fixed_schnorr_rng,
+        )?;
+        TxSigner::<Transaction>::sign_tx(unsigned_tx, &keys_manager)
+    }
+
+    fn to_sign_with_private_key(
+        message: &[u8],
+        address: &MessageAddress,
+        private_key: &[u8],
+        dangerous_use_fixed_schnorr_rng: bool,
+    ) -> SigningResult<(UnsignedTransaction<Transaction>, KeysManager)> {
+        let mut keys_manager = KeysManager::default();
+
+        let public_key = match address {
+            MessageAddress::P2PKH(_) | MessageAddress::P2WPKH(_) => {
+                let private_key = ecdsa::secp256k1::PrivateKey::try_from(private_key)
+                    .into_tw()
+                    .context("Invalid ecdsa secp256k1 private key")?;
+                let public_key = private_key.public().compressed().to_vec();
+                keys_manager.add_ecdsa_private(private_key);
+                public_key
+            },
+            MessageAddress::P2TR(_) => {
+                let mut private_key = schnorr::PrivateKey::try_from(private_key)
+                    .into_tw()
+                    .context("Invalid schnorr private key")?;
+                if dangerous_use_fixed_schnorr_rng {
+                    private_key = private_key.no_aux_rand();
+                }
+                let public_key = private_key.public().compressed().to_vec();
+                keys_manager.add_schnorr_private(private_key);
+                public_key
+            },
+        };
+
+        let unsigned_tx = Self::to_sign_with_public_key(message, address, &public_key)?;
+        Ok((unsigned_tx, keys_manager))
+    }
+
+    fn to_sign_with_public_key(
+        message: &[u8],
+        address: &MessageAddress,
+        public_key: &[u8],
+    ) -> SigningResult<UnsignedTransaction<Transaction>> {
+        let utxo = Self::to_spend_utxo(message, address)?;
+
+        let (input, utxo_args) = match address {
+            MessageAddress::P2PKH(_) => utxo.p2pkh(&Self::ecdsa_public_key(public_key)?)?,
+            MessageAddress::P2WPKH(_) => utxo.p2wpkh(&Self::ecdsa_public_key(public_key)?)?,
+            MessageAddress::P2TR(_) => {
+                let public_key = schnorr::PublicKey::try_from(public_key)
+                    .into_tw()
+                    .context("Invalid schnorr public key")?;
+                utxo.p2tr_key_path(&public_key)?
+            },
+        };
+
+        Self::check_address(address, &utxo_args)?;
+        Self::to_sign(input, utxo_args)
+    }
+
+    /// Returns a builder of the `to_sign` input that spends the `to_spend` output.
+    fn to_spend_utxo(message: &[u8], address: &MessageAddress) -> SigningResult<UtxoBuilder> {
+        let to_spend = Self::to_spend(message, address.script_pubkey());
+
+        Ok(UtxoBuilder::new()
+            .prev_txid(Self::to_spend_txid(&to_spend))
+            .prev_index(0)
+            .sequence(VIRTUAL_TX_SEQUENCE)
+            .amount(0)
+            .sighash_type(SighashType::from_u32(SIGHASH_ALL)?))
+    }
+
+    /// Builds the virtual `to_sign` transaction.
+    fn to_sign(
+        input: TransactionInput,
+        utxo_args: UtxoToSign,
+    ) -> SigningResult<UnsignedTransaction<Transaction>> {
+        let mut builder = TransactionBuilder::new();
+        builder
+            .version(VIRTUAL_TX_VERSION)
+            .push_input(input, utxo_args)
+            .push_output(Self::to_sign_output());
+        builder.build()
+    }
+
+    fn to_sign_output() -> TransactionOutput {
+        OutputBuilder::new(0).custom_script_pubkey(Script::from(vec![OP_RETURN]))
+    }
+
+    /// Returns the transaction hash as it's referenced by the `to_sign` input.
+    fn to_spend_txid(to_spend: &Transaction) -> H256 {
+        let hash = sha256_d(&to_spend.without_witness().encode_out());
+        H256::try_from(hash.as_slice()).expect("sha256d returns 32 bytes")
+    }
+
+    fn sighash(unsigned_tx: &UnsignedTransaction<Transaction>) -> SigningResult<UtxoSighash> {
+        SighashComputer::<Transaction>::preimage_tx(unsigned_tx)?
+            .sighashes
+            .into_iter()
+            .next()
+            .or_tw_err(SigningErrorType::Error_internal)
+            .context("Expected exactly one 'to_sign' sighash")
+    }
+
+    /// Returns the `to_sign` sighash that commits to the explicit SIGHASH_ALL type,
+    /// as [`SighashComputer`] computes SIGHASH_DEFAULT sighashes for Taproot instead.
+    fn taproot_sighash_all(
+        unsigned_tx: &UnsignedTransaction<Transaction>,
+        address: &MessageAddress,
+    ) -> SigningResult<H256> {
+        let to_sign: bitcoin::Transaction =
+            bitcoin::consensus::deserialize(&unsigned_tx.transaction().encode_out())
+                .tw_err(|_| SigningErrorType::Error_internal)
+                .context("Error converting the 'to_sign' transaction")?;
+        let prevouts = [bitcoin::TxOut {
+            value: 0,
+            script_pubkey: bitcoin::ScriptBuf::from_bytes(address.script_pubkey().to_vec()),
+        }];
+
+        let sighash = SighashCache::new(&to_sign)
+            .taproot_key_spend_signature_hash(0, &Prevouts::All(&prevouts), TapSighashType::All)
+            .tw_err(|_| SigningErrorType::Error_internal)
+            .context("Error computing 'to_sign' SIGHASH_ALL sighash")?;
+        Ok(H256::from(sighash.to_byte_array()))
+    }
+
+    fn check_address(address: &MessageAddress, utxo_args: &UtxoToSign) -> SigningResult<()> {
+        if utxo_args.prevout_script_pubkey != address.script_pubkey() {
+            return SigningError::err(SigningErrorType::Error_invalid_address)
+                .context("The given address doesn't belong to the public key");
+        }
+        Ok(())
+    }
+
+    fn verify_ecdsa(
+        address: &MessageAddress,
+        (input, utxo_args): (TransactionInput, UtxoToSign),
+        public_key: &ecdsa::secp256k1::PublicKey,
+        signature: &[u8],
+    ) -> SigningResult<()> {
+        Self::check_address(address, &utxo_args)?;
+
+        let Some((sighash_ty, der_signature)) = signature.split_last() else {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Empty ecdsa signature");
+        };
+        if *sighash_ty as u32 != SIGHASH_ALL {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Only SIGHASH_ALL signatures are supported");
+        }
+
+        let unsigned_tx = Self::to_sign(input, utxo_args)?;
+        let sighash = Self::sighash(&unsigned_tx)?;
+        SighashVerifier::<Transaction>::verify_ecdsa_signature(
+            &sighash,
+            public_key.compressed().as_slice(),
+            der_signature,
+        )
+    }
+
+    /// Tries to parse the signature as a full `to_sign` transaction.
+    /// Returns `None` if the signature is not a transaction.
+    fn parse_full_signature(
+        message: &[u8],
+        address: &MessageAddress,
+        signature: &[u8],
+    ) -> Option<SigningResult<(Script, Witness)>> {
+        let to_sign =
+            BitcoinTransactionDecoder::<StandardBitcoinContext>::parse_transaction(signature)
+                .ok()?;
+        Some(Self::check_full_signature(message, address, to_sign))
+    }
+
+    fn check_full_signature(
+        message: &[u8],
+        address: &MessageAddress,
+        to_sign: Transaction,
+    ) -> SigningResult<(Script, Witness)> {
+        let to_spend = Self::to_spend(message, address.script_pubkey());
+        let expected_out_point = OutPoint {
+            hash: Self::to_spend_txid(&to_spend),
+            index: 0,
+        };
+        let expected_output = Self::to_sign_output();
+
+        let valid_structure = to_sign.version == VIRTUAL_TX_VERSION as i32
+            && to_sign.locktime == 0
+            && to_sign.inputs.len() == 1
+            && to_sign.inputs[0].previous_output == expected_out_point
+            && to_sign.inputs[0].sequence == VIRTUAL_TX_SEQUENCE
+            && to_sign.outputs.len() == 1
+            && to_sign.outputs[0].value == expected_output.value
+            && to_sign.outputs[0].script_pubkey == expected_output.script_pubkey;
+        if !valid_structure {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("The signature is not a valid 'to_sign' transaction of the message");
+        }
+
+        let input = to_sign.inputs.into_iter().next().expect("Checked above");
+        Ok((input.script_sig, input.witness))
+    }
+
+    fn parse_simple_signature(signature: &[u8]) -> SigningResult<Witness> {
+        let items: bitcoin::Witness = bitcoin::consensus::deserialize(signature)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("The signature is neither a witness stack nor a 'to_sign' transaction")?;
+
+        let mut witness = Witness::default();
+        for item in items.iter() {
+            witness.push_item(Script::from(item.to_vec()));
+        }
+        Ok(witness)
+    }
+
+    /// Parses a push-only scriptSig.
+    fn parse_script_sig(script_sig: &Script) -> SigningResult<Vec<Data>> {
+        bitcoin::Script::from_bytes(script_sig.as_slice())
+            .instructions()
+            .map(|instruction| match instruction {
+                Ok(Instruction::PushBytes(push)) => Ok(push.as_bytes().to_vec()),
+                _ => SigningError::err(SigningErrorType::Error_input_parse)
+                    .context("Expected a push-only scriptSig"),
+            })
+            .collect()
+    }
+
+    fn check_empty<T>(items: &[T], name: &str) -> SigningResult<()> {
+        if !items.is_empty() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .with_context(|| format!("Unexpected non-empty {name}"));
+        }
+        Ok(())
+    }
+
+    fn ecdsa_public_key(public_key: &[u8]) -> SigningResult<ecdsa::secp256k1::PublicKey> {
+        ecdsa::secp256k1::PublicKey::try_from(public_key)
+            .into_tw()
+            .context("Invalid ecdsa secp256k1 public key")
+    }
+}
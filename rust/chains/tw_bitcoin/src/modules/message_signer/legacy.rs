@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Legacy "Bitcoin Signed Message" compact signatures, compatible with Bitcoin Core `signmessage`.
+
+use crate::modules::message_signer::MessageAddress;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::hasher::{sha256_d, sha256_ripemd};
+use tw_hash::{H160, H256};
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_memory::Data;
+use tw_utxo::encode::stream::Stream;
+
+const MESSAGE_PREFIX: &str = "Bitcoin Signed Message:\n";
+const SIGNATURE_LEN: usize = 65;
+/// The first byte of a compact signature is `27 + recovery_id`, plus 4 if the public key is compressed.
+const RECOVERY_ID_OFFSET: u8 = 27;
+const COMPRESSED_FLAG: u8 = 4;
+const MAX_HEADER: u8 = RECOVERY_ID_OFFSET + COMPRESSED_FLAG + 3;
+
+pub struct LegacyMessage;
+
+impl LegacyMessage {
+    /// Returns the prefixed message and its hash to be signed.
+    pub fn preimage(message: &[u8]) -> (Data, H256) {
+        let mut stream = Stream::default();
+        stream
+            .append(&MESSAGE_PREFIX.as_bytes().to_vec())
+            .append(&message.to_vec());
+        let data = stream.out();
+
+        let hash = H256::try_from(sha256_d(&data).as_slice()).expect("sha256d returns 32 bytes");
+        (data, hash)
+    }
+
+    /// Signs the message with a compressed public key.
+    pub fn sign(
+        message: &[u8],
+        address: &MessageAddress,
+        private_key: &[u8],
+    ) -> SigningResult<Data> {
+        let MessageAddress::P2PKH(pubkey_hash) = address else {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Legacy message signature supports P2PKH addresses only");
+        };
+
+        let private_key = secp256k1::PrivateKey::try_from(private_key)
+            .into_tw()
+            .context("Invalid ecdsa secp256k1 private key")?;
+        if Self::pubkey_hash(private_key.public().compressed().as_slice()) != *pubkey_hash {
+            return SigningError::err(SigningErrorType::Error_invalid_address)
+                .context("The given address doesn't belong to the private key");
+        }
+
+        let (_, hash) = Self::preimage(message);
+        let signature = private_key
+            .sign(hash)
+            .into_tw()
+            .context("Error signing a message")?;
+
+        let mut compact = Vec::with_capacity(SIGNATURE_LEN);
+        compact.push(RECOVERY_ID_OFFSET + COMPRESSED_FLAG + signature.v());
+        compact.extend_from_slice(signature.r().as_slice());
+        compact.extend_from_slice(signature.s().as_slice());
+        Ok(compact)
+    }
+
+    /// Whether the given bytes look like a compact signature.
+    pub fn is_legacy_signature(signature: &[u8]) -> bool {
+        signature.len() == SIGNATURE_LEN
+            && (RECOVERY_ID_OFFSET..=MAX_HEADER).contains(&signature[0])
+    }
+
+    /// Recovers the public key from the signature and checks whether it corresponds to the address.
+    /// Signatures of both compressed and uncompressed public keys are supported.
+    pub fn verify(message: &[u8], address: &MessageAddress, signature: &[u8]) -> SigningResult<()> {
+        let MessageAddress::P2PKH(pubkey_hash) = address else {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Legacy message signature supports P2PKH addresses only");
+        };
+        if !Self::is_legacy_signature(signature) {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Invalid compact signature");
+        }
+
+        let header = signature[0] - RECOVERY_ID_OFFSET;
+        let compressed = header >= COMPRESSED_FLAG;
+        let recovery_id = header % COMPRESSED_FLAG;
+
+        let r = H256::try_from(&signature[1..33]).expect("Expected 32 bytes");
+        let s = H256::try_from(&signature[33..65]).expect("Expected 32 bytes");
+        let signature = secp256k1::Signature::try_from_parts(r, s, recovery_id)
+            .into_tw()
+            .context("Invalid compact signature")?;
+
+        let (_, hash) = Self::preimage(message);
+        let public_key = secp256k1::PublicKey::recover(signature, hash)
+            .into_tw()
+            .context("Error recovering a public key from the signature")?;
+
+        let actual_pubkey_hash = if compressed {
+            Self::pubkey_hash(public_key.compressed().as_slice())
+        } else {
+            Self::pubkey_hash(public_key.uncompressed().as_slice())
+        };
+        if actual_pubkey_hash != *pubkey_hash {
+            return SigningError::err(SigningErrorType::Error_signing)
+                .context("The message is signed by another address");
+        }
+        Ok(())
+    }
+
+    fn pubkey_hash(public_key: &[u8]) -> H160 {
+        H160::try_from(sha256_ripemd(public_key).as_slice())
+            .expect("sha256_ripemd returns 20 bytes")
+    }
+}
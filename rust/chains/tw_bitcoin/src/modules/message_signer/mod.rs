@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::context::StandardBitcoinContext;
+use crate::modules::message_signer::bip322::Bip322Message;
+use crate::modules::message_signer::legacy::LegacyMessage;
+use crate::modules::signing_request::SigningRequestBuilder;
+use crate::modules::tx_builder::script_parser::{StandardScript, StandardScriptParser};
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64::{self, STANDARD};
+use tw_hash::H160;
+use tw_keypair::schnorr;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use tw_utxo::address::standard_bitcoin::StandardBitcoinAddress;
+use tw_utxo::context::UtxoContext;
+use tw_utxo::script::standard_script::conditions;
+use tw_utxo::script::Script;
+
+pub mod bip322;
+pub mod legacy;
+
+/// Signs and verifies messages with either legacy "Bitcoin Signed Message" or BIP322 signatures.
+pub struct BitcoinMessageSigner;
+
+impl MessageSigner for BitcoinMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(coin, input).is_ok()
+    }
+}
+
+impl BitcoinMessageSigner {
+    fn message_preimage_hashes_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let address = MessageAddress::from_str_with_coin(coin, &input.address)?;
+        let message = input.message.as_bytes();
+
+        let (data, data_hash) = match input.signing_type {
+            Proto::MessageSigningType::MessageSigningType_legacy => {
+                LegacyMessage::preimage(message)
+            },
+            Proto::MessageSigningType::MessageSigningType_bip322_simple
+            | Proto::MessageSigningType::MessageSigningType_bip322_full => {
+                Bip322Message::preimage(message, &address, &input.public_key)?
+            },
+        };
+
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::from(data),
+            data_hash: Cow::from(data_hash.to_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let address = MessageAddress::from_str_with_coin(coin, &input.address)?;
+        let message = input.message.as_bytes();
+
+        let signature = match input.signing_type {
+            Proto::MessageSigningType::MessageSigningType_legacy => {
+                LegacyMessage::sign(message, &address, &input.private_key)?
+            },
+            Proto::MessageSigningType::MessageSigningType_bip322_simple => {
+                Bip322Message::sign_simple(
+                    message,
+                    &address,
+                    &input.private_key,
+                    input.dangerous_use_fixed_schnorr_rng,
+                )?
+            },
+            Proto::MessageSigningType::MessageSigningType_bip322_full => Bip322Message::sign_full(
+                message,
+                &address,
+                &input.private_key,
+                input.dangerous_use_fixed_schnorr_rng,
+            )?,
+        };
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::from(base64::encode(&signature, STANDARD)),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageVerifyingInput<'_>,
+    ) -> SigningResult<()> {
+        let address = MessageAddress::from_str_with_coin(coin, &input.address)?;
+        let message = input.message.as_bytes();
+        let signature = base64::decode(&input.signature, STANDARD)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Expected a Base64-encoded signature")?;
+
+        if LegacyMessage::is_legacy_signature(&signature) {
+            return LegacyMessage::verify(message, &address, &signature);
+        }
+        Bip322Message::verify(message, &address, &signature)
+    }
+}
+
+/// An address which the ownership of can be proved by signing a message.
+pub enum MessageAddress {
+    /// Public key hash.
+    P2PKH(H160),
+    /// Public key hash.
+    P2WPKH(H160),
+    /// Tweaked public key.
+    P2TR(schnorr::XOnlyPublicKey),
+}
+
+impl MessageAddress {
+    pub fn from_str_with_coin(coin: &dyn CoinContext, address: &str) -> SigningResult<Self> {
+        let chain_info = SigningRequestBuilder::<StandardBitcoinContext>::chain_info(coin, &None)?;
+        let address = StandardBitcoinAddress::from_str_with_coin_and_prefix(coin, address, None)
+            .into_tw()
+            .context("Invalid signer address")?;
+        let script_pubkey = StandardBitcoinContext::addr_to_script_pubkey(
+            &address,
+            chain_info.to_address_prefixes(),
+        )?;
+
+        match StandardScriptParser.parse(&script_pubkey)? {
+            StandardScript::P2PKH(pubkey_hash) => Ok(MessageAddress::P2PKH(pubkey_hash)),
+            StandardScript::P2WPKH(pubkey_hash) => Ok(MessageAddress::P2WPKH(pubkey_hash)),
+            StandardScript::P2TR(tweaked_pubkey) => Ok(MessageAddress::P2TR(tweaked_pubkey)),
+            _ => SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Only P2PKH, P2WPKH and P2TR addresses can sign a message"),
+        }
+    }
+
+    pub fn script_pubkey(&self) -> Script {
+        match self {
+            MessageAddress::P2PKH(pubkey_hash) => conditions::new_p2pkh(pubkey_hash),
+            MessageAddress::P2WPKH(pubkey_hash) => conditions::new_p2wpkh(pubkey_hash),
+            MessageAddress::P2TR(tweaked_pubkey) => {
+                conditions::new_p2tr_dangerous_assume_tweaked(&tweaked_pubkey.bytes())
+            },
+        }
+    }
+}
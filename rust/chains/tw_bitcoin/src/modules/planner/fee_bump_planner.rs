@@ -0,0 +1,372 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::planner::BitcoinPlanner;
+use crate::modules::signing_request::SigningRequestBuilder;
+use crate::modules::transaction_decoder::BitcoinTransactionDecoder;
+use crate::modules::tx_builder::utxo_protobuf::parse_out_point;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+use tw_proto::BitcoinV2::Proto;
+use tw_utxo::context::UtxoContext;
+use tw_utxo::modules::fee_estimator::FeeEstimator;
+use tw_utxo::modules::tx_planner::TxPlanner;
+use tw_utxo::modules::utxo_selector::SelectResult;
+use tw_utxo::transaction::standard_transaction::{Transaction, TransactionOutput};
+use tw_utxo::transaction::transaction_parts::{Amount, OutPoint};
+use Proto::mod_FeeBump::OneOfdust_policy as FeeBumpDustPolicy;
+use Proto::mod_FeeBump::OneOfmethod as FeeBumpMethod;
+use Proto::mod_TransactionBuilder::OneOfdust_policy as ProtoDustPolicy;
+
+/// A transaction signals replaceability if any of its inputs has a sequence number not greater than this value.
+/// https://github.com/bitcoin/bips/blob/master/bip-0125.mediawiki#summary
+const MAX_BIP125_RBF_SEQUENCE: u32 = 0xfffffffd;
+/// Bitcoin Core's default incremental relay fee rate in satoshis per vbyte.
+const DEFAULT_INCREMENTAL_RELAY_FEE_RATE: Amount = 1;
+/// The maximum number of attempts to raise the fee rate to pay the required fee.
+const MAX_FEE_RATE_ADJUSTMENTS: usize = 8;
+
+/// The original transaction being fee-bumped.
+struct OriginalTx<'a> {
+    txid: H256,
+    tx: Transaction,
+    /// The UTXOs spent by the original transaction in the same order as the transaction inputs.
+    inputs: Vec<Proto::Input<'a>>,
+    fee: Amount,
+}
+
+impl OriginalTx<'_> {
+    fn change_output(&self, index: u32) -> SigningResult<&TransactionOutput> {
+        self.tx
+            .outputs
+            .get(index as usize)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("The original transaction has no '{index}' change output"))
+    }
+}
+
+/// Plans a transaction that bumps the fee of an already broadcast transaction.
+///
+/// The fee bump is converted into a [`Proto::TransactionBuilder`],
+/// so the same transaction is planned, signed and compiled through the standard paths.
+pub struct FeeBumpPlanner<Context: UtxoContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: UtxoContext> FeeBumpPlanner<Context> {
+    pub fn plan_fee_bump<'a>(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput<'a>,
+        fee_bump: &Proto::FeeBump<'a>,
+    ) -> SigningResult<Proto::TransactionPlan<'a>> {
+        let tx_builder = Self::tx_builder(coin, input, fee_bump)?;
+        BitcoinPlanner::<Context>::plan_with_tx_builder(coin, input, &tx_builder)
+    }
+
+    /// Builds either a replacement (RBF) or a child (CPFP) transaction.
+    pub fn tx_builder<'a>(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        fee_bump: &Proto::FeeBump<'a>,
+    ) -> SigningResult<Proto::TransactionBuilder<'a>> {
+        let original = Self::original_tx(fee_bump)?;
+
+        // The target fee rate must be higher than the original one, otherwise there is nothing to bump.
+        if original.fee >= FeeEstimator::estimate_fee(&original.tx, fee_bump.fee_per_vb)? {
+            return SigningError::err(SigningErrorType::Error_wrong_fee)
+                .context("The original transaction already pays the target fee rate");
+        }
+
+        match fee_bump.method {
+            FeeBumpMethod::replace_by_fee(ref rbf) => {
+                Self::replace_by_fee(coin, input, fee_bump, rbf, original)
+            },
+            FeeBumpMethod::child_pays_for_parent(ref cpfp) => {
+                Self::child_pays_for_parent(coin, input, fee_bump, cpfp, original)
+            },
+            FeeBumpMethod::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Either `ReplaceByFee` or `ChildPaysForParent` should be set"),
+        }
+    }
+
+    /// Builds a replacement transaction according to the BIP125 rules.
+    /// The replacement spends all the original inputs, and pays the extra fee from the change output.
+    /// The extra inputs are spent only if the original inputs are not enough.
+    fn replace_by_fee<'a>(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        fee_bump: &Proto::FeeBump<'a>,
+        rbf: &Proto::mod_FeeBump::ReplaceByFee,
+        original: OriginalTx<'a>,
+    ) -> SigningResult<Proto::TransactionBuilder<'a>> {
+        // BIP125 rule #1: the original transaction must signal replaceability.
+        let signals_rbf = original
+            .tx
+            .inputs
+            .iter()
+            .any(|txin| txin.sequence <= MAX_BIP125_RBF_SEQUENCE);
+        if !signals_rbf {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("The original transaction doesn't signal replaceability (BIP125)");
+        }
+
+        let incremental_fee_rate = if rbf.incremental_relay_fee_per_vb > 0 {
+            rbf.incremental_relay_fee_per_vb
+        } else {
+            DEFAULT_INCREMENTAL_RELAY_FEE_RATE
+        };
+
+        let change_output = original.change_output(fee_bump.change_output_index)?;
+        let outputs = original
+            .tx
+            .outputs
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != fee_bump.change_output_index as usize)
+            .map(|(_, output)| output_to_proto(output))
+            .collect();
+
+        let mut tx_builder = Proto::TransactionBuilder {
+            version: transaction_version(original.tx.version)?,
+            lock_time: original.tx.locktime,
+            inputs: original.inputs.clone(),
+            outputs,
+            input_selector: Proto::InputSelector::UseAll,
+            fee_per_vb: fee_bump.fee_per_vb,
+            change_output: Some(output_to_proto(change_output)),
+            dust_policy: dust_policy(&fee_bump.dust_policy),
+            ..Proto::TransactionBuilder::default()
+        };
+
+        // BIP125 rules #3 and #4: the replacement must pay at least the original fee
+        // plus its own bandwidth at the incremental relay fee rate.
+        let required_fee = |vsize: usize| -> SigningResult<Amount> {
+            Ok(original.fee + fee_of(vsize, incremental_fee_rate)?)
+        };
+
+        match Self::plan_with_required_fee(coin, input, tx_builder.clone(), required_fee) {
+            Err(e)
+                if *e.error_type() == SigningErrorType::Error_not_enough_utxos
+                    && !fee_bump.extra_inputs.is_empty() =>
+            {
+                // Spend all the original inputs first, and then as few extra inputs as possible.
+                tx_builder
+                    .inputs
+                    .extend(fee_bump.extra_inputs.iter().cloned());
+                tx_builder.input_selector = Proto::InputSelector::SelectInOrder;
+                Self::plan_with_required_fee(coin, input, tx_builder, required_fee)
+            },
+            res => res,
+        }
+        .map(|(tx_builder, _)| tx_builder)
+    }
+
+    /// Builds a child transaction that spends the change output of the original transaction,
+    /// so that the package pays the target fee rate.
+    fn child_pays_for_parent<'a>(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        fee_bump: &Proto::FeeBump<'a>,
+        cpfp: &Proto::mod_FeeBump::ChildPaysForParent<'a>,
+        original: OriginalTx<'a>,
+    ) -> SigningResult<Proto::TransactionBuilder<'a>> {
+        let change_output = original.change_output(fee_bump.change_output_index)?;
+        let change_out_point = OutPoint {
+            hash: original.txid,
+            index: fee_bump.change_output_index,
+        };
+
+        let mut change_utxo = cpfp
+            .change_utxo
+            .clone()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("`ChildPaysForParent.change_utxo` must be set")?;
+        change_utxo.out_point = Some(Proto::OutPoint {
+            hash: Cow::from(change_out_point.hash.to_vec()),
+            vout: change_out_point.index,
+        });
+        change_utxo.value = change_output.value;
+
+        let destination = cpfp
+            .destination
+            .clone()
+            .unwrap_or_else(|| output_to_proto(change_output));
+
+        // The child transaction sends the whole amount to the destination.
+        let mut tx_builder = Proto::TransactionBuilder {
+            version: transaction_version(original.tx.version)?,
+            inputs: vec![change_utxo],
+            input_selector: Proto::InputSelector::UseAll,
+            fee_per_vb: fee_bump.fee_per_vb,
+            max_amount_output: Some(destination),
+            dust_policy: dust_policy(&fee_bump.dust_policy),
+            ..Proto::TransactionBuilder::default()
+        };
+
+        // The package (original + child transactions) must pay the target fee rate.
+        let package_required_fee = FeeEstimator::estimate_fee(&original.tx, fee_bump.fee_per_vb)?;
+        let required_fee = |vsize: usize| -> SigningResult<Amount> {
+            Ok(package_required_fee - original.fee + fee_of(vsize, fee_bump.fee_per_vb)?)
+        };
+
+        let (tx_builder, SelectResult { unsigned_tx, .. }) =
+            match Self::plan_with_required_fee(coin, input, tx_builder.clone(), required_fee) {
+                Err(e)
+                    if *e.error_type() == SigningErrorType::Error_not_enough_utxos
+                        && !fee_bump.extra_inputs.is_empty() =>
+                {
+                    // The change UTXO goes first, so it's always spent.
+                    tx_builder
+                        .inputs
+                        .extend(fee_bump.extra_inputs.iter().cloned());
+                    Self::plan_with_required_fee(coin, input, tx_builder, required_fee)
+                },
+                res => res,
+            }?;
+
+        let spends_change = unsigned_tx
+            .inputs()
+            .iter()
+            .any(|txin| txin.previous_output == change_out_point);
+        if !spends_change {
+            return SigningError::err(SigningErrorType::Error_invalid_utxo).context(
+                "The change output of the original transaction is dust and cannot be spent",
+            );
+        }
+
+        Ok(tx_builder)
+    }
+
+    /// Plans the transaction raising its fee rate until it pays at least `required_fee(vsize)`.
+    fn plan_with_required_fee<'a, F>(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        mut tx_builder: Proto::TransactionBuilder<'a>,
+        required_fee: F,
+    ) -> SigningResult<(Proto::TransactionBuilder<'a>, SelectResult<Transaction>)>
+    where
+        F: Fn(usize) -> SigningResult<Amount>,
+    {
+        for _ in 0..MAX_FEE_RATE_ADJUSTMENTS {
+            let request = SigningRequestBuilder::<Context>::build(coin, input, &tx_builder)?;
+            let select_result = TxPlanner::plan(request)?;
+
+            let vsize = select_result.plan.vsize_estimate;
+            let required = required_fee(vsize)?;
+            if select_result.plan.fee_estimate >= required {
+                return Ok((tx_builder, select_result));
+            }
+
+            // Round the fee rate up, and raise it by at least one satoshi per vbyte.
+            let vsize = Amount::try_from(vsize.max(1))
+                .tw_err(|_| SigningErrorType::Error_tx_too_big)
+                .context("Transaction is too big")?;
+            let fee_rate = (required + vsize - 1) / vsize;
+            tx_builder.fee_per_vb = fee_rate.max(tx_builder.fee_per_vb + 1);
+        }
+
+        SigningError::err(SigningErrorType::Error_wrong_fee)
+            .context("Cannot find a fee rate to pay the required fee")
+    }
+
+    fn original_tx<'a>(fee_bump: &Proto::FeeBump<'a>) -> SigningResult<OriginalTx<'a>> {
+        let tx = BitcoinTransactionDecoder::<Context>::parse_transaction(&fee_bump.original_tx)
+            .context("Invalid original transaction")?;
+        // `Transaction::txid` is in the reversed byte order, while outpoints refer to the hash as is.
+        let mut txid = tx.txid();
+        txid.reverse();
+        let txid = H256::try_from(txid.as_slice()).expect("Transaction hash is 32 bytes");
+
+        // Find the original UTXOs in the same order as the transaction inputs.
+        let mut inputs = Vec::with_capacity(tx.inputs.len());
+        for txin in tx.inputs.iter() {
+            let mut utxo = None;
+            for utxo_proto in fee_bump.original_inputs.iter() {
+                if parse_out_point(&utxo_proto.out_point)? == txin.previous_output {
+                    utxo = Some(utxo_proto.clone());
+                    break;
+                }
+            }
+            let mut utxo = utxo
+                .or_tw_err(SigningErrorType::Error_invalid_utxo)
+                .context(
+                    "An original transaction input is not provided in `FeeBump.original_inputs`",
+                )?;
+
+            // Keep the original sequence unless it's set explicitly.
+            if utxo.sequence.is_none() {
+                utxo.sequence = Some(Proto::mod_Input::Sequence {
+                    sequence: txin.sequence,
+                });
+            }
+            inputs.push(utxo);
+        }
+
+        let total_in = inputs
+            .iter()
+            .try_fold(0, |total_in: Amount, utxo| total_in.checked_add(utxo.value))
+            .or_tw_err(SigningErrorType::Error_invalid_utxo_amount)
+            .context("Sum of the original inputs amounts is too big")?;
+        let total_out = tx
+            .outputs
+            .iter()
+            .try_fold(0, |total_out: Amount, output| {
+                total_out.checked_add(output.value)
+            })
+            .or_tw_err(SigningErrorType::Error_invalid_utxo_amount)
+            .context("Sum of the original outputs amounts is too big")?;
+        if total_in < total_out {
+            return SigningError::err(SigningErrorType::Error_invalid_utxo_amount)
+                .context("The original inputs amount is less than the original outputs amount");
+        }
+
+        Ok(OriginalTx {
+            txid,
+            tx,
+            inputs,
+            fee: total_in - total_out,
+        })
+    }
+}
+
+fn output_to_proto(output: &TransactionOutput) -> Proto::Output<'static> {
+    Proto::Output {
+        value: output.value,
+        to_recipient: Proto::mod_Output::OneOfto_recipient::custom_script_pubkey(Cow::from(
+            output.script_pubkey.to_vec(),
+        )),
+    }
+}
+
+fn dust_policy(proto: &FeeBumpDustPolicy) -> ProtoDustPolicy {
+    match proto {
+        FeeBumpDustPolicy::fixed_dust_threshold(fixed) => {
+            ProtoDustPolicy::fixed_dust_threshold(*fixed)
+        },
+        FeeBumpDustPolicy::dust_relay_fee_per_kvb(fee_rate) => {
+            ProtoDustPolicy::dust_relay_fee_per_kvb(*fee_rate)
+        },
+        FeeBumpDustPolicy::None => ProtoDustPolicy::None,
+    }
+}
+
+fn transaction_version(version: i32) -> SigningResult<Proto::TransactionVersion> {
+    match version {
+        1 => Ok(Proto::TransactionVersion::V1),
+        2 => Ok(Proto::TransactionVersion::V2),
+        _ => SigningError::err(SigningErrorType::Error_not_supported)
+            .with_context(|| format!("Unsupported original transaction version: {version}")),
+    }
+}
+
+fn fee_of(vsize: usize, fee_rate: Amount) -> SigningResult<Amount> {
+    Amount::try_from(vsize)
+        .ok()
+        .and_then(|vsize| vsize.checked_mul(fee_rate))
+        .or_tw_err(SigningErrorType::Error_wrong_fee)
+        .with_context(|| format!("Fee rate is too large: '{vsize} * {fee_rate}' overflow"))
+}
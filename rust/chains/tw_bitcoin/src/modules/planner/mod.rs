@@ -16,6 +16,7 @@ use tw_utxo::context::UtxoContext;
 use tw_utxo::modules::tx_planner::TxPlanner;
 use tw_utxo::modules::utxo_selector::SelectResult;
 
+pub mod fee_bump_planner;
 pub mod psbt_planner;
 
 #[derive(Default)]
@@ -31,10 +32,21 @@ impl<Context: UtxoContext> BitcoinPlanner<Context> {
         use Proto::mod_SigningInput::OneOftransaction as TransactionType;
 
         match input.transaction {
-            TransactionType::builder(ref tx) => Self::plan_with_tx_builder(coin, input, tx),
-            TransactionType::psbt(ref psbt) => {
+            TransactionType::builder(ref tx) | TransactionType::psbt_create(ref tx) => {
+                Self::plan_with_tx_builder(coin, input, tx)
+            },
+            TransactionType::psbt(ref psbt)
+            | TransactionType::psbt_sign(ref psbt)
+            | TransactionType::psbt_finalize(ref psbt) => {
                 psbt_planner::PsbtPlanner::<Context>::plan_psbt(coin, input, psbt)
             },
+            TransactionType::fee_bump(ref fee_bump) => {
+                fee_bump_planner::FeeBumpPlanner::<Context>::plan_fee_bump(coin, input, fee_bump)
+            },
+            TransactionType::psbt_combine(_) => {
+                SigningError::err(SigningErrorType::Error_not_supported)
+                    .context("Combining PSBTs doesn't require planning")
+            },
             TransactionType::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("Either `TransactionBuilder` or `Psbt` should be set"),
         }
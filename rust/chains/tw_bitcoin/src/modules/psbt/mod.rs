@@ -0,0 +1,357 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use bitcoin::psbt::{Psbt, PsbtSighashType};
+use std::borrow::Cow;
+use tw_coin_entry::coin_entry::SignatureBytes;
+use tw_coin_entry::error::prelude::*;
+use tw_keypair::{ecdsa, schnorr};
+use tw_proto::BitcoinV2::Proto;
+use tw_utxo::modules::tx_compiler::TxCompiler;
+use tw_utxo::script::{Script, Witness};
+use tw_utxo::sighash::SighashType;
+use tw_utxo::signature::{BitcoinEcdsaSignature, BitcoinSchnorrSignature, FromRawOrDerBytes};
+use tw_utxo::signing_mode::SigningMethod;
+use tw_utxo::spending_data::{SpendingData, SpendingDataConstructor};
+use tw_utxo::transaction::standard_transaction::Transaction;
+use tw_utxo::transaction::unsigned_transaction::UnsignedTransaction;
+use tw_utxo::transaction::UtxoToSign;
+
+pub mod psbt_v2;
+
+/// Finalizes the [Partially Signed Bitcoin Transaction](Psbt)
+/// by updating the final `script_sig` and/or `witness`.
+pub fn update_psbt_signed(psbt: &mut Psbt, signed_tx: &Transaction) {
+    for (signed_txin, utxo_psbt) in signed_tx.inputs.iter().zip(psbt.inputs.iter_mut()) {
+        if !signed_txin.script_sig.is_empty() {
+            utxo_psbt.final_script_sig = Some(bitcoin::ScriptBuf::from_bytes(
+                signed_txin.script_sig.to_vec(),
+            ));
+        }
+
+        if !signed_txin.witness.is_empty() {
+            let mut final_witness = bitcoin::Witness::new();
+            for witness_item in signed_txin.witness.as_items() {
+                final_witness.push(bitcoin::ScriptBuf::from_bytes(witness_item.to_vec()));
+            }
+            utxo_psbt.final_script_witness = Some(final_witness);
+        }
+    }
+}
+
+/// Serializes the [Partially Signed Bitcoin Transaction](Psbt) as both PSBTv0 and PSBTv2.
+pub fn psbt_to_proto(psbt: &Psbt) -> SigningResult<Proto::Psbt<'static>> {
+    Ok(Proto::Psbt {
+        psbt: Cow::from(psbt.serialize()),
+        psbt_v2: Cow::from(psbt_v2::serialize_v2(psbt)?),
+    })
+}
+
+/// Creates a [Partially Signed Bitcoin Transaction](Psbt) from the unsigned transaction (Creator role),
+/// and adds all the information required to sign the UTXOs (Updater role).
+///
+/// Please note that only segwit UTXOs are supported at the moment,
+/// as non-segwit UTXOs require the full previous transactions (`non_witness_utxo`).
+pub fn create_psbt(unsigned_tx: &UnsignedTransaction<Transaction>) -> SigningResult<Psbt> {
+    // The transaction doesn't contain any `script_sig` or `witness` yet.
+    let tx: bitcoin::Transaction =
+        bitcoin::consensus::deserialize(&unsigned_tx.transaction().encode_out())
+            .tw_err(|_| SigningErrorType::Error_internal)
+            .context("Error converting the unsigned transaction")?;
+
+    let mut psbt = Psbt::from_unsigned_tx(tx)
+        .tw_err(|_| SigningErrorType::Error_internal)
+        .context("Error creating PSBT from the unsigned transaction")?;
+
+    for (utxo_index, (utxo, utxo_psbt)) in unsigned_tx
+        .input_args()
+        .iter()
+        .zip(psbt.inputs.iter_mut())
+        .enumerate()
+    {
+        update_psbt_utxo(utxo_psbt, utxo)
+            .with_context(|| format!("Error updating '{utxo_index}' PSBT input"))?;
+    }
+
+    Ok(psbt)
+}
+
+/// Adds partial signatures to the [Partially Signed Bitcoin Transaction](Psbt) without finalizing it (Signer role).
+///
+/// `signatures` are expected in the same order as sighashes returned by
+/// [`tw_utxo::modules::sighash_computer::SighashComputer::preimage_tx`],
+/// where an empty signature means the signer hasn't signed the UTXO.
+pub fn update_psbt_partially_signed(
+    psbt: &mut Psbt,
+    unsigned_tx: &UnsignedTransaction<Transaction>,
+    signatures: &[SignatureBytes],
+) -> SigningResult<()> {
+    let expected_signatures: usize = unsigned_tx
+        .input_args()
+        .iter()
+        .map(|utxo| utxo.pending_signers().len())
+        .sum();
+    if expected_signatures != signatures.len() {
+        return SigningError::err(SigningErrorType::Error_signatures_count).context(format!(
+            "Expected '{expected_signatures}' signatures, but given '{}'",
+            signatures.len()
+        ));
+    }
+
+    let mut signatures = signatures.iter();
+    for (utxo, utxo_psbt) in unsigned_tx.input_args().iter().zip(psbt.inputs.iter_mut()) {
+        for (signer, signature) in utxo.pending_signers().into_iter().zip(signatures.by_ref()) {
+            if signature.is_empty() {
+                continue;
+            }
+
+            match utxo.signing_method {
+                SigningMethod::Legacy | SigningMethod::Segwit => {
+                    add_ecdsa_partial_signature(utxo_psbt, &signer, signature, utxo.sighash_ty)?
+                },
+                SigningMethod::Taproot => {
+                    add_schnorr_key_path_signature(utxo_psbt, utxo, signature)?
+                },
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Combines several [Partially Signed Bitcoin Transactions](Psbt) of the same unsigned transaction (Combiner role).
+pub fn combine_psbts<T: AsRef<[u8]>>(psbts: &[T]) -> SigningResult<Psbt> {
+    let mut psbts = psbts.iter().enumerate().map(|(psbt_index, psbt)| {
+        Psbt::deserialize(psbt.as_ref())
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .with_context(|| format!("Error deserializing '{psbt_index}' PSBT"))
+    });
+
+    let mut combined = psbts
+        .next()
+        .or_tw_err(SigningErrorType::Error_invalid_params)
+        .context("At least one PSBT must be provided")??;
+
+    for psbt in psbts {
+        combined
+            .combine(psbt?)
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Cannot combine PSBTs of different unsigned transactions")?;
+    }
+
+    Ok(combined)
+}
+
+/// Finalizes the [Partially Signed Bitcoin Transaction](Psbt) from its partial signatures (Input Finalizer role),
+/// and returns the signed transaction (Transaction Extractor role).
+/// UTXOs finalized before are left as is.
+pub fn finalize_psbt(
+    psbt: &mut Psbt,
+    unsigned_tx: UnsignedTransaction<Transaction>,
+) -> SigningResult<Transaction> {
+    let claims = unsigned_tx
+        .input_args()
+        .iter()
+        .zip(psbt.inputs.iter())
+        .enumerate()
+        .map(|(utxo_index, (utxo, utxo_psbt))| {
+            finalize_utxo(utxo, utxo_psbt)
+                .with_context(|| format!("Error finalizing '{utxo_index}' PSBT input"))
+        })
+        .collect::<SigningResult<Vec<_>>>()?;
+
+    let signed_tx = TxCompiler::compile_spending_data(unsigned_tx, claims)?;
+
+    update_psbt_signed(psbt, &signed_tx);
+    psbt.inputs.iter_mut().for_each(clear_finalized_utxo);
+
+    Ok(signed_tx)
+}
+
+fn update_psbt_utxo(utxo_psbt: &mut bitcoin::psbt::Input, utxo: &UtxoToSign) -> SigningResult<()> {
+    if utxo.leaf_hash_code_separator.is_some() {
+        return SigningError::err(SigningErrorType::Error_not_supported)
+            .context("P2TR script path is not supported for PSBT at the moment");
+    }
+    // BIP174 requires `non_witness_utxo` for non-segwit UTXOs, but the previous transactions are unknown.
+    if utxo.signing_method == SigningMethod::Legacy {
+        return SigningError::err(SigningErrorType::Error_not_supported)
+            .context("Non-segwit UTXOs are not supported for PSBT at the moment");
+    }
+
+    let amount = u64::try_from(utxo.amount)
+        .tw_err(|_| SigningErrorType::Error_invalid_utxo_amount)
+        .context("UTXO amount must not be negative")?;
+    utxo_psbt.witness_utxo = Some(bitcoin::TxOut {
+        value: amount,
+        script_pubkey: bitcoin::ScriptBuf::from_bytes(utxo.prevout_script_pubkey.to_vec()),
+    });
+    utxo_psbt.sighash_type = Some(PsbtSighashType::from_u32(utxo.sighash_ty.raw_sighash()));
+
+    let SpendingDataConstructor::EcdsaMulti(ref multi_constructor) = utxo.spending_data_constructor
+    else {
+        return Ok(());
+    };
+
+    // `UtxoToSign::script_pubkey` is the redeem (or witness) script the UTXO is signed with.
+    let script = bitcoin::ScriptBuf::from_bytes(utxo.script_pubkey.to_vec());
+    match utxo.signing_method {
+        SigningMethod::Segwit => utxo_psbt.witness_script = Some(script),
+        SigningMethod::Legacy => {
+            return SigningError::err(SigningErrorType::Error_internal)
+                .context("Non-segwit UTXOs must be rejected already")
+        },
+        SigningMethod::Taproot => {
+            return SigningError::err(SigningErrorType::Error_internal)
+                .context("Taproot UTXO cannot be signed by several ecdsa signers")
+        },
+    }
+
+    // Keep the signatures collected on the previous signing rounds.
+    for signer in multi_constructor.signers() {
+        if let Some(ref partial_signature) = signer.partial_signature {
+            add_ecdsa_partial_signature(
+                utxo_psbt,
+                signer.public_key.as_slice(),
+                partial_signature,
+                utxo.sighash_ty,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn add_ecdsa_partial_signature(
+    utxo_psbt: &mut bitcoin::psbt::Input,
+    signer: &[u8],
+    signature: &[u8],
+    sighash_ty: SighashType,
+) -> SigningResult<()> {
+    let public_key = bitcoin::PublicKey::from_slice(signer)
+        .tw_err(|_| SigningErrorType::Error_invalid_params)
+        .context("Expected a valid ecdsa secp256k1 signer public key")?;
+
+    let der_sign = ecdsa::secp256k1::VerifySignature::from_raw_or_der_bytes(signature)?
+        .to_der()
+        .into_tw()
+        .context("Error getting ASN.1 DER-encoded signature")?;
+    let bitcoin_sign = BitcoinEcdsaSignature::new(der_sign, sighash_ty)?.serialize();
+
+    let partial_signature = bitcoin::ecdsa::Signature::from_slice(&bitcoin_sign)
+        .tw_err(|_| SigningErrorType::Error_invalid_params)
+        .context("Invalid PSBT partial signature")?;
+    utxo_psbt.partial_sigs.insert(public_key, partial_signature);
+    Ok(())
+}
+
+fn add_schnorr_key_path_signature(
+    utxo_psbt: &mut bitcoin::psbt::Input,
+    utxo: &UtxoToSign,
+    signature: &[u8],
+) -> SigningResult<()> {
+    if utxo.leaf_hash_code_separator.is_some() {
+        return SigningError::err(SigningErrorType::Error_not_supported)
+            .context("P2TR script path is not supported for PSBT at the moment");
+    }
+    // BIP174 requires `non_witness_utxo` for non-segwit UTXOs, but the previous transactions are unknown.
+    if utxo.signing_method == SigningMethod::Legacy {
+        return SigningError::err(SigningErrorType::Error_not_supported)
+            .context("Non-segwit UTXOs are not supported for PSBT at the moment");
+    }
+
+    let schnorr_sign = schnorr::Signature::try_from(signature)
+        .into_tw()
+        .context("Invalid schnorr signature")?;
+    let bitcoin_sign = BitcoinSchnorrSignature::new(schnorr_sign, utxo.sighash_ty)?.serialize();
+
+    let tap_key_sig = bitcoin::taproot::Signature::from_slice(&bitcoin_sign)
+        .tw_err(|_| SigningErrorType::Error_invalid_params)
+        .context("Invalid PSBT taproot signature")?;
+    utxo_psbt.tap_key_sig = Some(tap_key_sig);
+    Ok(())
+}
+
+fn finalize_utxo(
+    utxo: &UtxoToSign,
+    utxo_psbt: &bitcoin::psbt::Input,
+) -> SigningResult<SpendingData> {
+    if utxo_psbt.final_script_sig.is_some() || utxo_psbt.final_script_witness.is_some() {
+        return Ok(finalized_spending_data(utxo_psbt));
+    }
+
+    match utxo.spending_data_constructor {
+        SpendingDataConstructor::Ecdsa(ref ecdsa_constructor) => {
+            let public_key = bitcoin::PublicKey::from_slice(&utxo.spender_public_key)
+                .tw_err(|_| SigningErrorType::Error_internal)
+                .context("Expected a valid ecdsa secp256k1 spender public key")?;
+            let partial_signature = utxo_psbt
+                .partial_sigs
+                .get(&public_key)
+                .or_tw_err(SigningErrorType::Error_signatures_count)
+                .context("The UTXO hasn't been signed yet")?;
+
+            TxCompiler::<Transaction>::get_ecdsa_spending_data(
+                ecdsa_constructor.as_ref(),
+                &partial_signature.sig.serialize_der(),
+                utxo.sighash_ty,
+            )
+        },
+        SpendingDataConstructor::Schnorr(ref schnorr_constructor) => {
+            let tap_key_sig = utxo_psbt
+                .tap_key_sig
+                .or_tw_err(SigningErrorType::Error_signatures_count)
+                .context("The UTXO hasn't been signed yet")?;
+
+            TxCompiler::<Transaction>::get_schnorr_spending_data(
+                schnorr_constructor.as_ref(),
+                &tap_key_sig.sig[..],
+                utxo.sighash_ty,
+            )
+        },
+        SpendingDataConstructor::EcdsaMulti(ref multi_constructor) => {
+            // Partial signatures are already attached to the script signers,
+            // others are considered as not signed.
+            let no_signature = SignatureBytes::default();
+            TxCompiler::<Transaction>::get_ecdsa_multi_spending_data(
+                multi_constructor.as_ref(),
+                &mut std::iter::repeat(&no_signature),
+                utxo.sighash_ty,
+            )
+        },
+    }
+}
+
+fn finalized_spending_data(utxo_psbt: &bitcoin::psbt::Input) -> SpendingData {
+    let script_sig = match utxo_psbt.final_script_sig {
+        Some(ref script_sig) => Script::from(script_sig.to_bytes()),
+        None => Script::default(),
+    };
+
+    let mut witness = Witness::new();
+    if let Some(ref final_witness) = utxo_psbt.final_script_witness {
+        for witness_item in final_witness.iter() {
+            witness.push_item(Script::from(witness_item.to_vec()));
+        }
+    }
+
+    SpendingData {
+        script_sig,
+        witness,
+    }
+}
+
+/// All data except the UTXO, final scripts and unknown fields should be cleared once the input is finalized.
+/// See [BIP174](https://github.com/bitcoin/bips/blob/master/bip-0174.mediawiki#input-finalizer).
+fn clear_finalized_utxo(utxo_psbt: &mut bitcoin::psbt::Input) {
+    *utxo_psbt = bitcoin::psbt::Input {
+        non_witness_utxo: utxo_psbt.non_witness_utxo.take(),
+        witness_utxo: utxo_psbt.witness_utxo.take(),
+        final_script_sig: utxo_psbt.final_script_sig.take(),
+        final_script_witness: utxo_psbt.final_script_witness.take(),
+        proprietary: std::mem::take(&mut utxo_psbt.proprietary),
+        unknown: std::mem::take(&mut utxo_psbt.unknown),
+        ..bitcoin::psbt::Input::default()
+    };
+}
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! PSBTv2 ([BIP370](https://github.com/bitcoin/bips/blob/master/bip-0370.mediawiki)) serialization.
+//! PSBTv2 doesn't contain the unsigned transaction, but its fields are spread over the global, input and output maps.
+//! All other key-value pairs are the same as in PSBTv0.
+
+use bitcoin::hashes::Hash;
+use bitcoin::psbt::Psbt;
+use std::collections::BTreeMap;
+use tw_coin_entry::error::prelude::*;
+use tw_memory::Data;
+use tw_utxo::encode::compact_integer::CompactInteger;
+use tw_utxo::encode::stream::Stream;
+use tw_utxo::encode::{encode, Encodable};
+
+const PSBT_MAGIC: &[u8] = b"psbt\xff";
+const PSBT_SEPARATOR: u8 = 0x00;
+const PSBT_V2: u32 = 2;
+
+const PSBT_GLOBAL_UNSIGNED_TX: u8 = 0x00;
+const PSBT_GLOBAL_TX_VERSION: u8 = 0x02;
+const PSBT_GLOBAL_FALLBACK_LOCKTIME: u8 = 0x03;
+const PSBT_GLOBAL_INPUT_COUNT: u8 = 0x04;
+const PSBT_GLOBAL_OUTPUT_COUNT: u8 = 0x05;
+const PSBT_GLOBAL_VERSION: u8 = 0xFB;
+
+const PSBT_IN_PREVIOUS_TXID: u8 = 0x0E;
+const PSBT_IN_OUTPUT_INDEX: u8 = 0x0F;
+const PSBT_IN_SEQUENCE: u8 = 0x10;
+
+const PSBT_OUT_AMOUNT: u8 = 0x03;
+const PSBT_OUT_SCRIPT: u8 = 0x04;
+
+/// Raw key-value pairs of a PSBT map ordered by the key.
+type KeyValueMap = BTreeMap<Data, Data>;
+
+/// Serializes the given PSBT as PSBTv2.
+pub fn serialize_v2(psbt: &Psbt) -> SigningResult<Data> {
+    let tx = &psbt.unsigned_tx;
+
+    // Reuse PSBTv0 serialization of all key-value pairs.
+    let psbt_v0 = psbt.serialize();
+    let mut reader = MapReader::new(&psbt_v0)?;
+
+    let mut global = reader.read_map()?;
+    let mut inputs = (0..psbt.inputs.len())
+        .map(|_| reader.read_map())
+        .collect::<SigningResult<Vec<_>>>()?;
+    let mut outputs = (0..psbt.outputs.len())
+        .map(|_| reader.read_map())
+        .collect::<SigningResult<Vec<_>>>()?;
+
+    global.remove([PSBT_GLOBAL_UNSIGNED_TX].as_slice());
+    global.insert(
+        vec![PSBT_GLOBAL_TX_VERSION],
+        tx.version.to_le_bytes().to_vec(),
+    );
+    global.insert(
+        vec![PSBT_GLOBAL_FALLBACK_LOCKTIME],
+        tx.lock_time.to_consensus_u32().to_le_bytes().to_vec(),
+    );
+    global.insert(
+        vec![PSBT_GLOBAL_INPUT_COUNT],
+        encode(&CompactInteger::from(tx.input.len())),
+    );
+    global.insert(
+        vec![PSBT_GLOBAL_OUTPUT_COUNT],
+        encode(&CompactInteger::from(tx.output.len())),
+    );
+    global.insert(vec![PSBT_GLOBAL_VERSION], PSBT_V2.to_le_bytes().to_vec());
+
+    for (txin, input) in tx.input.iter().zip(inputs.iter_mut()) {
+        input.insert(
+            vec![PSBT_IN_PREVIOUS_TXID],
+            txin.previous_output.txid.to_byte_array().to_vec(),
+        );
+        input.insert(
+            vec![PSBT_IN_OUTPUT_INDEX],
+            txin.previous_output.vout.to_le_bytes().to_vec(),
+        );
+        input.insert(
+            vec![PSBT_IN_SEQUENCE],
+            txin.sequence.0.to_le_bytes().to_vec(),
+        );
+    }
+
+    for (txout, output) in tx.output.iter().zip(outputs.iter_mut()) {
+        output.insert(vec![PSBT_OUT_AMOUNT], txout.value.to_le_bytes().to_vec());
+        output.insert(vec![PSBT_OUT_SCRIPT], txout.script_pubkey.to_bytes());
+    }
+
+    let mut stream = Stream::default();
+    stream.append_raw_slice(PSBT_MAGIC);
+    for map in std::iter::once(&global)
+        .chain(inputs.iter())
+        .chain(outputs.iter())
+    {
+        write_map(&mut stream, map);
+    }
+    Ok(stream.out())
+}
+
+fn write_map(stream: &mut Stream, map: &KeyValueMap) {
+    for (key, value) in map.iter() {
+        CompactInteger::from(key.len()).encode(stream);
+        stream.append_raw_slice(key);
+        CompactInteger::from(value.len()).encode(stream);
+        stream.append_raw_slice(value);
+    }
+    stream.append(&PSBT_SEPARATOR);
+}
+
+/// Reads raw key-value maps of a serialized PSBT.
+struct MapReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> MapReader<'a> {
+    fn new(psbt: &'a [u8]) -> SigningResult<Self> {
+        let bytes = psbt
+            .strip_prefix(PSBT_MAGIC)
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Invalid PSBT magic bytes")?;
+        Ok(MapReader { bytes })
+    }
+
+    fn read_map(&mut self) -> SigningResult<KeyValueMap> {
+        let mut map = KeyValueMap::new();
+        loop {
+            let key_len = self.read_compact_size()?;
+            if key_len == 0 {
+                return Ok(map);
+            }
+            let key = self.read_slice(key_len)?.to_vec();
+
+            let value_len = self.read_compact_size()?;
+            let value = self.read_slice(value_len)?.to_vec();
+
+            map.insert(key, value);
+        }
+    }
+
+    fn read_compact_size(&mut self) -> SigningResult<usize> {
+        let flag = self.read_slice(1)?[0];
+        let size_len = match flag {
+            0xFD => 2,
+            0xFE => 4,
+            0xFF => 8,
+            _ => return Ok(flag as usize),
+        };
+
+        let mut size = [0_u8; 8];
+        size[..size_len].copy_from_slice(self.read_slice(size_len)?);
+        usize::try_from(u64::from_le_bytes(size))
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("PSBT key or value is too long")
+    }
+
+    fn read_slice(&mut self, len: usize) -> SigningResult<&'a [u8]> {
+        if self.bytes.len() < len {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Unexpected end of PSBT");
+        }
+        let (slice, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(slice)
+    }
+}
@@ -37,7 +37,16 @@ impl<Context: UtxoContext> PsbtRequest<Context> {
             .context("Invalid PSBT transaction version")?;
         let lock_time = psbt.unsigned_tx.lock_time.to_consensus_u32();
 
-        let public_keys = SigningRequestBuilder::<Context>::get_public_keys(input)?;
+        let mut public_keys = SigningRequestBuilder::<Context>::get_public_keys(input)?;
+        // Public keys of the UTXO signers can also be found in the PSBT itself.
+        for txin_psbt in psbt.inputs.iter() {
+            for pubkey in txin_psbt.partial_sigs.keys() {
+                public_keys.add_public_key(pubkey.to_bytes());
+            }
+            for pubkey in txin_psbt.bip32_derivation.keys() {
+                public_keys.add_public_key(pubkey.serialize().to_vec());
+            }
+        }
 
         let mut builder = TransactionBuilder::default();
         builder.version(version).lock_time(lock_time);
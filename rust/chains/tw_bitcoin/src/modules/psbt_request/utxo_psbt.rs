@@ -4,11 +4,15 @@
 
 use crate::modules::tx_builder::public_keys::PublicKeys;
 use crate::modules::tx_builder::script_parser::{StandardScript, StandardScriptParser};
+use crate::modules::tx_builder::utxo_protobuf::standard_redeem_script_signers;
 use secp256k1::ThirtyTwoByteHash;
 use tw_coin_entry::error::prelude::*;
-use tw_hash::H256;
+use tw_hash::{H256, H264};
+use tw_memory::Data;
 use tw_utxo::script::Script;
 use tw_utxo::sighash::SighashType;
+use tw_utxo::spending_data::standard_constructor::RedeemScript;
+use tw_utxo::spending_data::ScriptSigner;
 use tw_utxo::transaction::standard_transaction::builder::UtxoBuilder;
 use tw_utxo::transaction::standard_transaction::TransactionInput;
 use tw_utxo::transaction::UtxoToSign;
@@ -94,15 +98,72 @@ impl<'a> UtxoPsbt<'a> {
                 }
                 builder.p2tr_key_path_with_tweaked_pubkey(&tweaked_pubkey)
             },
-            StandardScript::P2SH(_) | StandardScript::P2WSH(_) => {
-                SigningError::err(SigningErrorType::Error_not_supported)
-                    .context("P2SH and P2WSH scriptPubkey's are not supported yet")
+            StandardScript::P2SH(_) => {
+                let redeem_script =
+                    self.redeem_script(self.utxo_psbt.redeem_script.as_ref(), "redeem_script")?;
+                Self::check_script_hash(builder.p2sh(redeem_script)?, script)
+            },
+            StandardScript::P2WSH(_) => {
+                let witness_script =
+                    self.redeem_script(self.utxo_psbt.witness_script.as_ref(), "witness_script")?;
+                Self::check_script_hash(builder.p2wsh(witness_script)?, script)
             },
             StandardScript::OpReturn(_) => SigningError::err(SigningErrorType::Error_invalid_utxo)
                 .context("Cannot spend an OP_RETURN output"),
         }
     }
 
+    /// Parses a standard redeem (or witness) script and attaches the partial signatures collected in the PSBT.
+    fn redeem_script(
+        &self,
+        script: Option<&bitcoin::ScriptBuf>,
+        field: &str,
+    ) -> SigningResult<RedeemScript> {
+        let script = script
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("'Psbt::{field}' must be set to spend a script hash UTXO"))?;
+        let script = Script::from(script.to_bytes());
+
+        let (kind, signer_pubkeys) = standard_redeem_script_signers(&script, self.public_keys)?
+            .or_tw_err(SigningErrorType::Error_not_supported)
+            .context("Only multisig, P2PK and P2PKH redeem scripts are supported for PSBT")?;
+
+        let signers = signer_pubkeys
+            .into_iter()
+            .map(|public_key| ScriptSigner {
+                public_key,
+                partial_signature: self.partial_signature(&public_key),
+            })
+            .collect();
+
+        Ok(RedeemScript {
+            script,
+            kind,
+            signers,
+        })
+    }
+
+    /// Returns an ASN.1 DER signature of the `public_key` signer if it has signed the UTXO already.
+    fn partial_signature(&self, public_key: &H264) -> Option<Data> {
+        let public_key = bitcoin::PublicKey::from_slice(public_key.as_slice()).ok()?;
+        self.utxo_psbt
+            .partial_sigs
+            .get(&public_key)
+            .map(|sig| sig.sig.serialize_der().to_vec())
+    }
+
+    /// Checks if the redeem (or witness) script corresponds to the UTXO scriptPubkey.
+    fn check_script_hash(
+        (utxo, utxo_args): (TransactionInput, UtxoToSign),
+        script_pubkey: &Script,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        if utxo_args.prevout_script_pubkey != *script_pubkey {
+            return SigningError::err(SigningErrorType::Error_invalid_utxo)
+                .context("Redeem script doesn't match the UTXO scriptPubkey");
+        }
+        Ok((utxo, utxo_args))
+    }
+
     fn prepare_builder(&self, amount: u64) -> SigningResult<UtxoBuilder> {
         let prevout_hash = H256::from(self.utxo.previous_output.txid.to_raw_hash().into_32());
         let prevout_index = self.utxo.previous_output.vout;
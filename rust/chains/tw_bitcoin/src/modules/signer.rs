@@ -2,13 +2,18 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::modules::planner::fee_bump_planner::FeeBumpPlanner;
 use crate::modules::protobuf_builder::ProtobufBuilder;
-use crate::modules::psbt::update_psbt_signed;
+use crate::modules::psbt::{
+    combine_psbts, create_psbt, finalize_psbt, psbt_to_proto, update_psbt_partially_signed,
+    update_psbt_signed,
+};
 use crate::modules::psbt_request::PsbtRequest;
 use crate::modules::signing_request::SigningRequestBuilder;
 use std::borrow::Cow;
 use std::marker::PhantomData;
 use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::SignatureBytes;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
 use tw_keypair::{ecdsa, schnorr};
@@ -45,6 +50,14 @@ impl<Context: UtxoContext> BitcoinSigner<Context> {
         match input.transaction {
             TransactionType::builder(ref tx) => Self::sign_with_tx_builder(coin, input, tx),
             TransactionType::psbt(ref psbt) => Self::sign_psbt(coin, input, psbt),
+            TransactionType::fee_bump(ref fee_bump) => {
+                let tx_builder = FeeBumpPlanner::<Context>::tx_builder(coin, input, fee_bump)?;
+                Self::sign_with_tx_builder(coin, input, &tx_builder)
+            },
+            TransactionType::psbt_create(ref tx) => Self::psbt_create(coin, input, tx),
+            TransactionType::psbt_sign(ref psbt) => Self::psbt_sign(coin, input, psbt),
+            TransactionType::psbt_combine(ref combine) => Self::psbt_combine(coin, combine),
+            TransactionType::psbt_finalize(ref psbt) => Self::psbt_finalize(coin, input, psbt),
             TransactionType::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("Either `TransactionBuilder` or `Psbt` should be set"),
         }
@@ -112,13 +125,119 @@ impl<Context: UtxoContext> BitcoinSigner<Context> {
             vsize: signed_tx.vsize() as u64,
             fee,
             weight: signed_tx.weight() as u64,
-            psbt: Some(Proto::Psbt {
-                psbt: Cow::from(psbt.serialize()),
-            }),
+            psbt: Some(psbt_to_proto(&psbt)?),
             ..Proto::SigningOutput::default()
         })
     }
 
+    /// Creates a PSBT from the transaction builder.
+    /// UTXOs are partially signed if the corresponding private keys are provided.
+    pub fn psbt_create(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        tx_builder_input: &Proto::TransactionBuilder,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let request = SigningRequestBuilder::<Context>::build(coin, input, tx_builder_input)?;
+        let SelectResult { unsigned_tx, plan } = TxPlanner::plan(request)?;
+
+        let mut psbt = create_psbt(&unsigned_tx)?;
+        if !input.private_keys.is_empty() {
+            let signatures = Self::sign_partially(input, &unsigned_tx)?;
+            update_psbt_partially_signed(&mut psbt, &unsigned_tx, &signatures)?;
+        }
+
+        Ok(Proto::SigningOutput {
+            fee: plan.fee_estimate,
+            psbt: Some(psbt_to_proto(&psbt)?),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    /// Adds partial signatures to the PSBT without finalizing it.
+    pub fn psbt_sign(
+        _coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        psbt_input: &Proto::Psbt,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let PsbtRequest {
+            mut psbt,
+            unsigned_tx,
+            ..
+        } = PsbtRequest::<Context>::build(input, psbt_input)?;
+
+        let fee = unsigned_tx.fee()?;
+
+        let signatures = Self::sign_partially(input, &unsigned_tx)?;
+        update_psbt_partially_signed(&mut psbt, &unsigned_tx, &signatures)?;
+
+        Ok(Proto::SigningOutput {
+            fee,
+            psbt: Some(psbt_to_proto(&psbt)?),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    /// Combines several PSBTs of the same unsigned transaction into one.
+    pub fn psbt_combine(
+        _coin: &dyn CoinContext,
+        combine: &Proto::PsbtCombine,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let psbt = combine_psbts(&combine.psbts)?;
+
+        Ok(Proto::SigningOutput {
+            psbt: Some(psbt_to_proto(&psbt)?),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    /// Finalizes the PSBT from its partial signatures and extracts the signed transaction.
+    pub fn psbt_finalize(
+        _coin: &dyn CoinContext,
+        input: &Proto::SigningInput,
+        psbt_input: &Proto::Psbt,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let PsbtRequest {
+            mut psbt,
+            unsigned_tx,
+            ..
+        } = PsbtRequest::<Context>::build(input, psbt_input)?;
+
+        let fee = unsigned_tx.fee()?;
+        let signed_tx = finalize_psbt(&mut psbt, unsigned_tx)?;
+
+        Ok(Proto::SigningOutput {
+            transaction: Some(ProtobufBuilder::tx_to_proto(&signed_tx)),
+            encoded: Cow::from(signed_tx.encode_out()),
+            txid: Cow::from(signed_tx.txid()),
+            vsize: signed_tx.vsize() as u64,
+            fee,
+            weight: signed_tx.weight() as u64,
+            psbt: Some(psbt_to_proto(&psbt)?),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    /// Signs the sighashes that correspond to the given private keys.
+    /// Fails if none of the sighashes can be signed.
+    fn sign_partially(
+        input: &Proto::SigningInput,
+        unsigned_tx: &UnsignedTransaction<Transaction>,
+    ) -> SigningResult<Vec<SignatureBytes>> {
+        let keys_manager = Self::keys_manager_for_tx(
+            &input.private_keys,
+            unsigned_tx,
+            input.dangerous_use_fixed_schnorr_rng,
+        )?;
+
+        let signatures = TxSigner::sign_tx_partially(unsigned_tx, &keys_manager)?;
+
+        if signatures.iter().all(|signature| signature.is_empty()) {
+            return SigningError::err(SigningErrorType::Error_missing_private_key)
+                .context("None of the UTXOs can be signed with the given private keys");
+        }
+        Ok(signatures)
+    }
+
     fn keys_manager_for_tx<P>(
         private_keys: &[P],
         unsigned_tx: &UnsignedTransaction<Transaction>,
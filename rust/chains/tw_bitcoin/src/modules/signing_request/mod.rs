@@ -20,6 +20,9 @@ use tw_utxo::transaction::standard_transaction::Transaction;
 use Proto::mod_TransactionBuilder::OneOfdust_policy as ProtoDustPolicy;
 
 const DEFAULT_TX_VERSION: u32 = 1;
+/// The long-term fee rate used by the Branch-and-Bound input selector if not specified.
+/// Matches Bitcoin Core's default `-consolidatefeerate` of 10 sat/vB.
+const DEFAULT_LONG_TERM_FEE_PER_VB: i64 = 10;
 
 pub type StandardSigningRequest = PlanRequest<Transaction>;
 
@@ -91,7 +94,7 @@ impl<Context: UtxoContext> SigningRequestBuilder<Context> {
             })
             .transpose()?;
 
-        let input_selector = Self::input_selector(&transaction_builder.input_selector);
+        let input_selector = Self::input_selector(transaction_builder);
 
         let unsigned_tx = builder.build()?;
         Ok(StandardSigningRequest {
@@ -121,11 +124,19 @@ impl<Context: UtxoContext> SigningRequestBuilder<Context> {
         Ok(public_keys)
     }
 
-    fn input_selector(selector: &Proto::InputSelector) -> InputSelector {
-        match selector {
+    fn input_selector(transaction_builder: &Proto::TransactionBuilder) -> InputSelector {
+        match transaction_builder.input_selector {
             Proto::InputSelector::SelectAscending => InputSelector::Ascending,
             Proto::InputSelector::SelectInOrder => InputSelector::InOrder,
             Proto::InputSelector::SelectDescending => InputSelector::Descending,
+            Proto::InputSelector::SelectBranchAndBound => {
+                let long_term_fee_rate = if transaction_builder.long_term_fee_per_vb > 0 {
+                    transaction_builder.long_term_fee_per_vb
+                } else {
+                    DEFAULT_LONG_TERM_FEE_PER_VB
+                };
+                InputSelector::BranchAndBound { long_term_fee_rate }
+            },
             Proto::InputSelector::UseAll => InputSelector::UseAll,
         }
     }
@@ -133,6 +144,15 @@ impl<Context: UtxoContext> SigningRequestBuilder<Context> {
     fn dust_policy(proto: &ProtoDustPolicy) -> SigningResult<DustPolicy> {
         match proto {
             ProtoDustPolicy::fixed_dust_threshold(fixed) => Ok(DustPolicy::FixedAmount(*fixed)),
+            ProtoDustPolicy::dust_relay_fee_per_kvb(fee_rate) => {
+                if *fee_rate < 0 {
+                    return SigningError::err(SigningErrorType::Error_invalid_params)
+                        .context("Dust relay fee rate cannot be negative");
+                }
+                Ok(DustPolicy::Dynamic {
+                    dust_relay_fee_per_kvb: *fee_rate,
+                })
+            },
             ProtoDustPolicy::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("No dust policy provided"),
         }
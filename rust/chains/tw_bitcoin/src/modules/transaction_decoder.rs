@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::protobuf_builder::ProtobufBuilder;
+use crate::modules::signing_request::SigningRequestBuilder;
+use bitcoin::hashes::Hash;
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_hash::H256;
+use tw_proto::BitcoinV2::Proto;
+use tw_utxo::context::UtxoContext;
+use tw_utxo::script::{Script, Witness};
+use tw_utxo::transaction::standard_transaction::{
+    Transaction, TransactionInput, TransactionOutput,
+};
+use tw_utxo::transaction::transaction_interface::TransactionInterface;
+use tw_utxo::transaction::transaction_parts::OutPoint;
+
+pub struct BitcoinTransactionDecoder<Context: UtxoContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: UtxoContext> Default for BitcoinTransactionDecoder<Context> {
+    fn default() -> Self {
+        BitcoinTransactionDecoder {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Context> TransactionDecoder for BitcoinTransactionDecoder<Context>
+where
+    Context: UtxoContext,
+    Context::Address: fmt::Display,
+{
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl<Context> BitcoinTransactionDecoder<Context>
+where
+    Context: UtxoContext,
+    Context::Address: fmt::Display,
+{
+    pub(crate) fn decode_transaction_impl(
+        coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let decoded_tx = Self::parse_transaction(tx)?;
+        let chain_info = SigningRequestBuilder::<Context>::chain_info(coin, &None)?;
+
+        let output_addresses = decoded_tx
+            .outputs
+            .iter()
+            .map(|output| {
+                let address = Context::addr_from_script_pubkey(
+                    &output.script_pubkey,
+                    chain_info.to_address_prefixes(),
+                    chain_info.hrp.clone(),
+                )
+                .into_tw()
+                .context("Error recovering an output address")?;
+                Ok(Cow::from(
+                    address.map(|addr| addr.to_string()).unwrap_or_default(),
+                ))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(ProtobufBuilder::tx_to_proto(&decoded_tx)),
+            output_addresses,
+            txid: Cow::from(decoded_tx.txid()),
+            vsize: decoded_tx.vsize() as u64,
+            weight: decoded_tx.weight() as u64,
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+}
+
+impl<Context: UtxoContext> BitcoinTransactionDecoder<Context> {
+    /// Parses a legacy or segwit raw transaction.
+    pub fn parse_transaction(tx: &[u8]) -> SigningResult<Transaction> {
+        let tx: bitcoin::Transaction = bitcoin::consensus::deserialize(tx)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Error decoding a raw transaction")?;
+
+        let inputs = tx.input.iter().map(|txin| {
+            let mut witness = Witness::default();
+            for item in txin.witness.iter() {
+                witness.push_item(Script::from(item.to_vec()));
+            }
+            TransactionInput {
+                previous_output: OutPoint {
+                    hash: H256::from(txin.previous_output.txid.to_byte_array()),
+                    index: txin.previous_output.vout,
+                },
+                sequence: txin.sequence.0,
+                script_sig: Script::from(txin.script_sig.to_bytes()),
+                witness,
+            }
+        });
+
+        let outputs = tx
+            .output
+            .iter()
+            .map(|txout| {
+                let value = txout
+                    .value
+                    .try_into()
+                    .tw_err(|_| SigningErrorType::Error_invalid_utxo_amount)
+                    .context("Transaction output amount is too large")?;
+                Ok(TransactionOutput {
+                    value,
+                    script_pubkey: Script::from(txout.script_pubkey.to_bytes()),
+                })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(Transaction {
+            version: tx.version,
+            inputs: inputs.collect(),
+            outputs,
+            locktime: tx.lock_time.to_consensus_u32(),
+        })
+    }
+}
@@ -8,14 +8,18 @@ use crate::modules::tx_builder::BitcoinChainInfo;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
-use tw_hash::{H160, H256};
+use tw_hash::{H160, H256, H264};
 use tw_keypair::{ecdsa, schnorr};
 use tw_memory::Data;
 use tw_misc::traits::ToBytesVec;
 use tw_proto::BitcoinV2::Proto;
 use tw_utxo::context::UtxoContext;
+use tw_utxo::script::standard_script::conditions;
+use tw_utxo::script::taproot::{TapLeaf, TapLeafSpendInfo, EVEN_Y_PREFIX};
 use tw_utxo::script::Script;
 use tw_utxo::sighash::SighashType;
+use tw_utxo::spending_data::standard_constructor::{RedeemScript, RedeemScriptKind};
+use tw_utxo::spending_data::ScriptSigner;
 use tw_utxo::transaction::standard_transaction::builder::UtxoBuilder;
 use tw_utxo::transaction::standard_transaction::TransactionInput;
 use tw_utxo::transaction::transaction_parts::OutPoint;
@@ -48,13 +52,15 @@ impl<'a, Context: UtxoContext> UtxoProtobuf<'a, Context> {
 
         match self.input.claiming_script {
             ScriptType::script_builder(ref builder) => match builder.variant {
-                // BuilderType::p2sh(ref redeem_script) => self.p2sh(redeem_script.to_vec()),
+                BuilderType::p2sh(ref script_hash) => self.p2sh(script_hash),
                 BuilderType::p2pk(ref pubkey) => self.p2pk(pubkey),
                 BuilderType::p2pkh(ref pubkey_or_hash) => self.p2pkh(pubkey_or_hash),
-                // BuilderType::p2wsh(ref redeem_script) => self.p2wsh(redeem_script.to_vec()),
+                BuilderType::p2wsh(ref script_hash) => self.p2wsh(script_hash),
                 BuilderType::p2wpkh(ref pubkey_or_hash) => self.p2wpkh(pubkey_or_hash),
                 BuilderType::p2tr_key_path(ref key_path) => self.p2tr_key_path(key_path),
-                // BuilderType::p2tr_script_path(ref script) => self.p2tr_script_path(script),
+                BuilderType::p2tr_script_path(ref script_path) => {
+                    self.p2tr_script_path(script_path)
+                },
                 BuilderType::brc20_inscribe(ref inscription) => self.brc20_inscribe(inscription),
                 BuilderType::None => SigningError::err(SigningErrorType::Error_invalid_params)
                     .context("No Input Builder type provided"),
@@ -66,11 +72,15 @@ impl<'a, Context: UtxoContext> UtxoProtobuf<'a, Context> {
         }
     }
 
-    // TODO next iteration
-    // pub fn p2sh(&self, redeem_script: Data) -> SigningResult<(TransactionInput, UtxoToSign)> {
-    //     let redeem_script = Script::from(redeem_script);
-    //     self.prepare_builder()?.p2sh(redeem_script)
-    // }
+    pub fn p2sh(
+        &self,
+        script_hash: &Proto::mod_Input::InputScriptHash,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        let redeem_script = self
+            .redeem_script_from_proto(script_hash)
+            .context("Invalid P2SH redeem script")?;
+        self.prepare_builder()?.p2sh(redeem_script)
+    }
 
     pub fn p2pk(&self, pubkey: &[u8]) -> SigningResult<(TransactionInput, UtxoToSign)> {
         let pubkey = ecdsa::secp256k1::PublicKey::try_from(pubkey)
@@ -88,11 +98,15 @@ impl<'a, Context: UtxoContext> UtxoProtobuf<'a, Context> {
         self.prepare_builder()?.p2pkh(&pubkey_hash)
     }
 
-    // TODO next iteration
-    // pub fn p2wsh(&self, redeem_script: Data) -> SigningResult<(TransactionInput, UtxoToSign)> {
-    //     let script = Script::from(redeem_script);
-    //     self.prepare_builder()?.p2wsh(script)
-    // }
+    pub fn p2wsh(
+        &self,
+        script_hash: &Proto::mod_Input::InputScriptHash,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        let witness_script = self
+            .redeem_script_from_proto(script_hash)
+            .context("Invalid P2WSH witness script")?;
+        self.prepare_builder()?.p2wsh(witness_script)
+    }
 
     pub fn p2wpkh(
         &self,
@@ -107,16 +121,63 @@ impl<'a, Context: UtxoContext> UtxoProtobuf<'a, Context> {
         self.prepare_builder()?.p2tr_key_path(&public_key)
     }
 
-    // TODO next iteration
-    // pub fn p2tr_script_path(
-    //     &self,
-    //     taproot_script_path: &Proto::mod_Input::InputTaprootScriptPath,
-    // ) -> SigningResult<(TransactionInput, UtxoToSign)> {
-    //     let payload = Script::from(taproot_script_path.payload.to_vec());
-    //     // let x = taproot_script_path.
-    //     self.prepare_builder()?
-    //         .p2tr_script_path(payload, taproot_script_path.control_block.to_vec())
-    // }
+    pub fn p2tr_script_path(
+        &self,
+        script_path: &Proto::mod_Input::InputTaprootScriptPath,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        use Proto::mod_Input::mod_InputTaprootScriptPath::OneOfspend_info as SpendInfoType;
+
+        let signer = schnorr_public_key_from_proto(&script_path.public_key)
+            .context("Invalid Taproot script-path signer public key")?;
+        let payload = Script::from(script_path.payload.to_vec());
+
+        let spend_info = match script_path.spend_info {
+            SpendInfoType::control_block(ref control_block) => {
+                TapLeafSpendInfo::with_control_block(payload, control_block)?
+            },
+            SpendInfoType::merkle_branch(ref branch) => {
+                let internal_key = schnorr::XOnlyPublicKey::try_from(branch.internal_key.as_ref())
+                    .into_tw()
+                    .context("Invalid Taproot internal key")?;
+                let hashes = branch
+                    .hashes
+                    .iter()
+                    .map(|hash| {
+                        H256::try_from(hash.as_ref())
+                            .tw_err(|_| SigningErrorType::Error_invalid_params)
+                            .context("Taproot merkle branch hash must be 32 bytes")
+                    })
+                    .collect::<SigningResult<Vec<_>>>()?;
+                TapLeafSpendInfo::with_merkle_branch(payload, &internal_key, &hashes)?
+            },
+            SpendInfoType::script_tree(ref tree) => {
+                let internal_key = schnorr::XOnlyPublicKey::try_from(tree.internal_key.as_ref())
+                    .into_tw()
+                    .context("Invalid Taproot internal key")?;
+                let leaves = tree
+                    .leaves
+                    .iter()
+                    .map(|leaf| {
+                        let depth = u8::try_from(leaf.depth)
+                            .tw_err(|_| SigningErrorType::Error_invalid_params)
+                            .context("Taproot leaf depth is too large")?;
+                        Ok(TapLeaf {
+                            depth,
+                            script: Script::from(leaf.script.to_vec()),
+                        })
+                    })
+                    .collect::<SigningResult<Vec<_>>>()?;
+                TapLeafSpendInfo::with_tap_tree(payload, &internal_key, &leaves)?
+            },
+            SpendInfoType::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No Taproot control block, merkle branch or script tree provided")
+            },
+        };
+
+        self.prepare_builder()?
+            .p2tr_script_path(&signer, spend_info)
+    }
 
     pub fn brc20_inscribe(
         &self,
@@ -197,6 +258,76 @@ impl<'a, Context: UtxoContext> UtxoProtobuf<'a, Context> {
             .sighash_type(sighash_ty))
     }
 
+    /// Parses the redeem (or witness) script, finds its signers and attaches the signatures
+    /// collected on the previous signing rounds.
+    fn redeem_script_from_proto(
+        &self,
+        script_hash: &Proto::mod_Input::InputScriptHash,
+    ) -> SigningResult<RedeemScript> {
+        let script = Script::from(script_hash.redeem_script.to_vec());
+        let (kind, signer_pubkeys) = self.redeem_script_signers(&script, &script_hash.signers)?;
+
+        let mut signers: Vec<_> = signer_pubkeys
+            .into_iter()
+            .map(|public_key| ScriptSigner {
+                public_key,
+                partial_signature: None,
+            })
+            .collect();
+
+        for partial in script_hash.partial_signatures.iter() {
+            let public_key = H264::try_from(partial.public_key.as_ref())
+                .tw_err(|_| SigningErrorType::Error_invalid_params)
+                .context("Expected a compressed public key of the partial signature")?;
+
+            let signer = signers
+                .iter_mut()
+                .find(|signer| signer.public_key == public_key)
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .with_context(|| {
+                    format!("Partial signature public key '{public_key}' is not a script signer")
+                })?;
+            signer.partial_signature = Some(partial.signature.to_vec());
+        }
+
+        Ok(RedeemScript {
+            script,
+            kind,
+            signers,
+        })
+    }
+
+    /// Returns the redeem script kind and compressed public keys of its signers.
+    fn redeem_script_signers<T: AsRef<[u8]>>(
+        &self,
+        script: &Script,
+        custom_signers: &[T],
+    ) -> SigningResult<(RedeemScriptKind, Vec<H264>)> {
+        match standard_redeem_script_signers(script, self.public_keys)? {
+            Some(_) if !custom_signers.is_empty() => {
+                SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Signers must not be specified for a standard redeem script")
+            },
+            Some(recognized) => Ok(recognized),
+            None if custom_signers.is_empty() => {
+                SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Signers must be specified for a custom redeem script")
+            },
+            None => {
+                let signers = custom_signers
+                    .iter()
+                    .map(|signer| {
+                        ecdsa::secp256k1::PublicKey::try_from(signer.as_ref())
+                            .map(|pubkey| pubkey.compressed())
+                            .into_tw()
+                            .context("Expected a valid ecdsa secp256k1 signer public key")
+                    })
+                    .collect::<SigningResult<Vec<_>>>()?;
+                Ok((RedeemScriptKind::Custom, signers))
+            },
+        }
+    }
+
     /// Tries to convert [`Proto::PublicKeyOrHash`] to [`Hash<N>`].
     /// Please note `P2PKH` and `P2WPKH` use the same `ripemd(sha256(x))` hash function.
     fn get_ecdsa_pubkey_from_proto(
@@ -225,6 +356,27 @@ impl<'a, Context: UtxoContext> UtxoProtobuf<'a, Context> {
     }
 }
 
+/// Returns the kind and compressed public keys of the signers of a standard redeem (or witness) script,
+/// i.e. multisig, P2PK or P2PKH. Returns `None` if the script is not standard.
+pub fn standard_redeem_script_signers(
+    script: &Script,
+    public_keys: &PublicKeys,
+) -> SigningResult<Option<(RedeemScriptKind, Vec<H264>)>> {
+    if let Some((threshold, pubkeys)) = conditions::match_multisig(script) {
+        Ok(Some((RedeemScriptKind::Multisig { threshold }, pubkeys)))
+    } else if let Some(pubkey) = conditions::match_p2pk(script) {
+        let pubkey = ecdsa::secp256k1::PublicKey::try_from(pubkey)
+            .into_tw()
+            .context("Invalid P2PK public key")?;
+        Ok(Some((RedeemScriptKind::P2PK, vec![pubkey.compressed()])))
+    } else if let Some(pubkey_hash) = conditions::match_p2pkh(script) {
+        let pubkey = public_keys.get_ecdsa_public_key(&pubkey_hash)?;
+        Ok(Some((RedeemScriptKind::P2PKH, vec![pubkey.compressed()])))
+    } else {
+        Ok(None)
+    }
+}
+
 pub fn parse_out_point(maybe_out_point: &Option<Proto::OutPoint>) -> SigningResult<OutPoint> {
     let out_point = maybe_out_point
         .as_ref()
@@ -240,3 +392,15 @@ pub fn parse_out_point(maybe_out_point: &Option<Proto::OutPoint>) -> SigningResu
         index: out_point.vout,
     })
 }
+
+/// Parses a Schnorr public key given as 33 bytes compressed or 32 bytes x-only.
+/// The x-only key is lifted to a compressed public key with an even Y coordinate.
+fn schnorr_public_key_from_proto(pubkey: &[u8]) -> SigningResult<schnorr::PublicKey> {
+    if pubkey.len() == H256::LEN {
+        let mut compressed = H264::default();
+        compressed[0] = EVEN_Y_PREFIX;
+        compressed[1..].copy_from_slice(pubkey);
+        return schnorr::PublicKey::try_from(compressed.as_slice()).into_tw();
+    }
+    schnorr::PublicKey::try_from(pubkey).into_tw()
+}
@@ -35,6 +35,16 @@ pub struct CashAddress {
 }
 
 impl CashAddress {
+    pub fn new(hrp: String, ty: CashAddressType, key_hash: H160) -> AddressResult<CashAddress> {
+        let address_str = Self::encode(&hrp, ty, &key_hash)?;
+        Ok(CashAddress {
+            hrp,
+            ty,
+            key_hash,
+            address_str,
+        })
+    }
+
     pub fn p2pkh_with_coin(
         coin: &dyn CoinContext,
         public_key: &ecdsa::secp256k1::PublicKey,
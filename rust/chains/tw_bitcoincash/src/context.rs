@@ -3,7 +3,12 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::Address;
+use crate::cash_address::{CashAddress, CashAddressType};
+use tw_bitcoin::modules::tx_builder::script_parser::{StandardScript, StandardScriptParser};
 use tw_coin_entry::error::prelude::*;
+use tw_hash::hasher::sha256_ripemd;
+use tw_hash::H160;
+use tw_utxo::address::legacy::LegacyAddress;
 use tw_utxo::context::{AddressPrefixes, UtxoContext};
 use tw_utxo::script::Script;
 
@@ -28,4 +33,41 @@ impl UtxoContext for BitcoinCashContext {
             },
         }
     }
+
+    fn addr_from_script_pubkey(
+        script_pubkey: &Script,
+        prefixes: AddressPrefixes,
+        hrp: Option<String>,
+    ) -> AddressResult<Option<Self::Address>> {
+        let Ok(script) = StandardScriptParser.parse(script_pubkey) else {
+            return Ok(None);
+        };
+        let (ty, key_hash) = match script {
+            // Display P2PK output as P2PKH.
+            StandardScript::P2PK(pubkey) => {
+                let key_hash = sha256_ripemd(pubkey.compressed().as_slice());
+                let key_hash =
+                    H160::try_from(key_hash.as_slice()).expect("sha256_ripemd returns 20 bytes");
+                (CashAddressType::P2PKH, key_hash)
+            },
+            StandardScript::P2PKH(key_hash) => (CashAddressType::P2PKH, key_hash),
+            StandardScript::P2SH(key_hash) => (CashAddressType::P2SH, key_hash),
+            // BitcoinCash doesn't support Segwit and Taproot addresses.
+            _ => return Ok(None),
+        };
+
+        // Fallback to a legacy address if the chain has no CashAddress prefix.
+        let Some(hrp) = hrp else {
+            let prefix = match ty {
+                CashAddressType::P2PKH => prefixes.p2pkh_prefix,
+                CashAddressType::P2SH => prefixes.p2sh_prefix,
+            };
+            return LegacyAddress::new(prefix, key_hash.as_slice())
+                .map(Address::Legacy)
+                .map(Some);
+        };
+        CashAddress::new(hrp, ty, key_hash)
+            .map(Address::Cash)
+            .map(Some)
+    }
 }
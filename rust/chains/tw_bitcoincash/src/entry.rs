@@ -8,6 +8,7 @@ use std::str::FromStr;
 use tw_bitcoin::modules::compiler::BitcoinCompiler;
 use tw_bitcoin::modules::planner::BitcoinPlanner;
 use tw_bitcoin::modules::signer::BitcoinSigner;
+use tw_bitcoin::modules::transaction_decoder::BitcoinTransactionDecoder;
 use tw_bitcoin::modules::transaction_util::BitcoinTransactionUtil;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
@@ -15,7 +16,6 @@ use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::BitcoinBase58Prefix;
 use tw_keypair::tw::PublicKey;
@@ -36,7 +36,7 @@ impl CoinEntry for BitcoinCashEntry {
     type PlanBuilder = BitcoinPlanner<BitcoinCashContext>;
     type MessageSigner = NoMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = BitcoinTransactionDecoder<BitcoinCashContext>;
     type TransactionUtil = BitcoinTransactionUtil;
 
     #[inline]
@@ -98,6 +98,11 @@ impl CoinEntry for BitcoinCashEntry {
         Some(BitcoinPlanner::<BitcoinCashContext>::default())
     }
 
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(BitcoinTransactionDecoder::<BitcoinCashContext>::default())
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(BitcoinTransactionUtil)
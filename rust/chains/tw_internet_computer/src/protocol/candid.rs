@@ -0,0 +1,273 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! A minimal Candid encoder that covers the types used by the ICRC ledger methods.
+//! See: https://github.com/dfinity/candid/blob/master/spec/Candid.md#binary-format
+
+use super::principal::Principal;
+
+/// Magic number that all the Candid messages start with.
+const MAGIC_NUMBER: &[u8; 4] = b"DIDL";
+
+const TYPE_NAT: i64 = -3;
+const TYPE_NAT8: i64 = -5;
+const TYPE_NAT64: i64 = -8;
+const TYPE_OPT: i64 = -18;
+const TYPE_VEC: i64 = -19;
+const TYPE_RECORD: i64 = -20;
+const TYPE_PRINCIPAL: i64 = -24;
+
+/// Candid types supported by the encoder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CandidType {
+    Nat,
+    Nat64,
+    Principal,
+    /// `vec nat8`
+    Blob,
+    Opt(Box<CandidType>),
+    Record(Vec<(&'static str, CandidType)>),
+}
+
+/// Candid values supported by the encoder.
+#[derive(Debug, Clone)]
+pub enum CandidValue {
+    /// Unbounded natural number given as big endian bytes.
+    Nat(Vec<u8>),
+    Nat64(u64),
+    Principal(Principal),
+    Blob(Vec<u8>),
+    /// Optional value. The inner type is required to encode `null`.
+    Opt(CandidType, Option<Box<CandidValue>>),
+    Record(Vec<(&'static str, CandidValue)>),
+}
+
+impl CandidValue {
+    /// Returns an optional value.
+    pub fn opt(ty: CandidType, value: Option<CandidValue>) -> Self {
+        CandidValue::Opt(ty, value.map(Box::new))
+    }
+
+    /// Returns the type of the value.
+    pub fn candid_type(&self) -> CandidType {
+        match self {
+            CandidValue::Nat(_) => CandidType::Nat,
+            CandidValue::Nat64(_) => CandidType::Nat64,
+            CandidValue::Principal(_) => CandidType::Principal,
+            CandidValue::Blob(_) => CandidType::Blob,
+            CandidValue::Opt(ty, _) => CandidType::Opt(Box::new(ty.clone())),
+            CandidValue::Record(fields) => CandidType::Record(
+                fields
+                    .iter()
+                    .map(|(name, value)| (*name, value.candid_type()))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            CandidValue::Nat(be_bytes) => buf.extend(leb128_from_big_endian(be_bytes)),
+            CandidValue::Nat64(value) => buf.extend_from_slice(&value.to_le_bytes()),
+            CandidValue::Principal(principal) => {
+                // The principal is transparent, i.e. not an opaque reference.
+                buf.push(1);
+                encode_bytes(principal.as_slice(), buf);
+            },
+            CandidValue::Blob(bytes) => encode_bytes(bytes, buf),
+            CandidValue::Opt(_, None) => buf.push(0),
+            CandidValue::Opt(_, Some(value)) => {
+                buf.push(1);
+                value.encode(buf);
+            },
+            CandidValue::Record(fields) => {
+                for (_, value) in sorted_by_field_hash(fields) {
+                    value.encode(buf);
+                }
+            },
+        }
+    }
+}
+
+/// Encodes the given values as the arguments of a canister method.
+pub fn encode_args(args: &[CandidValue]) -> Vec<u8> {
+    let mut type_table = TypeTable::default();
+    let arg_types: Vec<i64> = args
+        .iter()
+        .map(|arg| type_table.type_ref(&arg.candid_type()))
+        .collect();
+
+    let mut buf = MAGIC_NUMBER.to_vec();
+    leb128(type_table.entries.len() as u64, &mut buf);
+    for entry in type_table.entries.iter() {
+        buf.extend_from_slice(entry);
+    }
+
+    leb128(arg_types.len() as u64, &mut buf);
+    for arg_type in arg_types {
+        sleb128(arg_type, &mut buf);
+    }
+
+    for arg in args {
+        arg.encode(&mut buf);
+    }
+    buf
+}
+
+/// The type table of a Candid message.
+/// Composite types are stored in the table once and referenced by their indexes.
+#[derive(Default)]
+struct TypeTable {
+    entries: Vec<Vec<u8>>,
+}
+
+impl TypeTable {
+    /// Returns a negative opcode of a primitive type or an index of a composite type in the table.
+    fn type_ref(&mut self, ty: &CandidType) -> i64 {
+        let mut entry = Vec::new();
+        match ty {
+            CandidType::Nat => return TYPE_NAT,
+            CandidType::Nat64 => return TYPE_NAT64,
+            CandidType::Principal => return TYPE_PRINCIPAL,
+            CandidType::Blob => {
+                sleb128(TYPE_VEC, &mut entry);
+                sleb128(TYPE_NAT8, &mut entry);
+            },
+            CandidType::Opt(inner) => {
+                let inner = self.type_ref(inner);
+                sleb128(TYPE_OPT, &mut entry);
+                sleb128(inner, &mut entry);
+            },
+            CandidType::Record(fields) => {
+                let fields: Vec<_> = sorted_by_field_hash(fields)
+                    .into_iter()
+                    .map(|(hash, ty)| (hash, self.type_ref(ty)))
+                    .collect();
+                sleb128(TYPE_RECORD, &mut entry);
+                leb128(fields.len() as u64, &mut entry);
+                for (hash, ty) in fields {
+                    leb128(hash as u64, &mut entry);
+                    sleb128(ty, &mut entry);
+                }
+            },
+        }
+
+        let index = match self.entries.iter().position(|existing| *existing == entry) {
+            Some(index) => index,
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            },
+        };
+        index as i64
+    }
+}
+
+/// Returns the ID of a record field.
+fn field_hash(name: &str) -> u32 {
+    name.bytes().fold(0_u32, |hash, byte| {
+        hash.wrapping_mul(223).wrapping_add(byte as u32)
+    })
+}
+
+/// Record fields must be ordered by their IDs.
+fn sorted_by_field_hash<'a, T>(fields: &'a [(&'static str, T)]) -> Vec<(u32, &'a T)> {
+    let mut fields: Vec<_> = fields
+        .iter()
+        .map(|(name, value)| (field_hash(name), value))
+        .collect();
+    fields.sort_by_key(|(hash, _)| *hash);
+    fields
+}
+
+fn encode_bytes(bytes: &[u8], buf: &mut Vec<u8>) {
+    leb128(bytes.len() as u64, buf);
+    buf.extend_from_slice(bytes);
+}
+
+fn leb128(mut value: u64, buf: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            break;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+fn sleb128(mut value: i64, buf: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let sign_bit_clear = byte & 0x40 == 0;
+        if (value == 0 && sign_bit_clear) || (value == -1 && !sign_bit_clear) {
+            buf.push(byte);
+            break;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+/// Encodes an unbounded natural number given as big endian bytes into LEB128.
+fn leb128_from_big_endian(be_bytes: &[u8]) -> Vec<u8> {
+    let le_bytes: Vec<u8> = be_bytes.iter().rev().copied().collect();
+    let bit_len = le_bytes
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .map(|i| i * 8 + (8 - le_bytes[i].leading_zeros() as usize))
+        .unwrap_or(0);
+    let bit = |i: usize| le_bytes.get(i / 8).map_or(0, |byte| (byte >> (i % 8)) & 1);
+
+    // Zero is encoded as a single byte.
+    let groups = bit_len.div_ceil(7).max(1);
+    (0..groups)
+        .map(|group| {
+            let mut byte = (0..7).fold(0, |byte, j| byte | (bit(group * 7 + j) << j));
+            if group + 1 < groups {
+                byte |= 0x80;
+            }
+            byte
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tw_encoding::hex;
+
+    #[test]
+    fn field_hash_of_icrc_account() {
+        assert_eq!(field_hash("owner"), 947296307);
+        assert_eq!(field_hash("subaccount"), 1349681965);
+    }
+
+    #[test]
+    fn encode_nat() {
+        let mut buf = Vec::new();
+        CandidValue::Nat(vec![]).encode(&mut buf);
+        CandidValue::Nat(vec![0x00, 0x7f]).encode(&mut buf);
+        CandidValue::Nat(vec![0x05, 0xf5, 0xe1, 0x00]).encode(&mut buf);
+        assert_eq!(hex::encode(buf, false), "007f80c2d72f");
+    }
+
+    #[test]
+    fn encode_empty_args() {
+        assert_eq!(hex::encode(encode_args(&[]), false), "4449444c0000");
+    }
+
+    #[test]
+    fn encode_account_record() {
+        let account = CandidValue::Record(vec![
+            ("owner", CandidValue::Principal(Principal::anonymous())),
+            ("subaccount", CandidValue::opt(CandidType::Blob, None)),
+        ]);
+        assert_eq!(
+            hex::encode(encode_args(&[account]), false),
+            "4449444c036d7b6e006c02b3b0dac30368ad86ca830501010201010400"
+        );
+    }
+}
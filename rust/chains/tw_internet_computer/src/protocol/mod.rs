@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod candid;
 pub mod envelope;
 pub mod identity;
 pub mod principal;
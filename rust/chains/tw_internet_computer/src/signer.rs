@@ -27,9 +27,15 @@ impl From<transactions::SignTransactionError> for SigningError {
             | transactions::SignTransactionError::EncodingArgsFailed => {
                 SigningError::new(CommonError::Error_internal)
             },
-            transactions::SignTransactionError::InvalidToAccountIdentifier => {
+            transactions::SignTransactionError::InvalidToAccountIdentifier
+            | transactions::SignTransactionError::InvalidCanisterId
+            | transactions::SignTransactionError::InvalidPrincipal => {
                 SigningError::new(CommonError::Error_invalid_address)
             },
+            transactions::SignTransactionError::InvalidSubaccount
+            | transactions::SignTransactionError::InvalidMemo => {
+                SigningError::new(CommonError::Error_invalid_params)
+            },
             transactions::SignTransactionError::InvalidAmount => {
                 SigningError::new(CommonError::Error_invalid_requested_token_amount)
             },
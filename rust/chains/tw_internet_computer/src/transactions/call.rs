@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::time::Duration;
+
+use tw_keypair::ecdsa::secp256k1::PrivateKey;
+
+use crate::protocol::{
+    envelope::{
+        Envelope, EnvelopeCallContent, EnvelopeReadStateContent, Label, RepresentationHashable,
+    },
+    get_ingress_expiry,
+    identity::Identity,
+    principal::Principal,
+    request_id::RequestId,
+    rosetta,
+};
+
+use super::SignTransactionError;
+
+/// Arguments to be used with [call] to create a signed transaction envelope pair.
+#[derive(Clone, Debug)]
+pub struct CallArgs {
+    /// The ID of the canister to be called.
+    pub canister_id: Principal,
+    /// The name of the canister method to be called.
+    pub method_name: String,
+    /// The encoded argument to pass to the canister method.
+    pub arg: Vec<u8>,
+    /// The current timestamp in nanoseconds.
+    pub current_timestamp_nanos: u64,
+    /// The duration to tune up ingress expiry in seconds.
+    pub permitted_drift: Option<u64>,
+}
+
+/// Given a secp256k1 private key and the canister method call args,
+/// this function creates a signed update call along with the read state call
+/// that is used to check the result of the update call.
+pub fn call(
+    private_key: PrivateKey,
+    args: CallArgs,
+) -> Result<rosetta::SignedTransaction, SignTransactionError> {
+    if args.method_name.is_empty() {
+        return Err(SignTransactionError::InvalidArguments);
+    }
+
+    let current_timestamp_duration = Duration::from_nanos(args.current_timestamp_nanos);
+    let ingress_expiry = get_ingress_expiry(current_timestamp_duration, args.permitted_drift);
+    let identity = Identity::new(private_key);
+
+    // Create the update envelope.
+    let (request_id, update_envelope) = create_update_envelope(
+        &identity,
+        args.canister_id,
+        args.method_name,
+        args.arg,
+        ingress_expiry,
+    )?;
+
+    // Create the read state envelope.
+    let (_, read_state_envelope) =
+        create_read_state_envelope(&identity, request_id, ingress_expiry)?;
+
+    // Create a new EnvelopePair with the update call and read_state envelopes.
+    let envelope_pair = rosetta::EnvelopePair::new(update_envelope, read_state_envelope)
+        .map_err(|_| SignTransactionError::InvalidEnvelopePair)?;
+
+    // Create a signed transaction containing the envelope pair.
+    let request: rosetta::Request = (rosetta::RequestType::Send, vec![envelope_pair]);
+    Ok(vec![request])
+}
+
+#[inline]
+fn create_update_envelope(
+    identity: &Identity,
+    canister_id: Principal,
+    method_name: String,
+    arg: Vec<u8>,
+    ingress_expiry: u64,
+) -> Result<(RequestId, Envelope<EnvelopeCallContent>), SignTransactionError> {
+    let sender = identity.sender();
+    let content = EnvelopeCallContent {
+        nonce: None,
+        ingress_expiry,
+        sender,
+        canister_id,
+        method_name,
+        arg,
+    };
+
+    let request_id = content.request_id();
+    let signature = identity
+        .sign(request_id.sig_data())
+        .map_err(SignTransactionError::Identity)?;
+
+    let env = Envelope {
+        content,
+        sender_pubkey: Some(signature.public_key),
+        sender_sig: Some(signature.signature),
+    };
+    Ok((request_id, env))
+}
+
+#[inline]
+fn create_read_state_envelope(
+    identity: &Identity,
+    update_request_id: RequestId,
+    ingress_expiry: u64,
+) -> Result<(RequestId, Envelope<EnvelopeReadStateContent>), SignTransactionError> {
+    let sender = identity.sender();
+
+    let content = EnvelopeReadStateContent {
+        ingress_expiry,
+        sender,
+        paths: vec![vec![
+            Label::from("request_status"),
+            Label::from(update_request_id),
+        ]],
+    };
+
+    let request_id = content.request_id();
+    let signature = identity
+        .sign(request_id.sig_data())
+        .map_err(SignTransactionError::Identity)?;
+
+    let env = Envelope {
+        content,
+        sender_pubkey: Some(signature.public_key),
+        sender_sig: Some(signature.signature),
+    };
+    Ok((request_id, env))
+}
@@ -0,0 +1,463 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! ICRC-1 and ICRC-2 ledger methods.
+//! See: https://github.com/dfinity/ICRC-1/tree/main/standards
+
+use tw_hash::H256;
+use tw_proto::InternetComputer::Proto::mod_Transaction as Proto;
+
+use crate::protocol::{
+    candid::{self, CandidType, CandidValue},
+    principal::Principal,
+};
+
+use super::{call::CallArgs, SignTransactionError};
+
+/// The endpoint of an ICRC-1 ledger canister that is used to make transfers.
+const ICRC1_TRANSFER_METHOD_NAME: &str = "icrc1_transfer";
+/// The endpoint of an ICRC-2 ledger canister that is used to approve a spender.
+const ICRC2_APPROVE_METHOD_NAME: &str = "icrc2_approve";
+/// The endpoint of an ICRC-2 ledger canister that is used by a spender to make transfers.
+const ICRC2_TRANSFER_FROM_METHOD_NAME: &str = "icrc2_transfer_from";
+/// The maximum memo length accepted by ICRC-1 ledgers.
+const MAX_MEMO_LEN: usize = 32;
+
+/// ICRC-1 account that consists of the owner principal and an optional subaccount.
+#[derive(Clone, Debug)]
+pub struct IcrcAccount {
+    pub owner: Principal,
+    pub subaccount: Option<H256>,
+}
+
+impl IcrcAccount {
+    fn to_candid(&self) -> CandidValue {
+        CandidValue::Record(vec![
+            ("owner", CandidValue::Principal(self.owner)),
+            ("subaccount", subaccount_to_candid(&self.subaccount)),
+        ])
+    }
+}
+
+impl TryFrom<&Proto::IcrcAccount<'_>> for IcrcAccount {
+    type Error = SignTransactionError;
+
+    fn try_from(account: &Proto::IcrcAccount<'_>) -> Result<Self, Self::Error> {
+        let owner = Principal::from_text(account.owner.as_ref())
+            .map_err(|_| SignTransactionError::InvalidPrincipal)?;
+        Ok(IcrcAccount {
+            owner,
+            subaccount: parse_subaccount(&account.subaccount)?,
+        })
+    }
+}
+
+/// Arguments of the ICRC-1 `icrc1_transfer` method.
+#[derive(Clone, Debug)]
+pub struct Icrc1TransferArgs {
+    pub from_subaccount: Option<H256>,
+    pub to: IcrcAccount,
+    /// The amount of tokens as big endian bytes.
+    pub amount: Vec<u8>,
+    /// The fee expected by the sender as big endian bytes.
+    pub fee: Option<Vec<u8>>,
+    pub memo: Option<Vec<u8>>,
+    /// The transaction creation time in nanoseconds used for the deduplication.
+    pub created_at_time: Option<u64>,
+}
+
+impl Icrc1TransferArgs {
+    pub fn to_candid(&self) -> CandidValue {
+        CandidValue::Record(vec![
+            (
+                "from_subaccount",
+                subaccount_to_candid(&self.from_subaccount),
+            ),
+            ("to", self.to.to_candid()),
+            ("amount", CandidValue::Nat(self.amount.clone())),
+            ("fee", opt_nat(&self.fee)),
+            ("memo", opt_blob(&self.memo)),
+            ("created_at_time", opt_nat64(self.created_at_time)),
+        ])
+    }
+}
+
+/// Arguments of the ICRC-2 `icrc2_approve` method.
+#[derive(Clone, Debug)]
+pub struct Icrc2ApproveArgs {
+    pub from_subaccount: Option<H256>,
+    pub spender: IcrcAccount,
+    /// The allowance of the spender as big endian bytes.
+    pub amount: Vec<u8>,
+    /// The current allowance the approval is applied to as big endian bytes.
+    pub expected_allowance: Option<Vec<u8>>,
+    /// The expiration timestamp of the approval in nanoseconds.
+    pub expires_at: Option<u64>,
+    /// The fee expected by the approver as big endian bytes.
+    pub fee: Option<Vec<u8>>,
+    pub memo: Option<Vec<u8>>,
+    /// The transaction creation time in nanoseconds used for the deduplication.
+    pub created_at_time: Option<u64>,
+}
+
+impl Icrc2ApproveArgs {
+    pub fn to_candid(&self) -> CandidValue {
+        CandidValue::Record(vec![
+            (
+                "from_subaccount",
+                subaccount_to_candid(&self.from_subaccount),
+            ),
+            ("spender", self.spender.to_candid()),
+            ("amount", CandidValue::Nat(self.amount.clone())),
+            ("expected_allowance", opt_nat(&self.expected_allowance)),
+            ("expires_at", opt_nat64(self.expires_at)),
+            ("fee", opt_nat(&self.fee)),
+            ("memo", opt_blob(&self.memo)),
+            ("created_at_time", opt_nat64(self.created_at_time)),
+        ])
+    }
+}
+
+/// Arguments of the ICRC-2 `icrc2_transfer_from` method.
+#[derive(Clone, Debug)]
+pub struct Icrc2TransferFromArgs {
+    pub spender_subaccount: Option<H256>,
+    pub from: IcrcAccount,
+    pub to: IcrcAccount,
+    /// The amount of tokens as big endian bytes.
+    pub amount: Vec<u8>,
+    /// The fee expected by the spender as big endian bytes.
+    pub fee: Option<Vec<u8>>,
+    pub memo: Option<Vec<u8>>,
+    /// The transaction creation time in nanoseconds used for the deduplication.
+    pub created_at_time: Option<u64>,
+}
+
+impl Icrc2TransferFromArgs {
+    pub fn to_candid(&self) -> CandidValue {
+        CandidValue::Record(vec![
+            (
+                "spender_subaccount",
+                subaccount_to_candid(&self.spender_subaccount),
+            ),
+            ("from", self.from.to_candid()),
+            ("to", self.to.to_candid()),
+            ("amount", CandidValue::Nat(self.amount.clone())),
+            ("fee", opt_nat(&self.fee)),
+            ("memo", opt_blob(&self.memo)),
+            ("created_at_time", opt_nat64(self.created_at_time)),
+        ])
+    }
+}
+
+impl TryFrom<&Proto::Icrc1Transfer<'_>> for CallArgs {
+    type Error = SignTransactionError;
+
+    fn try_from(transfer: &Proto::Icrc1Transfer<'_>) -> Result<Self, Self::Error> {
+        if is_zero(&transfer.amount) {
+            return Err(SignTransactionError::InvalidAmount);
+        }
+        let to = transfer
+            .to
+            .as_ref()
+            .ok_or(SignTransactionError::InvalidArguments)?;
+
+        let args = Icrc1TransferArgs {
+            from_subaccount: parse_subaccount(&transfer.from_subaccount)?,
+            to: IcrcAccount::try_from(to)?,
+            amount: transfer.amount.to_vec(),
+            fee: non_empty(&transfer.fee),
+            memo: parse_memo(&transfer.memo)?,
+            created_at_time: non_zero(transfer.current_timestamp_nanos),
+        };
+        Ok(CallArgs {
+            canister_id: parse_canister_id(&transfer.canister_id)?,
+            method_name: ICRC1_TRANSFER_METHOD_NAME.to_string(),
+            arg: candid::encode_args(&[args.to_candid()]),
+            current_timestamp_nanos: transfer.current_timestamp_nanos,
+            permitted_drift: non_zero(transfer.permitted_drift),
+        })
+    }
+}
+
+impl TryFrom<&Proto::Icrc2Approve<'_>> for CallArgs {
+    type Error = SignTransactionError;
+
+    fn try_from(approve: &Proto::Icrc2Approve<'_>) -> Result<Self, Self::Error> {
+        let spender = approve
+            .spender
+            .as_ref()
+            .ok_or(SignTransactionError::InvalidArguments)?;
+
+        // Zero amount is allowed to revoke the allowance.
+        let args = Icrc2ApproveArgs {
+            from_subaccount: parse_subaccount(&approve.from_subaccount)?,
+            spender: IcrcAccount::try_from(spender)?,
+            amount: approve.amount.to_vec(),
+            expected_allowance: non_empty(&approve.expected_allowance),
+            expires_at: non_zero(approve.expires_at),
+            fee: non_empty(&approve.fee),
+            memo: parse_memo(&approve.memo)?,
+            created_at_time: non_zero(approve.current_timestamp_nanos),
+        };
+        Ok(CallArgs {
+            canister_id: parse_canister_id(&approve.canister_id)?,
+            method_name: ICRC2_APPROVE_METHOD_NAME.to_string(),
+            arg: candid::encode_args(&[args.to_candid()]),
+            current_timestamp_nanos: approve.current_timestamp_nanos,
+            permitted_drift: non_zero(approve.permitted_drift),
+        })
+    }
+}
+
+impl TryFrom<&Proto::Icrc2TransferFrom<'_>> for CallArgs {
+    type Error = SignTransactionError;
+
+    fn try_from(transfer: &Proto::Icrc2TransferFrom<'_>) -> Result<Self, Self::Error> {
+        if is_zero(&transfer.amount) {
+            return Err(SignTransactionError::InvalidAmount);
+        }
+        let (Some(from), Some(to)) = (transfer.from.as_ref(), transfer.to.as_ref()) else {
+            return Err(SignTransactionError::InvalidArguments);
+        };
+
+        let args = Icrc2TransferFromArgs {
+            spender_subaccount: parse_subaccount(&transfer.spender_subaccount)?,
+            from: IcrcAccount::try_from(from)?,
+            to: IcrcAccount::try_from(to)?,
+            amount: transfer.amount.to_vec(),
+            fee: non_empty(&transfer.fee),
+            memo: parse_memo(&transfer.memo)?,
+            created_at_time: non_zero(transfer.current_timestamp_nanos),
+        };
+        Ok(CallArgs {
+            canister_id: parse_canister_id(&transfer.canister_id)?,
+            method_name: ICRC2_TRANSFER_FROM_METHOD_NAME.to_string(),
+            arg: candid::encode_args(&[args.to_candid()]),
+            current_timestamp_nanos: transfer.current_timestamp_nanos,
+            permitted_drift: non_zero(transfer.permitted_drift),
+        })
+    }
+}
+
+impl TryFrom<&Proto::CanisterCall<'_>> for CallArgs {
+    type Error = SignTransactionError;
+
+    fn try_from(call: &Proto::CanisterCall<'_>) -> Result<Self, Self::Error> {
+        Ok(CallArgs {
+            canister_id: parse_canister_id(&call.canister_id)?,
+            method_name: call.method_name.to_string(),
+            arg: call.arg.to_vec(),
+            current_timestamp_nanos: call.current_timestamp_nanos,
+            permitted_drift: non_zero(call.permitted_drift),
+        })
+    }
+}
+
+fn parse_canister_id(canister_id: &str) -> Result<Principal, SignTransactionError> {
+    Principal::from_text(canister_id).map_err(|_| SignTransactionError::InvalidCanisterId)
+}
+
+/// An empty subaccount stands for the default one.
+fn parse_subaccount(subaccount: &[u8]) -> Result<Option<H256>, SignTransactionError> {
+    if subaccount.is_empty() {
+        return Ok(None);
+    }
+    H256::try_from(subaccount)
+        .map(Some)
+        .map_err(|_| SignTransactionError::InvalidSubaccount)
+}
+
+/// An empty memo is omitted.
+fn parse_memo(memo: &[u8]) -> Result<Option<Vec<u8>>, SignTransactionError> {
+    if memo.len() > MAX_MEMO_LEN {
+        return Err(SignTransactionError::InvalidMemo);
+    }
+    Ok(non_empty(memo))
+}
+
+fn subaccount_to_candid(subaccount: &Option<H256>) -> CandidValue {
+    let subaccount = subaccount.map(|subaccount| CandidValue::Blob(subaccount.to_vec()));
+    CandidValue::opt(CandidType::Blob, subaccount)
+}
+
+fn opt_nat(value: &Option<Vec<u8>>) -> CandidValue {
+    CandidValue::opt(CandidType::Nat, value.clone().map(CandidValue::Nat))
+}
+
+fn opt_nat64(value: Option<u64>) -> CandidValue {
+    CandidValue::opt(CandidType::Nat64, value.map(CandidValue::Nat64))
+}
+
+fn opt_blob(value: &Option<Vec<u8>>) -> CandidValue {
+    CandidValue::opt(CandidType::Blob, value.clone().map(CandidValue::Blob))
+}
+
+fn is_zero(be_bytes: &[u8]) -> bool {
+    be_bytes.iter().all(|byte| *byte == 0)
+}
+
+fn non_empty(bytes: &[u8]) -> Option<Vec<u8>> {
+    (!bytes.is_empty()).then(|| bytes.to_vec())
+}
+
+fn non_zero(value: u64) -> Option<u64> {
+    (value > 0).then_some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use tw_encoding::hex;
+
+    use super::*;
+
+    const OWNER_PRINCIPAL_ID: &str =
+        "t4u4z-y3dur-j63pk-nw4rv-yxdbt-agtt6-nygn7-ywh6y-zm2f4-sdzle-3qe";
+    const CKBTC_LEDGER_CANISTER_ID: &str = "mxzaz-hqaaa-aaaar-qaada-cai";
+    const CURRENT_TIMESTAMP_NANOS: u64 = 1_691_709_940_000_000_000;
+
+    fn owner() -> Principal {
+        Principal::from_text(OWNER_PRINCIPAL_ID).unwrap()
+    }
+
+    #[test]
+    fn icrc1_transfer_candid_encoding() {
+        let args = Icrc1TransferArgs {
+            from_subaccount: None,
+            to: IcrcAccount {
+                owner: owner(),
+                subaccount: None,
+            },
+            amount: 100_000_000_u64.to_be_bytes().to_vec(),
+            fee: None,
+            memo: None,
+            created_at_time: Some(CURRENT_TIMESTAMP_NANOS),
+        };
+
+        let encoded = candid::encode_args(&[args.to_candid()]);
+        assert_eq!(
+            hex::encode(encoded, false),
+            "4449444c066d7b6e006c02b3b0dac30368ad86ca8305016e7d6e786c06fbca0102c6fcb60203ba89e5c20401a2de94eb060182f3f3910c04d8a38ca80d7d0105011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e487959370200000000010088b2343a297a1780c2d72f"
+        );
+    }
+
+    #[test]
+    fn icrc2_approve_candid_encoding() {
+        let args = Icrc2ApproveArgs {
+            from_subaccount: None,
+            spender: IcrcAccount {
+                owner: owner(),
+                subaccount: Some(H256::from([1; 32])),
+            },
+            // 5 * 10^18
+            amount: hex::decode("4563918244f40000").unwrap(),
+            expected_allowance: Some(vec![0]),
+            expires_at: Some(CURRENT_TIMESTAMP_NANOS + 3_600_000_000_000),
+            fee: Some(10_000_u64.to_be_bytes().to_vec()),
+            memo: Some(vec![1, 2, 3]),
+            created_at_time: Some(CURRENT_TIMESTAMP_NANOS),
+        };
+
+        let encoded = candid::encode_args(&[args.to_candid()]);
+        assert_eq!(
+            hex::encode(encoded, false),
+            "4449444c066e7d6d7b6e016e786c02b3b0dac30368ad86ca8305026c08c6fcb60200ba89e5c20402a2de94eb060282f3f3910c03d8a38ca80d7d919c9cbf0d00dea7f7da0d03cb96dcb40e04010501904e010301020300010088b2343a297a178080d0a7a4b0e4b14501000100286b65802c7a17011d63a453edbd4db7235c5c61980d39f9b8337f8b1fd8cb345e487959370201200101010101010101010101010101010101010101010101010101010101010101"
+        );
+    }
+
+    #[test]
+    fn icrc1_transfer_call_args() {
+        let transfer = Proto::Icrc1Transfer {
+            canister_id: CKBTC_LEDGER_CANISTER_ID.into(),
+            to: Some(Proto::IcrcAccount {
+                owner: OWNER_PRINCIPAL_ID.into(),
+                ..Proto::IcrcAccount::default()
+            }),
+            amount: 100_000_000_u64.to_be_bytes().to_vec().into(),
+            current_timestamp_nanos: CURRENT_TIMESTAMP_NANOS,
+            ..Proto::Icrc1Transfer::default()
+        };
+
+        let call_args = CallArgs::try_from(&transfer).unwrap();
+        assert_eq!(call_args.canister_id.to_text(), CKBTC_LEDGER_CANISTER_ID);
+        assert_eq!(call_args.method_name, "icrc1_transfer");
+        assert_eq!(call_args.permitted_drift, None);
+        assert!(hex::encode(call_args.arg, false).ends_with("010088b2343a297a1780c2d72f"));
+    }
+
+    #[test]
+    fn icrc1_transfer_invalid_subaccount() {
+        let transfer = Proto::Icrc1Transfer {
+            canister_id: CKBTC_LEDGER_CANISTER_ID.into(),
+            to: Some(Proto::IcrcAccount {
+                owner: OWNER_PRINCIPAL_ID.into(),
+                subaccount: vec![1; 31].into(),
+            }),
+            amount: vec![1].into(),
+            ..Proto::Icrc1Transfer::default()
+        };
+
+        assert!(matches!(
+            CallArgs::try_from(&transfer),
+            Err(SignTransactionError::InvalidSubaccount)
+        ));
+    }
+
+    #[test]
+    fn icrc1_transfer_invalid_memo() {
+        let transfer = Proto::Icrc1Transfer {
+            canister_id: CKBTC_LEDGER_CANISTER_ID.into(),
+            to: Some(Proto::IcrcAccount {
+                owner: OWNER_PRINCIPAL_ID.into(),
+                ..Proto::IcrcAccount::default()
+            }),
+            amount: vec![1].into(),
+            memo: vec![1; 33].into(),
+            ..Proto::Icrc1Transfer::default()
+        };
+
+        assert!(matches!(
+            CallArgs::try_from(&transfer),
+            Err(SignTransactionError::InvalidMemo)
+        ));
+    }
+
+    #[test]
+    fn icrc1_transfer_invalid_amount() {
+        let transfer = Proto::Icrc1Transfer {
+            canister_id: CKBTC_LEDGER_CANISTER_ID.into(),
+            to: Some(Proto::IcrcAccount {
+                owner: OWNER_PRINCIPAL_ID.into(),
+                ..Proto::IcrcAccount::default()
+            }),
+            amount: vec![0, 0].into(),
+            ..Proto::Icrc1Transfer::default()
+        };
+
+        assert!(matches!(
+            CallArgs::try_from(&transfer),
+            Err(SignTransactionError::InvalidAmount)
+        ));
+    }
+
+    #[test]
+    fn icrc2_transfer_from_invalid_canister_id() {
+        let account = Proto::IcrcAccount {
+            owner: OWNER_PRINCIPAL_ID.into(),
+            ..Proto::IcrcAccount::default()
+        };
+        let transfer = Proto::Icrc2TransferFrom {
+            canister_id: "invalid".into(),
+            from: Some(account.clone()),
+            to: Some(account),
+            amount: vec![1].into(),
+            ..Proto::Icrc2TransferFrom::default()
+        };
+
+        assert!(matches!(
+            CallArgs::try_from(&transfer),
+            Err(SignTransactionError::InvalidCanisterId)
+        ));
+    }
+}
@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod call;
+pub mod icrc;
 pub mod transfer;
 
 pub mod proto {
@@ -21,6 +23,10 @@ pub enum SignTransactionError {
     EncodingArgsFailed,
     InvalidToAccountIdentifier,
     InvalidEnvelopePair,
+    InvalidCanisterId,
+    InvalidPrincipal,
+    InvalidSubaccount,
+    InvalidMemo,
 }
 
 pub fn sign_transaction(
@@ -45,6 +51,18 @@ pub fn sign_transaction(
                 },
             },
         ),
+        Tx::icrc1_transfer(transfer_args) => {
+            call::call(private_key, call::CallArgs::try_from(transfer_args)?)
+        },
+        Tx::icrc2_approve(approve_args) => {
+            call::call(private_key, call::CallArgs::try_from(approve_args)?)
+        },
+        Tx::icrc2_transfer_from(transfer_from_args) => {
+            call::call(private_key, call::CallArgs::try_from(transfer_from_args)?)
+        },
+        Tx::canister_call(call_args) => {
+            call::call(private_key, call::CallArgs::try_from(call_args)?)
+        },
         Tx::None => Err(SignTransactionError::InvalidArguments),
     }
 }
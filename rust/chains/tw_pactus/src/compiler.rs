@@ -6,11 +6,12 @@ use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
-use tw_keypair::ed25519;
+use tw_keypair::{bls, ed25519};
 use tw_proto::Pactus::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
 
 use crate::modules::tx_builder::TxBuilder;
+use crate::transaction::Signatory;
 
 pub struct PactusCompiler;
 
@@ -64,18 +65,27 @@ impl PactusCompiler {
             .first()
             .or_tw_err(SigningErrorType::Error_signatures_count)?;
 
-        let public_key = ed25519::sha512::PublicKey::try_from(public_key_bytes.as_slice())?;
-        let signature = ed25519::Signature::try_from(signature_bytes.as_slice())?;
-
         let mut trx = TxBuilder::from_proto(&input)?;
-        trx.set_signatory(public_key.to_owned(), signature.to_owned());
+        let signatory = if trx.is_ed25519_signer() {
+            Signatory::Ed25519 {
+                public_key: ed25519::sha512::PublicKey::try_from(public_key_bytes.as_slice())?,
+                signature: ed25519::Signature::try_from(signature_bytes.as_slice())?,
+            }
+        } else {
+            Signatory::Bls {
+                public_key: bls::PublicKey::try_from(public_key_bytes.as_slice())?,
+                signature: bls::Signature::try_from(signature_bytes.as_slice())?,
+            }
+        };
+        let signature = signatory.signature_bytes();
+        trx.set_signatory(signatory);
 
         let data = trx.to_bytes()?;
 
         let output = Proto::SigningOutput {
             transaction_id: trx.id().into(),
             signed_transaction_data: data.into(),
-            signature: signature.to_bytes().to_vec().into(),
+            signature: signature.into(),
             ..Proto::SigningOutput::default()
         };
 
@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use tw_hash::Hash;
+use tw_keypair::bls;
 use tw_keypair::ed25519::{sha512::PublicKey, Signature};
 
 use super::error::Error;
@@ -55,6 +56,22 @@ impl Decodable for Signature {
     }
 }
 
+impl Decodable for bls::PublicKey {
+    fn decode(r: &mut dyn std::io::Read) -> Result<Self, Error> {
+        let data = decode_fix_slice::<{ bls::PublicKey::LEN }>(r)?;
+        bls::PublicKey::try_from(data.as_slice())
+            .map_err(|_| self::Error::ParseFailed("Invalid BLS Public Key"))
+    }
+}
+
+impl Decodable for bls::Signature {
+    fn decode(r: &mut dyn std::io::Read) -> Result<Self, Error> {
+        let data = decode_fix_slice::<{ bls::Signature::LEN }>(r)?;
+        bls::Signature::try_from(data.as_slice())
+            .map_err(|_| self::Error::ParseFailed("Invalid BLS Signature"))
+    }
+}
+
 impl<const N: usize> Decodable for Hash<N> {
     fn decode(r: &mut dyn std::io::Read) -> Result<Self, Error> {
         let data = decode_fix_slice::<N>(r)?;
@@ -4,7 +4,9 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use tw_hash::Hash;
+use tw_keypair::bls;
 use tw_keypair::ed25519::{sha512::PublicKey, Signature};
+use tw_misc::traits::ToBytesVec;
 
 use super::error::Error;
 use crate::encoder::var_int::VarInt;
@@ -63,6 +65,26 @@ impl Encodable for Signature {
     }
 }
 
+impl Encodable for bls::PublicKey {
+    fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), Error> {
+        encode_fix_slice(self.to_vec().as_slice(), w)
+    }
+
+    fn encoded_size(&self) -> usize {
+        bls::PublicKey::LEN
+    }
+}
+
+impl Encodable for bls::Signature {
+    fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), Error> {
+        encode_fix_slice(self.to_vec().as_slice(), w)
+    }
+
+    fn encoded_size(&self) -> usize {
+        bls::Signature::LEN
+    }
+}
+
 impl<const N: usize> Encodable for Hash<N> {
     fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), Error> {
         encode_fix_slice(self.as_slice(), w)
@@ -2,7 +2,10 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::transaction::payload::{BondPayload, Payload, TransferPayload};
+use crate::transaction::payload::{
+    BondPayload, Payload, SortitionPayload, TransferPayload, UnbondPayload, WithdrawPayload,
+    SORTITION_PROOF_SIZE,
+};
 use crate::transaction::Transaction;
 use crate::types::{Address, Amount, ValidatorPublicKey};
 use std::str::FromStr;
@@ -38,6 +41,28 @@ impl TxBuilder {
                             public_key,
                         ))
                     },
+                    Pactus::Proto::mod_TransactionMessage::OneOfpayload::sortition(pld) => {
+                        let validator = Address::from_str(&pld.validator)?;
+                        let proof: [u8; SORTITION_PROOF_SIZE] = pld
+                            .proof
+                            .as_ref()
+                            .try_into()
+                            .tw_err(|_| SigningErrorType::Error_invalid_params)
+                            .context("Invalid sortition proof")?;
+
+                        Box::new(SortitionPayload::new(validator, proof))
+                    },
+                    Pactus::Proto::mod_TransactionMessage::OneOfpayload::unbond(pld) => {
+                        let validator = Address::from_str(&pld.validator)?;
+
+                        Box::new(UnbondPayload::new(validator))
+                    },
+                    Pactus::Proto::mod_TransactionMessage::OneOfpayload::withdraw(pld) => {
+                        let validator = Address::from_str(&pld.validator_address)?;
+                        let account = Address::from_str(&pld.account_address)?;
+
+                        Box::new(WithdrawPayload::new(validator, account, Amount(pld.amount)))
+                    },
                     Pactus::Proto::mod_TransactionMessage::OneOfpayload::None => {
                         return SigningError::err(SigningErrorType::Error_invalid_params)
                     },
@@ -5,8 +5,9 @@
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
-use tw_keypair::ed25519;
 use tw_keypair::traits::KeyPairTrait;
+use tw_keypair::{bls, ed25519};
+use tw_misc::traits::ToBytesVec;
 use tw_proto::Pactus::Proto;
 
 use crate::modules::tx_builder::TxBuilder;
@@ -27,15 +28,20 @@ impl PactusSigner {
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
         let mut trx = TxBuilder::from_proto(&input)?;
-        let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
-        let signature = trx.sign(key_pair.private())?;
+        let signature = if trx.is_ed25519_signer() {
+            let key_pair = ed25519::sha512::KeyPair::try_from(input.private_key.as_ref())?;
+            trx.sign(key_pair.private())?.to_bytes().to_vec()
+        } else {
+            let key_pair = bls::KeyPair::try_from(input.private_key.as_ref())?;
+            trx.sign_bls(key_pair.private())?.to_vec()
+        };
 
         let data = trx.to_bytes()?;
 
         let output = Proto::SigningOutput {
             transaction_id: trx.id().into(),
             signed_transaction_data: data.into(),
-            signature: signature.to_bytes().to_vec().into(),
+            signature: signature.into(),
             ..Proto::SigningOutput::default()
         };
 
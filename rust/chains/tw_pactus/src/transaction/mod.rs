@@ -2,12 +2,16 @@ pub mod payload;
 
 use std::fmt::Debug;
 
-use payload::{BondPayload, Payload, PayloadType, TransferPayload};
+use payload::{
+    BondPayload, Payload, PayloadType, SortitionPayload, TransferPayload, UnbondPayload,
+    WithdrawPayload,
+};
 use tw_coin_entry::error::prelude::SigningResult;
 use tw_hash::blake2::blake2_b;
-use tw_keypair::ed25519::sha512::{PrivateKey, PublicKey};
-use tw_keypair::ed25519::Signature;
 use tw_keypair::traits::SigningKeyTrait;
+use tw_keypair::{bls, ed25519};
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
 
 use crate::encoder::error::Error as EncoderError;
 use crate::encoder::{deserialize, Decodable, Encodable};
@@ -16,6 +20,63 @@ use crate::types::Amount;
 const VERSION_LATEST: u8 = 1;
 const FLAG_NOT_SIGNED: u8 = 0x02;
 
+/// Public key and signature of the transaction signer.
+/// Ed25519 accounts sign with Ed25519 keys, while validators and BLS accounts sign with BLS keys.
+#[derive(Debug)]
+pub enum Signatory {
+    Ed25519 {
+        public_key: ed25519::sha512::PublicKey,
+        signature: ed25519::Signature,
+    },
+    Bls {
+        public_key: bls::PublicKey,
+        signature: bls::Signature,
+    },
+}
+
+impl Signatory {
+    pub fn signature_bytes(&self) -> Data {
+        match self {
+            Signatory::Ed25519 { signature, .. } => signature.to_bytes().to_vec(),
+            Signatory::Bls { signature, .. } => signature.to_vec(),
+        }
+    }
+}
+
+impl Encodable for Signatory {
+    fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), EncoderError> {
+        match self {
+            Signatory::Ed25519 {
+                public_key,
+                signature,
+            } => {
+                signature.encode(w)?;
+                public_key.encode(w)
+            },
+            Signatory::Bls {
+                public_key,
+                signature,
+            } => {
+                signature.encode(w)?;
+                public_key.encode(w)
+            },
+        }
+    }
+
+    fn encoded_size(&self) -> usize {
+        match self {
+            Signatory::Ed25519 {
+                public_key,
+                signature,
+            } => signature.encoded_size() + public_key.encoded_size(),
+            Signatory::Bls {
+                public_key,
+                signature,
+            } => signature.encoded_size() + public_key.encoded_size(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Transaction {
     flags: u8,
@@ -24,8 +85,7 @@ pub struct Transaction {
     fee: Amount,
     memo: String,
     payload: Box<dyn Payload>,
-    signature: Option<Signature>,
-    public_key: Option<PublicKey>,
+    signatory: Option<Signatory>,
 }
 
 impl Transaction {
@@ -37,8 +97,7 @@ impl Transaction {
             fee,
             memo,
             payload,
-            public_key: None,
-            signature: None,
+            signatory: None,
         }
     }
 
@@ -46,21 +105,44 @@ impl Transaction {
         Ok(deserialize::<Transaction>(input)?)
     }
 
-    pub fn sign(&mut self, private_key: &PrivateKey) -> SigningResult<Signature> {
+    pub fn sign(
+        &mut self,
+        private_key: &ed25519::sha512::PrivateKey,
+    ) -> SigningResult<ed25519::Signature> {
+        let sign_bytes = self.sign_bytes()?;
+        let signature = private_key.sign(sign_bytes)?;
+
+        self.set_signatory(Signatory::Ed25519 {
+            public_key: private_key.public(),
+            signature: signature.clone(),
+        });
+
+        Ok(signature)
+    }
+
+    pub fn sign_bls(&mut self, private_key: &bls::PrivateKey) -> SigningResult<bls::Signature> {
         let sign_bytes = self.sign_bytes()?;
         let signature = private_key.sign(sign_bytes)?;
 
-        self.set_signatory(private_key.public(), signature.clone());
+        self.set_signatory(Signatory::Bls {
+            public_key: private_key.public(),
+            signature: signature.clone(),
+        });
 
         Ok(signature)
     }
 
-    pub fn set_signatory(&mut self, public_key: PublicKey, signature: Signature) {
+    pub fn set_signatory(&mut self, signatory: Signatory) {
         // Unset "Not Signed" flag
         self.flags &= !FLAG_NOT_SIGNED;
 
-        self.public_key = Some(public_key);
-        self.signature = Some(signature);
+        self.signatory = Some(signatory);
+    }
+
+    /// Returns whether the payload signer is an Ed25519 account.
+    /// Otherwise, the transaction is signed with a BLS key.
+    pub fn is_ed25519_signer(&self) -> bool {
+        self.payload.signer().is_ed25519_account()
     }
 
     pub fn id(&self) -> Vec<u8> {
@@ -105,12 +187,8 @@ impl Encodable for Transaction {
     fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), EncoderError> {
         self.encode_with_no_signatory(w)?;
 
-        if let Some(sig) = &self.signature {
-            sig.encode(w)?;
-        }
-
-        if let Some(pub_key) = &self.public_key {
-            pub_key.encode(w)?;
+        if let Some(signatory) = &self.signatory {
+            signatory.encode(w)?;
         }
 
         Ok(())
@@ -125,12 +203,8 @@ impl Encodable for Transaction {
             + self.memo.encoded_size()
             + self.payload.encoded_size();
 
-        if let Some(sig) = &self.signature {
-            len += sig.encoded_size();
-        }
-
-        if let Some(pub_key) = &self.public_key {
-            len += pub_key.encoded_size();
+        if let Some(signatory) = &self.signatory {
+            len += signatory.encoded_size();
         }
 
         len
@@ -148,7 +222,9 @@ impl Decodable for Transaction {
         let payload: Box<dyn Payload> = match payload_type {
             PayloadType::Transfer => Box::new(TransferPayload::decode(r)?),
             PayloadType::Bond => Box::new(BondPayload::decode(r)?),
-            _ => return Err(EncoderError::ParseFailed("Unsupported payload")),
+            PayloadType::Sortition => Box::new(SortitionPayload::decode(r)?),
+            PayloadType::Unbond => Box::new(UnbondPayload::decode(r)?),
+            PayloadType::Withdraw => Box::new(WithdrawPayload::decode(r)?),
         };
 
         let mut trx = Transaction {
@@ -158,19 +234,25 @@ impl Decodable for Transaction {
             fee,
             memo,
             payload,
-            public_key: None,
-            signature: None,
+            signatory: None,
         };
 
         if !trx.is_signed() {
             return Ok(trx);
         }
 
-        let signature = Signature::decode(r)?;
-        let public_key = PublicKey::decode(r)?;
-
-        trx.signature = Some(signature);
-        trx.public_key = Some(public_key);
+        let signatory = if trx.is_ed25519_signer() {
+            Signatory::Ed25519 {
+                signature: ed25519::Signature::decode(r)?,
+                public_key: ed25519::sha512::PublicKey::decode(r)?,
+            }
+        } else {
+            Signatory::Bls {
+                signature: bls::Signature::decode(r)?,
+                public_key: bls::PublicKey::decode(r)?,
+            }
+        };
+        trx.signatory = Some(signatory);
 
         Ok(trx)
     }
@@ -237,7 +319,8 @@ mod tests {
         let private_key_data = "4e51f1f3721f644ac7a193be7f5e7b8c2abaa3467871daf4eacb5d3af080e5d6"
             .decode_hex()
             .unwrap();
-        let private_key = PrivateKey::try_from(private_key_data.as_slice()).unwrap();
+        let private_key =
+            ed25519::sha512::PrivateKey::try_from(private_key_data.as_slice()).unwrap();
         trx.sign(&private_key).unwrap();
 
         assert_eq!(expected_data, trx.to_bytes().unwrap());
@@ -271,4 +354,58 @@ mod tests {
         assert_eq!(trx.encoded_size(), data.len());
         assert!(trx.is_signed());
     }
+
+    #[test]
+    fn test_encoding_sortition() {
+        let data = concat!(
+            "02",                                                               // Flags
+            "01",                                                               // Version
+            "01020300",                                                         // LockTime
+            "00",                                                               // Fee
+            "00",                                                               // Memo
+            "03",                                                               // PayloadType
+            "0129288df0bf7bd4b5e9eeed8b932d0c76f451823d",                       // Validator
+            "8bd2b1c9e8eb0f5ab7e9e4cb2e0a6e5d2a4c0a0c6b5e9a3d1f7c2b8e4a6d0c3f", // Proof
+            "9e1b7a5c3d2f8e6b4a0c9d7e5f3a1b8c",
+        )
+        .decode_hex()
+        .unwrap();
+
+        let trx = Transaction::from_bytes(&data).unwrap();
+        assert!(matches!(trx.payload.payload_type(), PayloadType::Sortition));
+        assert_eq!(trx.payload.value(), Amount(0));
+        assert_eq!(
+            trx.payload.signer().to_string(),
+            "pc1p9y5gmu9l002tt60wak9extgvwm69rq3a9ackrl"
+        );
+        assert_eq!(trx.to_bytes().unwrap(), data);
+        assert_eq!(trx.encoded_size(), data.len());
+    }
+
+    #[test]
+    fn test_encoding_signed_bls() {
+        let data = concat!(
+            "00",                                               // Flags
+            "01",                                               // Version
+            "24a32300",                                         // LockTime
+            "80ade204",                                         // Fee
+            "0b77616c6c65742d636f7265",                         // Memo
+            "04",                                               // PayloadType
+            "014e5fc87259613f96da52548561a9209c8af43916",       // Validator
+            "b0a91b3f2c65c130349121ccd6dd3e545fa3f12d37ffda28", // Signature
+            "1a3a716f39f7102436191811120c2dddbb0d76d903fb4651",
+            "86388d26e3c3d29bd5013108af40c5dc9096e17c7571c6dcda7c22e4146925c1", // PublicKey
+            "401326267125516a14a1252f18bb7e3e18379eeef225990300d1a0c2bb809c42",
+            "6726c115a2c736d624e1d470f1192e946801daf504aed756a5bdef73fca0d6ee",
+        )
+        .decode_hex()
+        .unwrap();
+
+        let trx = Transaction::from_bytes(&data).unwrap();
+        assert!(trx.is_signed());
+        assert!(!trx.is_ed25519_signer());
+        assert!(matches!(trx.signatory, Some(Signatory::Bls { .. })));
+        assert_eq!(trx.to_bytes().unwrap(), data);
+        assert_eq!(trx.encoded_size(), data.len());
+    }
 }
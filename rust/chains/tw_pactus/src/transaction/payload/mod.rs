@@ -1,8 +1,14 @@
 mod bond;
+mod sortition;
 mod transfer;
+mod unbond;
+mod withdraw;
 
 pub use bond::BondPayload;
+pub use sortition::{SortitionPayload, SORTITION_PROOF_SIZE};
 pub use transfer::TransferPayload;
+pub use unbond::UnbondPayload;
+pub use withdraw::WithdrawPayload;
 
 use std::fmt::Debug;
 
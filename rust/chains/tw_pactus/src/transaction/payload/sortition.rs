@@ -0,0 +1,57 @@
+use crate::encoder::error::Error as EncoderError;
+use crate::encoder::{decode::decode_fix_slice, encode::encode_fix_slice};
+use crate::{
+    encoder::{Decodable, Encodable},
+    types::{Address, Amount},
+};
+
+use super::{Payload, PayloadType};
+
+/// The sortition proof is a BLS signature.
+pub const SORTITION_PROOF_SIZE: usize = 48;
+
+#[derive(Debug)]
+pub struct SortitionPayload {
+    validator: Address,
+    proof: [u8; SORTITION_PROOF_SIZE],
+}
+
+impl SortitionPayload {
+    pub fn new(validator: Address, proof: [u8; SORTITION_PROOF_SIZE]) -> Self {
+        SortitionPayload { validator, proof }
+    }
+}
+
+impl Encodable for SortitionPayload {
+    fn encode(&self, w: &mut dyn std::io::Write) -> Result<(), EncoderError> {
+        self.validator.encode(w)?;
+        encode_fix_slice(&self.proof, w)?;
+
+        Ok(())
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.validator.encoded_size() + SORTITION_PROOF_SIZE
+    }
+}
+
+impl Decodable for SortitionPayload {
+    fn decode(r: &mut dyn std::io::Read) -> Result<Self, EncoderError> {
+        let validator = Address::decode(r)?;
+        let proof = decode_fix_slice::<SORTITION_PROOF_SIZE>(r)?;
+
+        Ok(SortitionPayload { validator, proof })
+    }
+}
+
+impl Payload for SortitionPayload {
+    fn signer(&self) -> &Address {
+        &self.validator
+    }
+    fn value(&self) -> Amount {
+        Amount(0)
+    }
+    fn payload_type(&self) -> PayloadType {
+        PayloadType::Sortition
+    }
+}
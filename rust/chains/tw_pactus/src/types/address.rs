@@ -83,6 +83,10 @@ impl Address {
         })
     }
 
+    pub fn is_ed25519_account(&self) -> bool {
+        self.addr_type == AddressType::Ed25519Account
+    }
+
     pub fn is_treasury(&self) -> bool {
         self.addr_type == AddressType::Treasury && self.pub_hash.is_zero()
     }
@@ -26,6 +26,13 @@ impl Address {
 
 impl EvmAddress for Address {}
 
+impl From<EthAddress> for Address {
+    #[inline]
+    fn from(addr: EthAddress) -> Self {
+        Address(addr)
+    }
+}
+
 impl From<Address> for EthAddress {
     #[inline]
     fn from(addr: Address) -> Self {
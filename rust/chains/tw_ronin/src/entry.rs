@@ -11,7 +11,6 @@ use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::transaction_util::NoTransactionUtil;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
@@ -19,6 +18,7 @@ use tw_evm::evm_entry::EvmEntry;
 use tw_evm::modules::compiler::Compiler;
 use tw_evm::modules::message_signer::EthMessageSigner;
 use tw_evm::modules::signer::Signer;
+use tw_evm::modules::transaction_decoder::EvmTransactionDecoder;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Ethereum::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -37,7 +37,7 @@ impl CoinEntry for RoninEntry {
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = EthMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = EvmTransactionDecoder<RoninContext>;
     type TransactionUtil = NoTransactionUtil;
 
     #[inline]
@@ -97,6 +97,11 @@ impl CoinEntry for RoninEntry {
     fn message_signer(&self) -> Option<Self::MessageSigner> {
         Some(EthMessageSigner)
     }
+
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(EvmTransactionDecoder::<RoninContext>::default())
+    }
 }
 
 impl EvmEntry for RoninEntry {
@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::multisig::MultiSigPublicKey;
+use crate::signature::SuiPublicKey;
 use move_core_types::account_address::AccountAddress;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -13,11 +15,6 @@ use tw_hash::blake2::blake2_b;
 use tw_keypair::ed25519;
 use tw_memory::Data;
 
-#[repr(u8)]
-pub enum Scheme {
-    Ed25519 = 0,
-}
-
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct SuiAddress(AccountAddress);
 
@@ -26,22 +23,37 @@ impl SuiAddress {
 
     /// Initializes an address with a `ed25519` public key.
     pub fn with_ed25519_pubkey(pubkey: &ed25519::sha512::PublicKey) -> AddressResult<Self> {
-        const CAPACITY: usize = ed25519::sha512::PublicKey::LEN + 1;
+        SuiAddress::with_public_key(&SuiPublicKey::Ed25519(pubkey.clone()))
+    }
 
-        let mut to_hash = Vec::with_capacity(CAPACITY);
-        to_hash.push(Scheme::Ed25519 as u8);
-        to_hash.extend_from_slice(pubkey.as_slice());
-        let hashed =
-            blake2_b(to_hash.as_slice(), SuiAddress::LENGTH).map_err(|_| AddressError::Internal)?;
+    /// Initializes an address with a single-key account public key.
+    /// The address is `blake2b_256(flag || public_key)`.
+    pub fn with_public_key(pubkey: &SuiPublicKey) -> AddressResult<Self> {
+        let pubkey_bytes = pubkey.to_bytes();
 
-        AccountAddress::from_bytes(hashed)
-            .map(SuiAddress)
-            .map_err(|_| AddressError::Internal)
+        let mut to_hash = Vec::with_capacity(pubkey_bytes.len() + 1);
+        to_hash.push(pubkey.scheme().flag());
+        to_hash.extend_from_slice(&pubkey_bytes);
+        SuiAddress::with_hashed_data(&to_hash)
+    }
+
+    /// Initializes an address with a MultiSig account public key.
+    /// The address is `blake2b_256(flag || threshold || flag_1 || pk_1 || weight_1 || ...)`.
+    pub fn with_multisig_pubkey(pubkey: &MultiSigPublicKey) -> AddressResult<Self> {
+        SuiAddress::with_hashed_data(&pubkey.address_preimage())
     }
 
     pub fn into_inner(self) -> AccountAddress {
         self.0
     }
+
+    fn with_hashed_data(to_hash: &[u8]) -> AddressResult<Self> {
+        let hashed = blake2_b(to_hash, SuiAddress::LENGTH).map_err(|_| AddressError::Internal)?;
+
+        AccountAddress::from_bytes(hashed)
+            .map(SuiAddress)
+            .map_err(|_| AddressError::Internal)
+    }
 }
 
 impl FromStr for SuiAddress {
@@ -72,6 +84,9 @@ impl CoinAddress for SuiAddress {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::multisig::MultiSigMember;
+    use crate::signature::SignatureScheme;
+    use tw_encoding::hex::DecodeHex;
     use tw_keypair::ed25519::sha512::PrivateKey;
 
     #[test]
@@ -88,6 +103,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_secp256k1_public_key() {
+        let public = SuiPublicKey::new(
+            SignatureScheme::Secp256k1,
+            &"02f80b93ebf4a84ad10a92f4e83ab88a0a36de801c0b5ab2492b478fe48548ab76"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+        let addr = SuiAddress::with_public_key(&public).unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "0xbc6d9c914ecd51dee12657847cda0bea5459604a151b2d577108aa4cd2787871"
+        );
+    }
+
+    #[test]
+    fn test_from_secp256r1_public_key() {
+        let public = SuiPublicKey::new(
+            SignatureScheme::Secp256r1,
+            &"03ec15e4a8461ffd8030831bda34367a0b285ef4c70e32b10f81d16272e71fd909"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+        let addr = SuiAddress::with_public_key(&public).unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "0x92f119c7454df182218393f7d194cba362927522f83532f9ff40b65bee999ce3"
+        );
+    }
+
+    #[test]
+    fn test_from_multisig_public_key() {
+        let member = |scheme, public_key: &str, weight| MultiSigMember {
+            public_key: SuiPublicKey::new(scheme, &public_key.decode_hex().unwrap()).unwrap(),
+            weight,
+        };
+        let members = vec![
+            member(
+                SignatureScheme::ED25519,
+                "6a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e",
+                2,
+            ),
+            member(
+                SignatureScheme::Secp256k1,
+                "032ec37418f27fb4fa9680bfede082cadb12f950d18d046b0b2e126e1737336617",
+                1,
+            ),
+            member(
+                SignatureScheme::Secp256r1,
+                "030451a7fafbabc4f55d1c18455a30b5e2c8503cdfe43570691db7f8205ac15dcc",
+                1,
+            ),
+        ];
+        let multisig = MultiSigPublicKey::new(members, 2).unwrap();
+        let addr = SuiAddress::with_multisig_pubkey(&multisig).unwrap();
+        assert_eq!(
+            addr.to_string(),
+            "0x2e562f1e43fe8c4733e61ab1c1228bb13e1746bb8e15075917ad9807d7e0e5a8"
+        );
+    }
+
     /// https://github.com/trustwallet/wallet-core/issues/3837
     #[test]
     fn test_sui_address_str_with_leading_zero() {
@@ -4,7 +4,8 @@
 
 use crate::modules::tx_builder::{TWTransaction, TWTransactionBuilder};
 use crate::modules::tx_signer::{TransactionPreimage, TxSigner};
-use crate::signature::SuiSignatureInfo;
+use crate::multisig::{MultiSig, MultiSigPublicKey};
+use crate::signature::{SuiPublicKey, SuiSignatureInfo};
 use std::borrow::Cow;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
@@ -12,7 +13,6 @@ use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
 use tw_encoding::base64::{self, STANDARD};
-use tw_keypair::ed25519;
 use tw_proto::Sui::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
 
@@ -69,6 +69,8 @@ impl SuiCompiler {
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
         let builder = TWTransactionBuilder::new(input);
+        let signature_scheme = builder.signature_scheme();
+        let multisig = builder.multisig()?;
         let tx_to_sign = builder.build()?;
 
         let TransactionPreimage {
@@ -78,15 +80,22 @@ impl SuiCompiler {
             TWTransaction::SignDirect(tx_data) => TxSigner::preimage_direct(tx_data),
         }?;
 
-        let SingleSignaturePubkey {
-            signature: raw_signature,
-            public_key: public_key_bytes,
-        } = SingleSignaturePubkey::from_sign_pubkey_list(signatures, public_keys)?;
+        let signature_info = match multisig {
+            Some(multisig) => {
+                let multisig = Self::combine_multisig(&multisig, signatures, public_keys)?;
+                SuiSignatureInfo::MultiSig(multisig)
+            },
+            None => {
+                let SingleSignaturePubkey {
+                    signature: raw_signature,
+                    public_key: public_key_bytes,
+                } = SingleSignaturePubkey::from_sign_pubkey_list(signatures, public_keys)?;
 
-        let signature = ed25519::Signature::try_from(raw_signature.as_slice())?;
-        let public_key = ed25519::sha512::PublicKey::try_from(public_key_bytes.as_slice())?;
-
-        let signature_info = SuiSignatureInfo::ed25519(&signature, &public_key);
+                let public_key = SuiPublicKey::new(signature_scheme, &public_key_bytes)?;
+                let signature = public_key.compact_signature(&raw_signature)?;
+                SuiSignatureInfo::single(signature, public_key)
+            },
+        };
 
         let unsigned_tx = base64::encode(&unsigned_tx_data, STANDARD);
         Ok(Proto::SigningOutput {
@@ -95,4 +104,31 @@ impl SuiCompiler {
             ..Proto::SigningOutput::default()
         })
     }
+
+    /// Combines the signatures of the MultiSig members.
+    /// Each signature is matched with a member by the corresponding public key.
+    fn combine_multisig(
+        multisig: &MultiSigPublicKey,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<MultiSig> {
+        if signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Expected the same number of signatures and public keys");
+        }
+
+        let mut member_signatures = Vec::with_capacity(signatures.len());
+        for (signature, public_key) in signatures.iter().zip(public_keys.iter()) {
+            let member = multisig
+                .members()
+                .iter()
+                .find(|member| member.public_key.to_bytes() == *public_key)
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .context("Public key is not a member of the MultiSig")?;
+            let signature = member.public_key.compact_signature(signature)?;
+            member_signatures.push((member.public_key.clone(), signature));
+        }
+
+        multisig.combine(member_signatures)
+    }
 }
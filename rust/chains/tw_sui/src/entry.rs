@@ -4,7 +4,9 @@
 
 use crate::address::SuiAddress;
 use crate::compiler::SuiCompiler;
+use crate::modules::message_signer::SuiMessageSigner;
 use crate::modules::transaction_util::SuiTransactionUtil;
+use crate::signature::SuiPublicKey;
 use crate::signer::SuiSigner;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
@@ -12,7 +14,6 @@ use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
@@ -33,7 +34,7 @@ impl CoinEntry for SuiEntry {
     // Optional modules:
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
+    type MessageSigner = SuiMessageSigner;
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = SuiTransactionUtil;
@@ -61,10 +62,17 @@ impl CoinEntry for SuiEntry {
         _derivation: Derivation,
         _prefix: Option<Self::AddressPrefix>,
     ) -> AddressResult<Self::Address> {
-        let ed25519_public = public_key
-            .to_ed25519()
-            .ok_or(AddressError::PublicKeyTypeMismatch)?;
-        SuiAddress::with_ed25519_pubkey(ed25519_public)
+        let sui_public = match public_key {
+            PublicKey::Ed25519(public) => SuiPublicKey::Ed25519(public),
+            PublicKey::Secp256k1(public) | PublicKey::Secp256k1Extended(public) => {
+                SuiPublicKey::Secp256k1(public)
+            },
+            PublicKey::Nist256p1(public) | PublicKey::Nist256p1Extended(public) => {
+                SuiPublicKey::Secp256r1(public)
+            },
+            _ => return Err(AddressError::PublicKeyTypeMismatch),
+        };
+        SuiAddress::with_public_key(&sui_public)
     }
 
     #[inline]
@@ -92,6 +100,11 @@ impl CoinEntry for SuiEntry {
         SuiCompiler::compile(coin, input, signatures, public_keys)
     }
 
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(SuiMessageSigner)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(SuiTransactionUtil)
@@ -7,6 +7,7 @@ pub mod compiler;
 pub mod constants;
 pub mod entry;
 pub mod modules;
+pub mod multisig;
 pub mod signature;
 pub mod signer;
 pub mod transaction;
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::SuiAddress;
+use crate::modules::tx_signer::{AppId, Intent, IntentMessage, IntentScope, IntentVersion};
+use crate::signature::{SignatureScheme, SuiKeyPair, SuiSignatureInfo};
+use serde::Serialize;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64::{self, STANDARD};
+use tw_encoding::bcs;
+use tw_hash::blake2::blake2_b;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_proto::Sui::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// Personal message that is serialized as `vector<u8>`.
+#[derive(Serialize)]
+pub struct PersonalMessage {
+    pub message: Data,
+}
+
+pub struct PersonalMessagePreimage {
+    /// `bcs` encoded [`PersonalMessage`] extended with the `IntentMessage`.
+    pub data_to_sign: Data,
+    /// Hash of the [`PersonalMessagePreimage::data_to_sign`].
+    pub hash_to_sign: H256,
+}
+
+/// Signs and verifies personal messages as `signPersonalMessage` Sui wallet method does.
+pub struct SuiMessageSigner;
+
+impl SuiMessageSigner {
+    pub fn preimage(message: &[u8]) -> SigningResult<PersonalMessagePreimage> {
+        let intent_message = IntentMessage {
+            intent: Intent {
+                scope: IntentScope::PersonalMessage,
+                version: IntentVersion::V0,
+                app_id: AppId::Sui,
+            },
+            value: PersonalMessage {
+                message: message.to_vec(),
+            },
+        };
+        let data_to_sign = bcs::encode(&intent_message)
+            .tw_err(|_| SigningErrorType::Error_internal)
+            .context("Error serializing PersonalMessage")?;
+        let hash_to_sign = blake2_b(&data_to_sign, H256::LEN)
+            .and_then(|hash| H256::try_from(hash.as_slice()))
+            .tw_err(|_| SigningErrorType::Error_internal)?;
+
+        Ok(PersonalMessagePreimage {
+            data_to_sign,
+            hash_to_sign,
+        })
+    }
+
+    fn message_preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let preimage = Self::preimage(&input.message)?;
+        Ok(CompilerProto::PreSigningOutput {
+            data: preimage.data_to_sign.into(),
+            data_hash: preimage.hash_to_sign.to_vec().into(),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let scheme = SignatureScheme::from(input.signature_scheme);
+        let signer_key = SuiKeyPair::new(scheme, &input.private_key)?;
+
+        let preimage = Self::preimage(&input.message)?;
+        let signature = signer_key.sign(preimage.hash_to_sign)?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: signature.to_base64().into(),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::MessageVerifyingInput<'_>,
+    ) -> SigningResult<bool> {
+        let signature_bytes = base64::decode(&input.signature, STANDARD)?;
+        let SuiSignatureInfo::Single {
+            signature,
+            public_key,
+        } = SuiSignatureInfo::from_single_bytes(&signature_bytes)?
+        else {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Only single-key signatures are supported");
+        };
+
+        if !input.address.is_empty() {
+            let expected_address = SuiAddress::from_str(&input.address)?;
+            if SuiAddress::with_public_key(&public_key)? != expected_address {
+                return Ok(false);
+            }
+        }
+
+        let preimage = Self::preimage(&input.message)?;
+        Ok(public_key.verify(&signature, preimage.hash_to_sign))
+    }
+}
+
+impl MessageSigner for SuiMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(coin, input).unwrap_or(false)
+    }
+}
@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod message_signer;
 pub mod transaction_util;
 pub mod tx_builder;
 pub mod tx_signer;
@@ -3,17 +3,26 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::SuiAddress;
-use crate::transaction::sui_types::{ObjectDigest, ObjectID, ObjectRef, SequenceNumber};
+use crate::multisig::{MultiSigMember, MultiSigPublicKey};
+use crate::signature::{SignatureScheme, SuiKeyPair, SuiPublicKey};
+use crate::transaction::command::{Argument, Command};
+use crate::transaction::programmable_transaction::ProgrammableTransaction;
+use crate::transaction::sui_types::{
+    CallArg, ObjectArg, ObjectDigest, ObjectID, ObjectRef, SequenceNumber,
+};
 use crate::transaction::transaction_builder::TransactionBuilder;
 use crate::transaction::transaction_data::TransactionData;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::TypeTag;
 use std::borrow::Cow;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::base64::{self, STANDARD};
-use tw_keypair::ed25519;
-use tw_keypair::traits::KeyPairTrait;
 use tw_memory::Data;
 use tw_proto::Sui::Proto;
+use tw_proto::Sui::Proto::mod_Argument::OneOfkind as ArgumentType;
+use tw_proto::Sui::Proto::mod_CallArg::OneOfarg as CallArgType;
+use tw_proto::Sui::Proto::mod_Command::OneOfcommand as CommandType;
 use tw_proto::Sui::Proto::mod_SigningInput::OneOftransaction_payload as TransactionType;
 
 pub enum TWTransaction {
@@ -30,11 +39,42 @@ impl<'a> TWTransactionBuilder<'a> {
         TWTransactionBuilder { input }
     }
 
-    pub fn signer_key(&self) -> SigningResult<ed25519::sha512::KeyPair> {
-        ed25519::sha512::KeyPair::try_from(self.input.private_key.as_ref())
+    pub fn signer_key(&self) -> SigningResult<SuiKeyPair> {
+        SuiKeyPair::new(self.signature_scheme(), self.input.private_key.as_ref())
             .map_err(SigningError::from)
     }
 
+    pub fn signature_scheme(&self) -> SignatureScheme {
+        SignatureScheme::from(self.input.signature_scheme)
+    }
+
+    /// Returns a MultiSig public key if the transaction is signed on behalf of a MultiSig account.
+    pub fn multisig(&self) -> SigningResult<Option<MultiSigPublicKey>> {
+        let Some(ref multisig) = self.input.multisig else {
+            return Ok(None);
+        };
+
+        let members = multisig
+            .public_keys
+            .iter()
+            .map(|member| -> SigningResult<MultiSigMember> {
+                let scheme = SignatureScheme::from(member.scheme);
+                let public_key = SuiPublicKey::new(scheme, member.public_key.as_ref())
+                    .into_tw()
+                    .context("Invalid MultiSig public key")?;
+                let weight = u8::try_from(member.weight)
+                    .tw_err(|_| SigningErrorType::Error_invalid_params)
+                    .context("MultiSig public key weight must be in range [1, 255]")?;
+                Ok(MultiSigMember { public_key, weight })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+        let threshold = u16::try_from(multisig.threshold)
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("MultiSig threshold is too large")?;
+
+        MultiSigPublicKey::new(members, threshold).map(Some)
+    }
+
     pub fn build(self) -> SigningResult<TWTransaction> {
         let tx_data = match self.input.transaction_payload {
             TransactionType::sign_direct_message(ref direct) => {
@@ -53,6 +93,7 @@ impl<'a> TWTransactionBuilder<'a> {
             TransactionType::transfer_object(ref transfer_obj) => {
                 self.transfer_object_from_proto(transfer_obj)
             },
+            TransactionType::programmable_transaction(ref pt) => self.programmable_from_proto(pt),
             TransactionType::None => SigningError::err(SigningErrorType::Error_invalid_params),
         }?;
         Ok(TWTransaction::Transaction(tx_data))
@@ -182,14 +223,47 @@ impl<'a> TWTransactionBuilder<'a> {
         )
     }
 
+    fn programmable_from_proto(
+        &self,
+        pt: &Proto::ProgrammableTransaction<'_>,
+    ) -> SigningResult<TransactionData> {
+        let signer = self.signer_address()?;
+
+        let inputs = pt
+            .inputs
+            .iter()
+            .map(Self::build_call_arg)
+            .collect::<SigningResult<Vec<_>>>()?;
+        let commands = pt
+            .commands
+            .iter()
+            .enumerate()
+            .map(|(idx, command)| {
+                Self::build_command(command)
+                    .with_context(|| format!("Invalid programmable transaction command #{idx}"))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+        let gas_coins = Self::build_coins(&pt.gas_coins)?;
+
+        TransactionBuilder::programmable(
+            signer,
+            ProgrammableTransaction { inputs, commands },
+            gas_coins,
+            self.input.gas_budget,
+            self.input.reference_gas_price,
+        )
+    }
+
     fn signer_address(&self) -> SigningResult<SuiAddress> {
-        if self.input.private_key.is_empty() {
+        if let Some(multisig) = self.multisig()? {
+            SuiAddress::with_multisig_pubkey(&multisig).map_err(SigningError::from)
+        } else if self.input.private_key.is_empty() {
             SuiAddress::from_str(&self.input.signer)
                 .into_tw()
                 .context("Invalid signer address")
         } else {
             let keypair = self.signer_key()?;
-            SuiAddress::with_ed25519_pubkey(keypair.public()).map_err(SigningError::from)
+            SuiAddress::with_public_key(&keypair.public()).map_err(SigningError::from)
         }
     }
 
@@ -212,6 +286,121 @@ impl<'a> TWTransactionBuilder<'a> {
         Ok((object_id, version, object_digest))
     }
 
+    fn build_call_arg(call_arg: &Proto::CallArg) -> SigningResult<CallArg> {
+        match call_arg.arg {
+            CallArgType::pure_pb(ref value) => Ok(CallArg::Pure(value.to_vec())),
+            CallArgType::object(ref object) => {
+                let object = Self::build_coin(object)?;
+                Ok(CallArg::Object(ObjectArg::ImmOrOwnedObject(object)))
+            },
+            CallArgType::shared_object(ref shared) => {
+                let id = ObjectID::from_str(shared.object_id.as_ref())
+                    .context("Invalid shared Object ID")?;
+                Ok(CallArg::Object(ObjectArg::SharedObject {
+                    id,
+                    initial_shared_version: SequenceNumber(shared.initial_shared_version),
+                    mutable: shared.is_mutable,
+                }))
+            },
+            CallArgType::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Empty programmable transaction input"),
+        }
+    }
+
+    fn build_command(command: &Proto::Command) -> SigningResult<Command> {
+        match command.command {
+            CommandType::move_call(ref call) => {
+                let package =
+                    ObjectID::from_str(call.package.as_ref()).context("Invalid package ID")?;
+                let module = Identifier::from_str(call.module.as_ref())
+                    .tw_err(|_| SigningErrorType::Error_invalid_params)
+                    .context("Invalid module name")?;
+                let function = Identifier::from_str(call.function.as_ref())
+                    .tw_err(|_| SigningErrorType::Error_invalid_params)
+                    .context("Invalid function name")?;
+                let type_arguments = call
+                    .type_arguments
+                    .iter()
+                    .map(|type_tag| Self::parse_type_tag(type_tag))
+                    .collect::<SigningResult<Vec<_>>>()?;
+                let arguments = Self::build_arguments(&call.arguments)?;
+
+                Ok(Command::move_call(
+                    package,
+                    module,
+                    function,
+                    type_arguments,
+                    arguments,
+                ))
+            },
+            CommandType::transfer_objects(ref transfer) => {
+                let objects = Self::build_arguments(&transfer.objects)?;
+                let recipient = Self::require_argument(&transfer.recipient)
+                    .context("No 'recipient' specified")?;
+                Ok(Command::TransferObjects(objects, recipient))
+            },
+            CommandType::split_coins(ref split) => {
+                let coin = Self::require_argument(&split.coin).context("No 'coin' specified")?;
+                let amounts = Self::build_arguments(&split.amounts)?;
+                Ok(Command::SplitCoins(coin, amounts))
+            },
+            CommandType::merge_coins(ref merge) => {
+                let destination = Self::require_argument(&merge.destination)
+                    .context("No 'destination' specified")?;
+                let sources = Self::build_arguments(&merge.sources)?;
+                Ok(Command::MergeCoins(destination, sources))
+            },
+            CommandType::make_move_vec(ref make_vec) => {
+                let type_tag = if make_vec.type_tag.is_empty() {
+                    None
+                } else {
+                    Some(Self::parse_type_tag(&make_vec.type_tag)?)
+                };
+                let elements = Self::build_arguments(&make_vec.elements)?;
+                Ok(Command::MakeMoveVec(type_tag, elements))
+            },
+            CommandType::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Empty programmable transaction command"),
+        }
+    }
+
+    fn build_arguments(arguments: &[Proto::Argument]) -> SigningResult<Vec<Argument>> {
+        arguments.iter().map(Self::build_argument).collect()
+    }
+
+    fn require_argument(maybe_argument: &Option<Proto::Argument>) -> SigningResult<Argument> {
+        let argument = maybe_argument
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)?;
+        Self::build_argument(argument)
+    }
+
+    fn build_argument(argument: &Proto::Argument) -> SigningResult<Argument> {
+        fn to_index(index: u32) -> SigningResult<u16> {
+            u16::try_from(index)
+                .tw_err(|_| SigningErrorType::Error_invalid_params)
+                .context("Argument index is too large")
+        }
+
+        match argument.kind {
+            ArgumentType::gas_coin(_) => Ok(Argument::GasCoin),
+            ArgumentType::input(input) => Ok(Argument::Input(to_index(input)?)),
+            ArgumentType::result(result) => Ok(Argument::Result(to_index(result)?)),
+            ArgumentType::nested_result(ref nested) => Ok(Argument::NestedResult(
+                to_index(nested.result)?,
+                to_index(nested.index)?,
+            )),
+            ArgumentType::None => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Empty command argument"),
+        }
+    }
+
+    fn parse_type_tag(type_tag: &str) -> SigningResult<TypeTag> {
+        TypeTag::from_str(type_tag)
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("Invalid type tag: {type_tag}"))
+    }
+
     fn parse_addresses(addresses: &[Cow<'_, str>]) -> SigningResult<Vec<SuiAddress>> {
         let mut res = Vec::with_capacity(addresses.len());
         for addr in addresses {
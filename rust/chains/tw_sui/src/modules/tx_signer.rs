@@ -3,7 +3,8 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::SuiAddress;
-use crate::signature::SuiSignatureInfo;
+use crate::multisig::MultiSigPublicKey;
+use crate::signature::{SuiKeyPair, SuiSignatureInfo};
 use crate::transaction::transaction_data::TransactionData;
 use serde::Serialize;
 use serde_repr::Serialize_repr;
@@ -11,8 +12,6 @@ use tw_coin_entry::error::prelude::*;
 use tw_encoding::bcs;
 use tw_hash::blake2::blake2_b;
 use tw_hash::H256;
-use tw_keypair::ed25519;
-use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
 use tw_memory::Data;
 
 /// This enums specifies the intent scope.
@@ -21,6 +20,8 @@ use tw_memory::Data;
 pub enum IntentScope {
     /// Used for a user signature on a transaction data.
     TransactionData = 0,
+    /// Used for a user signature on a personal message.
+    PersonalMessage = 3,
 }
 
 /// The version here is to distinguish between signing different versions of the struct
@@ -81,12 +82,18 @@ pub struct TransactionPreimage {
 pub struct TxSigner;
 
 impl TxSigner {
+    /// Signs the transaction with the `signer_key`.
+    /// If `multisig` is set, the signature is wrapped into a MultiSig authenticator,
+    /// so the `signer_key` weight must reach the MultiSig threshold.
     pub fn sign(
         tx: &TransactionData,
-        signer_key: &ed25519::sha512::KeyPair,
+        signer_key: &SuiKeyPair,
+        multisig: Option<&MultiSigPublicKey>,
     ) -> SigningResult<(TransactionPreimage, SuiSignatureInfo)> {
-        let public_key = signer_key.public();
-        let signer_address = SuiAddress::with_ed25519_pubkey(public_key)?;
+        let signer_address = match multisig {
+            Some(multisig) => SuiAddress::with_multisig_pubkey(multisig)?,
+            None => SuiAddress::with_public_key(&signer_key.public())?,
+        };
         if signer_address != tx.sender() {
             return SigningError::err(SigningErrorType::Error_missing_private_key)
                 .context("Given private key does not belong to the sender address");
@@ -95,16 +102,27 @@ impl TxSigner {
         let unsigned_tx_data = bcs::encode(tx)
             .tw_err(|_| SigningErrorType::Error_internal)
             .context("Error serializing TransactionData")?;
-        Self::sign_direct(unsigned_tx_data, signer_key)
+        Self::sign_direct(unsigned_tx_data, signer_key, multisig)
     }
 
     pub fn sign_direct(
         unsigned_tx_data: Data,
-        signer_key: &ed25519::sha512::KeyPair,
+        signer_key: &SuiKeyPair,
+        multisig: Option<&MultiSigPublicKey>,
     ) -> SigningResult<(TransactionPreimage, SuiSignatureInfo)> {
         let preimage = Self::preimage_direct(unsigned_tx_data)?;
-        let signature = signer_key.sign(preimage.tx_hash_to_sign.into_vec())?;
-        let signature_info = SuiSignatureInfo::ed25519(&signature, signer_key.public());
+        let signature_info = signer_key.sign(preimage.tx_hash_to_sign)?;
+
+        let signature_info = match (multisig, signature_info) {
+            (
+                Some(multisig),
+                SuiSignatureInfo::Single {
+                    signature,
+                    public_key,
+                },
+            ) => SuiSignatureInfo::MultiSig(multisig.combine([(public_key, signature)])?),
+            (_, signature_info) => signature_info,
+        };
         Ok((preimage, signature_info))
     }
 
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Sui MultiSig authenticator.
+//! https://docs.sui.io/concepts/cryptography/transaction-auth/multisig
+
+use crate::signature::{SignatureScheme, SuiPublicKey};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H512;
+use tw_memory::Data;
+
+/// The maximum number of public keys a MultiSig account can consist of.
+pub const MAX_SIGNER_IN_MULTISIG: usize = 10;
+
+/// A weighted member of a MultiSig account.
+#[derive(Clone)]
+pub struct MultiSigMember {
+    pub public_key: SuiPublicKey,
+    pub weight: u8,
+}
+
+/// MultiSig account, i.e. a list of weighted public keys and a threshold.
+#[derive(Clone)]
+pub struct MultiSigPublicKey {
+    members: Vec<MultiSigMember>,
+    threshold: u16,
+}
+
+impl MultiSigPublicKey {
+    pub fn new(members: Vec<MultiSigMember>, threshold: u16) -> SigningResult<Self> {
+        if members.is_empty() || members.len() > MAX_SIGNER_IN_MULTISIG {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("MultiSig must have from 1 to {MAX_SIGNER_IN_MULTISIG} public keys")
+            });
+        }
+        if members.iter().any(|member| member.weight == 0) {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("MultiSig public key weight must be positive");
+        }
+        for (i, member) in members.iter().enumerate() {
+            let is_duplicate = members[..i]
+                .iter()
+                .any(|prev| prev.public_key == member.public_key);
+            if is_duplicate {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("MultiSig public keys must be unique");
+            }
+        }
+
+        let total_weight: u16 = members.iter().map(|member| member.weight as u16).sum();
+        if threshold == 0 || threshold > total_weight {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("MultiSig threshold must be in range [1, {total_weight}]")
+            });
+        }
+
+        Ok(MultiSigPublicKey { members, threshold })
+    }
+
+    pub fn members(&self) -> &[MultiSigMember] {
+        &self.members
+    }
+
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the data the MultiSig address is derived from:
+    /// `flag || threshold || flag_1 || pk_1 || weight_1 || ... || flag_n || pk_n || weight_n`.
+    pub fn address_preimage(&self) -> Data {
+        let mut data = vec![SignatureScheme::MultiSig.flag()];
+        data.extend_from_slice(&self.threshold.to_le_bytes());
+        for member in self.members.iter() {
+            data.push(member.public_key.scheme().flag());
+            data.extend_from_slice(&member.public_key.to_bytes());
+            data.push(member.weight);
+        }
+        data
+    }
+
+    /// Combines the signatures made by the MultiSig members into a MultiSig authenticator.
+    /// The sum of the signer weights must reach the threshold.
+    pub fn combine<I>(&self, signatures: I) -> SigningResult<MultiSig>
+    where
+        I: IntoIterator<Item = (SuiPublicKey, H512)>,
+    {
+        let mut signed: Vec<(usize, H512)> = Vec::with_capacity(self.members.len());
+        for (public_key, signature) in signatures {
+            let index = self
+                .members
+                .iter()
+                .position(|member| member.public_key == public_key)
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .context("Signer public key is not a member of the MultiSig")?;
+            if signed
+                .iter()
+                .any(|(signed_index, _)| *signed_index == index)
+            {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Each MultiSig member can sign only once");
+            }
+            signed.push((index, signature));
+        }
+        // Signatures must be ordered the same way as the MultiSig public keys.
+        signed.sort_by_key(|(index, _)| *index);
+
+        let weight: u16 = signed
+            .iter()
+            .map(|(index, _)| self.members[*index].weight as u16)
+            .sum();
+        if weight < self.threshold {
+            return SigningError::err(SigningErrorType::Error_signing).with_context(|| {
+                format!(
+                    "Insufficient MultiSig signatures weight: {weight}, threshold: {}",
+                    self.threshold
+                )
+            });
+        }
+
+        let bitmap = signed
+            .iter()
+            .fold(0_u16, |bitmap, (index, _)| bitmap | (1 << index));
+        let signatures = signed
+            .into_iter()
+            .map(|(index, signature)| (self.members[index].public_key.scheme(), signature))
+            .collect();
+
+        Ok(MultiSig {
+            signatures,
+            bitmap,
+            public_key: self.clone(),
+        })
+    }
+
+    fn encode_bcs(&self, dest: &mut Data) {
+        push_length(dest, self.members.len());
+        for member in self.members.iter() {
+            // `PublicKey` enum variant followed by the fixed size public key bytes.
+            dest.push(member.public_key.scheme().flag());
+            dest.extend_from_slice(&member.public_key.to_bytes());
+            dest.push(member.weight);
+        }
+        dest.extend_from_slice(&self.threshold.to_le_bytes());
+    }
+}
+
+/// MultiSig authenticator that consists of the member signatures,
+/// the bitmap of the members that signed, and the MultiSig public key.
+pub struct MultiSig {
+    signatures: Vec<(SignatureScheme, H512)>,
+    bitmap: u16,
+    public_key: MultiSigPublicKey,
+}
+
+impl MultiSig {
+    pub fn public_key(&self) -> &MultiSigPublicKey {
+        &self.public_key
+    }
+
+    pub fn bitmap(&self) -> u16 {
+        self.bitmap
+    }
+
+    /// Returns the `bcs` encoded MultiSig without the scheme flag.
+    ///
+    /// Please note that the compressed signatures and public keys are fixed size arrays,
+    /// so they are serialized without a length prefix.
+    pub fn to_bcs(&self) -> Data {
+        let mut dest = Data::new();
+        push_length(&mut dest, self.signatures.len());
+        for (scheme, signature) in self.signatures.iter() {
+            // `CompressedSignature` enum variant followed by the signature bytes.
+            dest.push(scheme.flag());
+            dest.extend_from_slice(signature.as_slice());
+        }
+        dest.extend_from_slice(&self.bitmap.to_le_bytes());
+        self.public_key.encode_bcs(&mut dest);
+        dest
+    }
+}
+
+/// `bcs` sequence length is ULEB128 encoded,
+/// but it always fits a byte as there are at most [`MAX_SIGNER_IN_MULTISIG`] items.
+fn push_length(dest: &mut Data, len: usize) {
+    debug_assert!(len <= MAX_SIGNER_IN_MULTISIG);
+    dest.push(len as u8);
+}
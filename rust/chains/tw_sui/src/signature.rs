@@ -2,21 +2,207 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::multisig::MultiSig;
 use tw_encoding::base64::{self, STANDARD};
+use tw_hash::sha2::sha256;
 use tw_hash::{H256, H512};
-use tw_keypair::ed25519;
+use tw_keypair::ecdsa::{nist256p1, secp256k1};
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+use tw_keypair::{ed25519, KeyPairError, KeyPairResult};
 use tw_memory::Data;
+use tw_proto::Sui::Proto;
 
-#[derive(Clone, Copy)]
+/// Signature scheme flag that prefixes serialized signatures and public keys.
+/// https://docs.sui.io/concepts/cryptography/transaction-auth/signatures
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(u8)]
 pub enum SignatureScheme {
     ED25519 = 0,
+    Secp256k1 = 1,
+    Secp256r1 = 2,
+    MultiSig = 3,
 }
 
-pub struct SuiSignatureInfo {
-    scheme: SignatureScheme,
-    signature: H512,
-    public_key: H256,
+impl SignatureScheme {
+    pub fn flag(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_flag(flag: u8) -> Option<Self> {
+        match flag {
+            0 => Some(SignatureScheme::ED25519),
+            1 => Some(SignatureScheme::Secp256k1),
+            2 => Some(SignatureScheme::Secp256r1),
+            3 => Some(SignatureScheme::MultiSig),
+            _ => None,
+        }
+    }
+}
+
+impl From<Proto::SignatureScheme> for SignatureScheme {
+    fn from(scheme: Proto::SignatureScheme) -> Self {
+        match scheme {
+            Proto::SignatureScheme::ED25519 => SignatureScheme::ED25519,
+            Proto::SignatureScheme::SECP256K1 => SignatureScheme::Secp256k1,
+            Proto::SignatureScheme::SECP256R1 => SignatureScheme::Secp256r1,
+        }
+    }
+}
+
+/// Public key of a single-key Sui account.
+#[derive(Clone)]
+pub enum SuiPublicKey {
+    Ed25519(ed25519::sha512::PublicKey),
+    Secp256k1(secp256k1::PublicKey),
+    Secp256r1(nist256p1::PublicKey),
+}
+
+impl SuiPublicKey {
+    /// Parses a public key of the given signature `scheme`.
+    /// Secp256k1 and Secp256r1 public keys are expected to be compressed.
+    pub fn new(scheme: SignatureScheme, bytes: &[u8]) -> KeyPairResult<Self> {
+        match scheme {
+            SignatureScheme::ED25519 => {
+                ed25519::sha512::PublicKey::try_from(bytes).map(SuiPublicKey::Ed25519)
+            },
+            SignatureScheme::Secp256k1 if bytes.len() == secp256k1::PublicKey::COMPRESSED => {
+                secp256k1::PublicKey::try_from(bytes).map(SuiPublicKey::Secp256k1)
+            },
+            SignatureScheme::Secp256r1 if bytes.len() == nist256p1::PublicKey::COMPRESSED => {
+                nist256p1::PublicKey::try_from(bytes).map(SuiPublicKey::Secp256r1)
+            },
+            _ => Err(KeyPairError::InvalidPublicKey),
+        }
+    }
+
+    pub fn scheme(&self) -> SignatureScheme {
+        match self {
+            SuiPublicKey::Ed25519(_) => SignatureScheme::ED25519,
+            SuiPublicKey::Secp256k1(_) => SignatureScheme::Secp256k1,
+            SuiPublicKey::Secp256r1(_) => SignatureScheme::Secp256r1,
+        }
+    }
+
+    /// Returns the public key bytes as they are serialized by Sui,
+    /// i.e. 32 bytes for Ed25519, and 33 compressed bytes for Secp256k1 and Secp256r1.
+    pub fn to_bytes(&self) -> Data {
+        match self {
+            SuiPublicKey::Ed25519(public) => public.to_bytes().to_vec(),
+            SuiPublicKey::Secp256k1(public) => public.compressed().to_vec(),
+            SuiPublicKey::Secp256r1(public) => public.compressed().to_vec(),
+        }
+    }
+
+    /// Converts a raw signature into the 64 bytes representation used by Sui.
+    /// Secp256k1 and Secp256r1 signatures can have an optional recovery byte,
+    /// and they are normalized into the "low S" form.
+    pub fn compact_signature(&self, signature: &[u8]) -> KeyPairResult<H512> {
+        match self {
+            SuiPublicKey::Ed25519(_) => {
+                ed25519::Signature::try_from(signature).map(|sig| sig.to_bytes())
+            },
+            SuiPublicKey::Secp256k1(_) => secp256k1::VerifySignature::try_from(signature)
+                .map(|sig| sig.normalize_s().to_bytes()),
+            SuiPublicKey::Secp256r1(_) => nist256p1::VerifySignature::try_from(signature)
+                .map(|sig| sig.normalize_s().to_bytes()),
+        }
+    }
+
+    /// Verifies the compact `signature` of the intent message `digest`.
+    pub fn verify(&self, signature: &H512, digest: H256) -> bool {
+        match self {
+            SuiPublicKey::Ed25519(public) => {
+                let Ok(signature) = ed25519::Signature::try_from(signature.as_slice()) else {
+                    return false;
+                };
+                public.verify(signature, digest.into_vec())
+            },
+            SuiPublicKey::Secp256k1(public) => {
+                let Ok(signature) = secp256k1::VerifySignature::try_from(signature.as_slice())
+                else {
+                    return false;
+                };
+                public.verify(signature, ecdsa_message(digest))
+            },
+            SuiPublicKey::Secp256r1(public) => {
+                let Ok(signature) = nist256p1::VerifySignature::try_from(signature.as_slice())
+                else {
+                    return false;
+                };
+                public.verify(signature, ecdsa_message(digest))
+            },
+        }
+    }
+}
+
+impl PartialEq for SuiPublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.scheme() == other.scheme() && self.to_bytes() == other.to_bytes()
+    }
+}
+
+/// Private key of a single-key Sui account.
+pub enum SuiKeyPair {
+    Ed25519(ed25519::sha512::KeyPair),
+    Secp256k1(secp256k1::KeyPair),
+    Secp256r1(nist256p1::KeyPair),
+}
+
+impl SuiKeyPair {
+    pub fn new(scheme: SignatureScheme, private_key: &[u8]) -> KeyPairResult<Self> {
+        match scheme {
+            SignatureScheme::ED25519 => {
+                ed25519::sha512::KeyPair::try_from(private_key).map(SuiKeyPair::Ed25519)
+            },
+            SignatureScheme::Secp256k1 => {
+                secp256k1::KeyPair::try_from(private_key).map(SuiKeyPair::Secp256k1)
+            },
+            SignatureScheme::Secp256r1 => {
+                nist256p1::KeyPair::try_from(private_key).map(SuiKeyPair::Secp256r1)
+            },
+            SignatureScheme::MultiSig => Err(KeyPairError::InvalidSecretKey),
+        }
+    }
+
+    pub fn public(&self) -> SuiPublicKey {
+        match self {
+            SuiKeyPair::Ed25519(keypair) => SuiPublicKey::Ed25519(keypair.public().clone()),
+            SuiKeyPair::Secp256k1(keypair) => SuiPublicKey::Secp256k1(keypair.public().clone()),
+            SuiKeyPair::Secp256r1(keypair) => SuiPublicKey::Secp256r1(keypair.public().clone()),
+        }
+    }
+
+    /// Signs the intent message `digest`.
+    /// Secp256k1 and Secp256r1 keys sign the SHA256 hash of the digest.
+    pub fn sign(&self, digest: H256) -> KeyPairResult<SuiSignatureInfo> {
+        let signature = match self {
+            SuiKeyPair::Ed25519(keypair) => keypair.sign(digest.into_vec())?.to_bytes(),
+            SuiKeyPair::Secp256k1(keypair) => {
+                let signature = keypair.sign(ecdsa_message(digest))?;
+                secp256k1::VerifySignature::from(signature)
+                    .normalize_s()
+                    .to_bytes()
+            },
+            SuiKeyPair::Secp256r1(keypair) => {
+                let signature = keypair.sign(ecdsa_message(digest))?;
+                nist256p1::VerifySignature::from(signature)
+                    .normalize_s()
+                    .to_bytes()
+            },
+        };
+        Ok(SuiSignatureInfo::single(signature, self.public()))
+    }
+}
+
+/// Serialized signature that authorizes a transaction or a personal message.
+pub enum SuiSignatureInfo {
+    /// `flag || signature || public_key`.
+    Single {
+        signature: H512,
+        public_key: SuiPublicKey,
+    },
+    /// `flag || bcs(MultiSig)`.
+    MultiSig(MultiSig),
 }
 
 impl SuiSignatureInfo {
@@ -24,18 +210,53 @@ impl SuiSignatureInfo {
         signature: &ed25519::Signature,
         public_key: &ed25519::sha512::PublicKey,
     ) -> SuiSignatureInfo {
-        SuiSignatureInfo {
-            scheme: SignatureScheme::ED25519,
-            signature: signature.to_bytes(),
-            public_key: public_key.to_bytes(),
+        SuiSignatureInfo::single(
+            signature.to_bytes(),
+            SuiPublicKey::Ed25519(public_key.clone()),
+        )
+    }
+
+    pub fn single(signature: H512, public_key: SuiPublicKey) -> SuiSignatureInfo {
+        SuiSignatureInfo::Single {
+            signature,
+            public_key,
+        }
+    }
+
+    /// Parses a serialized single-key signature, i.e. `flag || signature || public_key`.
+    /// MultiSig signatures are not supported.
+    pub fn from_single_bytes(bytes: &[u8]) -> KeyPairResult<SuiSignatureInfo> {
+        let (flag, rest) = bytes.split_first().ok_or(KeyPairError::InvalidSignature)?;
+        let scheme = SignatureScheme::from_flag(*flag).ok_or(KeyPairError::InvalidSignature)?;
+        if rest.len() < H512::LEN {
+            return Err(KeyPairError::InvalidSignature);
+        }
+
+        let (signature, public_key) = rest.split_at(H512::LEN);
+        let signature = H512::try_from(signature).map_err(|_| KeyPairError::InvalidSignature)?;
+        let public_key = SuiPublicKey::new(scheme, public_key)?;
+        Ok(SuiSignatureInfo::single(signature, public_key))
+    }
+
+    pub fn scheme(&self) -> SignatureScheme {
+        match self {
+            SuiSignatureInfo::Single { public_key, .. } => public_key.scheme(),
+            SuiSignatureInfo::MultiSig(_) => SignatureScheme::MultiSig,
         }
     }
 
     pub fn to_vec(&self) -> Data {
-        let mut scheme: Data = Vec::with_capacity(H512::LEN + H256::LEN + 1);
-        scheme.push(self.scheme as u8);
-        scheme.extend_from_slice(self.signature.as_slice());
-        scheme.extend_from_slice(self.public_key.as_slice());
+        let mut scheme: Data = vec![self.scheme().flag()];
+        match self {
+            SuiSignatureInfo::Single {
+                signature,
+                public_key,
+            } => {
+                scheme.extend_from_slice(signature.as_slice());
+                scheme.extend_from_slice(&public_key.to_bytes());
+            },
+            SuiSignatureInfo::MultiSig(multisig) => scheme.extend_from_slice(&multisig.to_bcs()),
+        }
         scheme
     }
 
@@ -43,3 +264,8 @@ impl SuiSignatureInfo {
         base64::encode(&self.to_vec(), STANDARD)
     }
 }
+
+/// Secp256k1 and Secp256r1 signatures are made over the SHA256 hash of the intent message digest.
+fn ecdsa_message(digest: H256) -> H256 {
+    H256::try_from(sha256(digest.as_slice()).as_slice()).expect("sha256 returns 32 bytes")
+}
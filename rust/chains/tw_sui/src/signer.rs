@@ -28,11 +28,14 @@ impl SuiSigner {
     ) -> SigningResult<Proto::SigningOutput<'static>> {
         let builder = TWTransactionBuilder::new(input);
         let signer_key = builder.signer_key()?;
+        let multisig = builder.multisig()?;
         let tx_to_sign = builder.build()?;
 
         let (preimage, signature) = match tx_to_sign {
-            TWTransaction::Transaction(tx) => TxSigner::sign(&tx, &signer_key)?,
-            TWTransaction::SignDirect(tx_data) => TxSigner::sign_direct(tx_data, &signer_key)?,
+            TWTransaction::Transaction(tx) => TxSigner::sign(&tx, &signer_key, multisig.as_ref())?,
+            TWTransaction::SignDirect(tx_data) => {
+                TxSigner::sign_direct(tx_data, &signer_key, multisig.as_ref())?
+            },
         };
 
         let unsigned_tx = base64::encode(&preimage.unsigned_tx_data, STANDARD);
@@ -33,6 +33,24 @@ pub enum Command {
 }
 
 impl Command {
+    /// Returns all arguments the command refers to.
+    pub fn arguments(&self) -> Vec<&Argument> {
+        match self {
+            Command::MoveCall(call) => call.arguments.iter().collect(),
+            Command::TransferObjects(objects, recipient) => {
+                objects.iter().chain(std::iter::once(recipient)).collect()
+            },
+            Command::SplitCoins(coin, amounts) => {
+                std::iter::once(coin).chain(amounts.iter()).collect()
+            },
+            Command::MergeCoins(destination, sources) => {
+                std::iter::once(destination).chain(sources.iter()).collect()
+            },
+            Command::Publish(_, _) => Vec::default(),
+            Command::MakeMoveVec(_, elements) => elements.iter().collect(),
+        }
+    }
+
     pub fn move_call(
         package: ObjectID,
         module: Identifier,
@@ -23,6 +23,29 @@ pub struct ProgrammableTransaction {
     pub commands: Vec<Command>,
 }
 
+impl ProgrammableTransaction {
+    /// Checks if the command arguments refer to existing inputs and previous commands.
+    pub fn validate_arguments(&self) -> SigningResult<()> {
+        for (command_idx, command) in self.commands.iter().enumerate() {
+            for argument in command.arguments() {
+                let is_valid = match *argument {
+                    Argument::GasCoin => true,
+                    Argument::Input(input_idx) => (input_idx as usize) < self.inputs.len(),
+                    Argument::Result(result_idx) | Argument::NestedResult(result_idx, _) => {
+                        (result_idx as usize) < command_idx
+                    },
+                };
+                if !is_valid {
+                    return SigningError::err(SigningErrorType::Error_invalid_params).with_context(
+                        || format!("Command #{command_idx} has an invalid argument: {argument:?}"),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Eq, Hash, PartialEq)]
 enum BuilderArg {
     Object(ObjectID),
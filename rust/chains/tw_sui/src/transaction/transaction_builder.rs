@@ -8,7 +8,9 @@ use crate::constants::{
     WITHDRAW_STAKE_FUN_NAME,
 };
 use crate::transaction::command::Command;
-use crate::transaction::programmable_transaction::ProgrammableTransactionBuilder;
+use crate::transaction::programmable_transaction::{
+    ProgrammableTransaction, ProgrammableTransactionBuilder,
+};
 use crate::transaction::sui_types::{CallArg, ObjectArg, ObjectRef};
 use crate::transaction::transaction_data::{TransactionData, TransactionKind};
 use tw_coin_entry::error::prelude::*;
@@ -164,6 +166,25 @@ impl TransactionBuilder {
         ))
     }
 
+    /// Builds a programmable transaction of the given inputs and commands as is.
+    pub fn programmable(
+        signer: SuiAddress,
+        pt: ProgrammableTransaction,
+        gas_coins: Vec<ObjectRef>,
+        gas_budget: u64,
+        gas_price: u64,
+    ) -> SigningResult<TransactionData> {
+        if gas_coins.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Empty gas coins for Programmable transaction");
+        }
+        pt.validate_arguments()?;
+
+        Ok(TransactionData::new_programmable(
+            signer, gas_coins, pt, gas_budget, gas_price,
+        ))
+    }
+
     pub fn transfer_object(
         signer: SuiAddress,
         object: ObjectRef,
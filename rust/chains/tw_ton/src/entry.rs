@@ -4,6 +4,7 @@
 
 use crate::address::TonAddress;
 use crate::compiler::TheOpenNetworkCompiler;
+use crate::modules::transaction_decoder::TonTransactionDecoder;
 use crate::modules::transaction_util::TonTransactionUtil;
 use crate::signer::TheOpenNetworkSigner;
 use crate::wallet::{wallet_v4, VersionedTonWallet};
@@ -15,7 +16,6 @@ use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
-use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
@@ -36,7 +36,7 @@ impl CoinEntry for TheOpenNetworkEntry {
     type PlanBuilder = NoPlanBuilder;
     type MessageSigner = NoMessageSigner;
     type WalletConnector = NoWalletConnector;
-    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionDecoder = TonTransactionDecoder;
     type TransactionUtil = TonTransactionUtil;
 
     #[inline]
@@ -99,6 +99,11 @@ impl CoinEntry for TheOpenNetworkEntry {
         TheOpenNetworkCompiler::compile(coin, input, signatures, public_keys)
     }
 
+    #[inline]
+    fn transaction_decoder(&self) -> Option<Self::TransactionDecoder> {
+        Some(TonTransactionDecoder)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(TonTransactionUtil)
@@ -2,5 +2,6 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod wallet_v3;
 pub mod wallet_v4;
 pub mod wallet_v5;
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_memory::Data;
+use tw_ton_sdk::cell::cell_builder::CellBuilder;
+use tw_ton_sdk::cell::Cell;
+use tw_ton_sdk::error::CellResult;
+
+pub const ENCRYPTED_COMMENT: u32 = 0x2167da4b;
+
+/// `pub_xor (32 bytes) || msg_key (16 bytes)` followed by at least one AES block.
+pub const MIN_ENCRYPTED_DATA_LEN: usize = 32 + 16 + 16;
+/// Max number of bytes a cell can hold.
+const CELL_BYTES: usize = 127;
+
+/// Transaction payload that consists of an encrypted comment.
+/// The comment must be encrypted for the recipient beforehand.
+/// See: https://docs.ton.org/develop/smart-contracts/guidelines/internal-messages#messages-with-encrypted-comments
+pub struct EncryptedCommentPayload {
+    encrypted_data: Data,
+}
+
+impl EncryptedCommentPayload {
+    pub fn new(encrypted_data: Data) -> Self {
+        EncryptedCommentPayload { encrypted_data }
+    }
+
+    pub fn build(&self) -> CellResult<Cell> {
+        // The first cell contains the op code, so it can hold 4 bytes less.
+        let (head, tail) = self.encrypted_data.split_at(
+            self.encrypted_data
+                .len()
+                .min(CELL_BYTES - std::mem::size_of::<u32>()),
+        );
+
+        let mut builder = CellBuilder::new();
+        builder
+            .store_u32(32, ENCRYPTED_COMMENT)?
+            .store_slice(head)?;
+        if let Some(tail) = Self::snake_tail(tail)? {
+            builder.store_child(tail)?;
+        }
+        builder.build()
+    }
+
+    /// Stores the remaining data as a chain of cells (the "snake" format).
+    fn snake_tail(data: &[u8]) -> CellResult<Option<Cell>> {
+        let mut tail = None;
+        for chunk in data.chunks(CELL_BYTES).rev() {
+            let mut builder = CellBuilder::new();
+            builder.store_slice(chunk)?;
+            if let Some(next) = tail {
+                builder.store_child(next)?;
+            }
+            tail = Some(builder.build()?);
+        }
+        Ok(tail)
+    }
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use tw_number::U256;
+use tw_ton_sdk::cell::cell_builder::CellBuilder;
+use tw_ton_sdk::cell::{Cell, CellArc};
+use tw_ton_sdk::error::CellResult;
+
+pub const JETTON_BURN: u32 = 0x595f07bc;
+
+/// Jetton burn message payload (TEP-74).
+#[derive(Debug)]
+pub struct JettonBurnPayload {
+    /// Arbitrary request number.
+    query_id: u64,
+    /// Amount of burned jettons in elementary units.
+    jetton_amount: U256,
+    /// Address where to send a response with confirmation of a successful burn and the rest of the incoming message Toncoins.
+    response_destination: TonAddress,
+    /// Optional custom data.
+    custom_payload: Option<CellArc>,
+}
+
+impl JettonBurnPayload {
+    pub fn new(jetton_amount: U256) -> Self {
+        JettonBurnPayload {
+            query_id: 0,
+            jetton_amount,
+            response_destination: TonAddress::null(),
+            custom_payload: None,
+        }
+    }
+
+    pub fn with_query_id(&mut self, query_id: u64) -> &mut Self {
+        self.query_id = query_id;
+        self
+    }
+
+    pub fn with_response_destination(&mut self, response_destination: TonAddress) -> &mut Self {
+        self.response_destination = response_destination;
+        self
+    }
+
+    pub fn with_custom_payload(&mut self, custom_payload: Option<CellArc>) -> &mut Self {
+        self.custom_payload = custom_payload;
+        self
+    }
+
+    pub fn build(&self) -> CellResult<Cell> {
+        let mut message = CellBuilder::new();
+        message.store_u32(32, JETTON_BURN)?;
+        message.store_u64(64, self.query_id)?;
+        message.store_coins(&self.jetton_amount)?;
+        message.store_address(&self.response_destination)?;
+
+        if let Some(ref cp) = self.custom_payload {
+            message.store_bit(true)?;
+            message.store_reference(cp)?;
+        } else {
+            message.store_bit(false)?;
+        }
+
+        message.build()
+    }
+}
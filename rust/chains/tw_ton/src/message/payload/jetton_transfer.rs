@@ -27,10 +27,8 @@ pub struct JettonTransferPayload {
     /// Amount of nanotons to be sent to the destination address.
     forward_ton_amount: U256,
     /// Optional custom data that should be sent to the destination address.
-    /// At WalletCore, we do not use `forward_payload` at the moment.
-    #[allow(dead_code)]
     forward_payload: Option<CellArc>,
-    /// Optional transfer comment.
+    /// Optional transfer comment. Ignored if `forward_payload` is set.
     comment: Option<String>,
 }
 
@@ -73,6 +71,11 @@ impl JettonTransferPayload {
         self
     }
 
+    pub fn with_forward_payload(&mut self, forward_payload: Option<CellArc>) -> &mut Self {
+        self.forward_payload = forward_payload;
+        self
+    }
+
     pub fn build(&self) -> CellResult<Cell> {
         if self.forward_ton_amount.is_zero() && self.forward_payload.is_some() {
             return CellError::err(CellErrorType::CellBuilderError)
@@ -95,16 +98,16 @@ impl JettonTransferPayload {
 
         message.store_coins(&self.forward_ton_amount)?;
 
+        // (Either Cell ^Cell)
         if let Some(ref fp) = self.forward_payload {
             message.store_bit(true)?;
             message.store_reference(fp)?;
         } else {
             message.store_bit(false)?;
-        }
-
-        if let Some(ref comment) = self.comment {
-            message.store_u32(32, 0)?;
-            message.store_string(comment)?;
+            if let Some(ref comment) = self.comment {
+                message.store_u32(32, 0)?;
+                message.store_string(comment)?;
+            }
         }
 
         message.build()
@@ -4,4 +4,7 @@
 
 pub mod comment;
 pub mod empty;
+pub mod encrypted_comment;
+pub mod jetton_burn;
 pub mod jetton_transfer;
+pub mod nft_transfer;
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use tw_number::U256;
+use tw_ton_sdk::cell::cell_builder::CellBuilder;
+use tw_ton_sdk::cell::{Cell, CellArc};
+use tw_ton_sdk::error::CellResult;
+
+pub const NFT_TRANSFER: u32 = 0x5fcc3d14;
+
+/// NFT item transfer message payload (TEP-62) with an optional comment.
+#[derive(Debug)]
+pub struct NftTransferPayload {
+    /// Arbitrary request number.
+    query_id: u64,
+    /// Address of the new owner of the NFT item.
+    new_owner: TonAddress,
+    /// Address where to send a response with confirmation of a successful transfer and the rest of the incoming message Toncoins.
+    response_destination: TonAddress,
+    /// Optional custom data.
+    custom_payload: Option<CellArc>,
+    /// Amount of nanotons to be sent to the new owner.
+    forward_amount: U256,
+    /// Optional custom data that should be sent to the new owner.
+    forward_payload: Option<CellArc>,
+    /// Optional transfer comment. Ignored if `forward_payload` is set.
+    comment: Option<String>,
+}
+
+impl NftTransferPayload {
+    pub fn new(new_owner: TonAddress) -> Self {
+        NftTransferPayload {
+            query_id: 0,
+            new_owner,
+            response_destination: TonAddress::null(),
+            custom_payload: None,
+            forward_amount: U256::zero(),
+            forward_payload: None,
+            comment: None,
+        }
+    }
+
+    pub fn with_query_id(&mut self, query_id: u64) -> &mut Self {
+        self.query_id = query_id;
+        self
+    }
+
+    pub fn with_response_destination(&mut self, response_destination: TonAddress) -> &mut Self {
+        self.response_destination = response_destination;
+        self
+    }
+
+    pub fn with_comment(&mut self, comment: String) -> &mut Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    pub fn with_custom_payload(&mut self, custom_payload: Option<CellArc>) -> &mut Self {
+        self.custom_payload = custom_payload;
+        self
+    }
+
+    pub fn with_forward_amount(&mut self, forward_amount: U256) -> &mut Self {
+        self.forward_amount = forward_amount;
+        self
+    }
+
+    pub fn with_forward_payload(&mut self, forward_payload: Option<CellArc>) -> &mut Self {
+        self.forward_payload = forward_payload;
+        self
+    }
+
+    pub fn build(&self) -> CellResult<Cell> {
+        let mut message = CellBuilder::new();
+        message.store_u32(32, NFT_TRANSFER)?;
+        message.store_u64(64, self.query_id)?;
+        message.store_address(&self.new_owner)?;
+        message.store_address(&self.response_destination)?;
+
+        if let Some(ref cp) = self.custom_payload {
+            message.store_bit(true)?;
+            message.store_reference(cp)?;
+        } else {
+            message.store_bit(false)?;
+        }
+
+        message.store_coins(&self.forward_amount)?;
+
+        // (Either Cell ^Cell)
+        if let Some(ref fp) = self.forward_payload {
+            message.store_bit(true)?;
+            message.store_reference(fp)?;
+        } else {
+            message.store_bit(false)?;
+            if let Some(ref comment) = self.comment {
+                message.store_u32(32, 0)?;
+                message.store_string(comment)?;
+            }
+        }
+
+        message.build()
+    }
+}
@@ -16,7 +16,7 @@ impl SignedMessageV4 {
     pub fn build(&self) -> CellResult<Cell> {
         let mut body_builder = CellBuilder::new();
 
-        // In the case of WALLET_V4_R2, the signature is stored before the external message.
+        // In the case of WALLET_V3 and WALLET_V4_R2, the signature is stored before the external message.
         body_builder.store_slice(self.signature.as_slice())?;
         body_builder.store_cell(&self.external_message)?;
 
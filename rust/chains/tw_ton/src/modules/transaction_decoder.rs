@@ -0,0 +1,452 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use crate::compiler::HAS_CRC32;
+use crate::message::external_message::wallet_v5::V5R1OpCode;
+use crate::message::out_list::out_action::OutActionType;
+use crate::message::payload::jetton_transfer::JETTON_TRANSFER;
+use crate::message::payload::nft_transfer::NFT_TRANSFER;
+use crate::transaction::INCOMING_EXTERNAL_TRANSACTION;
+use std::slice::Iter;
+use std::sync::Arc;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::TheOpenNetwork::Proto::mod_Transfer::OneOfpayload as PayloadType;
+use tw_ton_sdk::address::address_data::AddressData;
+use tw_ton_sdk::boc::BagOfCells;
+use tw_ton_sdk::cell::cell_builder::CellBuilder;
+use tw_ton_sdk::cell::cell_parser::CellParser;
+use tw_ton_sdk::cell::{Cell, CellArc};
+use tw_ton_sdk::error::{cell_to_signing_error, CellError, CellErrorType, CellResult};
+
+const COMMENT_OP: u32 = 0;
+const SIGNATURE_LEN: usize = 64;
+
+/// A text comment and a typed payload recognized in a message body.
+type DecodedPayload = (Option<String>, PayloadType<'static>);
+
+pub struct TonTransactionDecoder;
+
+impl TransactionDecoder for TonTransactionDecoder {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(coin, tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl TonTransactionDecoder {
+    /// Decodes a signed external message of a V4R2 or V5R1 wallet serialized as a Bag of Cells.
+    pub(crate) fn decode_transaction_impl(
+        _coin: &dyn CoinContext,
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let boc = BagOfCells::parse(tx)
+            .map_err(cell_to_signing_error)
+            .context("Error parsing transaction as BoC")?;
+        let root = boc.single_root().map_err(cell_to_signing_error)?;
+
+        let transaction = decode_external_message(root)
+            .map_err(cell_to_signing_error)
+            .context("Error decoding an external message")?;
+
+        Ok(Proto::DecodingTransactionOutput {
+            transaction: Some(transaction),
+            ..Proto::DecodingTransactionOutput::default()
+        })
+    }
+}
+
+fn decode_external_message(root: &CellArc) -> CellResult<Proto::DecodedTransaction<'static>> {
+    let mut parser = root.parser();
+    let mut refs = root.references().iter();
+
+    if parser.load_u8(2)? != INCOMING_EXTERNAL_TRANSACTION {
+        return CellError::err(CellErrorType::CellParserError)
+            .context("Expected an incoming external message");
+    }
+    let _src = parser.load_address()?;
+    let wallet_address = parser.load_address()?;
+    let _import_fee = parser.load_coins()?;
+    let state_init = load_maybe_state_init(&mut parser, &mut refs)?;
+    let body = load_either_cell(&mut parser, &mut refs)?;
+
+    let is_v5 =
+        body.parser().load_u32(32).ok() == Some(V5R1OpCode::AuthSignedExternal.to_ser_tag());
+    let mut transaction = if is_v5 {
+        decode_wallet_v5_body(&body)?
+    } else {
+        decode_wallet_v4_body(&body)?
+    };
+
+    transaction.wallet_address = TonAddress::with_address_data(wallet_address)
+        .to_string()
+        .into();
+    transaction.state_init = maybe_to_base64(state_init.as_ref())?.into();
+    Ok(transaction)
+}
+
+/// In the case of WALLET_V4_R2, the signature is stored before the external message.
+fn decode_wallet_v4_body(body: &Cell) -> CellResult<Proto::DecodedTransaction<'static>> {
+    let mut parser = body.parser();
+
+    let signature = parser.load_bytes(SIGNATURE_LEN)?;
+    let _wallet_id = parser.load_u32(32)?;
+    let expire_at = parser.load_u32(32)?;
+    let sequence_number = parser.load_u32(32)?;
+    if parser.load_u8(8)? != 0 {
+        return CellError::err(CellErrorType::CellParserError)
+            .context("Only simple send V4 wallet operations are supported");
+    }
+
+    let mut messages = Vec::with_capacity(body.references().len());
+    for message in body.references() {
+        let mode = parser.load_u8(8)?;
+        messages.push(decode_internal_message(message, mode)?);
+    }
+    parser.ensure_empty()?;
+
+    Ok(Proto::DecodedTransaction {
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        sequence_number,
+        expire_at,
+        signature: signature.into(),
+        messages,
+        ..Proto::DecodedTransaction::default()
+    })
+}
+
+/// In the case of WALLET_V5_R1, the signature is stored after the external message.
+fn decode_wallet_v5_body(body: &Cell) -> CellResult<Proto::DecodedTransaction<'static>> {
+    let mut parser = body.parser();
+    let mut refs = body.references().iter();
+
+    let _opcode = parser.load_u32(32)?;
+    let _wallet_id = parser.load_u32(32)?;
+    let expire_at = parser.load_u32(32)?;
+    let sequence_number = parser.load_u32(32)?;
+    let messages = if parser.load_bit()? {
+        decode_out_list(next_ref(&mut refs)?)?
+    } else {
+        Vec::default()
+    };
+    if parser.load_bit()? {
+        return CellError::err(CellErrorType::CellParserError)
+            .context("Extended actions are not supported");
+    }
+    let signature = parser.load_bytes(SIGNATURE_LEN)?;
+    parser.ensure_empty()?;
+
+    Ok(Proto::DecodedTransaction {
+        wallet_version: Proto::WalletVersion::WALLET_V5_R1,
+        sequence_number,
+        expire_at,
+        signature: signature.into(),
+        messages,
+        ..Proto::DecodedTransaction::default()
+    })
+}
+
+/// The out list is a chain of cells that starts with the last action.
+fn decode_out_list(out_list: &CellArc) -> CellResult<Vec<Proto::Transfer<'static>>> {
+    let mut messages = Vec::new();
+
+    let mut current = Arc::clone(out_list);
+    while !is_empty_cell(&current) {
+        let (prev, message) = match current.references() {
+            [prev, message] => (Arc::clone(prev), message),
+            _ => {
+                return CellError::err(CellErrorType::CellParserError)
+                    .context("Out action must contain a previous action and a message references")
+            },
+        };
+
+        let mut parser = current.parser();
+        if parser.load_u32(32)? != OutActionType::SendMsg.to_ser_tag() {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Only SendMsg out actions are supported");
+        }
+        let mode = parser.load_u8(8)?;
+        parser.ensure_empty()?;
+
+        messages.push(decode_internal_message(message, mode)?);
+        current = prev;
+    }
+
+    messages.reverse();
+    Ok(messages)
+}
+
+fn decode_internal_message(message: &Cell, mode: u8) -> CellResult<Proto::Transfer<'static>> {
+    let mut parser = message.parser();
+    let mut refs = message.references().iter();
+
+    if parser.load_bit()? {
+        return CellError::err(CellErrorType::CellParserError)
+            .context("Expected an internal message");
+    }
+    let _ihr_disabled = parser.load_bit()?;
+    let bounceable = parser.load_bit()?;
+    let _bounced = parser.load_bit()?;
+    let _src = parser.load_address()?;
+    let dest = parser.load_address()?;
+    let amount = load_coins_u64(&mut parser)?;
+    if parser.load_bit()? {
+        return CellError::err(CellErrorType::CellParserError)
+            .context("Extra currencies are not supported");
+    }
+    let _ihr_fee = parser.load_coins()?;
+    let _fwd_fee = parser.load_coins()?;
+    let _created_lt = parser.load_u64(64)?;
+    let _created_at = parser.load_u32(32)?;
+    let state_init = load_maybe_state_init(&mut parser, &mut refs)?;
+    let body = load_either_cell(&mut parser, &mut refs)?;
+
+    let dest = TonAddress::with_address_data(dest).set_bounceable(bounceable);
+    let mut transfer = Proto::Transfer {
+        dest: dest.to_string().into(),
+        amount,
+        mode: mode as u32,
+        bounceable,
+        state_init: maybe_to_base64(state_init.as_ref())?.into(),
+        ..Proto::Transfer::default()
+    };
+
+    if !is_empty_cell(&body) {
+        // Fall back to the raw payload if the body is not recognized.
+        let (comment, payload) = match decode_payload(&body) {
+            Ok(decoded) => decoded,
+            Err(_) => (None, PayloadType::custom_payload(to_base64(&body)?.into())),
+        };
+        transfer.comment = comment.unwrap_or_default().into();
+        transfer.payload = payload;
+    }
+    Ok(transfer)
+}
+
+fn decode_payload(body: &Cell) -> CellResult<DecodedPayload> {
+    match body.parser().load_u32(32)? {
+        COMMENT_OP => Ok((Some(decode_comment(body)?), PayloadType::None)),
+        JETTON_TRANSFER => decode_jetton_transfer(body),
+        NFT_TRANSFER => decode_nft_transfer(body),
+        op => CellError::err(CellErrorType::CellParserError)
+            .context(format!("Unknown payload op: {op:#x}")),
+    }
+}
+
+fn decode_comment(cell: &Cell) -> CellResult<String> {
+    let mut parser = cell.parser();
+    if parser.load_u32(32)? != COMMENT_OP {
+        return CellError::err(CellErrorType::CellParserError).context("Expected a text comment");
+    }
+    let comment = load_snake_bytes(cell, parser)?;
+    String::from_utf8(comment)
+        .tw_err(|_| CellErrorType::CellParserError)
+        .context("Comment must be a valid UTF-8 string")
+}
+
+fn decode_jetton_transfer(body: &Cell) -> CellResult<DecodedPayload> {
+    let mut parser = body.parser();
+    let mut refs = body.references().iter();
+
+    let _op = parser.load_u32(32)?;
+    let query_id = parser.load_u64(64)?;
+    let jetton_amount = load_coins_u64(&mut parser)?;
+    let to_owner = parser.load_address()?;
+    let response_address = parser.load_address()?;
+    let custom_payload = load_maybe_ref(&mut parser, &mut refs)?;
+    let forward_amount = load_coins_u64(&mut parser)?;
+    let forward_payload = load_either_cell(&mut parser, &mut refs)?;
+
+    let (comment, forward_payload) = decode_forward_payload(&forward_payload)?;
+
+    let jetton_transfer = Proto::JettonTransfer {
+        query_id,
+        jetton_amount,
+        to_owner: address_to_string(to_owner).into(),
+        response_address: address_to_string(response_address).into(),
+        forward_amount,
+        custom_payload: maybe_to_base64(custom_payload.as_ref())?.into(),
+        forward_payload: forward_payload.into(),
+    };
+    Ok((comment, PayloadType::jetton_transfer(jetton_transfer)))
+}
+
+fn decode_nft_transfer(body: &Cell) -> CellResult<DecodedPayload> {
+    let mut parser = body.parser();
+    let mut refs = body.references().iter();
+
+    let _op = parser.load_u32(32)?;
+    let query_id = parser.load_u64(64)?;
+    let new_owner = parser.load_address()?;
+    let response_destination = parser.load_address()?;
+    let custom_payload = load_maybe_ref(&mut parser, &mut refs)?;
+    let forward_amount = load_coins_u64(&mut parser)?;
+    let forward_payload = load_either_cell(&mut parser, &mut refs)?;
+
+    let (comment, forward_payload) = decode_forward_payload(&forward_payload)?;
+
+    let nft_transfer = Proto::NftTransfer {
+        query_id,
+        new_owner: address_to_string(new_owner).into(),
+        response_destination: address_to_string(response_destination).into(),
+        custom_payload: maybe_to_base64(custom_payload.as_ref())?.into(),
+        forward_amount,
+        forward_payload: forward_payload.into(),
+    };
+    Ok((comment, PayloadType::nft_transfer(nft_transfer)))
+}
+
+/// Returns either a text comment or the raw forward payload encoded in Base64.
+fn decode_forward_payload(forward_payload: &CellArc) -> CellResult<(Option<String>, String)> {
+    if is_empty_cell(forward_payload) {
+        return Ok((None, String::default()));
+    }
+    match decode_comment(forward_payload) {
+        Ok(comment) => Ok((Some(comment), String::default())),
+        Err(_) => Ok((None, to_base64(forward_payload)?)),
+    }
+}
+
+/// Loads the remaining bytes of the cell and the chain of its child cells (the "snake" format).
+fn load_snake_bytes<'a>(cell: &'a Cell, mut parser: CellParser<'a>) -> CellResult<Data> {
+    let mut data = Data::default();
+    let mut current = cell;
+    loop {
+        let remaining_bits = parser.remaining_bits();
+        let rest_bits = remaining_bits % 8;
+        if rest_bits != 0 {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Snake data must consist of whole bytes");
+        }
+        data.extend(parser.load_bytes(remaining_bits / 8)?);
+
+        match current.references() {
+            [] => return Ok(data),
+            [next] => {
+                current = next;
+                parser = current.parser();
+            },
+            _ => {
+                return CellError::err(CellErrorType::CellParserError)
+                    .context("Snake data cell must contain one reference at most")
+            },
+        }
+    }
+}
+
+/// (Maybe (Either StateInit ^StateInit))
+fn load_maybe_state_init(
+    parser: &mut CellParser,
+    refs: &mut Iter<CellArc>,
+) -> CellResult<Option<CellArc>> {
+    if !parser.load_bit()? {
+        return Ok(None);
+    }
+    if parser.load_bit()? {
+        return next_ref(refs).map(|state_init| Some(Arc::clone(state_init)));
+    }
+    load_inline_state_init(parser, refs).map(Some)
+}
+
+/// split_depth:(Maybe (## 5)) special:(Maybe TickTock) code:(Maybe ^Cell) data:(Maybe ^Cell)
+/// library:(HashmapE 256 SimpleLib)
+/// Returns a new cell with the StateInit stored inline.
+fn load_inline_state_init(
+    parser: &mut CellParser,
+    refs: &mut Iter<CellArc>,
+) -> CellResult<CellArc> {
+    const SPLIT_DEPTH_BITS: usize = 5;
+    const TICK_TOCK_BITS: usize = 2;
+
+    let mut builder = CellBuilder::new();
+    for bit_len in [SPLIT_DEPTH_BITS, TICK_TOCK_BITS] {
+        let is_set = parser.load_bit()?;
+        builder.store_bit(is_set)?;
+        if is_set {
+            builder.store_u8(bit_len, parser.load_u8(bit_len)?)?;
+        }
+    }
+    // `code`, `data` and a non-empty `library` dictionary are stored as references.
+    for _ in 0..3 {
+        let is_set = parser.load_bit()?;
+        builder.store_bit(is_set)?;
+        if is_set {
+            builder.store_reference(next_ref(refs)?)?;
+        }
+    }
+    builder.build().map(Cell::into_arc)
+}
+
+/// (Maybe ^Cell)
+fn load_maybe_ref(
+    parser: &mut CellParser,
+    refs: &mut Iter<CellArc>,
+) -> CellResult<Option<CellArc>> {
+    if parser.load_bit()? {
+        next_ref(refs).map(|cell| Some(Arc::clone(cell)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// (Either Cell ^Cell)
+/// If the cell is stored inline, returns a new cell with the remaining bits and references.
+fn load_either_cell(parser: &mut CellParser, refs: &mut Iter<CellArc>) -> CellResult<CellArc> {
+    if parser.load_bit()? {
+        return next_ref(refs).map(Arc::clone);
+    }
+
+    let mut builder = CellBuilder::new();
+    builder.store_remaining_bits(parser)?;
+    for reference in refs {
+        builder.store_reference(reference)?;
+    }
+    builder.build().map(Cell::into_arc)
+}
+
+fn next_ref<'a>(refs: &mut Iter<'a, CellArc>) -> CellResult<&'a CellArc> {
+    refs.next()
+        .or_tw_err(CellErrorType::CellParserError)
+        .context("Expected a cell reference")
+}
+
+fn load_coins_u64(parser: &mut CellParser) -> CellResult<u64> {
+    let coins: U256 = parser.load_coins()?;
+    u64::try_from(coins)
+        .tw_err(|_| CellErrorType::CellParserError)
+        .context("Amount doesn't fit uint64")
+}
+
+fn is_empty_cell(cell: &Cell) -> bool {
+    cell.bit_len() == 0 && cell.references().is_empty()
+}
+
+/// Returns an empty string if the address is `addr_none`.
+fn address_to_string(address: AddressData) -> String {
+    if address == AddressData::NULL {
+        return String::default();
+    }
+    TonAddress::with_address_data(address).to_string()
+}
+
+fn to_base64(cell: &CellArc) -> CellResult<String> {
+    BagOfCells {
+        roots: vec![Arc::clone(cell)],
+    }
+    .to_base64(HAS_CRC32)
+}
+
+fn maybe_to_base64(cell: Option<&CellArc>) -> CellResult<String> {
+    cell.map(to_base64)
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
@@ -2,7 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::wallet::{wallet_v4, wallet_v5};
+use crate::wallet::wallet_v3::WalletV3Revision;
+use crate::wallet::{wallet_v3, wallet_v4, wallet_v5};
 use tw_keypair::ed25519::sha512::PublicKey;
 use tw_ton_sdk::boc::BagOfCells;
 use tw_ton_sdk::error::CellResult;
@@ -13,6 +14,24 @@ const HAS_CRC32: bool = true;
 pub struct WalletProvider;
 
 impl WalletProvider {
+    /// Constructs a TON Wallet V3R1 stateInit encoded as BoC (BagOfCells) for the given `public_key`.
+    pub fn v3r1_state_init(
+        public_key: PublicKey,
+        workchain: i32,
+        wallet_id: i32,
+    ) -> CellResult<String> {
+        Self::v3_state_init(WalletV3Revision::R1, public_key, workchain, wallet_id)
+    }
+
+    /// Constructs a TON Wallet V3R2 stateInit encoded as BoC (BagOfCells) for the given `public_key`.
+    pub fn v3r2_state_init(
+        public_key: PublicKey,
+        workchain: i32,
+        wallet_id: i32,
+    ) -> CellResult<String> {
+        Self::v3_state_init(WalletV3Revision::R2, public_key, workchain, wallet_id)
+    }
+
     /// Constructs a TON Wallet V4R2 stateInit encoded as BoC (BagOfCells) for the given `public_key`.
     pub fn v4r2_state_init(
         public_key: PublicKey,
@@ -36,4 +55,17 @@ impl WalletProvider {
             .to_cell()?;
         BagOfCells::from_root(state_init).to_base64(HAS_CRC32)
     }
+
+    fn v3_state_init(
+        revision: WalletV3Revision,
+        public_key: PublicKey,
+        workchain: i32,
+        wallet_id: i32,
+    ) -> CellResult<String> {
+        let state_init =
+            wallet_v3::WalletV3::with_public_key(revision, workchain, public_key, wallet_id)?
+                .state_init()?
+                .to_cell()?;
+        BagOfCells::from_root(state_init).to_base64(HAS_CRC32)
+    }
 }
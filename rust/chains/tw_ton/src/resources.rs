@@ -14,6 +14,14 @@ pub const BASE_WORKCHAIN: i32 = 0;
 pub const MASTER_WORKCHAIN: i32 = -1;
 
 lazy_static! {
+    pub static ref WALLET_V3R1_CODE: BagOfCells = {
+        let code = include_str!("../resources/wallet/wallet_v3r1.code");
+        BagOfCells::parse_base64(code).expect("Cannot decode wallet_v3r1.code")
+    };
+    pub static ref WALLET_V3R2_CODE: BagOfCells = {
+        let code = include_str!("../resources/wallet/wallet_v3r2.code");
+        BagOfCells::parse_base64(code).expect("Cannot decode wallet_v3r2.code")
+    };
     pub static ref WALLET_V4R2_CODE: BagOfCells = {
         let code = include_str!("../resources/wallet/wallet_v4r2.code");
         BagOfCells::parse_base64(code).expect("Cannot decode wallet_v4r2.code")
@@ -3,10 +3,13 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::TonAddress;
+use crate::message::payload::encrypted_comment::MIN_ENCRYPTED_DATA_LEN;
 use crate::signing_request::{
-    JettonTransferRequest, SigningRequest, TransferPayload, TransferRequest,
+    JettonBurnRequest, JettonTransferRequest, NftTransferRequest, SigningRequest, TransferPayload,
+    TransferRequest,
 };
-use crate::wallet::{wallet_v4, wallet_v5, VersionedTonWallet};
+use crate::wallet::wallet_v3::WalletV3Revision;
+use crate::wallet::{wallet_v3, wallet_v4, wallet_v5, VersionedTonWallet};
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_keypair::ed25519::sha512::{KeyPair, PublicKey};
@@ -46,7 +49,7 @@ impl SigningRequestBuilder {
         })
     }
 
-    /// Currently, V4R2 and V5R1 wallets supported.
+    /// All of V3R1, V3R2, V4R2 and V5R1 wallets are supported.
     fn wallet(input: &Proto::SigningInput) -> SigningResult<VersionedTonWallet> {
         if !input.private_key.is_empty() {
             let key_pair = KeyPair::try_from(input.private_key.as_ref())
@@ -54,6 +57,14 @@ impl SigningRequestBuilder {
                 .context("Invalid private key")?;
 
             return match input.wallet_version {
+                Proto::WalletVersion::WALLET_V3_R1 => Ok(VersionedTonWallet::V3(
+                    wallet_v3::WalletV3::std_with_key_pair(WalletV3Revision::R1, &key_pair)
+                        .map_err(cell_to_signing_error)?,
+                )),
+                Proto::WalletVersion::WALLET_V3_R2 => Ok(VersionedTonWallet::V3(
+                    wallet_v3::WalletV3::std_with_key_pair(WalletV3Revision::R2, &key_pair)
+                        .map_err(cell_to_signing_error)?,
+                )),
                 Proto::WalletVersion::WALLET_V4_R2 => Ok(VersionedTonWallet::V4R2(
                     wallet_v4::WalletV4R2::std_with_key_pair(&key_pair)
                         .map_err(cell_to_signing_error)?,
@@ -62,8 +73,6 @@ impl SigningRequestBuilder {
                     wallet_v5::WalletV5R1::std_with_key_pair(&key_pair)
                         .map_err(cell_to_signing_error)?,
                 )),
-                _ => SigningError::err(SigningErrorType::Error_not_supported)
-                    .context("Wallet version not supported"),
             };
         }
 
@@ -72,6 +81,14 @@ impl SigningRequestBuilder {
             .context("Expected either 'private_key' or 'public_key' to be set")?;
 
         match input.wallet_version {
+            Proto::WalletVersion::WALLET_V3_R1 => Ok(VersionedTonWallet::V3(
+                wallet_v3::WalletV3::std_with_public_key(WalletV3Revision::R1, public_key)
+                    .map_err(cell_to_signing_error)?,
+            )),
+            Proto::WalletVersion::WALLET_V3_R2 => Ok(VersionedTonWallet::V3(
+                wallet_v3::WalletV3::std_with_public_key(WalletV3Revision::R2, public_key)
+                    .map_err(cell_to_signing_error)?,
+            )),
             Proto::WalletVersion::WALLET_V4_R2 => Ok(VersionedTonWallet::V4R2(
                 wallet_v4::WalletV4R2::std_with_public_key(public_key)
                     .map_err(cell_to_signing_error)?,
@@ -80,8 +97,6 @@ impl SigningRequestBuilder {
                 wallet_v5::WalletV5R1::std_with_public_key(public_key)
                     .map_err(cell_to_signing_error)?,
             )),
-            _ => SigningError::err(SigningErrorType::Error_not_supported)
-                .context("Wallet version not supported"),
         }
     }
 
@@ -113,6 +128,11 @@ impl SigningRequestBuilder {
                 Some(Self::jetton_transfer_request(jetton)?)
             },
             PayloadType::custom_payload(ref custom) => Some(Self::custom_request(custom)?),
+            PayloadType::nft_transfer(ref nft) => Some(Self::nft_transfer_request(nft)?),
+            PayloadType::jetton_burn(ref burn) => Some(Self::jetton_burn_request(burn)?),
+            PayloadType::encrypted_comment(ref data) => {
+                Some(Self::encrypted_comment_request(data)?)
+            },
             PayloadType::None => None,
         };
 
@@ -148,11 +168,65 @@ impl SigningRequestBuilder {
             response_address,
             custom_payload,
             forward_ton_amount: U256::from(input.forward_amount),
+            forward_payload: Self::maybe_boc(&input.forward_payload),
         };
 
         Ok(TransferPayload::JettonTransfer(jetton_payload))
     }
 
+    fn nft_transfer_request(input: &Proto::NftTransfer) -> SigningResult<TransferPayload> {
+        let new_owner = TonAddress::from_str(input.new_owner.as_ref())
+            .into_tw()
+            .context("Invalid 'new_owner' address")?;
+
+        let response_destination = TonAddress::from_str(input.response_destination.as_ref())
+            .into_tw()
+            .context("Invalid 'response_destination' address")?;
+
+        let nft_payload = NftTransferRequest {
+            query_id: input.query_id,
+            new_owner,
+            response_destination,
+            custom_payload: Self::maybe_boc(&input.custom_payload),
+            forward_amount: U256::from(input.forward_amount),
+            forward_payload: Self::maybe_boc(&input.forward_payload),
+        };
+
+        Ok(TransferPayload::NftTransfer(nft_payload))
+    }
+
+    fn jetton_burn_request(input: &Proto::JettonBurn) -> SigningResult<TransferPayload> {
+        let response_address = TonAddress::from_str(input.response_address.as_ref())
+            .into_tw()
+            .context("Invalid 'response_address' address")?;
+
+        let burn_payload = JettonBurnRequest {
+            query_id: input.query_id,
+            jetton_amount: U256::from(input.jetton_amount),
+            response_address,
+            custom_payload: Self::maybe_boc(&input.custom_payload),
+        };
+
+        Ok(TransferPayload::JettonBurn(burn_payload))
+    }
+
+    fn encrypted_comment_request(data: &[u8]) -> SigningResult<TransferPayload> {
+        if data.len() < MIN_ENCRYPTED_DATA_LEN {
+            return SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                "'encrypted_comment' must be at least {MIN_ENCRYPTED_DATA_LEN} bytes long"
+            ));
+        }
+        Ok(TransferPayload::EncryptedComment(data.to_vec()))
+    }
+
+    fn maybe_boc(boc: &str) -> Option<String> {
+        if boc.is_empty() {
+            None
+        } else {
+            Some(boc.to_string())
+        }
+    }
+
     fn custom_request(input: &str) -> SigningResult<TransferPayload> {
         Ok(TransferPayload::Custom(input.to_string()))
     }
@@ -6,9 +6,13 @@ use crate::message::internal_message::transfer::TransferInternalMessage;
 use crate::message::internal_message::InternalMessage;
 use crate::message::payload::comment::CommentPayload;
 use crate::message::payload::empty::EmptyPayload;
+use crate::message::payload::encrypted_comment::EncryptedCommentPayload;
+use crate::message::payload::jetton_burn::JettonBurnPayload;
 use crate::message::payload::jetton_transfer::JettonTransferPayload;
+use crate::message::payload::nft_transfer::NftTransferPayload;
 use crate::signing_request::{
-    JettonTransferRequest, SigningRequest, TransferPayload, TransferRequest,
+    JettonBurnRequest, JettonTransferRequest, NftTransferRequest, SigningRequest, TransferPayload,
+    TransferRequest,
 };
 use std::sync::Arc;
 use tw_coin_entry::error::prelude::ResultContext;
@@ -50,6 +54,13 @@ impl InternalMessageCreator {
                 Self::jetton_transfer_payload(jetton, transfer_request.comment.clone())
             },
             Some(TransferPayload::Custom(ref custom)) => Self::custom_payload(custom),
+            Some(TransferPayload::NftTransfer(ref nft)) => {
+                Self::nft_transfer_payload(nft, transfer_request.comment.clone())
+            },
+            Some(TransferPayload::JettonBurn(ref burn)) => Self::jetton_burn_payload(burn),
+            Some(TransferPayload::EncryptedComment(ref data)) => {
+                Self::encrypted_comment_payload(data)
+            },
             // Otherwise, this is an ordinary TON transfer with an optional comment.
             None => Self::maybe_comment_payload(transfer_request.comment.clone()),
         }
@@ -82,13 +93,16 @@ impl InternalMessageCreator {
         } else {
             None
         };
+        let forward_payload_cell = Self::maybe_single_root(jetton.forward_payload.as_deref())
+            .context("Error parsing JettonTransfer forward_payload")?;
 
         let mut payload = JettonTransferPayload::new(jetton.dest.clone(), jetton.jetton_amount);
         payload
             .with_query_id(jetton.query_id)
             .with_response_destination(jetton.response_address.clone())
             .with_custom_payload(custom_payload_cell)
-            .with_forward_ton_amount(jetton.forward_ton_amount);
+            .with_forward_ton_amount(jetton.forward_ton_amount)
+            .with_forward_payload(forward_payload_cell);
 
         if let Some(comment) = comment {
             payload.with_comment(comment);
@@ -100,6 +114,68 @@ impl InternalMessageCreator {
             .context("Error generating Jetton Transfer payload")
     }
 
+    fn nft_transfer_payload(
+        nft: &NftTransferRequest,
+        comment: Option<String>,
+    ) -> CellResult<CellArc> {
+        let custom_payload_cell = Self::maybe_single_root(nft.custom_payload.as_deref())
+            .context("Error parsing NftTransfer custom_payload")?;
+        let forward_payload_cell = Self::maybe_single_root(nft.forward_payload.as_deref())
+            .context("Error parsing NftTransfer forward_payload")?;
+
+        let mut payload = NftTransferPayload::new(nft.new_owner.clone());
+        payload
+            .with_query_id(nft.query_id)
+            .with_response_destination(nft.response_destination.clone())
+            .with_custom_payload(custom_payload_cell)
+            .with_forward_amount(nft.forward_amount)
+            .with_forward_payload(forward_payload_cell);
+
+        if let Some(comment) = comment {
+            payload.with_comment(comment);
+        }
+
+        payload
+            .build()
+            .map(Cell::into_arc)
+            .context("Error generating NFT Transfer payload")
+    }
+
+    fn jetton_burn_payload(burn: &JettonBurnRequest) -> CellResult<CellArc> {
+        let custom_payload_cell = Self::maybe_single_root(burn.custom_payload.as_deref())
+            .context("Error parsing JettonBurn custom_payload")?;
+
+        let mut payload = JettonBurnPayload::new(burn.jetton_amount);
+        payload
+            .with_query_id(burn.query_id)
+            .with_response_destination(burn.response_address.clone())
+            .with_custom_payload(custom_payload_cell);
+
+        payload
+            .build()
+            .map(Cell::into_arc)
+            .context("Error generating Jetton Burn payload")
+    }
+
+    fn encrypted_comment_payload(data: &[u8]) -> CellResult<CellArc> {
+        EncryptedCommentPayload::new(data.to_vec())
+            .build()
+            .map(Cell::into_arc)
+            .context("Error generating Transfer's encrypted comment payload")
+    }
+
+    fn maybe_single_root(boc: Option<&str>) -> CellResult<Option<CellArc>> {
+        let Some(boc) = boc else {
+            return Ok(None);
+        };
+
+        let cell = BagOfCells::parse_base64(boc)?
+            .single_root()
+            .map(Arc::clone)
+            .context("BoC must contain only one single root")?;
+        Ok(Some(cell))
+    }
+
     fn custom_payload(payload: &str) -> CellResult<CellArc> {
         BagOfCells::parse_base64(payload)
             .context("Error parsing custom Transfer payload")?
@@ -4,6 +4,7 @@
 
 use crate::address::TonAddress;
 use crate::wallet::VersionedTonWallet;
+use tw_memory::Data;
 use tw_number::U256;
 
 pub mod builder;
@@ -14,6 +15,12 @@ pub enum TransferPayload {
     JettonTransfer(JettonTransferRequest),
     /// Custom Transfer message payload.
     Custom(String),
+    /// NFT item Transfer message payload.
+    NftTransfer(NftTransferRequest),
+    /// Jetton Burn message payload.
+    JettonBurn(JettonBurnRequest),
+    /// Encrypted comment data.
+    EncryptedComment(Data),
 }
 
 pub struct TransferRequest {
@@ -48,6 +55,34 @@ pub struct JettonTransferRequest {
     pub custom_payload: Option<String>,
     /// Amount in nanotons to forward to recipient. Basically minimum amount - 1 nanoton should be used.
     pub forward_ton_amount: U256,
+    /// Optional payload to forward to the recipient.
+    pub forward_payload: Option<String>,
+}
+
+pub struct NftTransferRequest {
+    /// Arbitrary request number.
+    pub query_id: u64,
+    /// Address of the new owner of the NFT item.
+    pub new_owner: TonAddress,
+    /// Address where to send a response with confirmation of a successful transfer and the rest of the incoming message Toncoins.
+    pub response_destination: TonAddress,
+    /// Optional custom payload.
+    pub custom_payload: Option<String>,
+    /// Amount in nanotons to forward to the new owner.
+    pub forward_amount: U256,
+    /// Optional payload to forward to the new owner.
+    pub forward_payload: Option<String>,
+}
+
+pub struct JettonBurnRequest {
+    /// Arbitrary request number.
+    pub query_id: u64,
+    /// Amount of burned jettons in elementary integer units.
+    pub jetton_amount: U256,
+    /// Address where to send a response with confirmation of a successful burn and the rest of the incoming message Toncoins.
+    pub response_address: TonAddress,
+    /// Optional custom payload.
+    pub custom_payload: Option<String>,
 }
 
 pub struct SigningRequest {
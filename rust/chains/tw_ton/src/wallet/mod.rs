@@ -15,6 +15,7 @@ use tw_ton_sdk::cell::Cell;
 use tw_ton_sdk::error::{cell_to_signing_error, CellResult};
 use tw_ton_sdk::message::state_init::StateInit;
 
+pub mod wallet_v3;
 pub mod wallet_v4;
 pub mod wallet_v5;
 
@@ -22,6 +23,7 @@ pub mod wallet_v5;
 /// Currently, we use a workaround to implement the versioned TonWallet struct.
 /// After the Rust specialization feature is finished, maybe we can remove this workaround.
 pub enum VersionedTonWallet {
+    V3(wallet_v3::WalletV3),
     V4R2(wallet_v4::WalletV4R2),
     V5R1(wallet_v5::WalletV5R1),
 }
@@ -29,6 +31,7 @@ pub enum VersionedTonWallet {
 impl VersionedTonWallet {
     pub fn address(&self) -> &TonAddress {
         match self {
+            Self::V3(wallet_v3) => &wallet_v3.address,
             Self::V4R2(wallet_v4r2) => &wallet_v4r2.address,
             Self::V5R1(wallet_v5r1) => &wallet_v5r1.address,
         }
@@ -36,6 +39,7 @@ impl VersionedTonWallet {
 
     pub fn state_init(&self) -> CellResult<StateInit> {
         match self {
+            Self::V3(wallet_v3) => wallet_v3.state_init(),
             Self::V4R2(wallet_v4r2) => wallet_v4r2.state_init(),
             Self::V5R1(wallet_v5r1) => wallet_v5r1.state_init(),
         }
@@ -48,6 +52,9 @@ impl VersionedTonWallet {
         internal_messages: Vec<InternalMessage>,
     ) -> CellResult<Cell> {
         match self {
+            Self::V3(wallet_v3) => {
+                wallet_v3.create_external_body(expire_at, seqno, internal_messages)
+            },
             Self::V4R2(wallet_v4r2) => {
                 wallet_v4r2.create_external_body(expire_at, seqno, internal_messages)
             },
@@ -60,6 +67,7 @@ impl VersionedTonWallet {
     pub fn sign_external_message(&self, external_message: Cell) -> SigningResult<Cell> {
         let message_hash = external_message.cell_hash();
         let sig = match self {
+            Self::V3(wallet_v3) => wallet_v3.private_key.as_ref(),
             Self::V4R2(wallet_v4r2) => wallet_v4r2.private_key.as_ref(),
             Self::V5R1(wallet_v5r1) => wallet_v5r1.private_key.as_ref(),
         }
@@ -76,7 +84,8 @@ impl VersionedTonWallet {
         sig: Signature,
     ) -> SigningResult<Cell> {
         match self {
-            Self::V4R2(_) => Ok(SignedMessageV4 {
+            // V3 wallets store the signature the same way as V4R2 does.
+            Self::V3(_) | Self::V4R2(_) => Ok(SignedMessageV4 {
                 signature: sig.to_bytes(),
                 external_message,
             }
@@ -1,5 +1,6 @@
 use tw_keypair::ed25519::sha512::KeyPair;
 use tw_keypair::traits::KeyPairTrait;
+use tw_ton::wallet::wallet_v3::{self, WalletV3Revision};
 use tw_ton::wallet::wallet_v5;
 
 /// Tests for TON V5R1 address.
@@ -37,3 +38,28 @@ fn test_ton_v5r1_address_derive() {
         assert_eq!(actual_address, expected_address);
     }
 }
+
+#[test]
+fn test_ton_v3_address_derive() {
+    let private_key_hex = "63474e5fe9511f1526a50567ce142befc343e71a49b865ac3908f58667319cb8";
+    let test_cases = [
+        (
+            WalletV3Revision::R1,
+            "UQDfIkGnoMsIG_lZ0ouqUc03XxpCH9jC0cTgjNbTldRVMJlB",
+        ),
+        (
+            WalletV3Revision::R2,
+            "UQCspB93tcxmTGVunU6axO056IRcItBUJLQWuoRQjSDY5dl6",
+        ),
+    ];
+
+    let private_key_bytes = tw_encoding::hex::decode(private_key_hex).expect("Invalid hex string");
+    let key_pair =
+        KeyPair::try_from(private_key_bytes.as_slice()).expect("Failed to create key pair");
+
+    for (revision, expected_address) in test_cases {
+        let wallet = wallet_v3::WalletV3::std_with_public_key(revision, key_pair.public().clone())
+            .expect("Failed to create wallet");
+        assert_eq!(wallet.address.to_string(), expected_address);
+    }
+}
@@ -7,6 +7,10 @@
 use crate::address::address_data::AddressData;
 use crate::boc::binary_writer::BinaryWriter;
 use crate::cell::cell_parser::CellParser;
+use crate::cell::dict::dict_extra::DictExtra;
+use crate::cell::dict::dict_key::DictKey;
+use crate::cell::dict::dict_value::DictValue;
+use crate::cell::dict::{AugDictionary, Dictionary};
 use crate::cell::{Cell, CellArc};
 use crate::error::{CellError, CellErrorType, CellResult};
 use bitstream_io::Numeric;
@@ -29,6 +33,12 @@ impl CellBuilder {
         CellBuilder::default()
     }
 
+    /// Marks the cell as exotic. The cell type is determined by the first byte of the cell data.
+    pub fn set_cell_is_exotic(&mut self, val: bool) -> &mut Self {
+        self.is_cell_exotic = val;
+        self
+    }
+
     pub fn store_bit(&mut self, val: bool) -> CellResult<&mut Self> {
         self.bit_writer.write_bit(val)?;
         Ok(self)
@@ -171,6 +181,43 @@ impl CellBuilder {
         self.store_reference(&cell.into_arc())
     }
 
+    /// Stores a `HashmapE n X` dictionary: a bit and a reference to the root if the dictionary is not empty.
+    pub fn store_dict<K, V>(&mut self, dict: &Dictionary<K, V>) -> CellResult<&mut Self>
+    where
+        K: DictKey,
+        V: DictValue,
+    {
+        match dict.build_root()? {
+            Some(root) => self.store_bit(true)?.store_child(root),
+            None => self.store_bit(false),
+        }
+    }
+
+    /// Stores a `HashmapAugE n X Y` dictionary: a bit, a reference to the root if the dictionary is not empty,
+    /// and the aggregated extra of all entries or [`DictExtra::empty`] otherwise.
+    pub fn store_aug_dict<K, V, E>(
+        &mut self,
+        dict: &AugDictionary<K, V, E>,
+    ) -> CellResult<&mut Self>
+    where
+        K: DictKey,
+        V: DictValue,
+        E: DictExtra,
+    {
+        let extra = match dict.build_root()? {
+            Some((root, extra)) => {
+                self.store_bit(true)?.store_child(root)?;
+                extra
+            },
+            None => {
+                self.store_bit(false)?;
+                E::empty()
+            },
+        };
+        extra.store_value(self)?;
+        Ok(self)
+    }
+
     pub fn store_remaining_bits(&mut self, parser: &mut CellParser) -> CellResult<&mut Self> {
         let num_full_bytes = parser.remaining_bits() / 8;
         let bytes = parser.load_bytes(num_full_bytes)?;
@@ -5,22 +5,31 @@
 //! Original source code: https://github.com/ston-fi/tonlib-rs/blob/b96a5252df583261ed755656292930af46c2039a/src/cell/parser.rs
 
 use crate::address::address_data::AddressData;
+use crate::cell::dict::dict_extra::DictExtra;
+use crate::cell::dict::dict_key::DictKey;
+use crate::cell::dict::dict_value::DictValue;
+use crate::cell::dict::{AugDictionary, Dictionary};
+use crate::cell::CellArc;
 use crate::error::{CellError, CellErrorType, CellResult};
 use bitreader::BitReader;
 use num_bigint::BigUint;
-use tw_coin_entry::error::prelude::{MapTWError, ResultContext};
+use tw_coin_entry::error::prelude::{MapTWError, OrTWError, ResultContext};
 use tw_hash::H256;
 use tw_memory::Data;
 use tw_number::U256;
 
 pub struct CellParser<'a> {
     bit_reader: BitReader<'a>,
+    references: &'a [CellArc],
+    next_reference_index: usize,
 }
 
 impl<'a> CellParser<'a> {
-    pub fn new(data: &'a [u8], bit_len: usize) -> Self {
+    pub fn new(data: &'a [u8], bit_len: usize, references: &'a [CellArc]) -> Self {
         CellParser {
             bit_reader: BitReader::new(data).relative_reader_atmost(bit_len as u64),
+            references,
+            next_reference_index: 0,
         }
     }
 
@@ -111,6 +120,69 @@ impl<'a> CellParser<'a> {
         }
     }
 
+    /// Returns the next cell reference that has not been loaded yet.
+    pub fn next_reference(&mut self) -> CellResult<&'a CellArc> {
+        let reference = self
+            .references
+            .get(self.next_reference_index)
+            .or_tw_err(CellErrorType::CellParserError)
+            .with_context(|| format!("Cell contains {} references only", self.references.len()))?;
+        self.next_reference_index += 1;
+        Ok(reference)
+    }
+
+    /// Returns all cell references that have not been loaded yet.
+    pub fn load_remaining_references(&mut self) -> &'a [CellArc] {
+        let remaining = self
+            .references
+            .get(self.next_reference_index..)
+            .unwrap_or_default();
+        self.next_reference_index = self.references.len();
+        remaining
+    }
+
+    /// Loads a `HashmapE n X` dictionary, where `n` is `key_bit_len`.
+    pub fn load_dict<K, V>(&mut self, key_bit_len: usize) -> CellResult<Dictionary<K, V>>
+    where
+        K: DictKey,
+        V: DictValue,
+    {
+        if self.load_bit()? {
+            let root = self.next_reference()?;
+            Dictionary::parse_root(key_bit_len, root)
+        } else {
+            Ok(Dictionary::new(key_bit_len))
+        }
+    }
+
+    /// Loads a `HashmapAugE n X Y` dictionary, where `n` is `key_bit_len`.
+    /// Returns the dictionary and the extra stored next to the root reference.
+    pub fn load_aug_dict<K, V, E>(
+        &mut self,
+        key_bit_len: usize,
+    ) -> CellResult<(AugDictionary<K, V, E>, E)>
+    where
+        K: DictKey,
+        V: DictValue,
+        E: DictExtra,
+    {
+        if !self.load_bit()? {
+            // ahme_empty
+            let extra = E::load_value(self)?;
+            return Ok((AugDictionary::new(key_bit_len), extra));
+        }
+
+        // ahme_root
+        let root = self.next_reference()?;
+        let (dict, root_extra) = AugDictionary::parse_root(key_bit_len, root)?;
+        let extra = E::load_value(self)?;
+        if extra != root_extra {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Dictionary extra doesn't match the extra of its root");
+        }
+        Ok((dict, extra))
+    }
+
     pub fn ensure_empty(&self) -> CellResult<()> {
         let remaining = self.remaining_bits();
         if remaining == 0 {
@@ -5,13 +5,24 @@
 //! Original source code: https://github.com/ston-fi/tonlib-rs/blob/b96a5252df583261ed755656292930af46c2039a/src/cell/cell_type.rs
 
 use crate::cell::level_mask::LevelMask;
-use crate::cell::{Cell, CellArc};
+use crate::cell::{Cell, CellArc, MAX_LEVEL};
 use crate::error::{CellError, CellErrorType, CellResult};
 use bitstream_io::{BigEndian, ByteRead, ByteReader};
 use std::io::Cursor;
 use tw_coin_entry::error::prelude::*;
 use tw_hash::H256;
 
+pub(crate) const PRUNED_BRANCH_TAG: u8 = 1;
+pub(crate) const LIBRARY_TAG: u8 = 2;
+pub(crate) const MERKLE_PROOF_TAG: u8 = 3;
+pub(crate) const MERKLE_UPDATE_TAG: u8 = 4;
+
+/// Size of a cell hash and depth stored in exotic cells data.
+const HASH_BITS: usize = H256::LEN * 8;
+const DEPTH_BITS: usize = 16;
+const TAG_BITS: usize = 8;
+const CONFIG_PROOF_BITS: usize = TAG_BITS + HASH_BITS + DEPTH_BITS;
+
 struct Pruned {
     hash: H256,
     depth: u16,
@@ -38,11 +49,11 @@ impl CellType {
                 .context("Not enough data to determine exotic cell type");
         };
 
-        let cell_type = match type_byte {
-            1 => CellType::PrunedBranch,
-            2 => CellType::Library,
-            3 => CellType::MerkleProof,
-            4 => CellType::MerkleUpdate,
+        let cell_type = match *type_byte {
+            PRUNED_BRANCH_TAG => CellType::PrunedBranch,
+            LIBRARY_TAG => CellType::Library,
+            MERKLE_PROOF_TAG => CellType::MerkleProof,
+            MERKLE_UPDATE_TAG => CellType::MerkleUpdate,
             cell_type => {
                 return CellError::err(CellErrorType::InvalidExoticCell).context(format!(
                     "Invalid first byte in exotic cell data: {cell_type}"
@@ -54,19 +65,17 @@ impl CellType {
 
     pub(crate) fn validate(
         &self,
-        _data: &[u8],
-        _bit_len: usize,
-        _references: impl AsRef<[CellArc]>,
+        data: &[u8],
+        bit_len: usize,
+        references: &[CellArc],
     ) -> CellResult<()> {
-        // TODO consider implementing data validation according to the cell type.
-        // match self {
-        //     CellType::Ordinary => Ok(()),
-        //     CellType::PrunedBranch => self.validate_exotic_pruned(data, bit_len, references),
-        //     CellType::Library => self.validate_library(bit_len),
-        //     CellType::MerkleProof => self.validate_merkle_proof(data, bit_len, references),
-        //     CellType::MerkleUpdate => self.validate_merkle_update(data, bit_len, references),
-        // }
-        Ok(())
+        match self {
+            CellType::Ordinary => Ok(()),
+            CellType::PrunedBranch => self.validate_pruned(data, bit_len, references),
+            CellType::Library => self.validate_library(bit_len, references),
+            CellType::MerkleProof => self.validate_merkle_proof(data, bit_len, references),
+            CellType::MerkleUpdate => self.validate_merkle_update(data, bit_len, references),
+        }
     }
 
     pub(crate) fn level_mask(
@@ -182,7 +191,8 @@ impl CellType {
         let cursor = Cursor::new(&data[current_index..]);
         let mut reader = ByteReader::endian(cursor, BigEndian);
 
-        let level = level_mask.level() as usize;
+        // Pruned Branch stores hashes and depths of all significant levels except its own.
+        let level = level_mask.hash_index();
         let hashes = (0..level)
             .map(|_| reader.read::<RawCellHash>().map(H256::from))
             .collect::<Result<Vec<_>, _>>()
@@ -201,11 +211,134 @@ impl CellType {
         Ok(result)
     }
 
+    /// Pruned Branch: `tag(8) + level_mask(8) + hashes(256 * n) + depths(16 * n)`,
+    /// where `n` is the number of significant levels below the cell level.
+    fn validate_pruned(
+        &self,
+        data: &[u8],
+        bit_len: usize,
+        references: &[CellArc],
+    ) -> CellResult<()> {
+        ensure_no_references(references, "Pruned Branch")?;
+        if self.is_config_proof(bit_len) {
+            return Ok(());
+        }
+
+        let level_mask = self.pruned_level_mask(data, bit_len)?;
+        let level = level_mask.level();
+        if level == 0 || level > MAX_LEVEL {
+            return CellError::err(CellErrorType::InvalidExoticCell)
+                .context(format!("Pruned Branch has an invalid level: {level}"));
+        }
+
+        let expected_bit_len = 2 * TAG_BITS + level_mask.hash_index() * (HASH_BITS + DEPTH_BITS);
+        ensure_bit_len(bit_len, expected_bit_len, "Pruned Branch")
+    }
+
+    /// Library: `tag(8) + library_hash(256)`.
+    fn validate_library(&self, bit_len: usize, references: &[CellArc]) -> CellResult<()> {
+        ensure_no_references(references, "Library")?;
+        ensure_bit_len(bit_len, TAG_BITS + HASH_BITS, "Library")
+    }
+
+    /// Merkle Proof: `tag(8) + virtual_hash(256) + depth(16)` and a reference to the virtual root.
+    fn validate_merkle_proof(
+        &self,
+        data: &[u8],
+        bit_len: usize,
+        references: &[CellArc],
+    ) -> CellResult<()> {
+        ensure_bit_len(bit_len, TAG_BITS + HASH_BITS + DEPTH_BITS, "Merkle Proof")?;
+        let [virtual_root] = references else {
+            return CellError::err(CellErrorType::InvalidExoticCell)
+                .context("Merkle Proof must contain exactly one reference");
+        };
+
+        let mut reader = ByteReader::endian(Cursor::new(&data[1..]), BigEndian);
+        let hash = read_hash(&mut reader)?;
+        let depth = read_depth(&mut reader)?;
+        ensure_proven_cell(virtual_root, hash, depth, "Merkle Proof")
+    }
+
+    /// Merkle Update: `tag(8) + old_hash(256) + new_hash(256) + old_depth(16) + new_depth(16)`
+    /// and references to the old and new virtual roots.
+    fn validate_merkle_update(
+        &self,
+        data: &[u8],
+        bit_len: usize,
+        references: &[CellArc],
+    ) -> CellResult<()> {
+        ensure_bit_len(
+            bit_len,
+            TAG_BITS + 2 * (HASH_BITS + DEPTH_BITS),
+            "Merkle Update",
+        )?;
+        let [old_root, new_root] = references else {
+            return CellError::err(CellErrorType::InvalidExoticCell)
+                .context("Merkle Update must contain exactly two references");
+        };
+
+        let mut reader = ByteReader::endian(Cursor::new(&data[1..]), BigEndian);
+        let old_hash = read_hash(&mut reader)?;
+        let new_hash = read_hash(&mut reader)?;
+        let old_depth = read_depth(&mut reader)?;
+        let new_depth = read_depth(&mut reader)?;
+        ensure_proven_cell(old_root, old_hash, old_depth, "Merkle Update old")?;
+        ensure_proven_cell(new_root, new_hash, new_depth, "Merkle Update new")
+    }
+
     /// Special case for config proof
     /// This test proof is generated in the moment of voting for a slashing
     /// it seems that tools generate it incorrectly and therefore doesn't have mask in it
     /// so we need to hardcode it equal to 1 in this case
     fn is_config_proof(&self, bit_len: usize) -> bool {
-        self == &CellType::PrunedBranch && bit_len == 280
+        self == &CellType::PrunedBranch && bit_len == CONFIG_PROOF_BITS
     }
 }
+
+fn ensure_no_references(references: &[CellArc], cell_name: &str) -> CellResult<()> {
+    if references.is_empty() {
+        return Ok(());
+    }
+    CellError::err(CellErrorType::InvalidExoticCell).context(format!(
+        "{cell_name} cell can't contain references, got {}",
+        references.len()
+    ))
+}
+
+fn ensure_bit_len(bit_len: usize, expected: usize, cell_name: &str) -> CellResult<()> {
+    if bit_len == expected {
+        return Ok(());
+    }
+    CellError::err(CellErrorType::InvalidExoticCell).context(format!(
+        "{cell_name} cell must contain {expected} bits, got {bit_len}"
+    ))
+}
+
+/// Checks if the hash and depth stored in a Merkle Proof or Update cell correspond to the proven cell.
+fn ensure_proven_cell(cell: &Cell, hash: H256, depth: u16, cell_name: &str) -> CellResult<()> {
+    if cell.get_hash(0) != hash {
+        return CellError::err(CellErrorType::InvalidExoticCell)
+            .context(format!("{cell_name} hash mismatch"));
+    }
+    if cell.get_depth(0) != depth {
+        return CellError::err(CellErrorType::InvalidExoticCell)
+            .context(format!("{cell_name} depth mismatch"));
+    }
+    Ok(())
+}
+
+fn read_hash<R: ByteRead>(reader: &mut R) -> CellResult<H256> {
+    reader
+        .read::<[u8; H256::LEN]>()
+        .map(H256::from)
+        .tw_err(|_| CellErrorType::InvalidExoticCell)
+        .context("Not enough data to read a cell hash")
+}
+
+fn read_depth<R: ByteRead>(reader: &mut R) -> CellResult<u16> {
+    reader
+        .read::<u16>()
+        .tw_err(|_| CellErrorType::InvalidExoticCell)
+        .context("Not enough data to read a cell depth")
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! TON augmented dictionaries, i.e. dictionaries with an extra value in every node.
+//! https://docs.ton.org/develop/data-formats/tl-b-types#hashmapaug
+//!
+//! ```tlb
+//! ahm_edge#_ {n:#} {X:Type} {Y:Type} {l:#} {m:#} label:(HmLabel ~l n)
+//!            {n = (~m) + l} node:(HashmapAugNode m X Y) = HashmapAug n X Y;
+//! ahmn_leaf#_ {X:Type} {Y:Type} extra:Y value:X = HashmapAugNode 0 X Y;
+//! ahmn_fork#_ {n:#} {X:Type} {Y:Type} left:^(HashmapAug n X Y)
+//!             right:^(HashmapAug n X Y) extra:Y = HashmapAugNode (n + 1) X Y;
+//!
+//! ahme_empty$0 {n:#} {X:Type} {Y:Type} extra:Y = HashmapAugE n X Y;
+//! ahme_root$1 {n:#} {X:Type} {Y:Type} root:^(HashmapAug n X Y) extra:Y = HashmapAugE n X Y;
+//! ```
+
+use crate::cell::cell_builder::CellBuilder;
+use crate::cell::dict::dict_extra::DictExtra;
+use crate::cell::dict::dict_key::DictKey;
+use crate::cell::dict::dict_value::DictValue;
+use crate::cell::dict::label::{load_label, store_label};
+use crate::cell::Cell;
+use crate::error::{CellError, CellErrorType, CellResult};
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use tw_coin_entry::error::prelude::*;
+
+/// Key bits and the corresponding value and extra sorted by the key bits.
+type SerializedEntries<'a, V, E> = [(Vec<bool>, &'a (V, E))];
+
+/// A typed `HashmapAugE n X Y` dictionary, where `n` is [`AugDictionary::key_bit_len`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AugDictionary<K, V, E> {
+    key_bit_len: usize,
+    entries: BTreeMap<K, (V, E)>,
+}
+
+impl<K: DictKey, V: DictValue, E: DictExtra> AugDictionary<K, V, E> {
+    /// Creates an empty dictionary with keys of the `key_bit_len` length.
+    pub fn new(key_bit_len: usize) -> Self {
+        AugDictionary {
+            key_bit_len,
+            entries: BTreeMap::default(),
+        }
+    }
+
+    /// Parses a non-empty dictionary from the `HashmapAug n X Y` root cell.
+    /// Returns the dictionary and the aggregated extra stored in the root.
+    ///
+    /// Every fork extra is checked to match the aggregated extras of its subtrees.
+    pub fn parse_root(key_bit_len: usize, root: &Cell) -> CellResult<(Self, E)> {
+        let mut dict = AugDictionary::new(key_bit_len);
+        let root_extra = dict.parse_edge(root, Vec::with_capacity(key_bit_len))?;
+        Ok((dict, root_extra))
+    }
+
+    pub fn key_bit_len(&self) -> usize {
+        self.key_bit_len
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts a key-value pair with the leaf `extra` into the dictionary.
+    /// Returns the previous value and extra if the dictionary already contained the `key`.
+    ///
+    /// Please note the `key` is checked to fit [`AugDictionary::key_bit_len`] on serialization only.
+    pub fn insert(&mut self, key: K, value: V, extra: E) -> Option<(V, E)> {
+        self.entries.insert(key, (value, extra))
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    /// Returns the leaf extra of the `key`.
+    pub fn get_extra(&self, key: &K) -> Option<&E> {
+        self.entries.get(key).map(|(_, extra)| extra)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<(V, E)> {
+        self.entries.remove(key)
+    }
+
+    pub fn iter(&self) -> btree_map::Iter<'_, K, (V, E)> {
+        self.entries.iter()
+    }
+
+    /// Builds the `HashmapAug n X Y` root cell and returns it with the aggregated extra of all entries.
+    /// Returns `None` if the dictionary is empty.
+    pub fn build_root(&self) -> CellResult<Option<(Cell, E)>> {
+        if self.entries.is_empty() {
+            return Ok(None);
+        }
+
+        let mut entries = self
+            .entries
+            .iter()
+            .map(|(key, entry)| Ok((key.to_key_bits(self.key_bit_len)?, entry)))
+            .collect::<CellResult<Vec<_>>>()?;
+        // Keys are expected to be sorted already, but the order of `K` may differ from the order of the key bits.
+        entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        let offset = 0;
+        self.build_edge(&entries, offset).map(Some)
+    }
+
+    /// Builds an edge of the keys that have a common prefix of `offset` bits.
+    /// Returns the edge cell and the aggregated extra of the edge.
+    fn build_edge(
+        &self,
+        entries: &SerializedEntries<V, E>,
+        offset: usize,
+    ) -> CellResult<(Cell, E)> {
+        let ((first_key, (first_value, first_extra)), (last_key, _)) = match entries {
+            [first, .., last] => (first, last),
+            [single] => (single, single),
+            [] => {
+                return CellError::err(CellErrorType::InternalError)
+                    .context("Cannot build an empty dictionary edge")
+            },
+        };
+
+        // Entries are sorted, so the first and last keys have the shortest common prefix.
+        let label_len = first_key[offset..]
+            .iter()
+            .zip(last_key[offset..].iter())
+            .take_while(|(left, right)| left == right)
+            .count();
+        let label = &first_key[offset..offset + label_len];
+
+        let mut builder = CellBuilder::new();
+        store_label(&mut builder, label, self.key_bit_len - offset)?;
+
+        let fork_offset = offset + label_len;
+        if fork_offset == self.key_bit_len {
+            // ahmn_leaf: the key is fully determined by the labels.
+            first_extra.store_value(&mut builder)?;
+            first_value.store_value(&mut builder)?;
+            return Ok((builder.build()?, first_extra.clone()));
+        }
+
+        // ahmn_fork: left subtree contains keys with `0` bit at the fork offset, right with `1`.
+        let split_at = entries.partition_point(|(key, _)| !key[fork_offset]);
+        let (left, right) = entries.split_at(split_at);
+        let child_offset = fork_offset + 1;
+        let (left_edge, left_extra) = self.build_edge(left, child_offset)?;
+        let (right_edge, right_extra) = self.build_edge(right, child_offset)?;
+
+        let extra = E::aggregate(&left_extra, &right_extra)?;
+        builder.store_child(left_edge)?.store_child(right_edge)?;
+        extra.store_value(&mut builder)?;
+        Ok((builder.build()?, extra))
+    }
+
+    /// Parses an edge which keys start with the given `prefix`.
+    /// Returns the extra of the edge.
+    fn parse_edge(&mut self, edge: &Cell, mut prefix: Vec<bool>) -> CellResult<E> {
+        let mut parser = edge.parser();
+
+        let label = load_label(&mut parser, self.key_bit_len - prefix.len())?;
+        prefix.extend(label);
+
+        if prefix.len() == self.key_bit_len {
+            let extra = E::load_value(&mut parser)?;
+            let value = V::load_value(&mut parser)?;
+            parser.ensure_empty()?;
+
+            let key = K::from_key_bits(&prefix)?;
+            if self.entries.insert(key, (value, extra.clone())).is_some() {
+                return CellError::err(CellErrorType::CellParserError)
+                    .context("Dictionary contains duplicate keys");
+            }
+            return Ok(extra);
+        }
+
+        let left = parser.next_reference()?;
+        let right = parser.next_reference()?;
+        let extra = E::load_value(&mut parser)?;
+        parser.ensure_empty()?;
+
+        let mut left_prefix = prefix.clone();
+        left_prefix.push(false);
+        let left_extra = self.parse_edge(left, left_prefix)?;
+
+        let mut right_prefix = prefix;
+        right_prefix.push(true);
+        let right_extra = self.parse_edge(right, right_prefix)?;
+
+        if E::aggregate(&left_extra, &right_extra)? != extra {
+            return CellError::err(CellErrorType::CellParserError)
+                .context("Dictionary fork extra doesn't match the extras of its subtrees");
+        }
+        Ok(extra)
+    }
+}
+
+impl<K, V, E> IntoIterator for AugDictionary<K, V, E> {
+    type Item = (K, (V, E));
+    type IntoIter = btree_map::IntoIter<K, (V, E)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
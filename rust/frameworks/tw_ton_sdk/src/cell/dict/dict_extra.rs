@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::cell::dict::dict_value::DictValue;
+use crate::error::CellResult;
+
+/// An extra value of a [`super::AugDictionary`].
+/// Every leaf stores the extra of its entry, and every fork stores the aggregated extra of both subtrees.
+pub trait DictExtra: DictValue + Clone + PartialEq {
+    /// Returns the extra of an empty dictionary.
+    fn empty() -> Self;
+
+    /// Aggregates the extras of the left and right subtrees.
+    fn aggregate(left: &Self, right: &Self) -> CellResult<Self>;
+}
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::error::{CellError, CellErrorType, CellResult};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+
+/// A key of a [`super::Dictionary`].
+/// Keys are serialized as big-endian unsigned integers of the dictionary key length.
+pub trait DictKey: Ord + Sized {
+    /// Serializes the key into exactly `key_bit_len` bits, the most significant bit first.
+    fn to_key_bits(&self, key_bit_len: usize) -> CellResult<Vec<bool>>;
+
+    /// Deserializes the key from the bits of the dictionary key length.
+    fn from_key_bits(bits: &[bool]) -> CellResult<Self>;
+}
+
+macro_rules! impl_dict_key_for_uint {
+    ($($uint:ty),+) => {
+        $(
+            impl DictKey for $uint {
+                fn to_key_bits(&self, key_bit_len: usize) -> CellResult<Vec<bool>> {
+                    be_bytes_to_key_bits(&self.to_be_bytes(), key_bit_len)
+                }
+
+                fn from_key_bits(bits: &[bool]) -> CellResult<Self> {
+                    key_bits_to_be_bytes(bits).map(<$uint>::from_be_bytes)
+                }
+            }
+        )+
+    };
+}
+
+impl_dict_key_for_uint!(u8, u16, u32, u64, u128);
+
+impl DictKey for H256 {
+    fn to_key_bits(&self, key_bit_len: usize) -> CellResult<Vec<bool>> {
+        be_bytes_to_key_bits(self.as_slice(), key_bit_len)
+    }
+
+    fn from_key_bits(bits: &[bool]) -> CellResult<Self> {
+        key_bits_to_be_bytes(bits).map(H256::from)
+    }
+}
+
+/// Returns `key_bit_len` least significant bits of the big-endian `bytes`.
+/// Returns an error if the value doesn't fit `key_bit_len` bits.
+fn be_bytes_to_key_bits(bytes: &[u8], key_bit_len: usize) -> CellResult<Vec<bool>> {
+    let value_bit_len = bytes.len() * 8;
+    let bit_at = |i: usize| bytes[i / 8] & (0x80 >> (i % 8)) != 0;
+
+    if key_bit_len < value_bit_len {
+        let skip_bits = value_bit_len - key_bit_len;
+        if (0..skip_bits).any(bit_at) {
+            return CellError::err(CellErrorType::CellBuilderError)
+                .context(format!("Dictionary key doesn't fit in {key_bit_len} bits"));
+        }
+        Ok((skip_bits..value_bit_len).map(bit_at).collect())
+    } else {
+        let mut bits = vec![false; key_bit_len - value_bit_len];
+        bits.extend((0..value_bit_len).map(bit_at));
+        Ok(bits)
+    }
+}
+
+/// Packs the key `bits` into big-endian bytes of the `N` length.
+/// Returns an error if the key doesn't fit `N` bytes.
+fn key_bits_to_be_bytes<const N: usize>(bits: &[bool]) -> CellResult<[u8; N]> {
+    let value_bit_len = N * 8;
+    let (leading_bits, value_bits) = bits.split_at(bits.len().saturating_sub(value_bit_len));
+    if leading_bits.iter().any(|bit| *bit) {
+        return CellError::err(CellErrorType::CellParserError).context(format!(
+            "Dictionary key doesn't fit in {value_bit_len} bits"
+        ));
+    }
+
+    let mut bytes = [0; N];
+    let offset = value_bit_len - value_bits.len();
+    for (i, bit) in value_bits.iter().enumerate() {
+        if *bit {
+            let bit_idx = offset + i;
+            bytes[bit_idx / 8] |= 0x80 >> (bit_idx % 8);
+        }
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::cell::cell_builder::CellBuilder;
+use crate::cell::cell_parser::CellParser;
+use crate::cell::{Cell, CellArc};
+use crate::error::CellResult;
+use std::sync::Arc;
+use tw_hash::H256;
+use tw_number::U256;
+
+/// A value of a [`super::Dictionary`].
+/// The value is stored in a leaf cell right after the key label.
+pub trait DictValue: Sized {
+    fn store_value(&self, builder: &mut CellBuilder) -> CellResult<()>;
+
+    fn load_value(parser: &mut CellParser) -> CellResult<Self>;
+}
+
+/// `Bit`. Also can be used for `int1` values like in Wallet V5 extensions dictionary.
+impl DictValue for bool {
+    fn store_value(&self, builder: &mut CellBuilder) -> CellResult<()> {
+        builder.store_bit(*self).map(|_| ())
+    }
+
+    fn load_value(parser: &mut CellParser) -> CellResult<Self> {
+        parser.load_bit()
+    }
+}
+
+macro_rules! impl_dict_value_for_uint {
+    ($($uint:ty => ($store:ident, $load:ident)),+) => {
+        $(
+            /// Unsigned integer of the full type width.
+            impl DictValue for $uint {
+                fn store_value(&self, builder: &mut CellBuilder) -> CellResult<()> {
+                    builder.$store(<$uint>::BITS as usize, *self).map(|_| ())
+                }
+
+                fn load_value(parser: &mut CellParser) -> CellResult<Self> {
+                    parser.$load(<$uint>::BITS as usize)
+                }
+            }
+        )+
+    };
+}
+
+impl_dict_value_for_uint!(
+    u8 => (store_u8, load_u8),
+    u32 => (store_u32, load_u32),
+    u64 => (store_u64, load_u64)
+);
+
+/// `uint256`.
+impl DictValue for U256 {
+    fn store_value(&self, builder: &mut CellBuilder) -> CellResult<()> {
+        builder.store_uint(256, self).map(|_| ())
+    }
+
+    fn load_value(parser: &mut CellParser) -> CellResult<Self> {
+        parser.load_uint(256)
+    }
+}
+
+/// `bits256`.
+impl DictValue for H256 {
+    fn store_value(&self, builder: &mut CellBuilder) -> CellResult<()> {
+        builder.store_slice(self.as_slice()).map(|_| ())
+    }
+
+    fn load_value(parser: &mut CellParser) -> CellResult<Self> {
+        let mut hash = H256::default();
+        parser.load_slice(hash.as_mut_slice())?;
+        Ok(hash)
+    }
+}
+
+/// `^Cell`.
+impl DictValue for CellArc {
+    fn store_value(&self, builder: &mut CellBuilder) -> CellResult<()> {
+        builder.store_reference(self).map(|_| ())
+    }
+
+    fn load_value(parser: &mut CellParser) -> CellResult<Self> {
+        parser.next_reference().map(Arc::clone)
+    }
+}
+
+/// Remaining bits and references of the leaf cell, i.e. an inline `Cell` slice.
+impl DictValue for Cell {
+    fn store_value(&self, builder: &mut CellBuilder) -> CellResult<()> {
+        builder.store_cell(self).map(|_| ())
+    }
+
+    fn load_value(parser: &mut CellParser) -> CellResult<Self> {
+        let mut builder = CellBuilder::new();
+        builder
+            .store_remaining_bits(parser)?
+            .store_references(parser.load_remaining_references())?;
+        builder.build()
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! ```tlb
+//! hml_short$0 {m:#} {n:#} len:(Unary ~n) {n <= m} s:(n * Bit) = HmLabel ~n m;
+//! hml_long$10 {m:#} n:(#<= m) s:(n * Bit) = HmLabel ~n m;
+//! hml_same$11 {m:#} v:Bit n:(#<= m) = HmLabel ~n m;
+//! ```
+
+use crate::cell::cell_builder::CellBuilder;
+use crate::cell::cell_parser::CellParser;
+use crate::error::{CellError, CellErrorType, CellResult};
+use tw_coin_entry::error::prelude::*;
+
+/// Stores the `label` choosing the shortest of `hml_short`, `hml_long` and `hml_same` encodings.
+/// `max_len` is the number of the key bits left at the current edge.
+pub(crate) fn store_label(
+    builder: &mut CellBuilder,
+    label: &[bool],
+    max_len: usize,
+) -> CellResult<()> {
+    let len = label.len();
+    if len > max_len {
+        return CellError::err(CellErrorType::CellBuilderError).context(format!(
+            "Dictionary label length {len} exceeds the remaining key length {max_len}"
+        ));
+    }
+
+    let len_bit_len = len_bit_len(max_len);
+    let short_bit_len = 2 * len + 2;
+    let long_bit_len = 2 + len_bit_len + len;
+    let same_bit_len = 3 + len_bit_len;
+
+    let is_same = len > 1 && label.iter().all(|bit| *bit == label[0]);
+    if is_same && same_bit_len < short_bit_len.min(long_bit_len) {
+        builder
+            .store_u8(2, 0b11)?
+            .store_bit(label[0])?
+            .store_u32(len_bit_len, len as u32)?;
+    } else if short_bit_len <= long_bit_len {
+        builder.store_bit(false)?;
+        for _ in 0..len {
+            builder.store_bit(true)?;
+        }
+        builder.store_bit(false)?;
+        store_bits(builder, label)?;
+    } else {
+        builder
+            .store_u8(2, 0b10)?
+            .store_u32(len_bit_len, len as u32)?;
+        store_bits(builder, label)?;
+    }
+    Ok(())
+}
+
+/// Loads a label of the `max_len` bits at most.
+pub(crate) fn load_label(parser: &mut CellParser, max_len: usize) -> CellResult<Vec<bool>> {
+    let label = if !parser.load_bit()? {
+        // hml_short$0
+        let mut len = 0;
+        while parser.load_bit()? {
+            len += 1;
+            ensure_label_len(len, max_len)?;
+        }
+        load_bits(parser, len)?
+    } else if !parser.load_bit()? {
+        // hml_long$10
+        let len = load_len(parser, max_len)?;
+        load_bits(parser, len)?
+    } else {
+        // hml_same$11
+        let bit = parser.load_bit()?;
+        let len = load_len(parser, max_len)?;
+        vec![bit; len]
+    };
+    Ok(label)
+}
+
+/// Returns the number of bits required to store an integer in `0..=max_len` range.
+fn len_bit_len(max_len: usize) -> usize {
+    (usize::BITS - max_len.leading_zeros()) as usize
+}
+
+fn load_len(parser: &mut CellParser, max_len: usize) -> CellResult<usize> {
+    let len_bit_len = len_bit_len(max_len);
+    let len = if len_bit_len == 0 {
+        0
+    } else {
+        parser.load_u32(len_bit_len)? as usize
+    };
+    ensure_label_len(len, max_len)?;
+    Ok(len)
+}
+
+fn ensure_label_len(len: usize, max_len: usize) -> CellResult<()> {
+    if len > max_len {
+        return CellError::err(CellErrorType::CellParserError).context(format!(
+            "Dictionary label length {len} exceeds the remaining key length {max_len}"
+        ));
+    }
+    Ok(())
+}
+
+fn store_bits(builder: &mut CellBuilder, bits: &[bool]) -> CellResult<()> {
+    for bit in bits {
+        builder.store_bit(*bit)?;
+    }
+    Ok(())
+}
+
+fn load_bits(parser: &mut CellParser, len: usize) -> CellResult<Vec<bool>> {
+    (0..len).map(|_| parser.load_bit()).collect()
+}
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! TON dictionaries, i.e. Patricia trees of fixed length keys.
+//! https://docs.ton.org/develop/data-formats/tl-b-types#hashmap
+//!
+//! ```tlb
+//! hm_edge#_ {n:#} {X:Type} {l:#} {m:#} label:(HmLabel ~l n)
+//!           {n = (~m) + l} node:(HashmapNode m X) = Hashmap n X;
+//! hmn_leaf#_ {X:Type} value:X = HashmapNode 0 X;
+//! hmn_fork#_ {n:#} {X:Type} left:^(Hashmap n X)
+//!            right:^(Hashmap n X) = HashmapNode (n + 1) X;
+//!
+//! hme_empty$0 {n:#} {X:Type} = HashmapE n X;
+//! hme_root$1 {n:#} {X:Type} root:^(Hashmap n X) = HashmapE n X;
+//! ```
+
+use crate::cell::cell_builder::CellBuilder;
+use crate::cell::dict::dict_key::DictKey;
+use crate::cell::dict::dict_value::DictValue;
+use crate::cell::dict::label::{load_label, store_label};
+use crate::cell::Cell;
+use crate::error::{CellError, CellErrorType, CellResult};
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use tw_coin_entry::error::prelude::*;
+
+pub mod aug_dict;
+pub mod dict_extra;
+pub mod dict_key;
+pub mod dict_value;
+mod label;
+
+pub use aug_dict::AugDictionary;
+
+/// Key bits and the corresponding value sorted by the key bits.
+type SerializedEntries<'a, V> = [(Vec<bool>, &'a V)];
+
+/// A typed `HashmapE n X` dictionary, where `n` is [`Dictionary::key_bit_len`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dictionary<K, V> {
+    key_bit_len: usize,
+    entries: BTreeMap<K, V>,
+}
+
+impl<K: DictKey, V: DictValue> Dictionary<K, V> {
+    /// Creates an empty dictionary with keys of the `key_bit_len` length.
+    pub fn new(key_bit_len: usize) -> Self {
+        Dictionary {
+            key_bit_len,
+            entries: BTreeMap::default(),
+        }
+    }
+
+    /// Parses a non-empty dictionary from the `Hashmap n X` root cell.
+    pub fn parse_root(key_bit_len: usize, root: &Cell) -> CellResult<Self> {
+        let mut dict = Dictionary::new(key_bit_len);
+        dict.parse_edge(root, Vec::with_capacity(key_bit_len))?;
+        Ok(dict)
+    }
+
+    pub fn key_bit_len(&self) -> usize {
+        self.key_bit_len
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts a key-value pair into the dictionary.
+    /// Returns the previous value if the dictionary already contained the `key`.
+    ///
+    /// Please note the `key` is checked to fit [`Dictionary::key_bit_len`] on serialization only.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.entries.insert(key, value)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.remove(key)
+    }
+
+    pub fn iter(&self) -> btree_map::Iter<'_, K, V> {
+        self.entries.iter()
+    }
+
+    /// Builds the `Hashmap n X` root cell.
+    /// Returns `None` if the dictionary is empty.
+    pub fn build_root(&self) -> CellResult<Option<Cell>> {
+        if self.entries.is_empty() {
+            return Ok(None);
+        }
+
+        let mut entries = self
+            .entries
+            .iter()
+            .map(|(key, value)| Ok((key.to_key_bits(self.key_bit_len)?, value)))
+            .collect::<CellResult<Vec<_>>>()?;
+        // Keys are expected to be sorted already, but the order of `K` may differ from the order of the key bits.
+        entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        let offset = 0;
+        self.build_edge(&entries, offset).map(Some)
+    }
+
+    /// Builds an edge of the keys that have a common prefix of `offset` bits.
+    fn build_edge(&self, entries: &SerializedEntries<V>, offset: usize) -> CellResult<Cell> {
+        let ((first_key, first_value), (last_key, _)) = match entries {
+            [first, .., last] => (first, last),
+            [single] => (single, single),
+            [] => {
+                return CellError::err(CellErrorType::InternalError)
+                    .context("Cannot build an empty dictionary edge")
+            },
+        };
+
+        // Entries are sorted, so the first and last keys have the shortest common prefix.
+        let label_len = first_key[offset..]
+            .iter()
+            .zip(last_key[offset..].iter())
+            .take_while(|(left, right)| left == right)
+            .count();
+        let label = &first_key[offset..offset + label_len];
+
+        let mut builder = CellBuilder::new();
+        store_label(&mut builder, label, self.key_bit_len - offset)?;
+
+        let fork_offset = offset + label_len;
+        if fork_offset == self.key_bit_len {
+            // hmn_leaf: the key is fully determined by the labels.
+            first_value.store_value(&mut builder)?;
+            return builder.build();
+        }
+
+        // hmn_fork: left subtree contains keys with `0` bit at the fork offset, right with `1`.
+        let split_at = entries.partition_point(|(key, _)| !key[fork_offset]);
+        let (left, right) = entries.split_at(split_at);
+        let child_offset = fork_offset + 1;
+        builder
+            .store_child(self.build_edge(left, child_offset)?)?
+            .store_child(self.build_edge(right, child_offset)?)?;
+        builder.build()
+    }
+
+    /// Parses an edge which keys start with the given `prefix`.
+    fn parse_edge(&mut self, edge: &Cell, mut prefix: Vec<bool>) -> CellResult<()> {
+        let mut parser = edge.parser();
+
+        let label = load_label(&mut parser, self.key_bit_len - prefix.len())?;
+        prefix.extend(label);
+
+        if prefix.len() == self.key_bit_len {
+            let value = V::load_value(&mut parser)?;
+            parser.ensure_empty()?;
+
+            let key = K::from_key_bits(&prefix)?;
+            if self.entries.insert(key, value).is_some() {
+                return CellError::err(CellErrorType::CellParserError)
+                    .context("Dictionary contains duplicate keys");
+            }
+            return Ok(());
+        }
+
+        let left = parser.next_reference()?;
+        let right = parser.next_reference()?;
+        parser.ensure_empty()?;
+
+        let mut left_prefix = prefix.clone();
+        left_prefix.push(false);
+        self.parse_edge(left, left_prefix)?;
+
+        let mut right_prefix = prefix;
+        right_prefix.push(true);
+        self.parse_edge(right, right_prefix)
+    }
+}
+
+impl<K, V> IntoIterator for Dictionary<K, V> {
+    type Item = (K, V);
+    type IntoIter = btree_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Construction and verification of exotic cells.
+//! https://docs.ton.org/develop/data-formats/exotic-cells
+
+use crate::cell::cell_builder::CellBuilder;
+use crate::cell::cell_type::{
+    CellType, LIBRARY_TAG, MERKLE_PROOF_TAG, MERKLE_UPDATE_TAG, PRUNED_BRANCH_TAG,
+};
+use crate::cell::level_mask::LevelMask;
+use crate::cell::{Cell, CellArc, MAX_LEVEL};
+use crate::error::{CellError, CellErrorType, CellResult};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+
+/// Creates a Pruned Branch cell that replaces the given `cell` in a Merkle Proof or Update.
+///
+/// `merkle_depth` is the number of Merkle Proof/Update cells the Pruned Branch is located under,
+/// and it must be greater than the level of the pruned `cell`.
+pub fn create_pruned_branch(cell: &Cell, merkle_depth: u8) -> CellResult<Cell> {
+    let level = cell.level();
+    if merkle_depth <= level || merkle_depth > MAX_LEVEL {
+        return CellError::err(CellErrorType::InvalidExoticCell).context(format!(
+            "Cannot prune a cell of level {level} at the Merkle depth {merkle_depth}"
+        ));
+    }
+
+    let new_level_mask = cell
+        .level_mask
+        .apply_or(LevelMask::new(1 << (merkle_depth - 1)));
+    // Store hashes and depths of every significant level of the pruned cell.
+    let levels: Vec<u8> = (0..=level)
+        .filter(|&i| cell.level_mask.is_significant(i))
+        .collect();
+
+    let mut builder = CellBuilder::new();
+    builder
+        .set_cell_is_exotic(true)
+        .store_u8(8, PRUNED_BRANCH_TAG)?
+        .store_u32(8, new_level_mask.mask())?;
+    for &i in levels.iter() {
+        builder.store_slice(cell.get_hash(i).as_slice())?;
+    }
+    for &i in levels.iter() {
+        builder.store_u32(16, cell.get_depth(i) as u32)?;
+    }
+    builder.build()
+}
+
+/// Creates a Library cell that refers to a library cell by its representation hash.
+pub fn create_library_cell(library_hash: &H256) -> CellResult<Cell> {
+    let mut builder = CellBuilder::new();
+    builder
+        .set_cell_is_exotic(true)
+        .store_u8(8, LIBRARY_TAG)?
+        .store_slice(library_hash.as_slice())?;
+    builder.build()
+}
+
+/// Creates a Merkle Proof of the given cell tree.
+///
+/// The `root` cell is always included in the proof, whereas any other cell is included only
+/// if `keep` returns `true` for it and for all of its ancestors.
+/// Otherwise, the cell is replaced with a Pruned Branch.
+pub fn create_merkle_proof<F>(root: &Cell, keep: F) -> CellResult<Cell>
+where
+    F: Fn(&Cell) -> bool,
+{
+    let merkle_depth = 1;
+    let virtual_root = prune_cell(root, merkle_depth, &keep)?.into_arc();
+    wrap_merkle_proof(virtual_root)
+}
+
+/// Creates a Merkle Update cell from the old and new virtual cell trees.
+/// The trees are usually pruned by [`create_pruned_branch`] at the Merkle depth of 1.
+pub fn create_merkle_update(old_root: CellArc, new_root: CellArc) -> CellResult<Cell> {
+    let mut builder = CellBuilder::new();
+    builder
+        .set_cell_is_exotic(true)
+        .store_u8(8, MERKLE_UPDATE_TAG)?
+        .store_slice(old_root.get_hash(0).as_slice())?
+        .store_slice(new_root.get_hash(0).as_slice())?
+        .store_u32(16, old_root.get_depth(0) as u32)?
+        .store_u32(16, new_root.get_depth(0) as u32)?
+        .store_reference(&old_root)?
+        .store_reference(&new_root)?;
+    builder.build()
+}
+
+/// Verifies that the Merkle Proof proves a cell tree with the `expected_hash` representation hash.
+/// Returns the virtual root of the proven tree.
+pub fn verify_merkle_proof<'a>(proof: &'a Cell, expected_hash: &H256) -> CellResult<&'a CellArc> {
+    if proof.cell_type != CellType::MerkleProof {
+        return CellError::err(CellErrorType::InvalidExoticCell)
+            .context("Expected a Merkle Proof cell");
+    }
+
+    // Stored hash and depth are validated against the virtual root on the cell construction.
+    let virtual_root = proof
+        .references
+        .first()
+        .or_tw_err(CellErrorType::InternalError)
+        .context("Merkle Proof must contain the virtual root")?;
+    ensure_hash(virtual_root, expected_hash, "Merkle Proof")?;
+    Ok(virtual_root)
+}
+
+/// Verifies that the Merkle Update transforms a cell tree with the `expected_old_hash` hash
+/// to a cell tree with the `expected_new_hash` hash.
+/// Returns the old and new virtual roots.
+pub fn verify_merkle_update<'a>(
+    update: &'a Cell,
+    expected_old_hash: &H256,
+    expected_new_hash: &H256,
+) -> CellResult<(&'a CellArc, &'a CellArc)> {
+    if update.cell_type != CellType::MerkleUpdate {
+        return CellError::err(CellErrorType::InvalidExoticCell)
+            .context("Expected a Merkle Update cell");
+    }
+
+    // Stored hashes and depths are validated against the virtual roots on the cell construction.
+    let [old_root, new_root] = update.references() else {
+        return CellError::err(CellErrorType::InternalError)
+            .context("Merkle Update must contain the old and new virtual roots");
+    };
+    ensure_hash(old_root, expected_old_hash, "Merkle Update old")?;
+    ensure_hash(new_root, expected_new_hash, "Merkle Update new")?;
+    Ok((old_root, new_root))
+}
+
+fn wrap_merkle_proof(virtual_root: CellArc) -> CellResult<Cell> {
+    let mut builder = CellBuilder::new();
+    builder
+        .set_cell_is_exotic(true)
+        .store_u8(8, MERKLE_PROOF_TAG)?
+        .store_slice(virtual_root.get_hash(0).as_slice())?
+        .store_u32(16, virtual_root.get_depth(0) as u32)?
+        .store_reference(&virtual_root)?;
+    builder.build()
+}
+
+fn prune_cell<F>(cell: &Cell, merkle_depth: u8, keep: &F) -> CellResult<Cell>
+where
+    F: Fn(&Cell) -> bool,
+{
+    // Children of nested Merkle Proof/Update cells are located one level deeper.
+    let children_merkle_depth = if matches!(
+        cell.cell_type,
+        CellType::MerkleProof | CellType::MerkleUpdate
+    ) {
+        merkle_depth + 1
+    } else {
+        merkle_depth
+    };
+
+    let references = cell
+        .references()
+        .iter()
+        .map(|child| {
+            let child = if keep(child) {
+                prune_cell(child, children_merkle_depth, keep)?
+            } else {
+                create_pruned_branch(child, children_merkle_depth)?
+            };
+            Ok(child.into_arc())
+        })
+        .collect::<CellResult<Vec<_>>>()?;
+
+    Cell::new(
+        cell.data.clone(),
+        cell.bit_len,
+        references,
+        cell.is_exotic(),
+    )
+}
+
+fn ensure_hash(virtual_root: &Cell, expected_hash: &H256, cell_name: &str) -> CellResult<()> {
+    if virtual_root.get_hash(0) == *expected_hash {
+        return Ok(());
+    }
+    CellError::err(CellErrorType::InvalidExoticCell).context(format!(
+        "{cell_name} hash mismatch: expected {expected_hash}, got {}",
+        virtual_root.get_hash(0)
+    ))
+}
@@ -18,6 +18,8 @@ use tw_memory::Data;
 pub mod cell_builder;
 pub mod cell_parser;
 pub mod cell_type;
+pub mod dict;
+pub mod exotic;
 pub mod level_mask;
 
 use crate::cell::cell_type::{CellType, HashesAndDepths};
@@ -90,6 +92,11 @@ impl Cell {
         self.level_mask.mask()
     }
 
+    /// Returns the cell level, i.e. the number of Merkle proofs/updates the cell is pruned under.
+    pub fn level(&self) -> u8 {
+        self.level_mask.level()
+    }
+
     pub fn is_exotic(&self) -> bool {
         self.cell_type != CellType::Ordinary
     }
@@ -111,7 +118,7 @@ impl Cell {
     }
 
     pub fn parser(&self) -> CellParser {
-        CellParser::new(&self.data, self.bit_len)
+        CellParser::new(&self.data, self.bit_len, &self.references)
     }
 
     pub fn parse_fully<F, T>(&self, parse: F) -> Result<T, CellError>
@@ -0,0 +1,306 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::H256;
+use tw_ton_sdk::boc::BagOfCells;
+use tw_ton_sdk::cell::cell_builder::CellBuilder;
+use tw_ton_sdk::cell::cell_parser::CellParser;
+use tw_ton_sdk::cell::dict::dict_extra::DictExtra;
+use tw_ton_sdk::cell::dict::dict_value::DictValue;
+use tw_ton_sdk::cell::dict::{AugDictionary, Dictionary};
+use tw_ton_sdk::cell::{Cell, CellArc};
+use tw_ton_sdk::error::{CellError, CellErrorType, CellResult};
+
+const WALLET_V4R2_CODE: &str = "te6cckECFAEAAtQAART/APSkE/S88sgLAQIBIAIDAgFIBAUE+PKDCNcYINMf0x/THwL4I7vyZO1E0NMf0x/T//QE0VFDuvKhUVG68qIF+QFUEGT5EPKj+AAkpMjLH1JAyx9SMMv/UhD0AMntVPgPAdMHIcAAn2xRkyDXSpbTB9QC+wDoMOAhwAHjACHAAuMAAcADkTDjDQOkyMsfEssfy/8QERITAubQAdDTAyFxsJJfBOAi10nBIJJfBOAC0x8hghBwbHVnvSKCEGRzdHK9sJJfBeAD+kAwIPpEAcjKB8v/ydDtRNCBAUDXIfQEMFyBAQj0Cm+hMbOSXwfgBdM/yCWCEHBsdWe6kjgw4w0DghBkc3RyupJfBuMNBgcCASAICQB4AfoA9AQw+CdvIjBQCqEhvvLgUIIQcGx1Z4MesXCAGFAEywUmzxZY+gIZ9ADLaRfLH1Jgyz8gyYBA+wAGAIpQBIEBCPRZMO1E0IEBQNcgyAHPFvQAye1UAXKwjiOCEGRzdHKDHrFwgBhQBcsFUAPPFiP6AhPLassfyz/JgED7AJJfA+ICASAKCwBZvSQrb2omhAgKBrkPoCGEcNQICEekk30pkQzmkD6f+YN4EoAbeBAUiYcVnzGEAgFYDA0AEbjJftRNDXCx+AA9sp37UTQgQFA1yH0BDACyMoHy//J0AGBAQj0Cm+hMYAIBIA4PABmtznaiaEAga5Drhf/AABmvHfaiaEAQa5DrhY/AAG7SB/oA1NQi+QAFyMoHFcv/ydB3dIAYyMsFywIizxZQBfoCFMtrEszMyXP7AMhAFIEBCPRR8qcCAHCBAQjXGPoA0z/IVCBHgQEI9FHyp4IQbm90ZXB0gBjIywXLAlAGzxZQBPoCFMtqEssfyz/Jc/sAAgBsgQEI1xj6ANM/MFIkgQEI9Fnyp4IQZHN0cnB0gBjIywXLAlAFzxZQA/oCE8tqyx8Syz/Jc/sAAAr0AMntVGliJeU=";
+
+/// An extra that sums up the balances of the dictionary entries.
+#[derive(Clone, Debug, PartialEq)]
+struct Balance(u64);
+
+impl DictValue for Balance {
+    fn store_value(&self, builder: &mut CellBuilder) -> CellResult<()> {
+        builder.store_u64(64, self.0).map(|_| ())
+    }
+
+    fn load_value(parser: &mut CellParser) -> CellResult<Self> {
+        parser.load_u64(64).map(Balance)
+    }
+}
+
+impl DictExtra for Balance {
+    fn empty() -> Self {
+        Balance(0)
+    }
+
+    fn aggregate(left: &Self, right: &Self) -> CellResult<Self> {
+        left.0
+            .checked_add(right.0)
+            .map(Balance)
+            .ok_or_else(|| CellError::new(CellErrorType::InternalError))
+    }
+}
+
+fn cell_with_byte(byte: u8) -> CellArc {
+    let mut builder = CellBuilder::new();
+    builder.store_byte(byte).unwrap();
+    builder.build().unwrap().into_arc()
+}
+
+/// Stores the dictionary as `HashmapE` into a new cell and parses it back.
+#[track_caller]
+fn store_and_load_dict<K, V>(dict: &Dictionary<K, V>) -> Dictionary<K, V>
+where
+    K: tw_ton_sdk::cell::dict::dict_key::DictKey,
+    V: tw_ton_sdk::cell::dict::dict_value::DictValue,
+{
+    let mut builder = CellBuilder::new();
+    builder.store_dict(dict).unwrap();
+    let cell = builder.build().unwrap();
+
+    let boc = BagOfCells::from_root(cell);
+    let boc_base64 = boc.to_base64(true).unwrap();
+    let cell = BagOfCells::parse_base64(&boc_base64)
+        .unwrap()
+        .single_root()
+        .unwrap()
+        .clone();
+
+    let mut parser = cell.parser();
+    let actual = parser.load_dict(dict.key_bit_len()).unwrap();
+    parser.ensure_empty().unwrap();
+    actual
+}
+
+/// The wallet V4R2 code consists of a small prologue and the dictionary of methods with 19-bit keys.
+#[test]
+fn test_dict_parse_wallet_v4r2_methods() {
+    let boc = BagOfCells::parse_base64(WALLET_V4R2_CODE).unwrap();
+    let code = boc.single_root().unwrap();
+    let methods_root = &code.references()[0];
+
+    let methods = Dictionary::<u32, Cell>::parse_root(19, methods_root).unwrap();
+    let keys: Vec<_> = methods.iter().map(|(key, _)| *key).collect();
+    assert_eq!(keys, [0, 76407, 78748, 81467, 85143, 107653, 524287]);
+
+    let recv_internal = methods.get(&0).unwrap();
+    assert_eq!(recv_internal.bit_len(), 912);
+    assert_eq!(recv_internal.references().len(), 2);
+
+    let recv_external = methods.get(&524287).unwrap();
+    assert_eq!(recv_external.bit_len(), 984);
+    assert_eq!(recv_external.references().len(), 4);
+
+    // Serializing the same dictionary must produce the very same cell tree.
+    let actual_root = methods.build_root().unwrap().unwrap();
+    assert_eq!(
+        actual_root.cell_hash().to_string(),
+        "d814641364bd13e0a8a646226d7eb6e174cfd4f47235f8f8b29d1fd68c05a399"
+    );
+    assert_eq!(actual_root.cell_hash(), methods_root.cell_hash());
+}
+
+#[test]
+fn test_dict_store_load_u64_keys() {
+    let mut dict = Dictionary::<u64, u32>::new(64);
+    dict.insert(0, 1);
+    dict.insert(1, 2);
+    dict.insert(u64::MAX, 3);
+    dict.insert(0x8000_0000_0000_0000, 4);
+    dict.insert(0x0123_4567_89ab_cdef, 5);
+
+    let actual = store_and_load_dict(&dict);
+    assert_eq!(actual, dict);
+    assert_eq!(actual.get(&u64::MAX), Some(&3));
+}
+
+#[test]
+fn test_dict_store_load_single_entry() {
+    let mut dict = Dictionary::<u8, bool>::new(8);
+    dict.insert(0xAB, true);
+
+    let root = dict.build_root().unwrap().unwrap();
+    assert!(root.references().is_empty());
+
+    let actual = store_and_load_dict(&dict);
+    assert_eq!(actual, dict);
+}
+
+#[test]
+fn test_dict_store_load_h256_keys() {
+    let key_1 = H256::from("c5403cf3bb28e0aa0ab06e2699ef8a68445d4b61346a047afb0a02d06bf44952");
+    let key_2 = H256::from("c5403cf3bb28e0aa0ab06e2699ef8a68445d4b61346a047afb0a02d06bf44953");
+    let key_3 = H256::from("0000000000000000000000000000000000000000000000000000000000000000");
+
+    let mut dict = Dictionary::<H256, CellArc>::new(256);
+    dict.insert(key_1, cell_with_byte(1));
+    dict.insert(key_2, cell_with_byte(2));
+    dict.insert(key_3, cell_with_byte(3));
+
+    let actual = store_and_load_dict(&dict);
+    assert_eq!(actual.len(), 3);
+    assert_eq!(actual.get(&key_2).unwrap().data(), [2]);
+    assert_eq!(actual.get(&key_3).unwrap().data(), [3]);
+}
+
+#[test]
+fn test_dict_store_load_keys_shorter_than_type() {
+    let mut dict = Dictionary::<u32, u8>::new(4);
+    for key in 0..16 {
+        dict.insert(key, key as u8 * 10);
+    }
+    assert!(dict.remove(&7).is_some());
+
+    let actual = store_and_load_dict(&dict);
+    assert_eq!(actual.len(), 15);
+    assert!(!actual.contains_key(&7));
+    assert_eq!(actual.into_iter().next_back(), Some((15, 150)));
+}
+
+#[test]
+fn test_dict_store_load_empty() {
+    let dict = Dictionary::<u32, u32>::new(32);
+    assert!(dict.build_root().unwrap().is_none());
+
+    let mut builder = CellBuilder::new();
+    builder.store_dict(&dict).unwrap();
+    let cell = builder.build().unwrap();
+    assert_eq!(cell.bit_len(), 1);
+    assert!(cell.references().is_empty());
+
+    let actual = store_and_load_dict(&dict);
+    assert!(actual.is_empty());
+}
+
+#[test]
+fn test_dict_key_too_long() {
+    let mut dict = Dictionary::<u32, bool>::new(8);
+    dict.insert(0xFF, true);
+    dict.insert(0x100, false);
+    dict.build_root().unwrap_err();
+
+    let mut builder = CellBuilder::new();
+    assert!(builder.store_dict(&dict).is_err());
+}
+
+#[test]
+fn test_dict_parse_key_too_long() {
+    let mut dict = Dictionary::<u32, bool>::new(40);
+    dict.insert(1, true);
+    let root = dict.build_root().unwrap().unwrap();
+
+    // 40-bit key with the most significant bits unset fits `u64` only.
+    Dictionary::<u64, bool>::parse_root(40, &root).unwrap();
+    let mut dict = Dictionary::<u64, bool>::new(40);
+    dict.insert(1 << 39, true);
+    let root = dict.build_root().unwrap().unwrap();
+    Dictionary::<u32, bool>::parse_root(40, &root).unwrap_err();
+}
+
+#[test]
+fn test_dict_parse_value_not_fully_read() {
+    let mut dict = Dictionary::<u8, u32>::new(8);
+    dict.insert(1, 1);
+    dict.insert(2, 2);
+    let root = dict.build_root().unwrap().unwrap();
+
+    // `u8` values do not consume the whole `u32` leaf.
+    Dictionary::<u8, u8>::parse_root(8, &root).unwrap_err();
+}
+
+#[test]
+fn test_aug_dict_store_load_empty() {
+    let dict = AugDictionary::<u8, u32, Balance>::new(8);
+    assert!(dict.build_root().unwrap().is_none());
+
+    // ahme_empty$0 extra:Y
+    let mut builder = CellBuilder::new();
+    builder.store_aug_dict(&dict).unwrap();
+    let cell = builder.build().unwrap();
+    assert_eq!(cell.bit_len(), 1 + 64);
+    assert!(cell.references().is_empty());
+
+    let mut parser = cell.parser();
+    let (actual, extra) = parser.load_aug_dict::<u8, u32, Balance>(8).unwrap();
+    parser.ensure_empty().unwrap();
+    assert!(actual.is_empty());
+    assert_eq!(extra, Balance(0));
+}
+
+#[test]
+fn test_aug_dict_store_load_root() {
+    let mut dict = AugDictionary::<u8, u32, Balance>::new(8);
+    dict.insert(0, 10, Balance(100));
+    dict.insert(1, 20, Balance(200));
+    dict.insert(0x80, 30, Balance(300));
+
+    // ahme_root$1 root:^(HashmapAug n X Y) extra:Y
+    let mut builder = CellBuilder::new();
+    builder.store_aug_dict(&dict).unwrap();
+    let cell = builder.build().unwrap();
+    assert_eq!(cell.bit_len(), 1 + 64);
+    assert_eq!(cell.references().len(), 1);
+
+    let mut parser = cell.parser();
+    let (actual, extra) = parser.load_aug_dict(8).unwrap();
+    parser.ensure_empty().unwrap();
+    assert_eq!(actual, dict);
+    assert_eq!(extra, Balance(600));
+    assert_eq!(actual.get(&1), Some(&20));
+    assert_eq!(actual.get_extra(&0x80), Some(&Balance(300)));
+}
+
+#[test]
+fn test_aug_dict_fork_and_leaf_extras() {
+    let mut dict = AugDictionary::<u8, u32, Balance>::new(8);
+    dict.insert(0, 10, Balance(100));
+    dict.insert(1, 20, Balance(200));
+
+    let (root, extra) = dict.build_root().unwrap().unwrap();
+    assert_eq!(extra, Balance(300));
+
+    // ahmn_fork: `hml_same$11` label of 7 zero bits, two references and the aggregated extra.
+    assert_eq!(root.references().len(), 2);
+    let mut parser = root.parser();
+    assert_eq!(parser.load_u8(7).unwrap(), 0b1100111);
+    assert_eq!(parser.load_u64(64).unwrap(), 300);
+    parser.ensure_empty().unwrap();
+
+    // ahmn_leaf: empty `hml_short$0` label, the extra and the value.
+    let mut parser = root.references()[1].parser();
+    assert_eq!(parser.load_u8(2).unwrap(), 0);
+    assert_eq!(parser.load_u64(64).unwrap(), 200);
+    assert_eq!(parser.load_u32(32).unwrap(), 20);
+    parser.ensure_empty().unwrap();
+}
+
+#[test]
+fn test_aug_dict_parse_fork_extra_mismatch() {
+    let mut dict = AugDictionary::<u8, u32, Balance>::new(8);
+    dict.insert(0, 10, Balance(100));
+    dict.insert(1, 20, Balance(200));
+    let (root, _) = dict.build_root().unwrap().unwrap();
+
+    // Replace the fork extra with an invalid aggregate.
+    let mut parser = root.parser();
+    let label = parser.load_u8(7).unwrap();
+    let mut builder = CellBuilder::new();
+    builder
+        .store_u8(7, label)
+        .unwrap()
+        .store_references(root.references())
+        .unwrap()
+        .store_u64(64, 301)
+        .unwrap();
+    let invalid_root = builder.build().unwrap();
+
+    AugDictionary::<u8, u32, Balance>::parse_root(8, &invalid_root).unwrap_err();
+}
+
+#[test]
+fn test_aug_dict_extra_overflow() {
+    let mut dict = AugDictionary::<u8, u32, Balance>::new(8);
+    dict.insert(0, 10, Balance(u64::MAX));
+    dict.insert(1, 20, Balance(1));
+    dict.build_root().unwrap_err();
+}
@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_hash::H256;
+use tw_ton_sdk::boc::BagOfCells;
+use tw_ton_sdk::cell::cell_builder::CellBuilder;
+use tw_ton_sdk::cell::exotic::{
+    create_library_cell, create_merkle_proof, create_merkle_update, create_pruned_branch,
+    verify_merkle_proof, verify_merkle_update,
+};
+use tw_ton_sdk::cell::{Cell, CellArc};
+
+const LEAF_HASH: &str = "d3a8929387899076b400f88663953c28e3df602e4eda5e89aded70dd0a98536b";
+const ROOT_HASH: &str = "c5403cf3bb28e0aa0ab06e2699ef8a68445d4b61346a047afb0a02d06bf44952";
+
+fn cell_with_byte(byte: u8, child: Option<CellArc>) -> CellArc {
+    let mut builder = CellBuilder::new();
+    builder.store_byte(byte).unwrap();
+    if let Some(child) = child {
+        builder.store_reference(&child).unwrap();
+    }
+    builder.build().unwrap().into_arc()
+}
+
+/// Creates a `root(30) -> inter(20) -> leaf(10)` cell tree.
+fn create_tree(root_byte: u8) -> CellArc {
+    let leaf = cell_with_byte(10, None);
+    let inter = cell_with_byte(20, Some(leaf));
+    cell_with_byte(root_byte, Some(inter))
+}
+
+#[track_caller]
+fn boc_round_trip(cell: Cell) -> CellArc {
+    let boc_base64 = BagOfCells::from_root(cell).to_base64(true).unwrap();
+    BagOfCells::parse_base64(&boc_base64)
+        .unwrap()
+        .single_root()
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn test_create_pruned_branch() {
+    let leaf = cell_with_byte(10, None);
+    assert_eq!(leaf.cell_hash().to_string(), LEAF_HASH);
+
+    let pruned = create_pruned_branch(&leaf, 1).unwrap();
+    assert!(pruned.is_exotic());
+    assert_eq!(pruned.level(), 1);
+    assert_eq!(pruned.bit_len(), 8 + 8 + 256 + 16);
+    assert_eq!(pruned.get_hash(0).to_string(), LEAF_HASH);
+    assert_eq!(pruned.get_depth(0), 0);
+    assert_eq!(
+        pruned.cell_hash().to_string(),
+        "23109bc56965d238a8aa2fcbebfe86c00cf0ee2b0cbd518531a321297362a3be"
+    );
+
+    let actual = boc_round_trip(pruned.clone());
+    assert_eq!(*actual, pruned);
+}
+
+#[test]
+fn test_create_pruned_branch_invalid_depth() {
+    let leaf = cell_with_byte(10, None);
+    create_pruned_branch(&leaf, 0).unwrap_err();
+    create_pruned_branch(&leaf, 4).unwrap_err();
+
+    // A pruned branch of level 1 can be pruned at the Merkle depth of 2 only.
+    let pruned = create_pruned_branch(&leaf, 1).unwrap();
+    create_pruned_branch(&pruned, 1).unwrap_err();
+    let pruned_twice = create_pruned_branch(&pruned, 2).unwrap();
+    assert_eq!(pruned_twice.level(), 2);
+    assert_eq!(pruned_twice.get_hash(0).to_string(), LEAF_HASH);
+    assert_eq!(pruned_twice.get_hash(1), pruned.get_hash(1));
+}
+
+#[test]
+fn test_create_library_cell() {
+    let library_hash = H256::from(LEAF_HASH);
+    let library = create_library_cell(&library_hash).unwrap();
+    assert!(library.is_exotic());
+    assert_eq!(library.level(), 0);
+    assert_eq!(library.bit_len(), 264);
+    assert_eq!(
+        format!("{library:?}"),
+        format!("Cell(root) {{ data=02{LEAF_HASH}, bit_len=264 exotic=Library }}\n")
+    );
+}
+
+#[test]
+fn test_create_merkle_proof_pruned() {
+    let root = create_tree(30);
+    assert_eq!(root.cell_hash().to_string(), ROOT_HASH);
+
+    // Keep the root cell only.
+    let proof = create_merkle_proof(&root, |_| false).unwrap();
+    assert!(proof.is_exotic());
+    assert_eq!(proof.level(), 0);
+    assert_eq!(
+        proof.cell_hash().to_string(),
+        "23d75a1e64c99010fe529b6cad09e4530a48d7d5ce23bf7d8774fcc85110be03"
+    );
+
+    let virtual_root = verify_merkle_proof(&proof, &root.cell_hash()).unwrap();
+    assert_eq!(virtual_root.level(), 1);
+    assert_eq!(virtual_root.get_hash(0), root.cell_hash());
+    assert_eq!(virtual_root.get_depth(0), root.get_depth(0));
+    assert_eq!(
+        virtual_root.cell_hash().to_string(),
+        "1d47207bd4ca8e94358ee335a21e1f4ad9d287d58f79e590d758d85bd52ee1f5"
+    );
+
+    let actual = boc_round_trip(proof.clone());
+    assert_eq!(*actual, proof);
+    verify_merkle_proof(&actual, &root.cell_hash()).unwrap();
+}
+
+#[test]
+fn test_create_merkle_proof_full() {
+    let root = create_tree(30);
+
+    let proof = create_merkle_proof(&root, |_| true).unwrap();
+    assert_eq!(
+        proof.cell_hash().to_string(),
+        "8499f2edf37ef7fd3ef0f516dd4a3150deebb6bbcda21f8774912633a62ce38d"
+    );
+
+    let virtual_root = verify_merkle_proof(&proof, &root.cell_hash()).unwrap();
+    assert_eq!(virtual_root.level(), 0);
+    assert_eq!(**virtual_root, *root);
+}
+
+#[test]
+fn test_create_merkle_proof_nested() {
+    let root = create_tree(30);
+    let proof = create_merkle_proof(&root, |_| false).unwrap().into_arc();
+
+    let mut builder = CellBuilder::new();
+    builder
+        .store_byte(40)
+        .unwrap()
+        .store_reference(&proof)
+        .unwrap();
+    let outer = builder.build().unwrap();
+
+    // Keep the inner Merkle Proof, whereas its virtual root is pruned at the Merkle depth of 2.
+    let outer_proof = create_merkle_proof(&outer, |cell| cell.is_exotic()).unwrap();
+    assert_eq!(
+        outer_proof.cell_hash().to_string(),
+        "ceef382021b504adebbab7b8791e98cba1816624a8d64cb4fdca49c53b02b15f"
+    );
+    verify_merkle_proof(&outer_proof, &outer.cell_hash()).unwrap();
+}
+
+#[test]
+fn test_verify_merkle_proof_invalid() {
+    let root = create_tree(30);
+    let proof = create_merkle_proof(&root, |_| false).unwrap();
+
+    let other_root = create_tree(31);
+    verify_merkle_proof(&proof, &other_root.cell_hash()).unwrap_err();
+
+    // Not a Merkle Proof.
+    verify_merkle_proof(&root, &root.cell_hash()).unwrap_err();
+}
+
+#[test]
+fn test_verify_merkle_proof_pruned_block() {
+    let boc = "te6ccgEBBAEArwAJRgPIr248LcbQSSCsDD5Rb27WLhRGYiTEGG+uChgAAXoNHAAIASJxwAtrH/x8t+GjDO5/X/f1fk4Rw3oYx+9S1gRE8vya04qzwiyFkEMdYglgAAAaNN8fbBluIJfFw9NAAgMoSAEB/rX/aCDi/w2Ug+fg1iyBfYRniftK5YDIeIZtlZ2r1cAAByhIAQEg0z54hgTX/ohMEnHs6qluCydagWgxQoxSyLwK8qfAOQAA";
+    let boc = BagOfCells::parse_base64(boc).unwrap();
+    let proof = boc.single_root().unwrap();
+
+    let expected_hash =
+        H256::from("c8af6e3c2dc6d04920ac0c3e516f6ed62e14466224c4186fae0a1800017a0d1c");
+    let virtual_root = verify_merkle_proof(proof, &expected_hash).unwrap();
+    assert_eq!(virtual_root.level(), 1);
+
+    // The first child is a Pruned Branch of the Wallet V4R2 code.
+    let pruned_code = &virtual_root.references()[0];
+    assert_eq!(pruned_code.level(), 1);
+    assert_eq!(
+        pruned_code.get_hash(0).to_string(),
+        "feb5ff6820e2ff0d9483e7e0d62c817d846789fb4ae580c878866d959dabd5c0"
+    );
+}
+
+#[test]
+fn test_create_merkle_update() {
+    let old_root = create_tree(30);
+    let new_root = create_tree(31);
+
+    let old_virtual_root = verify_merkle_proof(
+        &create_merkle_proof(&old_root, |_| false).unwrap(),
+        &old_root.cell_hash(),
+    )
+    .unwrap()
+    .clone();
+    let new_virtual_root = verify_merkle_proof(
+        &create_merkle_proof(&new_root, |_| false).unwrap(),
+        &new_root.cell_hash(),
+    )
+    .unwrap()
+    .clone();
+
+    let update = create_merkle_update(old_virtual_root, new_virtual_root).unwrap();
+    assert!(update.is_exotic());
+    assert_eq!(update.level(), 0);
+    assert_eq!(update.bit_len(), 8 + 256 * 2 + 16 * 2);
+    assert_eq!(
+        update.cell_hash().to_string(),
+        "b2bdefa06cf04c240c6df2344d28935ef7181ce02903c80feac99913b2cb35ca"
+    );
+
+    let update = boc_round_trip(update);
+    let (actual_old, actual_new) =
+        verify_merkle_update(&update, &old_root.cell_hash(), &new_root.cell_hash()).unwrap();
+    assert_eq!(actual_old.get_hash(0), old_root.cell_hash());
+    assert_eq!(actual_new.get_hash(0), new_root.cell_hash());
+
+    verify_merkle_update(&update, &new_root.cell_hash(), &old_root.cell_hash()).unwrap_err();
+    verify_merkle_update(&old_root, &old_root.cell_hash(), &new_root.cell_hash()).unwrap_err();
+}
+
+/// Exotic cells with inconsistent data must be rejected on construction.
+#[test]
+fn test_exotic_cell_invalid() {
+    let root = create_tree(30);
+
+    // Merkle Proof with a wrong stored hash.
+    let mut builder = CellBuilder::new();
+    builder
+        .set_cell_is_exotic(true)
+        .store_u8(8, 3)
+        .unwrap()
+        .store_slice(H256::from(LEAF_HASH).as_slice())
+        .unwrap()
+        .store_u32(16, root.get_depth(0) as u32)
+        .unwrap()
+        .store_reference(&root)
+        .unwrap();
+    builder.build().unwrap_err();
+
+    // Merkle Proof with a wrong stored depth.
+    let mut builder = CellBuilder::new();
+    builder
+        .set_cell_is_exotic(true)
+        .store_u8(8, 3)
+        .unwrap()
+        .store_slice(root.cell_hash().as_slice())
+        .unwrap()
+        .store_u32(16, 100)
+        .unwrap()
+        .store_reference(&root)
+        .unwrap();
+    builder.build().unwrap_err();
+
+    // Library cell with references.
+    let mut builder = CellBuilder::new();
+    builder
+        .set_cell_is_exotic(true)
+        .store_u8(8, 2)
+        .unwrap()
+        .store_slice(root.cell_hash().as_slice())
+        .unwrap()
+        .store_reference(&root)
+        .unwrap();
+    builder.build().unwrap_err();
+
+    // Pruned Branch with an invalid level mask.
+    let mut builder = CellBuilder::new();
+    builder
+        .set_cell_is_exotic(true)
+        .store_u8(8, 1)
+        .unwrap()
+        .store_u8(8, 0)
+        .unwrap()
+        .store_slice(root.cell_hash().as_slice())
+        .unwrap()
+        .store_u32(16, 0)
+        .unwrap();
+    builder.build().unwrap_err();
+}
@@ -4,7 +4,7 @@
 
 use crate::script::Script;
 use std::str::FromStr;
-use tw_coin_entry::error::prelude::{AddressError, SigningResult};
+use tw_coin_entry::error::prelude::{AddressError, AddressResult, SigningResult};
 
 pub struct AddressPrefixes {
     pub p2pkh_prefix: u8,
@@ -18,4 +18,16 @@ pub trait UtxoContext {
         addr: &Self::Address,
         prefixes: AddressPrefixes,
     ) -> SigningResult<Script>;
+
+    /// Recovers an address from the given `scriptPubkey`.
+    /// Returns `Ok(None)` if the script doesn't correspond to an address supported by the chain,
+    /// for example, OP_RETURN or a custom script.
+    /// Chains that can't derive addresses from scripts don't recover any by default.
+    fn addr_from_script_pubkey(
+        _script_pubkey: &Script,
+        _prefixes: AddressPrefixes,
+        _hrp: Option<String>,
+    ) -> AddressResult<Option<Self::Address>> {
+        Ok(None)
+    }
 }
@@ -28,9 +28,12 @@ impl<Transaction: TransactionInterface> DustFilter<Transaction> {
         &self,
         mut transaction: UnsignedTransaction<Transaction>,
     ) -> SigningResult<UnsignedTransaction<Transaction>> {
-        let dust_threshold = self.dust_policy.dust_threshold();
-
-        transaction.retain_inputs(|_utxo, utxo_args| utxo_args.amount >= dust_threshold)?;
+        transaction.retain_inputs(|_utxo, utxo_args| {
+            utxo_args.amount
+                >= self
+                    .dust_policy
+                    .dust_threshold(&utxo_args.prevout_script_pubkey)
+        })?;
 
         Ok(transaction)
     }
@@ -40,14 +43,12 @@ impl<Transaction: TransactionInterface> DustFilter<Transaction> {
         &self,
         transaction: &UnsignedTransaction<Transaction>,
     ) -> SigningResult<()> {
-        let dust_threshold = self.dust_policy.dust_threshold();
-
         let has_dust_output = transaction.transaction().outputs().iter().any(|output| {
             if conditions::is_op_return(output.script_pubkey()) {
                 // Ignore the OP_RETURN output value. It can (or even should) be 0.
                 return false;
             }
-            output.value() < dust_threshold
+            output.value() < self.dust_policy.dust_threshold(output.script_pubkey())
         });
 
         if has_dust_output {
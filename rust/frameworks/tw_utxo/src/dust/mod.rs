@@ -2,21 +2,123 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::encode::Encodable;
+use crate::script::standard_script::conditions;
+use crate::script::Script;
 use crate::transaction::transaction_parts::Amount;
 
 pub mod dust_filter;
 
+/// The max size of a spendable scriptPubkey.
+const MAX_SCRIPT_SIZE: usize = 10_000;
+/// The size of an output `value`. The scriptPubkey is encoded along with its length prefix.
+const OUTPUT_BASE_SIZE: usize = 8;
+/// The size of a non-witness input spending an output, assuming a P2PKH scriptSig:
+/// `prev_txid (32) + prev_index (4) + script_sig_len (1) + script_sig (107) + sequence (4)`.
+const LEGACY_INPUT_SIZE: usize = 32 + 4 + 1 + 107 + 4;
+/// The virtual size of a witness input spending an output, assuming a P2WPKH witness:
+/// `prev_txid (32) + prev_index (4) + script_sig_len (1) + witness (107 / 4) + sequence (4)`.
+const WITNESS_INPUT_VSIZE: usize = 32 + 4 + 1 + 107 / 4 + 4;
+
 /// Transaction dust amount calculator.
-/// Later, we plan to add support for `DynamicDust` policy with a `min_relay_fee` amount.
 #[derive(Clone, Copy)]
 pub enum DustPolicy {
+    /// Use the same dust threshold for all the inputs and outputs.
     FixedAmount(Amount),
+    /// Calculate the dust threshold of an output (or UTXO) depending on its scriptPubkey,
+    /// as the fee required to create and spend it at the dust relay fee rate.
+    /// Matches Bitcoin Core's `GetDustThreshold`.
+    Dynamic {
+        /// The dust relay fee rate in satoshis per kilo-vbyte (`-dustrelayfee`, 3000 in Bitcoin Core).
+        dust_relay_fee_per_kvb: Amount,
+    },
 }
 
 impl DustPolicy {
-    pub fn dust_threshold(&self) -> Amount {
+    /// Returns the dust threshold of an output (or UTXO) locked by the given `script_pubkey`.
+    pub fn dust_threshold(&self, script_pubkey: &Script) -> Amount {
         match self {
             DustPolicy::FixedAmount(amount) => *amount,
+            DustPolicy::Dynamic {
+                dust_relay_fee_per_kvb,
+            } => Self::dynamic_dust_threshold(script_pubkey, *dust_relay_fee_per_kvb),
+        }
+    }
+
+    fn dynamic_dust_threshold(script_pubkey: &Script, dust_relay_fee_per_kvb: Amount) -> Amount {
+        let is_unspendable =
+            conditions::is_op_return(script_pubkey) || script_pubkey.len() > MAX_SCRIPT_SIZE;
+        if is_unspendable {
+            return 0;
         }
+
+        let output_size = OUTPUT_BASE_SIZE + script_pubkey.encoded_size();
+        let spend_size = if conditions::is_witness_program(script_pubkey) {
+            WITNESS_INPUT_VSIZE
+        } else {
+            LEGACY_INPUT_SIZE
+        };
+
+        // Round the fee up, i.e. `ceil(size * fee_rate / 1000)`.
+        let size = Amount::try_from(output_size + spend_size).unwrap_or(Amount::MAX);
+        size.saturating_mul(dust_relay_fee_per_kvb)
+            .saturating_add(999)
+            / 1000
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    const DUST_RELAY_FEE: Amount = 3000;
+
+    fn dynamic_threshold(script_pubkey: &str) -> Amount {
+        let policy = DustPolicy::Dynamic {
+            dust_relay_fee_per_kvb: DUST_RELAY_FEE,
+        };
+        policy.dust_threshold(&Script::from(script_pubkey.decode_hex().unwrap()))
+    }
+
+    #[test]
+    fn test_dynamic_dust_threshold() {
+        // P2PKH
+        assert_eq!(
+            dynamic_threshold("76a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac"),
+            546
+        );
+        // P2SH
+        assert_eq!(
+            dynamic_threshold("a914e4c1ea86373d554b8f4efff2cfb0001ea19124d287"),
+            540
+        );
+        // P2WPKH
+        assert_eq!(
+            dynamic_threshold("0014e311b8d6ddff856ce8e9a4e03bc6d4fe5050a83d"),
+            294
+        );
+        // P2WSH
+        assert_eq!(
+            dynamic_threshold(
+                "00201863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262"
+            ),
+            330
+        );
+        // P2TR
+        assert_eq!(
+            dynamic_threshold(
+                "5120e8b706a97732e705e22ae7710703e7f589ed13c636324461afa443016134cc05"
+            ),
+            330
+        );
+        // OP_RETURN
+        assert_eq!(dynamic_threshold("6a0401020304"), 0);
+    }
+
+    #[test]
+    fn test_fixed_dust_threshold() {
+        let policy = DustPolicy::FixedAmount(1000);
+        assert_eq!(policy.dust_threshold(&Script::default()), 1000);
     }
 }
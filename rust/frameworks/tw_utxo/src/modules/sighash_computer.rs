@@ -12,12 +12,15 @@ use crate::transaction::{
 };
 use std::marker::PhantomData;
 use tw_coin_entry::coin_entry::PublicKeyBytes;
-use tw_coin_entry::error::prelude::SigningResult;
+use tw_coin_entry::error::prelude::*;
 use tw_hash::H256;
 
 #[derive(Debug, Clone)]
 pub struct TxPreimage {
     /// Transaction signatures in the same order as the transaction UTXOs.
+    /// Please note that a UTXO may require several signatures (e.g. multisig),
+    /// then there is a sighash per each signer that hasn't signed the UTXO yet.
+    /// See [`UtxoToSign::pending_signers`].
     pub sighashes: Vec<UtxoSighash>,
 }
 
@@ -61,71 +64,93 @@ where
             .iter()
             .enumerate()
             .map(|(signing_input_index, utxo)| {
-                let signing_method = utxo.signing_method;
+                let (sighash, taproot_tweak) =
+                    Self::preimage_utxo(unsigned_tx, signing_input_index)?;
 
-                let utxo_args = UtxoPreimageArgs {
-                    input_index: signing_input_index,
-                    script_pubkey: utxo.script_pubkey.clone(),
-                    amount: utxo.amount,
-                    // TODO move `leaf_hash_code_separator` to `UtxoTaprootPreimageArgs`.
-                    leaf_hash_code_separator: utxo.leaf_hash_code_separator,
-                    sighash_ty: utxo.sighash_ty,
-                    tx_hasher: utxo.tx_hasher,
-                    signing_method,
-                };
+                // All signers of the UTXO sign the same sighash.
+                Ok(utxo
+                    .pending_signers()
+                    .into_iter()
+                    .map(|signer_pubkey| UtxoSighash {
+                        signing_method: utxo.signing_method,
+                        sighash,
+                        signer_pubkey,
+                        taproot_tweak: taproot_tweak.clone(),
+                    })
+                    .collect())
+            })
+            // Collect the results as [`SigningResult<Vec<Vec<UtxoSighash>>>`].
+            .collect::<SigningResult<Vec<_>>>()
+            .map(|sighashes: Vec<Vec<UtxoSighash>>| TxPreimage {
+                sighashes: sighashes.into_iter().flatten().collect(),
+            })
+    }
 
-                let (sighash, taproot_tweak) = match signing_method {
-                    SigningMethod::Legacy | SigningMethod::Segwit => {
-                        let sighash = unsigned_tx.transaction().preimage_tx(&utxo_args)?;
-                        (sighash, None)
-                    },
-                    SigningMethod::Taproot => {
-                        // TODO Move `tr_spent_amounts` and `tr_spent_script_pubkeys` logic to `Transaction::preimage_taproot_tx()`.
-                        let tr_spent_amounts: Vec<Amount> = unsigned_tx
-                            .input_args()
-                            .iter()
-                            .map(|utxo| utxo.amount)
-                            .collect();
+    /// Computes a sighash of the UTXO at the given `signing_input_index`.
+    /// Returns the sighash and a Taproot tweak if the private key should be tweaked to sign the sighash.
+    pub fn preimage_utxo(
+        unsigned_tx: &UnsignedTransaction<Transaction>,
+        signing_input_index: usize,
+    ) -> SigningResult<(H256, Option<TaprootTweak>)> {
+        let utxo = unsigned_tx
+            .input_args()
+            .get(signing_input_index)
+            .or_tw_err(SigningErrorType::Error_internal)
+            .with_context(|| format!("There is no UTXO at the '{signing_input_index}' index"))?;
+        let signing_method = utxo.signing_method;
 
-                        let tr_spent_script_pubkeys: Vec<Script> = unsigned_tx
-                            .input_args()
-                            .iter()
-                            .enumerate()
-                            .map(|(i, utxo)| {
-                                if i == signing_input_index {
-                                    // Use the scriptPubkey required to spend this UTXO.
-                                    utxo.script_pubkey.clone()
-                                } else {
-                                    // Use the original scriptPubkey declared in the unspent output for other UTXOs
-                                    // (different from that we sign at this iteration).
-                                    utxo.prevout_script_pubkey.clone()
-                                }
-                            })
-                            .collect();
+        let utxo_args = UtxoPreimageArgs {
+            input_index: signing_input_index,
+            script_pubkey: utxo.script_pubkey.clone(),
+            amount: utxo.amount,
+            // TODO move `leaf_hash_code_separator` to `UtxoTaprootPreimageArgs`.
+            leaf_hash_code_separator: utxo.leaf_hash_code_separator,
+            sighash_ty: utxo.sighash_ty,
+            tx_hasher: utxo.tx_hasher,
+            signing_method,
+        };
 
-                        let tr = UtxoTaprootPreimageArgs {
-                            args: utxo_args,
-                            spent_amounts: tr_spent_amounts,
-                            spent_script_pubkeys: tr_spent_script_pubkeys.clone(),
-                        };
+        match signing_method {
+            SigningMethod::Legacy | SigningMethod::Segwit => {
+                let sighash = unsigned_tx.transaction().preimage_tx(&utxo_args)?;
+                Ok((sighash, None))
+            },
+            SigningMethod::Taproot => {
+                // TODO Move `tr_spent_amounts` and `tr_spent_script_pubkeys` logic to `Transaction::preimage_taproot_tx()`.
+                let tr_spent_amounts: Vec<Amount> = unsigned_tx
+                    .input_args()
+                    .iter()
+                    .map(|utxo| utxo.amount)
+                    .collect();
 
-                        let sighash = unsigned_tx.transaction().preimage_taproot_tx(&tr)?;
-                        let taproot_tweak = Self::get_taproot_tweak(utxo);
+                let tr_spent_script_pubkeys: Vec<Script> = unsigned_tx
+                    .input_args()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, utxo)| {
+                        if i == signing_input_index {
+                            // Use the scriptPubkey required to spend this UTXO.
+                            utxo.script_pubkey.clone()
+                        } else {
+                            // Use the original scriptPubkey declared in the unspent output for other UTXOs
+                            // (different from that we sign at this iteration).
+                            utxo.prevout_script_pubkey.clone()
+                        }
+                    })
+                    .collect();
 
-                        (sighash, taproot_tweak)
-                    },
+                let tr = UtxoTaprootPreimageArgs {
+                    args: utxo_args,
+                    spent_amounts: tr_spent_amounts,
+                    spent_script_pubkeys: tr_spent_script_pubkeys,
                 };
 
-                Ok(UtxoSighash {
-                    signing_method,
-                    sighash,
-                    signer_pubkey: utxo.spender_public_key.clone(),
-                    taproot_tweak,
-                })
-            })
-            // Collect the results as [`SigningResult<Vec<UtxoSighash>>`].
-            .collect::<SigningResult<Vec<_>>>()
-            .map(|sighashes: Vec<UtxoSighash>| TxPreimage { sighashes })
+                let sighash = unsigned_tx.transaction().preimage_taproot_tx(&tr)?;
+                let taproot_tweak = Self::get_taproot_tweak(utxo);
+
+                Ok((sighash, taproot_tweak))
+            },
+        }
     }
 
     pub fn get_taproot_tweak(utxo: &UtxoToSign) -> Option<TaprootTweak> {
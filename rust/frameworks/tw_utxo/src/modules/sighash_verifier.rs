@@ -5,6 +5,7 @@
 use crate::modules::sighash_computer::{SighashComputer, UtxoSighash};
 use crate::signature::FromRawOrDerBytes;
 use crate::signing_mode::SigningMethod;
+use crate::spending_data::{ScriptSigner, SpendingDataConstructor};
 use crate::transaction::transaction_interface::TransactionInterface;
 use crate::transaction::unsigned_transaction::UnsignedTransaction;
 use crate::transaction::{TransactionPreimage, UtxoToSign};
@@ -39,18 +40,64 @@ where
         unsigned_tx: &UnsignedTransaction<Transaction>,
         signatures: &[SignatureBytes],
     ) -> SigningResult<()> {
-        Self::check_signatures_number(unsigned_tx.inputs().len(), signatures.len())?;
-
         // Compute transaction preimage and verify if all given signatures correspond to the result sighashes.
         let tx_preimage = SighashComputer::preimage_tx(unsigned_tx)?;
+        Self::check_signatures_number(tx_preimage.sighashes.len(), signatures.len())?;
+
+        let mut sighashes = tx_preimage.sighashes.iter().zip(signatures);
+        for (input_index, utxo_args) in unsigned_tx.input_args().iter().enumerate() {
+            let multi_constructor = match utxo_args.spending_data_constructor {
+                SpendingDataConstructor::EcdsaMulti(ref multi_constructor) => {
+                    Some(multi_constructor)
+                },
+                SpendingDataConstructor::Ecdsa(_) | SpendingDataConstructor::Schnorr(_) => None,
+            };
+
+            let pending_signers = utxo_args.pending_signers().len();
+            for (sighash, signature) in sighashes.by_ref().take(pending_signers) {
+                // Not every signer of a multi-signature UTXO has to sign it.
+                if multi_constructor.is_some() && signature.is_empty() {
+                    continue;
+                }
+                Self::verify_signature(sighash, utxo_args, signature)?;
+            }
+
+            if let Some(multi_constructor) = multi_constructor {
+                Self::verify_partial_signatures(
+                    unsigned_tx,
+                    input_index,
+                    multi_constructor.signers(),
+                )?;
+            }
+        }
 
-        for ((sighash, utxo_args), signature) in tx_preimage
-            .sighashes
-            .iter()
-            .zip(unsigned_tx.input_args())
-            .zip(signatures)
-        {
-            Self::verify_signature(sighash, utxo_args, signature)?;
+        Ok(())
+    }
+
+    /// Verifies signatures of a multi-signature UTXO that have been collected on the previous signing rounds.
+    pub fn verify_partial_signatures(
+        unsigned_tx: &UnsignedTransaction<Transaction>,
+        input_index: usize,
+        signers: &[ScriptSigner],
+    ) -> SigningResult<()> {
+        let (sighash, _) = SighashComputer::preimage_utxo(unsigned_tx, input_index)?;
+
+        for signer in signers {
+            let Some(ref partial_signature) = signer.partial_signature else {
+                continue;
+            };
+            // Multi-signature UTXOs are signed with ecdsa only.
+            let utxo_sighash = UtxoSighash {
+                signing_method: SigningMethod::Legacy,
+                sighash,
+                signer_pubkey: signer.public_key.into_vec(),
+                taproot_tweak: None,
+            };
+            Self::verify_ecdsa_signature(
+                &utxo_sighash,
+                &utxo_sighash.signer_pubkey,
+                partial_signature,
+            )?;
         }
 
         Ok(())
@@ -63,10 +110,10 @@ where
     ) -> SigningResult<()> {
         match utxo_args.signing_method {
             SigningMethod::Legacy | SigningMethod::Segwit => {
-                Self::verify_ecdsa_signature(sighash, &utxo_args.spender_public_key, signature)
+                Self::verify_ecdsa_signature(sighash, &sighash.signer_pubkey, signature)
             },
             SigningMethod::Taproot => {
-                Self::verify_schnorr_signature(sighash, &utxo_args.spender_public_key, signature)
+                Self::verify_schnorr_signature(sighash, &sighash.signer_pubkey, signature)
             },
         }
     }
@@ -6,8 +6,8 @@ use crate::modules::sighash_verifier::SighashVerifier;
 use crate::sighash::SighashType;
 use crate::signature::{BitcoinEcdsaSignature, BitcoinSchnorrSignature, FromRawOrDerBytes};
 use crate::spending_data::{
-    EcdsaSpendingDataConstructor, SchnorrSpendingDataConstructor, SpendingData,
-    SpendingDataConstructor,
+    EcdsaMultiSpendingDataConstructor, EcdsaSpendingDataConstructor,
+    SchnorrSpendingDataConstructor, SpendingData, SpendingDataConstructor,
 };
 use crate::transaction::transaction_interface::{TransactionInterface, TxInputInterface};
 use crate::transaction::unsigned_transaction::UnsignedTransaction;
@@ -40,28 +40,43 @@ where
         unsigned_tx: UnsignedTransaction<Transaction>,
         signatures: &[SignatureBytes],
     ) -> SigningResult<Transaction> {
+        let expected_signatures = unsigned_tx
+            .input_args()
+            .iter()
+            .map(|utxo| utxo.pending_signers().len())
+            .sum();
         SighashVerifier::<Transaction>::check_signatures_number(
-            unsigned_tx.inputs().len(),
+            expected_signatures,
             signatures.len(),
         )?;
 
-        let claims = unsigned_tx
-            .input_args()
-            .iter()
-            .zip(signatures)
-            .map(|(utxo, sign)| match utxo.spending_data_constructor {
+        let mut signatures = signatures.iter();
+        let mut claims = Vec::with_capacity(unsigned_tx.inputs().len());
+
+        for utxo in unsigned_tx.input_args() {
+            let claim = match utxo.spending_data_constructor {
                 SpendingDataConstructor::Ecdsa(ref ecdsa_constructor) => {
+                    let sign = Self::next_signature(&mut signatures)?;
                     Self::get_ecdsa_spending_data(ecdsa_constructor.as_ref(), sign, utxo.sighash_ty)
                 },
                 SpendingDataConstructor::Schnorr(ref schnorr_constructor) => {
+                    let sign = Self::next_signature(&mut signatures)?;
                     Self::get_schnorr_spending_data(
                         schnorr_constructor.as_ref(),
                         sign,
                         utxo.sighash_ty,
                     )
                 },
-            })
-            .collect::<SigningResult<_>>()?;
+                SpendingDataConstructor::EcdsaMulti(ref multi_constructor) => {
+                    Self::get_ecdsa_multi_spending_data(
+                        multi_constructor.as_ref(),
+                        &mut signatures,
+                        utxo.sighash_ty,
+                    )
+                },
+            }?;
+            claims.push(claim);
+        }
 
         Self::compile_spending_data(unsigned_tx, claims)
     }
@@ -93,22 +108,79 @@ where
         Ok(signed_tx)
     }
 
-    fn get_ecdsa_spending_data(
+    pub fn get_ecdsa_spending_data(
         ecdsa_constructor: &dyn EcdsaSpendingDataConstructor,
         sign: &[u8],
         sighash_ty: SighashType,
     ) -> SigningResult<SpendingData> {
+        let signature = Self::bitcoin_ecdsa_signature(sign, sighash_ty)?;
+        Ok(ecdsa_constructor.get_spending_data(&signature))
+    }
+
+    /// Collects signatures of the multi-signature UTXO signers.
+    /// Signatures of the signers that have signed on the previous signing rounds are taken from
+    /// [`crate::spending_data::ScriptSigner::partial_signature`],
+    /// other signatures are taken from the `signatures` iterator, where an empty signature means the signer hasn't signed.
+    pub fn get_ecdsa_multi_spending_data<'a, I>(
+        multi_constructor: &dyn EcdsaMultiSpendingDataConstructor,
+        signatures: &mut I,
+        sighash_ty: SighashType,
+    ) -> SigningResult<SpendingData>
+    where
+        I: Iterator<Item = &'a SignatureBytes>,
+    {
+        let sigs = multi_constructor
+            .signers()
+            .iter()
+            .map(|signer| {
+                let sign = match signer.partial_signature {
+                    Some(ref partial_signature) => partial_signature.as_slice(),
+                    None => Self::next_signature(signatures)?,
+                };
+
+                if sign.is_empty() {
+                    return Ok(None);
+                }
+                Self::bitcoin_ecdsa_signature(sign, sighash_ty).map(Some)
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let signed = sigs.iter().filter(|sig| sig.is_some()).count();
+        let threshold = multi_constructor.threshold();
+        if signed < threshold {
+            return SigningError::err(SigningErrorType::Error_signatures_count).context(format!(
+                "Expected at least '{threshold}' signatures to spend the UTXO, but given '{signed}'"
+            ));
+        }
+
+        Ok(multi_constructor.get_spending_data(&sigs))
+    }
+
+    fn bitcoin_ecdsa_signature(
+        sign: &[u8],
+        sighash_ty: SighashType,
+    ) -> SigningResult<BitcoinEcdsaSignature> {
         let ecdsa_sign = ecdsa::secp256k1::VerifySignature::from_raw_or_der_bytes(sign)?;
         let der_sign = ecdsa_sign
             .to_der()
             .into_tw()
             .context("Error getting ASN.1 DER-encoded signature")?;
 
-        let signature = BitcoinEcdsaSignature::new(der_sign, sighash_ty)?;
-        Ok(ecdsa_constructor.get_spending_data(&signature))
+        BitcoinEcdsaSignature::new(der_sign, sighash_ty)
+    }
+
+    fn next_signature<'a, I>(signatures: &mut I) -> SigningResult<&'a [u8]>
+    where
+        I: Iterator<Item = &'a SignatureBytes>,
+    {
+        signatures
+            .next()
+            .map(|sign| sign.as_slice())
+            .or_tw_err(SigningErrorType::Error_signatures_count)
+            .context("Not enough signatures provided")
     }
 
-    fn get_schnorr_spending_data(
+    pub fn get_schnorr_spending_data(
         schnorr_constructor: &dyn SchnorrSpendingDataConstructor,
         sign: &[u8],
         sighash_ty: SighashType,
@@ -6,9 +6,10 @@ use crate::modules::keys_manager::KeysManager;
 use crate::modules::sighash_computer::{SighashComputer, TxPreimage, UtxoSighash};
 use crate::modules::tx_compiler::TxCompiler;
 use crate::signing_mode::SigningMethod;
+use crate::spending_data::SpendingDataConstructor;
 use crate::transaction::transaction_interface::TransactionInterface;
 use crate::transaction::unsigned_transaction::UnsignedTransaction;
-use crate::transaction::TransactionPreimage;
+use crate::transaction::{TransactionPreimage, UtxoToSign};
 use std::marker::PhantomData;
 use tw_coin_entry::coin_entry::SignatureBytes;
 use tw_coin_entry::error::prelude::*;
@@ -33,20 +34,66 @@ where
         unsigned_tx: UnsignedTransaction<Transaction>,
         keys_manager: &KeysManager,
     ) -> SigningResult<Transaction> {
+        // Other signers of a multi-signature UTXO can sign it on the next signing rounds.
+        let signatures = Self::sign_sighashes(&unsigned_tx, keys_manager, |utxo| {
+            matches!(
+                utxo.spending_data_constructor,
+                SpendingDataConstructor::EcdsaMulti(_)
+            )
+        })?;
+        TxCompiler::compile(unsigned_tx, &signatures)
+    }
+
+    /// Signs the sighashes that correspond to the private keys of the given `keys_manager`.
+    /// Returns signatures in the same order as the sighashes, where an empty signature means that
+    /// the sighash is expected to be signed by another signer.
+    pub fn sign_tx_partially(
+        unsigned_tx: &UnsignedTransaction<Transaction>,
+        keys_manager: &KeysManager,
+    ) -> SigningResult<Vec<SignatureBytes>> {
+        Self::sign_sighashes(unsigned_tx, keys_manager, |_| true)
+    }
+
+    /// Signs every sighash of the transaction.
+    /// A missing private key is tolerated for the UTXOs accepted by `can_skip` only,
+    /// in which case an empty signature is returned.
+    fn sign_sighashes<F>(
+        unsigned_tx: &UnsignedTransaction<Transaction>,
+        keys_manager: &KeysManager,
+        can_skip: F,
+    ) -> SigningResult<Vec<SignatureBytes>>
+    where
+        F: Fn(&UtxoToSign) -> bool,
+    {
         let TxPreimage { sighashes } =
-            SighashComputer::preimage_tx(&unsigned_tx).context("Error sighash pre-imaging")?;
+            SighashComputer::preimage_tx(unsigned_tx).context("Error sighash pre-imaging")?;
 
-        let signatures: Vec<_> = sighashes
-            .iter()
-            .map(|sighash| match sighash.signing_method {
-                SigningMethod::Legacy | SigningMethod::Segwit => {
-                    Self::sign_legacy_sighash(keys_manager, sighash)
-                },
-                SigningMethod::Taproot => Self::sign_taproot_sighash(keys_manager, sighash),
-            })
-            .collect::<SigningResult<_>>()?;
+        let mut sighashes = sighashes.iter();
+        let mut signatures = Vec::with_capacity(sighashes.len());
 
-        TxCompiler::compile(unsigned_tx, &signatures)
+        for utxo in unsigned_tx.input_args() {
+            for sighash in sighashes.by_ref().take(utxo.pending_signers().len()) {
+                let signature = match sighash.signing_method {
+                    SigningMethod::Legacy | SigningMethod::Segwit => {
+                        Self::sign_legacy_sighash(keys_manager, sighash)
+                    },
+                    SigningMethod::Taproot => Self::sign_taproot_sighash(keys_manager, sighash),
+                };
+
+                match signature {
+                    Ok(signature) => signatures.push(signature),
+                    Err(e)
+                        if *e.error_type() == SigningErrorType::Error_missing_private_key
+                            && can_skip(utxo) =>
+                    {
+                        signatures.push(SignatureBytes::default())
+                    },
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        Ok(signatures)
     }
 
     pub fn sign_legacy_sighash(
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Waste-minimizing coin selection algorithms similar to Bitcoin Core's:
+//! https://github.com/bitcoin/bitcoin/blob/master/src/wallet/coinselection.cpp
+
+use crate::transaction::transaction_parts::Amount;
+use std::cmp::Reverse;
+
+/// The maximum number of the Branch-and-Bound search tree nodes to visit.
+pub const BNB_TOTAL_TRIES: usize = 100_000;
+
+/// A UTXO to be selected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoinCandidate {
+    /// UTXO amount minus the fee required to spend it at the current fee rate.
+    pub effective_value: Amount,
+    /// The fee required to spend the UTXO at the current fee rate.
+    pub fee: Amount,
+    /// The fee required to spend the UTXO at the long-term fee rate.
+    pub long_term_fee: Amount,
+}
+
+impl CoinCandidate {
+    /// Waste of spending the UTXO now rather than later at the long-term fee rate.
+    /// It is negative if the current fee rate is lower than the long-term fee rate.
+    fn waste(&self) -> Amount {
+        self.fee - self.long_term_fee
+    }
+}
+
+/// Searches for a changeless selection of `candidates` whose total effective value is in
+/// `[target, target + cost_of_change]`, minimizing the waste metric.
+///
+/// Returns sorted indexes of the selected candidates, or `None` if there is no such selection
+/// or it cannot be found within [`BNB_TOTAL_TRIES`].
+pub fn select_coins_bnb(
+    candidates: &[CoinCandidate],
+    target: Amount,
+    cost_of_change: Amount,
+) -> Option<Vec<usize>> {
+    // Only the UTXOs with a positive effective value can contribute to the target.
+    let mut pool: Vec<(usize, CoinCandidate)> = candidates
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, candidate)| candidate.effective_value > 0)
+        .collect();
+    // Explore the largest UTXOs first.
+    pool.sort_by_key(|(_, c)| Reverse(c.effective_value));
+
+    let mut curr_value: Amount = 0;
+    let mut curr_available_value: Amount = pool.iter().map(|(_, c)| c.effective_value).sum();
+    let mut curr_waste: Amount = 0;
+    // Indexes of the `pool` items included in the current selection.
+    let mut curr_selection: Vec<usize> = Vec::with_capacity(pool.len());
+
+    let mut best_selection: Option<Vec<usize>> = None;
+    let mut best_waste = Amount::MAX;
+
+    if curr_available_value < target {
+        return None;
+    }
+
+    // If the fee rate is higher than the long-term one, adding more inputs only increases the waste.
+    let is_fee_rate_high = pool.first().is_some_and(|(_, c)| c.waste() > 0);
+
+    let mut pool_index = 0;
+    for _ in 0..BNB_TOTAL_TRIES {
+        let mut backtrack = false;
+
+        if curr_value + curr_available_value < target
+            || curr_value > target + cost_of_change
+            || (curr_waste > best_waste && is_fee_rate_high)
+        {
+            // Cannot reach the target, exceeded the upper bound, or the selection is already worse than the best one.
+            backtrack = true;
+        } else if curr_value >= target {
+            // The selection is within the bounds. The excess is counted as a waste as it goes to the miners.
+            let waste = curr_waste + (curr_value - target);
+            if waste <= best_waste {
+                best_selection = Some(curr_selection.clone());
+                best_waste = waste;
+            }
+            backtrack = true;
+        }
+
+        if backtrack {
+            let Some(&last_selected) = curr_selection.last() else {
+                // The whole tree has been explored.
+                break;
+            };
+
+            // Restore the available value of the omitted UTXOs after the last selected one.
+            pool_index -= 1;
+            while pool_index > last_selected {
+                curr_available_value += pool[pool_index].1.effective_value;
+                pool_index -= 1;
+            }
+
+            // Deselect the last selected UTXO and explore the omission branch.
+            let (_, candidate) = pool[pool_index];
+            curr_value -= candidate.effective_value;
+            curr_waste -= candidate.waste();
+            curr_selection.pop();
+        } else {
+            let (_, candidate) = pool[pool_index];
+            curr_available_value -= candidate.effective_value;
+
+            // Skip the UTXO if the previous one is equivalent and was omitted,
+            // as this branch has been explored already.
+            let is_duplicate = pool_index > 0
+                && !curr_selection.is_empty()
+                && curr_selection.last() != Some(&(pool_index - 1))
+                && pool[pool_index - 1].1 == candidate;
+            if !is_duplicate {
+                curr_selection.push(pool_index);
+                curr_value += candidate.effective_value;
+                curr_waste += candidate.waste();
+            }
+        }
+
+        pool_index += 1;
+    }
+
+    best_selection.map(|selection| {
+        let mut selected: Vec<usize> = selection.into_iter().map(|i| pool[i].0).collect();
+        selected.sort_unstable();
+        selected
+    })
+}
+
+/// Selects `candidates` whose total effective value is at least `target`, trying to minimize the excess.
+/// The deterministic version of the Bitcoin Core knapsack solver.
+///
+/// Returns sorted indexes of the selected candidates, or `None` if the funds are insufficient.
+pub fn select_coins_knapsack(candidates: &[CoinCandidate], target: Amount) -> Option<Vec<usize>> {
+    let pool = candidates
+        .iter()
+        .enumerate()
+        .filter(|(_, candidate)| candidate.effective_value > 0);
+
+    let mut lower: Vec<(usize, Amount)> = Vec::new();
+    let mut lowest_larger: Option<(usize, Amount)> = None;
+    for (i, candidate) in pool {
+        let value = candidate.effective_value;
+        if value == target {
+            // The exact match.
+            return Some(vec![i]);
+        }
+
+        if value < target {
+            lower.push((i, value));
+        } else if lowest_larger.filter(|(_, larger)| *larger <= value).is_none() {
+            lowest_larger = Some((i, value));
+        }
+    }
+
+    let total_lower: Amount = lower.iter().map(|(_, value)| value).sum();
+    if total_lower == target {
+        return Some(sorted_indexes(&lower));
+    }
+    if total_lower < target {
+        return lowest_larger.map(|(i, _)| vec![i]);
+    }
+
+    // Approximate the best subset of the lower UTXOs: accumulate the largest UTXOs first,
+    // and every time the target is reached, try to replace the last added UTXO with smaller ones.
+    lower.sort_by_key(|(_, value)| Reverse(*value));
+
+    let mut best: Vec<(usize, Amount)> = lower.clone();
+    let mut best_total = total_lower;
+
+    let mut included: Vec<(usize, Amount)> = Vec::with_capacity(lower.len());
+    let mut total: Amount = 0;
+    for &(i, value) in lower.iter() {
+        total += value;
+        included.push((i, value));
+
+        if total >= target {
+            if total < best_total {
+                best = included.clone();
+                best_total = total;
+            }
+            total -= value;
+            included.pop();
+        }
+    }
+
+    // Prefer a single larger UTXO if it is closer to the target than the best subset.
+    match lowest_larger {
+        Some((i, larger)) if best_total != target && larger <= best_total => Some(vec![i]),
+        _ => Some(sorted_indexes(&best)),
+    }
+}
+
+fn sorted_indexes(selection: &[(usize, Amount)]) -> Vec<usize> {
+    let mut indexes: Vec<usize> = selection.iter().map(|(i, _)| *i).collect();
+    indexes.sort_unstable();
+    indexes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(effective_values: &[Amount]) -> Vec<CoinCandidate> {
+        effective_values
+            .iter()
+            .map(|effective_value| CoinCandidate {
+                effective_value: *effective_value,
+                fee: 100,
+                long_term_fee: 50,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_select_coins_bnb_exact_match() {
+        let pool = candidates(&[1_000, 2_000, 3_000, 4_000]);
+        // Both `[0, 3]` and `[1, 2]` match the target with the same waste.
+        assert_eq!(select_coins_bnb(&pool, 5_000, 0), Some(vec![1, 2]));
+        assert_eq!(select_coins_bnb(&pool, 10_000, 0), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_select_coins_bnb_prefers_less_inputs() {
+        // Both `[0, 1]` and `[2]` match the target, but the latter wastes less on input fees.
+        let pool = candidates(&[1_000, 2_000, 3_000]);
+        assert_eq!(select_coins_bnb(&pool, 3_000, 0), Some(vec![2]));
+    }
+
+    #[test]
+    fn test_select_coins_bnb_within_cost_of_change() {
+        let pool = candidates(&[1_500, 2_700, 6_000]);
+        assert_eq!(select_coins_bnb(&pool, 4_000, 300), Some(vec![0, 1]));
+        assert_eq!(select_coins_bnb(&pool, 4_000, 100), None);
+    }
+
+    #[test]
+    fn test_select_coins_bnb_insufficient() {
+        let pool = candidates(&[1_000, 2_000, -100]);
+        assert_eq!(select_coins_bnb(&pool, 3_001, 0), None);
+    }
+
+    #[test]
+    fn test_select_coins_knapsack() {
+        let pool = candidates(&[1_000, 2_000, 5_000, 7_000]);
+        // The exact match.
+        assert_eq!(select_coins_knapsack(&pool, 5_000), Some(vec![2]));
+        // The lowest larger UTXO is closer to the target than a subset of lower UTXOs.
+        assert_eq!(select_coins_knapsack(&pool, 6_500), Some(vec![3]));
+        // A subset of lower UTXOs.
+        assert_eq!(select_coins_knapsack(&pool, 5_500), Some(vec![0, 2]));
+        // All the UTXOs are required.
+        assert_eq!(select_coins_knapsack(&pool, 15_000), Some(vec![0, 1, 2, 3]));
+        assert_eq!(select_coins_knapsack(&pool, 15_001), None);
+    }
+}
@@ -5,6 +5,9 @@
 use crate::constants::MAX_TRANSACTION_WEIGHT;
 use crate::dust::DustPolicy;
 use crate::modules::fee_estimator::FeeEstimator;
+use crate::modules::utxo_selector::bnb_selector::{
+    select_coins_bnb, select_coins_knapsack, CoinCandidate,
+};
 use crate::modules::utxo_selector::{InputSelector, SelectPlan, SelectResult};
 use crate::script::{Script, Witness};
 use crate::transaction::transaction_interface::{
@@ -48,13 +51,6 @@ where
         // Calculate the total output amount.
         let mut total_out = self.unsigned_tx.total_output()?;
 
-        if let Some(ref change_output) = self.change_output {
-            // Push the change output to the temporary "estimated" transaction only to get the most max possible fee.
-            // Later, if the result change amount is not dust (> dustThreshold),
-            // the change output will be pushed to the result unsigned transaction.
-            estimated_tx.push_output(change_output.clone());
-        }
-
         // Prepare the available UTXOs.
         // Please note all the Transaction inputs contain estimated script sigs.
         let mut utxos: Vec<(Transaction::Input, &UtxoToSign)> = estimated_tx
@@ -64,6 +60,9 @@ where
             .zip(self.unsigned_tx.input_args())
             .collect();
 
+        // Whether all the (pre-selected) UTXOs should be used.
+        let mut use_all = selector == InputSelector::UseAll;
+
         // Sort the UTXOs.
         match selector {
             // Nothing to do.
@@ -74,6 +73,31 @@ where
             InputSelector::Descending => {
                 utxos.sort_by(|(_, a), (_, b)| b.amount.cmp(&a.amount));
             },
+            InputSelector::BranchAndBound { long_term_fee_rate } => {
+                let (selected, changeless) = Self::select_waste_optimal(
+                    &estimated_tx,
+                    &utxos,
+                    self.change_output.as_ref(),
+                    total_out,
+                    dust_policy,
+                    fee_rate,
+                    long_term_fee_rate,
+                )?;
+                if changeless {
+                    // The excess is less than the cost of creating and spending a change output.
+                    self.change_output = None;
+                }
+
+                utxos = selected.into_iter().map(|i| utxos[i].clone()).collect();
+                use_all = true;
+            },
+        }
+
+        if let Some(ref change_output) = self.change_output {
+            // Push the change output to the temporary "estimated" transaction only to get the most max possible fee.
+            // Later, if the result change amount is not dust (> dustThreshold),
+            // the change output will be pushed to the result unsigned transaction.
+            estimated_tx.push_output(change_output.clone());
         }
 
         // Select the UTXOs to cover all the outputs and the fee.
@@ -112,7 +136,7 @@ where
                 total_covered = true;
 
                 // Unless we're told to use all inputs, we can stop here.
-                if InputSelector::UseAll != selector {
+                if !use_all {
                     break;
                 }
             }
@@ -131,9 +155,10 @@ where
         );
         let mut change = total_in - total_out - tx_fee;
 
-        let dust_threshold = dust_policy.dust_threshold();
         match self.change_output {
-            Some(mut change_output) if change >= dust_threshold => {
+            Some(mut change_output)
+                if change >= dust_policy.dust_threshold(change_output.script_pubkey()) =>
+            {
                 change_output.set_value(change);
                 self.unsigned_tx.push_output(change_output);
                 total_out += change;
@@ -168,4 +193,105 @@ where
             plan,
         })
     }
+
+    /// Selects UTXOs minimizing the waste.
+    /// Returns indexes of the selected `utxos`, and whether the selection is changeless.
+    ///
+    /// If there are not enough funds, returns all the UTXOs to let the caller report the error.
+    fn select_waste_optimal(
+        estimated_tx: &Transaction,
+        utxos: &[(Transaction::Input, &UtxoToSign)],
+        change_output: Option<&Transaction::Output>,
+        total_out: Amount,
+        dust_policy: DustPolicy,
+        fee_rate: Amount,
+        long_term_fee_rate: Amount,
+    ) -> SigningResult<(Vec<usize>, bool)> {
+        // A transaction without inputs is used to estimate the fee of each input separately.
+        let mut base_tx = estimated_tx.clone();
+        base_tx.replace_inputs(Vec::default());
+        let base_weight = base_tx.weight();
+
+        let fee_of = |vsize: usize, rate: Amount| -> SigningResult<Amount> {
+            Amount::try_from(vsize)
+                .ok()
+                .and_then(|vsize| vsize.checked_mul(rate))
+                .or_tw_err(SigningErrorType::Error_wrong_fee)
+                .with_context(|| format!("Fee rate is too large: '{vsize} * {rate}' overflow"))
+        };
+        // Round the weight up to vbytes, so the sum of the estimated fees is never less than the actual transaction fee.
+        let vsize_of = |weight: usize| weight.div_ceil(4);
+
+        let mut input_vsizes = Vec::with_capacity(utxos.len());
+        let mut candidates = Vec::with_capacity(utxos.len());
+        for (input, arg) in utxos {
+            let mut single_input_tx = base_tx.clone();
+            single_input_tx.replace_inputs(vec![input.clone()]);
+            let input_vsize = vsize_of(single_input_tx.weight() - base_weight);
+
+            let fee = fee_of(input_vsize, fee_rate)?;
+            input_vsizes.push(input_vsize);
+            candidates.push(CoinCandidate {
+                effective_value: arg.amount - fee,
+                fee,
+                long_term_fee: fee_of(input_vsize, long_term_fee_rate)?,
+            });
+        }
+
+        // The amount to be covered by the effective values of the selected UTXOs.
+        let target = total_out + FeeEstimator::estimate_fee(&base_tx, fee_rate)?;
+
+        // The fee of the change output itself, the cost of creating and spending it later,
+        // and the minimum change amount.
+        let (change_output_fee, cost_of_change, min_change) = match change_output {
+            Some(change_output) => {
+                let mut tx_with_change = base_tx.clone();
+                tx_with_change.push_output(change_output.clone());
+                let change_output_vsize = vsize_of(tx_with_change.weight() - base_weight);
+
+                // Estimate the size of an input spending the change output.
+                // Prefer the UTXOs locked by the same script as the change output.
+                let same_script_vsize = utxos
+                    .iter()
+                    .zip(input_vsizes.iter())
+                    .find(|((_, arg), _)| {
+                        &arg.prevout_script_pubkey == change_output.script_pubkey()
+                    })
+                    .map(|(_, vsize)| *vsize);
+                let change_spend_vsize = same_script_vsize.unwrap_or_else(|| {
+                    input_vsizes.iter().sum::<usize>() / input_vsizes.len().max(1)
+                });
+
+                let change_output_fee = fee_of(change_output_vsize, fee_rate)?;
+                let change_spend_fee = fee_of(change_spend_vsize, long_term_fee_rate)?;
+                let min_change = dust_policy.dust_threshold(change_output.script_pubkey());
+                (
+                    change_output_fee,
+                    change_output_fee + change_spend_fee,
+                    min_change,
+                )
+            },
+            // There is no change output, so the excess up to the dust threshold would be lost anyway.
+            None => {
+                let max_dust_threshold = base_tx
+                    .outputs()
+                    .iter()
+                    .map(|output| dust_policy.dust_threshold(output.script_pubkey()))
+                    .max()
+                    .unwrap_or_default();
+                (0, max_dust_threshold, 0)
+            },
+        };
+
+        if let Some(selected) = select_coins_bnb(&candidates, target, cost_of_change) {
+            return Ok((selected, true));
+        }
+
+        // Fallback to the knapsack solver. Leave enough amount for a non-dust change output if it's requested.
+        let knapsack_target = target + change_output_fee + min_change;
+        let selected = select_coins_knapsack(&candidates, knapsack_target)
+            .or_else(|| select_coins_knapsack(&candidates, target))
+            .unwrap_or_else(|| (0..utxos.len()).collect());
+        Ok((selected, false))
+    }
 }
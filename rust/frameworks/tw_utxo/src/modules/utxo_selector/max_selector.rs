@@ -89,7 +89,14 @@ where
             .set_inputs(selected_utxos, selected_utxo_args)?;
 
         let tx_fee = FeeEstimator::estimate_fee(&estimated_tx, fee_rate)?;
-        let dust_threshold = dust_policy.dust_threshold();
+        let dust_threshold = dust_policy.dust_threshold(
+            self.unsigned_tx
+                .transaction()
+                .outputs()
+                .first()
+                .expect("Transaction output length must be checked already")
+                .script_pubkey(),
+        );
 
         // Check if the total input amount covers the fee, and the remaining amount is not dust.
         if total_in < tx_fee + dust_threshold {
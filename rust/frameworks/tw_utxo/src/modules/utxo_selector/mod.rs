@@ -5,6 +5,7 @@
 use crate::transaction::transaction_parts::Amount;
 use crate::transaction::unsigned_transaction::UnsignedTransaction;
 
+pub mod bnb_selector;
 pub mod exact_selector;
 pub mod max_selector;
 
@@ -22,6 +23,13 @@ pub enum InputSelector {
     // Automatically select enough inputs in the given order to cover the
     // outputs of the transaction.
     InOrder,
+    // Automatically select inputs minimizing the waste (see Bitcoin Core's waste metric).
+    // Tries to find a changeless selection with the Branch-and-Bound algorithm first,
+    // and falls back to the knapsack solver creating a change output.
+    BranchAndBound {
+        // The fee rate at which the UTXOs are expected to be spent in the future, used to estimate the waste.
+        long_term_fee_rate: Amount,
+    },
 }
 
 pub struct SelectPlan {
@@ -9,6 +9,7 @@ use standard_script::opcodes::*;
 use tw_memory::Data;
 
 pub mod standard_script;
+pub mod taproot;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Script {
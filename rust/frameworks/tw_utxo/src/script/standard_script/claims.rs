@@ -16,21 +16,20 @@ pub fn new_p2pk(sig: &BitcoinEcdsaSignature) -> Script {
     s
 }
 
-// TODO next iteration.
-// /// Creates script items to claim a P2SH spending condition.
-// /// (_witness_).
-// ///
-// /// ```txt
-// /// <push><item_1><push><item_2><push><redeem_script>
-// /// ```
-// pub fn new_p2sh(items: Vec<Script>, redeem_script: Script) -> Script {
-//     let mut s = Script::new();
-//     for item in items {
-//         s.push_slice(item.as_slice());
-//     }
-//     s.push_slice(redeem_script.as_slice());
-//     s
-// }
+/// Creates a script to claim a P2SH spending condition (_scriptSig_).
+///
+/// ```txt
+/// <push><item_1><push><item_2><push><redeem_script>
+/// ```
+pub fn new_p2sh(items: Vec<Script>, redeem_script: &Script) -> Script {
+    let mut s = Script::new();
+    for item in items {
+        // Please note an empty item is pushed as `OP_0`.
+        s.push_slice(item.as_slice());
+    }
+    s.push_slice(redeem_script.as_slice());
+    s
+}
 
 /// Creates a script to claim a P2PKH spending condition (_scriptSig_).
 /// See [`tw_keypair::ecdsa::der::Signature::der_bytes`].
@@ -45,24 +44,23 @@ pub fn new_p2pkh(sig: &BitcoinEcdsaSignature, pubkey: &H264) -> Script {
     s
 }
 
-// TODO next iteration.
-// /// Creates witness script items to claim a P2WSH spending condition
-// /// (_witness_).
-// ///
-// /// ```txt
-// /// <witness_item_1>
-// /// <witness_item_2>
-// /// ...
-// /// <redeem_script>
-// /// ```
-// pub fn new_p2wsh(items: Vec<Script>, redeem_script: Script) -> Witness {
-//     let mut w = Witness::new();
-//     for item in items {
-//         w.push_item(item.clone());
-//     }
-//     w.push_item(redeem_script);
-//     w
-// }
+/// Creates witness script items to claim a P2WSH spending condition
+/// (_witness_).
+///
+/// ```txt
+/// <witness_item_1>
+/// <witness_item_2>
+/// ...
+/// <witness_script>
+/// ```
+pub fn new_p2wsh(items: Vec<Script>, witness_script: Script) -> Witness {
+    let mut w = Witness::new();
+    for item in items {
+        w.push_item(item);
+    }
+    w.push_item(witness_script);
+    w
+}
 
 /// Creates witness script items to claim a P2WPKH spending condition
 /// (_witness_).
@@ -11,6 +11,9 @@ use super::opcodes::*;
 use super::Script;
 use super::SEGWIT_VERSION;
 
+/// The max number of public keys in a bare multisig script that can be represented with a small integer opcode.
+pub const MAX_MULTISIG_PUBKEYS: usize = 16;
+
 /// Creates a P2SH spending condition (_scriptPubkey_).
 ///
 /// ```txt
@@ -109,6 +112,30 @@ pub fn new_p2tr_script_path(pubkey: &H264, merkle_root: &H256) -> Script {
     Script::from(script.to_vec())
 }
 
+/// Creates a bare `m-of-n` multisig spending condition.
+/// Usually used as a P2SH redeem script or P2WSH witness script.
+///
+/// ```txt
+/// OP_<m> <push><pubkey_1> ... <push><pubkey_n> OP_<n> OP_CHECKMULTISIG
+/// ```
+///
+/// Returns `None` if the `threshold` or the number of public keys are out of `1..=16` range,
+/// or `threshold` is greater than the number of public keys.
+pub fn new_multisig(threshold: usize, pubkeys: &[H264]) -> Option<Script> {
+    if threshold == 0 || threshold > pubkeys.len() || pubkeys.len() > MAX_MULTISIG_PUBKEYS {
+        return None;
+    }
+
+    let mut s = Script::with_capacity(3 + pubkeys.len() * (H264::LEN + 1));
+    s.push(small_int_opcode(threshold));
+    for pubkey in pubkeys {
+        s.push_slice(pubkey.as_slice());
+    }
+    s.push(small_int_opcode(pubkeys.len()));
+    s.push(OP_CHECKMULTISIG);
+    Some(s)
+}
+
 pub fn new_op_return(data: &[u8]) -> Script {
     let mut s = Script::with_capacity(83);
     s.push(OP_RETURN);
@@ -147,6 +174,15 @@ pub fn is_p2tr(s: &Script) -> bool {
     b.len() == 34 && b[0] == taproot_version_op_code && b[1] == OP_PUSHBYTES_32
 }
 
+/// Checks if the script is a witness program of any version, e.g. P2WPKH, P2WSH or P2TR.
+pub fn is_witness_program(s: &Script) -> bool {
+    let b = s.as_slice();
+    // A version opcode followed by a direct push of 2 to 40 bytes.
+    (4..=42).contains(&b.len())
+        && (b[0] == SEGWIT_VERSION || (OP_PUSHNUM_1..=OP_PUSHNUM_16).contains(&b[0]))
+        && b[1] as usize + 2 == b.len()
+}
+
 pub fn is_op_return(s: &Script) -> bool {
     let b = s.as_slice();
     !b.is_empty() && b[0] == OP_RETURN
@@ -207,6 +243,36 @@ pub fn match_p2tr(s: &Script) -> Option<H256> {
     }
 }
 
+/// Returns a threshold and compressed public keys if the script is a bare `m-of-n` multisig.
+pub fn match_multisig(s: &Script) -> Option<(usize, Vec<H264>)> {
+    let b = s.as_slice();
+    // OP_<m> <push><pubkey> OP_<n> OP_CHECKMULTISIG
+    if b.len() < 3 + H264::LEN + 1 || b[b.len() - 1] != OP_CHECKMULTISIG {
+        return None;
+    }
+
+    let threshold = small_int_from_opcode(b[0])?;
+    let pubkeys_num = small_int_from_opcode(b[b.len() - 2])?;
+
+    let pubkeys_data = &b[1..b.len() - 2];
+    if pubkeys_data.len() != pubkeys_num * (H264::LEN + 1) {
+        return None;
+    }
+
+    let pubkeys = pubkeys_data
+        .chunks(H264::LEN + 1)
+        .map(|chunk| match chunk.split_first() {
+            Some((&OP_PUSHBYTES_33, pubkey)) => H264::try_from(pubkey).ok(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    if threshold == 0 || threshold > pubkeys.len() {
+        return None;
+    }
+    Some((threshold, pubkeys))
+}
+
 /// Returns an OP_RETURN payload.
 pub fn match_op_return(s: &Script) -> Option<Data> {
     if is_op_return(s) {
@@ -215,3 +281,16 @@ pub fn match_op_return(s: &Script) -> Option<Data> {
         None
     }
 }
+
+/// Converts a small integer in `1..=16` range to the corresponding `OP_PUSHNUM_<n>` opcode.
+fn small_int_opcode(n: usize) -> u8 {
+    debug_assert!((1..=16).contains(&n));
+    OP_PUSHNUM_1 + (n as u8 - 1)
+}
+
+/// Converts an `OP_PUSHNUM_<n>` opcode to the corresponding small integer.
+fn small_int_from_opcode(op: u8) -> Option<usize> {
+    (OP_PUSHNUM_1..=OP_PUSHNUM_16)
+        .contains(&op)
+        .then(|| (op - OP_PUSHNUM_1) as usize + 1)
+}
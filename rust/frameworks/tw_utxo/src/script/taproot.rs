@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::script::standard_script::conditions;
+use crate::script::Script;
+use bitcoin::hashes::Hash;
+use bitcoin::key::TapTweak;
+use bitcoin::taproot::{ControlBlock, LeafVersion, TapLeafHash, TapNodeHash, TaprootBuilder};
+use secp256k1::XOnlyPublicKey;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::{H256, H264};
+use tw_keypair::schnorr;
+use tw_memory::Data;
+
+/// The even-Y prefix used to lift an x-only internal key to a compressed public key.
+pub const EVEN_Y_PREFIX: u8 = 0x02;
+
+/// A leaf of a Taproot script tree.
+#[derive(Clone, Debug)]
+pub struct TapLeaf {
+    /// Depth of the leaf in the tree, where `0` is the root.
+    pub depth: u8,
+    pub script: Script,
+}
+
+/// Everything required to spend a P2TR output through one of its script leaves:
+/// the leaf script being revealed and the control block that proves its inclusion in the tree.
+#[derive(Clone, Debug)]
+pub struct TapLeafSpendInfo {
+    leaf_script: Script,
+    control_block: ControlBlock,
+}
+
+impl TapLeafSpendInfo {
+    /// Creates the spend info from an already built (serialized) control block.
+    pub fn with_control_block(leaf_script: Script, control_block: &[u8]) -> SigningResult<Self> {
+        let control_block = ControlBlock::decode(control_block)
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Invalid Taproot control block")?;
+
+        if control_block.leaf_version != LeafVersion::TapScript {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Only TapScript leaf version is supported");
+        }
+
+        let spend_info = TapLeafSpendInfo {
+            leaf_script,
+            control_block,
+        };
+
+        // Make sure the output key parity is consistent with the internal key and the merkle root.
+        let (_, output_key_parity) = spend_info.internal_key_inner().tap_tweak(
+            &secp256k1::Secp256k1::new(),
+            Some(spend_info.merkle_root_inner()),
+        );
+        if output_key_parity != spend_info.control_block.output_key_parity {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Taproot control block has an invalid output key parity");
+        }
+
+        Ok(spend_info)
+    }
+
+    /// Creates the spend info from the internal key and the merkle branch (hashes of the sibling nodes)
+    /// of the leaf, ordered from the leaf level up to the root.
+    pub fn with_merkle_branch(
+        leaf_script: Script,
+        internal_key: &schnorr::XOnlyPublicKey,
+        merkle_branch: &[H256],
+    ) -> SigningResult<Self> {
+        let internal_key = XOnlyPublicKey::from_slice(internal_key.bytes().as_slice())
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Invalid Taproot internal key")?;
+
+        let merkle_root =
+            merkle_branch
+                .iter()
+                .fold(Self::leaf_node(&leaf_script), |node, sibling| {
+                    TapNodeHash::from_node_hashes(node, TapNodeHash::from_byte_array(**sibling))
+                });
+        let (_, output_key_parity) =
+            internal_key.tap_tweak(&secp256k1::Secp256k1::new(), Some(merkle_root));
+
+        // Serialize the control block as `leaf_version | parity || internal_key || merkle_branch`.
+        let mut control_block =
+            vec![LeafVersion::TapScript.to_consensus() | output_key_parity.to_u8()];
+        control_block.extend_from_slice(&internal_key.serialize());
+        for sibling in merkle_branch {
+            control_block.extend_from_slice(sibling.as_slice());
+        }
+
+        Self::with_control_block(leaf_script, &control_block)
+    }
+
+    /// Creates the spend info from the internal key and the whole script tree.
+    /// The `leaf_script` must be one of the `leaves`.
+    pub fn with_tap_tree(
+        leaf_script: Script,
+        internal_key: &schnorr::XOnlyPublicKey,
+        leaves: &[TapLeaf],
+    ) -> SigningResult<Self> {
+        let internal_key = XOnlyPublicKey::from_slice(internal_key.bytes().as_slice())
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Invalid Taproot internal key")?;
+
+        let mut builder = TaprootBuilder::new();
+        for leaf in leaves {
+            builder = builder
+                .add_leaf(
+                    leaf.depth,
+                    bitcoin::ScriptBuf::from_bytes(leaf.script.to_vec()),
+                )
+                .tw_err(|_| SigningErrorType::Error_invalid_params)
+                .context("Invalid Taproot script tree")?;
+        }
+
+        let spend_info = builder
+            .finalize(&secp256k1::Secp256k1::new(), internal_key)
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Incomplete Taproot script tree")?;
+
+        let control_block = spend_info
+            .control_block(&(
+                bitcoin::ScriptBuf::from_bytes(leaf_script.to_vec()),
+                LeafVersion::TapScript,
+            ))
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("The leaf script is not a part of the Taproot script tree")?;
+
+        Ok(TapLeafSpendInfo {
+            leaf_script,
+            control_block,
+        })
+    }
+
+    /// The leaf script being spent.
+    pub fn leaf_script(&self) -> &Script {
+        &self.leaf_script
+    }
+
+    /// The leaf hash committed to by the Taproot script-path sighash.
+    pub fn leaf_hash(&self) -> H256 {
+        H256::from(Self::leaf_node(&self.leaf_script).to_byte_array())
+    }
+
+    /// The merkle root of the script tree computed from the leaf and its merkle branch.
+    pub fn merkle_root(&self) -> H256 {
+        H256::from(self.merkle_root_inner().to_byte_array())
+    }
+
+    /// The x-only internal key.
+    pub fn internal_key(&self) -> H256 {
+        H256::from(self.internal_key_inner().serialize())
+    }
+
+    /// The internal key lifted to a compressed public key with an even Y coordinate.
+    pub fn internal_key_compressed(&self) -> H264 {
+        let mut compressed = H264::default();
+        compressed[0] = EVEN_Y_PREFIX;
+        compressed[1..].copy_from_slice(self.internal_key().as_slice());
+        compressed
+    }
+
+    /// The serialized control block to be put in the witness stack.
+    pub fn control_block(&self) -> Data {
+        self.control_block.serialize()
+    }
+
+    /// The scriptPubkey of the P2TR output being spent.
+    pub fn prevout_script_pubkey(&self) -> Script {
+        conditions::new_p2tr_script_path(&self.internal_key_compressed(), &self.merkle_root())
+    }
+
+    fn internal_key_inner(&self) -> XOnlyPublicKey {
+        self.control_block.internal_key
+    }
+
+    fn merkle_root_inner(&self) -> TapNodeHash {
+        self.control_block
+            .merkle_branch
+            .as_inner()
+            .iter()
+            .fold(Self::leaf_node(&self.leaf_script), |node, sibling| {
+                TapNodeHash::from_node_hashes(node, *sibling)
+            })
+    }
+
+    fn leaf_node(leaf_script: &Script) -> TapNodeHash {
+        let leaf_hash = TapLeafHash::from_script(
+            &bitcoin::ScriptBuf::from_bytes(leaf_script.to_vec()),
+            LeafVersion::TapScript,
+        );
+        TapNodeHash::from_byte_array(leaf_hash.to_byte_array())
+    }
+}
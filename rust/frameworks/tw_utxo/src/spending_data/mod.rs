@@ -6,6 +6,8 @@ use crate::script::{Script, Witness};
 use crate::signature::{BitcoinEcdsaSignature, BitcoinSchnorrSignature};
 use std::fmt;
 use std::rc::Rc;
+use tw_hash::H264;
+use tw_memory::Data;
 
 pub mod standard_constructor;
 
@@ -23,6 +25,28 @@ pub trait SchnorrSpendingDataConstructor: fmt::Debug {
     fn get_spending_data(&self, sig: &BitcoinSchnorrSignature) -> SpendingData;
 }
 
+/// A signer of a UTXO locked by a script that may require several signatures (e.g. multisig).
+#[derive(Clone, Debug)]
+pub struct ScriptSigner {
+    /// Compressed ecdsa public key of the signer.
+    pub public_key: H264,
+    /// Signature collected on a previous signing round if any.
+    /// Either raw (64-65 bytes) or ASN.1 DER encoded, without a sighash type.
+    pub partial_signature: Option<Data>,
+}
+
+pub trait EcdsaMultiSpendingDataConstructor: fmt::Debug {
+    /// Public keys allowed to sign the UTXO in the order their signatures must be pushed onto the stack.
+    fn signers(&self) -> &[ScriptSigner];
+
+    /// Minimum number of signatures required to spend the UTXO.
+    fn threshold(&self) -> usize;
+
+    /// Constructs `SpendingData` from the signatures in the same order as [`EcdsaMultiSpendingDataConstructor::signers`].
+    /// A signature is `None` if the corresponding signer hasn't signed the UTXO.
+    fn get_spending_data(&self, sigs: &[Option<BitcoinEcdsaSignature>]) -> SpendingData;
+}
+
 /// A UTXO `SpendingData` constructor.
 /// Each enum variant differs with a signature type it expects to construct `SpendingData`.
 /// TODO consider adding a `UtxoContext` with a `UtxoContext::SpendingDataConstructor: Clone` associated type.
@@ -30,6 +54,8 @@ pub trait SchnorrSpendingDataConstructor: fmt::Debug {
 pub enum SpendingDataConstructor {
     Ecdsa(Rc<dyn EcdsaSpendingDataConstructor>),
     Schnorr(Rc<dyn SchnorrSpendingDataConstructor>),
+    /// Expects one or several ecdsa signatures, e.g. to spend a multisig P2SH or P2WSH UTXO.
+    EcdsaMulti(Rc<dyn EcdsaMultiSpendingDataConstructor>),
 }
 
 impl SpendingDataConstructor {
@@ -40,4 +66,8 @@ impl SpendingDataConstructor {
     pub fn schnorr<T: SchnorrSpendingDataConstructor + 'static>(constructor: T) -> Self {
         SpendingDataConstructor::Schnorr(Rc::new(constructor))
     }
+
+    pub fn ecdsa_multi<T: EcdsaMultiSpendingDataConstructor + 'static>(constructor: T) -> Self {
+        SpendingDataConstructor::EcdsaMulti(Rc::new(constructor))
+    }
 }
@@ -6,7 +6,8 @@ use crate::script::standard_script::claims;
 use crate::script::{Script, Witness};
 use crate::signature::{BitcoinEcdsaSignature, BitcoinSchnorrSignature};
 use crate::spending_data::{
-    EcdsaSpendingDataConstructor, SchnorrSpendingDataConstructor, SpendingData,
+    EcdsaMultiSpendingDataConstructor, EcdsaSpendingDataConstructor,
+    SchnorrSpendingDataConstructor, ScriptSigner, SpendingData,
 };
 use tw_hash::H264;
 use tw_memory::Data;
@@ -81,3 +82,114 @@ impl SchnorrSpendingDataConstructor for P2TRScriptPath {
         }
     }
 }
+
+/// Describes how signatures are pushed onto the stack to satisfy a redeem (or witness) script.
+#[derive(Clone, Debug)]
+pub enum RedeemScriptKind {
+    /// `OP_<m> <pubkey_1> ... <pubkey_n> OP_<n> OP_CHECKMULTISIG` claimed by `OP_0 <sig_1> ... <sig_m>`.
+    Multisig { threshold: usize },
+    /// `<pubkey> OP_CHECKSIG` claimed by `<sig>`.
+    P2PK,
+    /// `OP_DUP OP_HASH160 <pubkey_hash> OP_EQUALVERIFY OP_CHECKSIG` claimed by `<sig> <pubkey>`.
+    P2PKH,
+    /// Any other script claimed by signatures of all signers in the given order.
+    Custom,
+}
+
+/// A redeem script (P2SH) or witness script (P2WSH) with the signers expected to satisfy it.
+#[derive(Clone, Debug)]
+pub struct RedeemScript {
+    pub script: Script,
+    pub kind: RedeemScriptKind,
+    pub signers: Vec<ScriptSigner>,
+}
+
+impl RedeemScript {
+    /// Minimum number of signatures required to satisfy the script.
+    pub fn threshold(&self) -> usize {
+        match self.kind {
+            RedeemScriptKind::Multisig { threshold } => threshold,
+            RedeemScriptKind::P2PK | RedeemScriptKind::P2PKH | RedeemScriptKind::Custom => {
+                self.signers.len()
+            },
+        }
+    }
+
+    /// Returns the stack items that satisfy the script, excluding the script itself.
+    fn claim_items(&self, sigs: &[Option<BitcoinEcdsaSignature>]) -> Vec<Script> {
+        let signatures = sigs
+            .iter()
+            .flatten()
+            .map(|sig| Script::from(sig.serialize()));
+
+        match self.kind {
+            RedeemScriptKind::Multisig { threshold } => {
+                // `OP_CHECKMULTISIG` pops an extra (dummy) element from the stack.
+                std::iter::once(Script::default())
+                    .chain(signatures.take(threshold))
+                    .collect()
+            },
+            RedeemScriptKind::P2PKH => {
+                let pubkeys = self
+                    .signers
+                    .iter()
+                    .map(|signer| Script::from(signer.public_key.into_vec()));
+                signatures
+                    .zip(pubkeys)
+                    .flat_map(|(sig, pubkey)| [sig, pubkey])
+                    .collect()
+            },
+            RedeemScriptKind::P2PK | RedeemScriptKind::Custom => signatures.collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct P2SH {
+    pub redeem_script: RedeemScript,
+}
+
+impl EcdsaMultiSpendingDataConstructor for P2SH {
+    fn signers(&self) -> &[ScriptSigner] {
+        &self.redeem_script.signers
+    }
+
+    fn threshold(&self) -> usize {
+        self.redeem_script.threshold()
+    }
+
+    fn get_spending_data(&self, sigs: &[Option<BitcoinEcdsaSignature>]) -> SpendingData {
+        SpendingData {
+            script_sig: claims::new_p2sh(
+                self.redeem_script.claim_items(sigs),
+                &self.redeem_script.script,
+            ),
+            witness: Witness::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct P2WSH {
+    pub witness_script: RedeemScript,
+}
+
+impl EcdsaMultiSpendingDataConstructor for P2WSH {
+    fn signers(&self) -> &[ScriptSigner] {
+        &self.witness_script.signers
+    }
+
+    fn threshold(&self) -> usize {
+        self.witness_script.threshold()
+    }
+
+    fn get_spending_data(&self, sigs: &[Option<BitcoinEcdsaSignature>]) -> SpendingData {
+        SpendingData {
+            script_sig: Script::default(),
+            witness: claims::new_p2wsh(
+                self.witness_script.claim_items(sigs),
+                self.witness_script.script.clone(),
+            ),
+        }
+    }
+}
@@ -66,3 +66,21 @@ pub struct UtxoToSign {
     pub tx_hasher: Hasher,
     pub sighash_ty: SighashType,
 }
+
+impl UtxoToSign {
+    /// Returns public keys that need to sign the UTXO.
+    /// Please note that signers which provided their signatures on previous signing rounds are skipped.
+    pub fn pending_signers(&self) -> Vec<Data> {
+        match self.spending_data_constructor {
+            SpendingDataConstructor::Ecdsa(_) | SpendingDataConstructor::Schnorr(_) => {
+                vec![self.spender_public_key.clone()]
+            },
+            SpendingDataConstructor::EcdsaMulti(ref multi_constructor) => multi_constructor
+                .signers()
+                .iter()
+                .filter(|signer| signer.partial_signature.is_none())
+                .map(|signer| signer.public_key.into_vec())
+                .collect(),
+        }
+    }
+}
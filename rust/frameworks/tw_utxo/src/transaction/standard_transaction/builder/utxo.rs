@@ -1,16 +1,16 @@
 use super::TransactionInput;
 use crate::sighash::SighashType;
+use crate::spending_data::standard_constructor::RedeemScript;
 use crate::spending_data::{standard_constructor, SpendingDataConstructor};
 use crate::transaction::UtxoToSign;
 use crate::{
-    script::{standard_script::conditions, Script, Witness},
+    script::{standard_script::conditions, taproot::TapLeafSpendInfo, Script, Witness},
     signing_mode::SigningMethod,
     transaction::asset::brc20::{BRC20TransferInscription, Brc20Ticker},
     transaction::transaction_parts::{Amount, OutPoint},
 };
-use bitcoin::hashes::Hash;
 use tw_coin_entry::error::prelude::*;
-use tw_hash::{hasher::Hasher, ripemd::bitcoin_hash_160, H160, H256};
+use tw_hash::{hasher::Hasher, ripemd::bitcoin_hash_160, sha2::sha256, H160, H256};
 use tw_keypair::{ecdsa, schnorr};
 use tw_memory::Data;
 use tw_misc::traits::ToBytesVec;
@@ -92,32 +92,52 @@ impl UtxoBuilder {
             .context("'UtxoBuilder::sighash_ty' is not set")
     }
 
-    // TODO next iteration.
-    // pub fn p2sh(self, redeem_script: Script) -> SigningResult<(TransactionInput, UtxoToSign)> {
-    //     let h = bitcoin_hash_160(redeem_script.as_slice());
-    //     let redeem_hash: H160 = h.as_slice().try_into().expect("hash length is 20 bytes");
-    //
-    //     self.p2sh_with_hash(redeem_hash)
-    // }
-    //
-    // pub fn p2sh_with_hash(
-    //     mut self,
-    //     redeem_hash: H160,
-    // ) -> SigningResult<(TransactionInput, UtxoToSign)> {
-    //     self.finalize_out_point()?;
-    //     let amount = self.finalize_amount()?;
-    //
-    //     Ok((
-    //         self.input,
-    //         UtxoToSign {
-    //             script_pubkey: conditions::new_p2sh(&redeem_hash),
-    //             // P2SH output can be spent by a legacy address only.
-    //             signing_method: SigningMethod::Legacy,
-    //             amount,
-    //             ..Default::default()
-    //         },
-    //     ))
-    // }
+    fn check_redeem_script_signers(redeem_script: &RedeemScript) -> SigningResult<()> {
+        let threshold = redeem_script.threshold();
+        if threshold == 0 || threshold > redeem_script.signers.len() {
+            return SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                "Redeem script requires '{threshold}' signatures, but there are '{}' signers",
+                redeem_script.signers.len()
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn p2sh(
+        mut self,
+        redeem_script: RedeemScript,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        Self::check_redeem_script_signers(&redeem_script)?;
+
+        let h = bitcoin_hash_160(redeem_script.script.as_slice());
+        let script_hash: H160 = h.as_slice().try_into().expect("hash length is 20 bytes");
+
+        self.finalize_out_point()?;
+        let amount = self.finalize_amount()?;
+        let sighash_ty = self.finalize_sighash_type()?;
+
+        Ok((
+            self.input,
+            UtxoToSign {
+                // Original P2SH scriptPubkey.
+                prevout_script_pubkey: conditions::new_p2sh(&script_hash),
+                // The redeem script is used to sign the UTXO.
+                script_pubkey: redeem_script.script.clone(),
+                // P2SH output can be spent by a legacy address only.
+                signing_method: SigningMethod::Legacy,
+                // When the sighash is signed by all required signers, build a P2SH script_sig.
+                spending_data_constructor: SpendingDataConstructor::ecdsa_multi(
+                    standard_constructor::P2SH { redeem_script },
+                ),
+                // There can be several signers. See `UtxoToSign::pending_signers`.
+                spender_public_key: Data::default(),
+                amount,
+                leaf_hash_code_separator: None,
+                tx_hasher: DEFAULT_TX_HASHER,
+                sighash_ty,
+            },
+        ))
+    }
 
     pub fn p2pk(
         mut self,
@@ -186,29 +206,41 @@ impl UtxoBuilder {
         ))
     }
 
-    // TODO next iteration.
-    // pub fn p2wsh(mut self, redeem_script: Script) -> SigningResult<(TransactionInput, UtxoToSign)> {
-    //     let h = sha256(redeem_script.as_slice());
-    //     let redeem_hash: H256 = h.as_slice().try_into().expect("hash length is 32 bytes");
-    //
-    //     self.finalize_out_point()?;
-    //     let amount = self.finalize_amount()?;
-    //     let sighash_ty = self.finalize_sighash_type()?;
-    //
-    //     Ok((
-    //         self.input,
-    //         UtxoToSign {
-    //             script_pubkey: conditions::new_p2wsh(&redeem_hash),
-    //             // P2WSH output can be spent by a legacy address only.
-    //             signing_method: SigningMethod::Segwit,
-    //             spending_data_constructor: SpendingDataConstructor::ecdsa(todo!()),
-    //             amount,
-    //             leaf_hash_code_separator: None,
-    //             tx_hasher: DEFAULT_TX_HASHER,
-    //             sighash_ty,
-    //         },
-    //     ))
-    // }
+    pub fn p2wsh(
+        mut self,
+        witness_script: RedeemScript,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        Self::check_redeem_script_signers(&witness_script)?;
+
+        let h = sha256(witness_script.script.as_slice());
+        let script_hash: H256 = h.as_slice().try_into().expect("hash length is 32 bytes");
+
+        self.finalize_out_point()?;
+        let amount = self.finalize_amount()?;
+        let sighash_ty = self.finalize_sighash_type()?;
+
+        Ok((
+            self.input,
+            UtxoToSign {
+                // Original P2WSH scriptPubkey.
+                prevout_script_pubkey: conditions::new_p2wsh(&script_hash),
+                // The witness script is used to sign the UTXO.
+                script_pubkey: witness_script.script.clone(),
+                // P2WSH output can be spent by a Witness (eg "bc1") address only.
+                signing_method: SigningMethod::Segwit,
+                // When the sighash is signed by all required signers, build a P2WSH witness.
+                spending_data_constructor: SpendingDataConstructor::ecdsa_multi(
+                    standard_constructor::P2WSH { witness_script },
+                ),
+                // There can be several signers. See `UtxoToSign::pending_signers`.
+                spender_public_key: Data::default(),
+                amount,
+                leaf_hash_code_separator: None,
+                tx_hasher: DEFAULT_TX_HASHER,
+                sighash_ty,
+            },
+        ))
+    }
 
     pub fn p2wpkh(
         mut self,
@@ -287,46 +319,37 @@ impl UtxoBuilder {
         ))
     }
 
+    /// Spends a P2TR output through the script leaf described by `spend_info`.
+    /// `signer` is the public key expected to sign the leaf script, not necessarily the internal key.
     pub fn p2tr_script_path(
         mut self,
-        internal_pubkey: &schnorr::PublicKey,
-        payload: Script,
-        control_block: Data,
-        merkle_root: &H256,
+        signer: &schnorr::PublicKey,
+        spend_info: TapLeafSpendInfo,
     ) -> SigningResult<(TransactionInput, UtxoToSign)> {
-        // Construct the leaf hash.
-        let script_buf = bitcoin::ScriptBuf::from_bytes(payload.to_vec());
-        let leaf_hash = bitcoin::taproot::TapLeafHash::from_script(
-            &script_buf,
-            bitcoin::taproot::LeafVersion::TapScript,
-        );
-
-        // Convert to native.
-        let leaf_hash: H256 = H256::from(leaf_hash.to_byte_array());
-
         self.finalize_out_point()?;
         let amount = self.finalize_amount()?;
         let sighash_ty = self.finalize_sighash_type()?;
 
         // Restore the original scriptPubkey declared at the unspent P2TR output.
-        let prevout_script_pubkey =
-            conditions::new_p2tr_script_path(&internal_pubkey.compressed(), merkle_root);
+        let prevout_script_pubkey = spend_info.prevout_script_pubkey();
+        let leaf_hash = spend_info.leaf_hash();
+        let payload = spend_info.leaf_script().clone();
 
         Ok((
             self.input,
             UtxoToSign {
+                // BIP341 requires the sighash to commit to the scriptPubkey of the spent output.
+                script_pubkey: prevout_script_pubkey.clone(),
                 prevout_script_pubkey,
-                // We use the full (revealed) script as scriptPubkey here.
-                script_pubkey: payload.clone(),
                 signing_method: SigningMethod::Taproot,
                 spending_data_constructor: SpendingDataConstructor::schnorr(
                     standard_constructor::P2TRScriptPath {
                         payload,
-                        control_block,
+                        control_block: spend_info.control_block(),
                     },
                 ),
                 // Taproot ScriptPath input should be signed with a non-tweaked private key.
-                spender_public_key: internal_pubkey.compressed().to_vec(),
+                spender_public_key: signer.compressed().to_vec(),
                 amount,
                 leaf_hash_code_separator: Some((leaf_hash, u32::MAX)),
                 // Note that we don't use the default double-hasher.
@@ -355,15 +378,13 @@ impl UtxoBuilder {
             .or_tw_err(SigningErrorType::Error_internal)
             .context("'TaprootSpendInfo::control_block' is None")?;
 
-        let merkle_root = transfer.merkle_root()?;
         let transfer_payload = Script::from(transfer.script.to_bytes());
-
-        self.p2tr_script_path(
-            pubkey,
+        let spend_info = TapLeafSpendInfo::with_control_block(
             transfer_payload,
-            control_block.serialize(),
-            &merkle_root,
-        )
+            &control_block.serialize(),
+        )?;
+
+        self.p2tr_script_path(pubkey, spend_info)
     }
 }
 
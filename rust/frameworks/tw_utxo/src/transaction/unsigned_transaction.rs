@@ -76,6 +76,14 @@ where
                     let estimated_sig = BitcoinSchnorrSignature::estimated();
                     schnorr_constructor.get_spending_data(&estimated_sig)
                 },
+                SpendingDataConstructor::EcdsaMulti(ref multi_constructor) => {
+                    // Expect the minimum required number of signatures.
+                    let threshold = multi_constructor.threshold();
+                    let estimated_sigs: Vec<_> = (0..multi_constructor.signers().len())
+                        .map(|i| (i < threshold).then(BitcoinEcdsaSignature::estimated))
+                        .collect();
+                    multi_constructor.get_spending_data(&estimated_sigs)
+                },
             };
 
             utxo.set_script_sig(estimated_spending.script_sig);
@@ -2,17 +2,22 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::abi::custom_error::{CustomError, DecodedRevert};
+use crate::abi::event::Event;
 use crate::abi::function::Function;
-use crate::abi::{AbiErrorKind, AbiResult};
+use crate::abi::param_token::NamedToken;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use serde::{Deserialize, Deserializer};
 use std::collections::BTreeMap;
 use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
 
 /// API building calls to contracts ABI.
-/// Consider adding missing field such as `errors`, `events` etc.
 #[derive(Clone, Debug, Default)]
 pub struct Contract {
     pub functions: BTreeMap<String, Vec<Function>>,
+    pub events: BTreeMap<String, Vec<Event>>,
+    pub errors: BTreeMap<String, Vec<CustomError>>,
 }
 
 impl Contract {
@@ -26,6 +31,56 @@ impl Contract {
             .or_tw_err(AbiErrorKind::Error_abi_mismatch)
             .with_context(|| format!("The given Smart Contract does not have '{name}' function"))
     }
+
+    /// Get the event named `name`, the first if there are overloaded versions of the same event.
+    pub fn event(&self, name: &str) -> AbiResult<&Event> {
+        self.events
+            .get(name)
+            .into_iter()
+            .flatten()
+            .next()
+            .or_tw_err(AbiErrorKind::Error_abi_mismatch)
+            .with_context(|| format!("The given Smart Contract does not have '{name}' event"))
+    }
+
+    /// Decodes the event log according to an event that matches the first topic.
+    /// If there is no such event, tries to decode the log according to anonymous events
+    /// with the same number of topics.
+    pub fn decode_log(&self, topics: &[H256], data: &[u8]) -> AbiResult<(&Event, Vec<NamedToken>)> {
+        let events = || self.events.values().flatten();
+
+        if let Some(topic0) = topics.first() {
+            let event = events().find(|event| !event.anonymous && event.topic0() == *topic0);
+            if let Some(event) = event {
+                return Ok((event, event.decode_log(topics, data)?));
+            }
+        }
+
+        events()
+            .filter(|event| event.anonymous && event.topics_count() == topics.len())
+            .find_map(|event| {
+                let tokens = event.decode_log(topics, data).ok()?;
+                Some((event, tokens))
+            })
+            .or_tw_err(AbiErrorKind::Error_abi_mismatch)
+            .context("The given Smart Contract does not have an event matching the log")
+    }
+
+    /// Decodes the revert data according to builtin `Error(string)`, `Panic(uint256)`
+    /// or custom errors of the contract.
+    pub fn decode_revert(&self, data: &[u8]) -> AbiResult<DecodedRevert> {
+        let builtin_errors = [CustomError::error_string(), CustomError::panic()];
+        let custom_errors = self.errors.values().flatten().cloned();
+
+        for error in builtin_errors.into_iter().chain(custom_errors) {
+            if let Some(inputs) = error.decode_revert(data)? {
+                return Ok(DecodedRevert { error, inputs });
+            }
+        }
+
+        AbiError::err(AbiErrorKind::Error_abi_mismatch)
+            .context("The given Smart Contract does not have an error matching the revert data")
+    }
 }
 
 impl<'de> Deserialize<'de> for Contract {
@@ -33,20 +88,19 @@ impl<'de> Deserialize<'de> for Contract {
     where
         D: Deserializer<'de>,
     {
-        /// Consider adding missing field such as `errors`, `events` etc.
         #[derive(Deserialize)]
         #[serde(tag = "type", rename_all = "snake_case")]
         enum Operation {
             Function(Function),
+            Event(Event),
+            Error(CustomError),
             #[serde(other)]
             Unsupported,
         }
 
         let operations: Vec<Operation> = Vec::deserialize(deserializer)?;
 
-        let mut result = Contract {
-            functions: BTreeMap::default(),
-        };
+        let mut result = Contract::default();
         for operation in operations {
             match operation {
                 Operation::Function(fun) => result
@@ -54,6 +108,16 @@ impl<'de> Deserialize<'de> for Contract {
                     .entry(fun.name.clone())
                     .or_default()
                     .push(fun),
+                Operation::Event(event) => result
+                    .events
+                    .entry(event.name.clone())
+                    .or_default()
+                    .push(event),
+                Operation::Error(error) => result
+                    .errors
+                    .entry(error.name.clone())
+                    .or_default()
+                    .push(error),
                 Operation::Unsupported => (),
             }
         }
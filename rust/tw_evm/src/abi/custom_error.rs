@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::decode::decode_params;
+use crate::abi::param::Param;
+use crate::abi::param_token::NamedToken;
+use crate::abi::param_type::ParamType;
+use crate::abi::signature::short_signature;
+use crate::abi::token::Token;
+use crate::abi::uint::UintBits;
+use crate::abi::AbiResult;
+use itertools::Itertools;
+use serde::Deserialize;
+use tw_encoding::hex;
+use tw_hash::H32;
+use tw_number::U256;
+
+/// `Error(string)` selector used by `revert("reason")` and `require(false, "reason")`.
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// `Panic(uint256)` selector used on failed assertions, arithmetic overflows etc.
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Custom error ABI item, i.e. `error InsufficientBalance(uint256 available, uint256 required)`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CustomError {
+    /// Error name.
+    pub name: String,
+    /// Error input.
+    pub inputs: Vec<Param>,
+}
+
+impl CustomError {
+    /// Returns the builtin `Error(string)` error.
+    pub fn error_string() -> CustomError {
+        CustomError {
+            name: "Error".to_string(),
+            inputs: vec![Param {
+                name: Some("message".to_string()),
+                kind: ParamType::String,
+                internal_type: Some("string".to_string()),
+            }],
+        }
+    }
+
+    /// Returns the builtin `Panic(uint256)` error.
+    pub fn panic() -> CustomError {
+        CustomError {
+            name: "Panic".to_string(),
+            inputs: vec![Param {
+                name: Some("code".to_string()),
+                kind: ParamType::Uint {
+                    bits: UintBits::default(),
+                },
+                internal_type: Some("uint256".to_string()),
+            }],
+        }
+    }
+
+    /// Returns a signature that uniquely identifies this error.
+    ///
+    /// Examples:
+    /// - `Error(string)`
+    /// - `InsufficientBalance(uint256,uint256)`
+    pub fn signature(&self) -> String {
+        let inputs = self.inputs.iter().map(|p| p.kind.to_type_long()).join(",");
+        format!("{}({inputs})", self.name)
+    }
+
+    /// Returns the error selector, i.e. the first four bytes of the Keccak-256 hash of the signature.
+    pub fn selector(&self) -> H32 {
+        let input_param_types: Vec<_> =
+            self.inputs.iter().map(|param| param.kind.clone()).collect();
+        short_signature(&self.name, &input_param_types)
+    }
+
+    /// Parses the revert data, i.e. the error selector followed by the encoded input.
+    /// Returns `Ok(None)` if the revert data selector doesn't match the error.
+    pub fn decode_revert(&self, data: &[u8]) -> AbiResult<Option<Vec<NamedToken>>> {
+        let Some(encoded_input) = data.strip_prefix(self.selector().as_slice()) else {
+            return Ok(None);
+        };
+        if self.inputs.is_empty() {
+            return Ok(Some(Vec::default()));
+        }
+        decode_params(&self.inputs, encoded_input).map(Some)
+    }
+}
+
+/// Revert data decoded according to a builtin or a custom error.
+#[derive(Clone, Debug)]
+pub struct DecodedRevert {
+    pub error: CustomError,
+    pub inputs: Vec<NamedToken>,
+}
+
+impl DecodedRevert {
+    /// Returns a human readable failure reason if the revert was caused by
+    /// `Error(string)` or `Panic(uint256)`.
+    pub fn reason(&self) -> Option<String> {
+        let selector = self.error.selector().take();
+        match self.inputs.first().map(|input| &input.value) {
+            Some(Token::String(message)) if selector == ERROR_STRING_SELECTOR => {
+                Some(message.clone())
+            },
+            Some(Token::Uint { uint, .. }) if selector == PANIC_SELECTOR => {
+                Some(panic_reason(*uint))
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Returns a description of the given `Panic(uint256)` code.
+/// https://docs.soliditylang.org/en/v0.8.24/control-structures.html#panic-via-assert-and-error-via-require
+pub fn panic_reason(code: U256) -> String {
+    let description = match u8::try_from(code) {
+        Ok(0x00) => "generic compiler inserted panic",
+        Ok(0x01) => "assertion failed",
+        Ok(0x11) => "arithmetic operation overflowed or underflowed",
+        Ok(0x12) => "division or modulo by zero",
+        Ok(0x21) => "invalid enum value",
+        Ok(0x22) => "incorrectly encoded storage byte array",
+        Ok(0x31) => "pop on an empty array",
+        Ok(0x32) => "array index out of bounds",
+        Ok(0x41) => "too much memory allocated",
+        Ok(0x51) => "call to a zero-initialized internal function",
+        _ => "unknown panic code",
+    };
+    let code_hex = hex::encode(code.to_big_endian_compact_min_len(1), true);
+    format!("Panic({code_hex}): {description}")
+}
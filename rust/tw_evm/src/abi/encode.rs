@@ -3,6 +3,8 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use tw_coin_entry::error::prelude::*;
 use tw_hash::H256;
 use tw_memory::Data;
 
@@ -15,6 +17,66 @@ pub fn encode_tokens(tokens: &[Token]) -> Data {
         .collect()
 }
 
+/// Encodes the tokens in the non-standard packed mode, i.e. Solidity `abi.encodePacked`.
+/// https://docs.soliditylang.org/en/latest/abi-spec.html#non-standard-packed-mode
+///
+/// Please note that tuples and nested arrays are not supported in the packed mode,
+/// as well as arrays of dynamic types such as `string[]`.
+pub fn encode_packed(tokens: &[Token]) -> AbiResult<Data> {
+    let mut result = Data::new();
+    for token in tokens {
+        encode_packed_append(&mut result, token)?;
+    }
+    Ok(result)
+}
+
+fn encode_packed_append(data: &mut Data, token: &Token) -> AbiResult<()> {
+    match token {
+        Token::Address(address) => data.extend_from_slice(address.as_slice()),
+        Token::Bytes(bytes) => data.extend_from_slice(bytes),
+        Token::String(s) => data.extend_from_slice(s.as_bytes()),
+        Token::FixedBytes(bytes) => data.extend_from_slice(bytes),
+        Token::Int { int, bits } => {
+            let bytes_len = bits.get() / 8;
+            data.extend_from_slice(&int.to_big_endian()[H256::LEN - bytes_len..]);
+        },
+        Token::Uint { uint, bits } => {
+            let bytes_len = bits.get() / 8;
+            data.extend_from_slice(&uint.to_big_endian()[H256::LEN - bytes_len..]);
+        },
+        Token::Bool(b) => data.push(u8::from(*b)),
+        // Array elements are padded to 32 bytes.
+        Token::Array { arr, .. } => encode_packed_array_append(data, arr)?,
+        Token::FixedArray { arr, .. } => encode_packed_array_append(data, arr)?,
+        Token::Tuple { .. } => {
+            return AbiError::err(AbiErrorKind::Error_invalid_param_type)
+                .context("Tuples are not supported in the packed mode")
+        },
+    }
+    Ok(())
+}
+
+fn encode_packed_array_append(data: &mut Data, elements: &[Token]) -> AbiResult<()> {
+    let mut padded = Vec::with_capacity(elements.len());
+    for element in elements {
+        match element {
+            Token::Address(_)
+            | Token::FixedBytes(_)
+            | Token::Int { .. }
+            | Token::Uint { .. }
+            | Token::Bool(_) => encode_token_append(&mut padded, element),
+            _ => {
+                let element_type = element.type_short();
+                return AbiError::err(AbiErrorKind::Error_invalid_param_type).with_context(|| {
+                    format!("'{element_type}' array elements are not supported in the packed mode")
+                });
+            },
+        }
+    }
+    data.extend(padded.into_iter().flat_map(H256::take));
+    Ok(())
+}
+
 #[derive(Debug)]
 enum Mediate<'a> {
     // head
@@ -210,6 +272,7 @@ mod tests {
     use crate::abi::param_token::NamedToken;
     use crate::abi::param_type::constructor::TypeConstructor;
     use crate::abi::param_type::ParamType;
+    use crate::abi::uint::UintBits;
     use tw_encoding::hex::DecodeHex;
     use tw_number::{I256, U256};
 
@@ -1083,4 +1146,67 @@ mod tests {
         .unwrap();
         assert_eq!(encoded, expected);
     }
+
+    #[test]
+    fn encode_packed_elementary_types() {
+        // https://docs.soliditylang.org/en/latest/abi-spec.html#non-standard-packed-mode
+        let tokens = [
+            Token::int(16, -1_i64).unwrap(),
+            Token::FixedBytes(NonEmptyBytes::new(vec![0x42]).unwrap()),
+            Token::uint(16, 3_u64).unwrap(),
+            Token::String("Hello, world!".to_string()),
+        ];
+        let encoded = encode_packed(&tokens).unwrap();
+        let expected = "ffff42000348656c6c6f2c20776f726c6421".decode_hex().unwrap();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn encode_packed_address_bool_and_array() {
+        let tokens = [
+            Token::Address("0x1111111111111111111111111111111111111111".into()),
+            Token::Bool(true),
+            Token::Array {
+                arr: vec![
+                    Token::uint(16, 1_u64).unwrap(),
+                    Token::uint(16, 2_u64).unwrap(),
+                ],
+                kind: ParamType::Uint {
+                    bits: UintBits::new(16).unwrap(),
+                },
+            },
+            Token::Bytes(vec![0xde, 0xad]),
+        ];
+        let encoded = encode_packed(&tokens).unwrap();
+        let expected = concat!(
+            "1111111111111111111111111111111111111111",
+            "01",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "dead",
+        )
+        .decode_hex()
+        .unwrap();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn encode_packed_unsupported_types() {
+        let tuple = Token::Tuple {
+            params: vec![NamedToken::with_token(Token::Bool(true))],
+        };
+        assert_eq!(
+            *encode_packed(&[tuple]).unwrap_err().error_type(),
+            AbiErrorKind::Error_invalid_param_type
+        );
+
+        let strings = Token::Array {
+            arr: vec![Token::String("foo".to_string())],
+            kind: ParamType::String,
+        };
+        assert_eq!(
+            *encode_packed(&[strings]).unwrap_err().error_type(),
+            AbiErrorKind::Error_invalid_param_type
+        );
+    }
 }
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::decode::{decode_params, decode_value};
+use crate::abi::non_empty_array::NonEmptyBytes;
+use crate::abi::param::Param;
+use crate::abi::param_token::NamedToken;
+use crate::abi::param_type::ParamType;
+use crate::abi::signature::long_signature;
+use crate::abi::token::Token;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use itertools::Itertools;
+use serde::Deserialize;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+
+/// Event parameter specification.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct EventParam {
+    #[serde(flatten)]
+    pub param: Param,
+    /// Whether the parameter is stored in the log topics instead of the log data.
+    #[serde(default)]
+    pub indexed: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Event {
+    /// Event name.
+    pub name: String,
+    /// Event input.
+    pub inputs: Vec<EventParam>,
+    /// Whether the event signature is omitted from the log topics.
+    #[serde(default)]
+    pub anonymous: bool,
+}
+
+impl Event {
+    /// Returns a signature that uniquely identifies this event.
+    ///
+    /// Examples:
+    /// - `Transfer(address,address,uint256)`
+    /// - `URI(string,uint256)`
+    pub fn signature(&self) -> String {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|p| p.param.kind.to_type_long())
+            .join(",");
+        format!("{}({inputs})", self.name)
+    }
+
+    /// Returns the event selector, i.e. the Keccak-256 hash of the signature.
+    /// It is expected to be the first log topic unless the event is anonymous.
+    pub fn topic0(&self) -> H256 {
+        let input_param_types: Vec<_> = self
+            .inputs
+            .iter()
+            .map(|input| input.param.kind.clone())
+            .collect();
+        long_signature(&self.name, &input_param_types)
+    }
+
+    /// Returns the number of log topics expected for this event.
+    pub fn topics_count(&self) -> usize {
+        let indexed = self.inputs.iter().filter(|input| input.indexed).count();
+        if self.anonymous {
+            indexed
+        } else {
+            indexed + 1
+        }
+    }
+
+    /// Parses the event log to a list of tokens in the order of the event inputs.
+    ///
+    /// Indexed parameters are taken from the `topics`, others are decoded from the `data`.
+    /// Please note that indexed parameters of a dynamic or composite type (e.g. `string`, `uint256[]`)
+    /// are stored as a Keccak-256 hash of their value, so they are returned as `bytes32`.
+    pub fn decode_log(&self, topics: &[H256], data: &[u8]) -> AbiResult<Vec<NamedToken>> {
+        if topics.len() != self.topics_count() {
+            return AbiError::err(AbiErrorKind::Error_abi_mismatch).with_context(|| {
+                format!(
+                    "'{}' event expects {} topics, found {}",
+                    self.name,
+                    self.topics_count(),
+                    topics.len()
+                )
+            });
+        }
+
+        let mut topics = topics.iter();
+        if !self.anonymous {
+            let topic0 = topics
+                .next()
+                .or_tw_err(AbiErrorKind::Error_abi_mismatch)
+                .context("Expected an event selector topic")?;
+            if *topic0 != self.topic0() {
+                return AbiError::err(AbiErrorKind::Error_abi_mismatch).with_context(|| {
+                    format!("The first topic doesn't match '{}' event", self.signature())
+                });
+            }
+        }
+
+        let non_indexed: Vec<_> = self
+            .inputs
+            .iter()
+            .filter(|input| !input.indexed)
+            .map(|input| input.param.clone())
+            .collect();
+        // `decode_params` doesn't allow empty data, so skip it if there are no non-indexed params.
+        let mut non_indexed_tokens = if non_indexed.is_empty() {
+            Vec::default().into_iter()
+        } else {
+            decode_params(&non_indexed, data)
+                .context("Error decoding non-indexed event parameters")?
+                .into_iter()
+        };
+
+        self.inputs
+            .iter()
+            .map(|input| {
+                if input.indexed {
+                    let topic = topics
+                        .next()
+                        .or_tw_err(AbiErrorKind::Error_internal)
+                        .context("Expected an indexed parameter topic")?;
+                    decode_indexed_param(&input.param, topic)
+                } else {
+                    non_indexed_tokens
+                        .next()
+                        .or_tw_err(AbiErrorKind::Error_internal)
+                        .context("Expected a decoded non-indexed parameter")
+                }
+            })
+            .collect()
+    }
+}
+
+fn decode_indexed_param(param: &Param, topic: &H256) -> AbiResult<NamedToken> {
+    let token = match param.kind {
+        ParamType::Address
+        | ParamType::FixedBytes { .. }
+        | ParamType::Int { .. }
+        | ParamType::Uint { .. }
+        | ParamType::Bool => decode_value(&param.kind, topic.as_slice())
+            .context("Error decoding an indexed parameter")?,
+        // Only the hash of the value is stored in the topic.
+        _ => {
+            let hash = NonEmptyBytes::new(topic.to_vec())?;
+            Token::FixedBytes(hash)
+        },
+    };
+    Ok(NamedToken::with_param_and_token(param, token))
+}
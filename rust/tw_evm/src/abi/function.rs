@@ -12,6 +12,7 @@ use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use itertools::Itertools;
 use serde::Deserialize;
 use tw_coin_entry::error::prelude::*;
+use tw_hash::H32;
 use tw_memory::Data;
 
 #[derive(Clone, Debug, Default, Deserialize)]
@@ -48,6 +49,27 @@ impl Function {
         decode_params(&self.inputs, data)
     }
 
+    /// Returns the function selector, i.e. the first four bytes of the Keccak-256 hash of the signature.
+    pub fn selector(&self) -> H32 {
+        let input_param_types: Vec<_> =
+            self.inputs.iter().map(|param| param.kind.clone()).collect();
+        short_signature(&self.name, &input_param_types)
+    }
+
+    /// Parses the function call data, i.e. the function selector followed by the encoded input.
+    /// Returns `Ok(None)` if the call data selector doesn't match the function.
+    pub fn decode_call(&self, data: &[u8]) -> AbiResult<Option<Vec<Token>>> {
+        let Some(encoded_input) = data.strip_prefix(self.selector().as_slice()) else {
+            return Ok(None);
+        };
+        let tokens = self
+            .decode_input(encoded_input)?
+            .into_iter()
+            .map(|named| named.value)
+            .collect();
+        Ok(Some(tokens))
+    }
+
     /// Encodes function input to Eth ABI binary.
     pub fn encode_input(&self, tokens: &[Token]) -> AbiResult<Data> {
         // Check if the given tokens match `Self::inputs` ABI.
@@ -0,0 +1,475 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Human-readable ABI parser.
+//!
+//! Examples of supported signatures:
+//! - `function transfer(address to, uint256 amount) external returns (bool)`
+//! - `function balanceOf(address) view returns (uint256)`
+//! - `event Transfer(address indexed from, address indexed to, uint256 value)`
+//! - `error InsufficientBalance(uint256 available, uint256 required)`
+//! - `function fill((address maker, uint256 amount)[] orders, bytes signature)`
+
+use crate::abi::contract::Contract;
+use crate::abi::custom_error::CustomError;
+use crate::abi::event::{Event, EventParam};
+use crate::abi::function::Function;
+use crate::abi::param::Param;
+use crate::abi::param_type::constructor::TypeConstructor;
+use crate::abi::param_type::ParamType;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+
+const FUNCTION_KEYWORD: &str = "function";
+const EVENT_KEYWORD: &str = "event";
+const ERROR_KEYWORD: &str = "error";
+const RETURNS_KEYWORD: &str = "returns";
+const TUPLE_KEYWORD: &str = "tuple";
+
+/// Function modifiers that don't affect the function selector and the encoding.
+const FUNCTION_MODIFIERS: [&str; 10] = [
+    "external",
+    "public",
+    "internal",
+    "private",
+    "view",
+    "pure",
+    "payable",
+    "nonpayable",
+    "virtual",
+    "override",
+];
+
+/// Parses a contract from a list of human-readable signatures.
+/// Constructors, `fallback` and `receive` functions are skipped.
+pub fn parse_contract<'a, I>(signatures: I) -> AbiResult<Contract>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut contract = Contract::default();
+    for signature in signatures {
+        let signature = signature.trim();
+        let keyword = signature
+            .split(|ch: char| ch.is_whitespace() || ch == '(')
+            .next()
+            .unwrap_or_default();
+
+        match keyword {
+            FUNCTION_KEYWORD => {
+                let function = parse_function(signature)?;
+                contract
+                    .functions
+                    .entry(function.name.clone())
+                    .or_default()
+                    .push(function);
+            },
+            EVENT_KEYWORD => {
+                let event = parse_event(signature)?;
+                contract
+                    .events
+                    .entry(event.name.clone())
+                    .or_default()
+                    .push(event);
+            },
+            ERROR_KEYWORD => {
+                let error = parse_error(signature)?;
+                contract
+                    .errors
+                    .entry(error.name.clone())
+                    .or_default()
+                    .push(error);
+            },
+            "constructor" | "fallback" | "receive" => (),
+            _ => {
+                return AbiError::err(AbiErrorKind::Error_invalid_abi)
+                    .with_context(|| format!("Unexpected ABI item: {signature}"))
+            },
+        }
+    }
+    Ok(contract)
+}
+
+/// Parses a function signature, e.g. `function transfer(address to, uint256 amount) returns (bool)`.
+/// The `function` keyword is optional.
+pub fn parse_function(s: &str) -> AbiResult<Function> {
+    let s = strip_keyword(s.trim(), FUNCTION_KEYWORD);
+    let (name, inputs, remaining) = split_item(s)?;
+    let inputs = parse_params(inputs)?;
+
+    let (modifiers, outputs) = match remaining.split_once(RETURNS_KEYWORD) {
+        Some((modifiers, returns)) => {
+            let returns = returns.trim();
+            let outputs = returns
+                .strip_prefix('(')
+                .and_then(|returns| returns.strip_suffix(')'))
+                .or_tw_err(AbiErrorKind::Error_invalid_abi)
+                .with_context(|| format!("Expected function outputs in parentheses: {returns}"))?;
+            (modifiers, parse_params(outputs)?)
+        },
+        None => (remaining, Vec::default()),
+    };
+
+    if let Some(unknown) = modifiers
+        .split_whitespace()
+        .find(|modifier| !FUNCTION_MODIFIERS.contains(modifier))
+    {
+        return AbiError::err(AbiErrorKind::Error_invalid_abi)
+            .with_context(|| format!("Unexpected function modifier: {unknown}"));
+    }
+
+    Ok(Function {
+        name: name.to_string(),
+        inputs,
+        outputs,
+    })
+}
+
+/// Parses an event signature, e.g. `event Transfer(address indexed from, address indexed to, uint256 value)`.
+/// The `event` keyword is optional.
+pub fn parse_event(s: &str) -> AbiResult<Event> {
+    let s = strip_keyword(s.trim(), EVENT_KEYWORD);
+    let (name, inputs, remaining) = split_item(s)?;
+
+    let anonymous = match remaining {
+        "" => false,
+        "anonymous" => true,
+        other => {
+            return AbiError::err(AbiErrorKind::Error_invalid_abi)
+                .with_context(|| format!("Unexpected event modifier: {other}"))
+        },
+    };
+
+    let inputs = split_params(inputs)?
+        .into_iter()
+        .map(|input| {
+            let (param, indexed) = parse_param_impl(input)?;
+            Ok(EventParam { param, indexed })
+        })
+        .collect::<AbiResult<Vec<_>>>()?;
+
+    Ok(Event {
+        name: name.to_string(),
+        inputs,
+        anonymous,
+    })
+}
+
+/// Parses a custom error signature, e.g. `error InsufficientBalance(uint256 available, uint256 required)`.
+/// The `error` keyword is optional.
+pub fn parse_error(s: &str) -> AbiResult<CustomError> {
+    let s = strip_keyword(s.trim(), ERROR_KEYWORD);
+    let (name, inputs, remaining) = split_item(s)?;
+    if !remaining.is_empty() {
+        return AbiError::err(AbiErrorKind::Error_invalid_abi)
+            .with_context(|| format!("Unexpected error modifier: {remaining}"));
+    }
+
+    Ok(CustomError {
+        name: name.to_string(),
+        inputs: parse_params(inputs)?,
+    })
+}
+
+/// Parses a parameter, e.g. `address to`, `uint256[]`, `(uint256 amount, address to)[] orders`.
+/// Data location keywords such as `memory` or `calldata` are skipped.
+pub fn parse_param(s: &str) -> AbiResult<Param> {
+    let (param, indexed) = parse_param_impl(s)?;
+    if indexed {
+        return AbiError::err(AbiErrorKind::Error_invalid_abi)
+            .context("Only event parameters can be indexed");
+    }
+    Ok(param)
+}
+
+fn parse_params(s: &str) -> AbiResult<Vec<Param>> {
+    split_params(s)?.into_iter().map(parse_param).collect()
+}
+
+/// Returns a param and whether it's indexed.
+fn parse_param_impl(s: &str) -> AbiResult<(Param, bool)> {
+    let s = s.trim();
+
+    let (kind, remaining) = if s.starts_with('(') || s.starts_with("tuple(") {
+        let components_start = s.find('(').expect("Expected to be checked above");
+        let components_end = find_closing_paren(s, components_start)?;
+        let components = parse_params(&s[components_start + 1..components_end])?;
+        if components.is_empty() {
+            return AbiError::err(AbiErrorKind::Error_invalid_abi)
+                .context("Tuple must have at least one component");
+        }
+
+        let after_tuple = &s[components_end + 1..];
+        let suffix_len = after_tuple
+            .find(char::is_whitespace)
+            .unwrap_or(after_tuple.len());
+        let (array_suffix, remaining) = after_tuple.split_at(suffix_len);
+
+        let kind = ParamType::Tuple { params: components };
+        (apply_array_suffix(kind, array_suffix)?, remaining)
+    } else {
+        let (type_str, remaining) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        if type_str == TUPLE_KEYWORD {
+            return AbiError::err(AbiErrorKind::Error_invalid_abi)
+                .context("Tuple components must be specified");
+        }
+        (ParamType::try_from_type_short(type_str)?, remaining)
+    };
+
+    let mut name = None;
+    let mut indexed = false;
+    for word in remaining.split_whitespace() {
+        match word {
+            "indexed" if !indexed => indexed = true,
+            "memory" | "calldata" | "storage" => (),
+            _ if name.is_none() && is_identifier(word) => name = Some(word.to_string()),
+            _ => {
+                return AbiError::err(AbiErrorKind::Error_invalid_abi)
+                    .with_context(|| format!("Unexpected '{word}' in parameter: {s}"))
+            },
+        }
+    }
+
+    let param = Param {
+        name,
+        kind,
+        internal_type: None,
+    };
+    Ok((param, indexed))
+}
+
+/// Splits the given `name(params) remaining` string into its parts.
+fn split_item(s: &str) -> AbiResult<(&str, &str, &str)> {
+    let params_start = s
+        .find('(')
+        .or_tw_err(AbiErrorKind::Error_invalid_abi)
+        .with_context(|| format!("Expected parameters in parentheses: {s}"))?;
+    let params_end = find_closing_paren(s, params_start)?;
+
+    let name = s[..params_start].trim();
+    if !is_identifier(name) {
+        return AbiError::err(AbiErrorKind::Error_invalid_abi)
+            .with_context(|| format!("Invalid name: '{name}'"));
+    }
+
+    let params = &s[params_start + 1..params_end];
+    let remaining = s[params_end + 1..].trim();
+    Ok((name, params, remaining))
+}
+
+/// Splits comma-separated parameters skipping commas within nested tuples.
+fn split_params(s: &str) -> AbiResult<Vec<&str>> {
+    if s.trim().is_empty() {
+        return Ok(Vec::default());
+    }
+
+    let mut params = Vec::default();
+    let mut depth = 0_usize;
+    let mut param_start = 0;
+    for (idx, ch) in s.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .or_tw_err(AbiErrorKind::Error_invalid_abi)
+                    .with_context(|| format!("Unbalanced parentheses: {s}"))?
+            },
+            ',' if depth == 0 => {
+                params.push(&s[param_start..idx]);
+                param_start = idx + 1;
+            },
+            _ => (),
+        }
+    }
+    params.push(&s[param_start..]);
+    Ok(params)
+}
+
+fn find_closing_paren(s: &str, open_idx: usize) -> AbiResult<usize> {
+    let mut depth = 0_usize;
+    for (idx, ch) in s.char_indices().skip_while(|(idx, _)| *idx < open_idx) {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(idx);
+                }
+            },
+            _ => (),
+        }
+    }
+    AbiError::err(AbiErrorKind::Error_invalid_abi)
+        .with_context(|| format!("Unbalanced parentheses: {s}"))
+}
+
+/// Applies an array suffix such as `[]` or `[2][]` to the given element type.
+fn apply_array_suffix(mut kind: ParamType, mut suffix: &str) -> AbiResult<ParamType> {
+    while !suffix.is_empty() {
+        let (len_str, remaining) = suffix
+            .strip_prefix('[')
+            .and_then(|suffix| suffix.split_once(']'))
+            .or_tw_err(AbiErrorKind::Error_invalid_param_type)
+            .with_context(|| format!("Invalid array suffix: {suffix}"))?;
+
+        kind = if len_str.is_empty() {
+            ParamType::array(kind)
+        } else {
+            let len = usize::from_str(len_str)
+                .tw_err(|_| AbiErrorKind::Error_invalid_param_type)
+                .with_context(|| format!("Invalid array length: {len_str}"))?;
+            ParamType::fixed_array(len, kind)?
+        };
+        suffix = remaining;
+    }
+    Ok(kind)
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let is_valid_char = |ch: char| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$';
+    !first.is_ascii_digit() && is_valid_char(first) && chars.all(is_valid_char)
+}
+
+/// Removes the leading keyword if it's followed by a whitespace.
+fn strip_keyword<'a>(s: &'a str, keyword: &str) -> &'a str {
+    match s.strip_prefix(keyword) {
+        Some(remaining) if remaining.starts_with(char::is_whitespace) => remaining.trim_start(),
+        _ => s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::uint::UintBits;
+
+    #[test]
+    fn test_parse_function() {
+        let function =
+            parse_function("function transfer(address to, uint256 amount) external returns (bool)")
+                .unwrap();
+        assert_eq!(function.name, "transfer");
+        assert_eq!(
+            function.inputs,
+            vec![
+                Param {
+                    name: Some("to".to_string()),
+                    kind: ParamType::Address,
+                    internal_type: None,
+                },
+                Param {
+                    name: Some("amount".to_string()),
+                    kind: ParamType::u256(),
+                    internal_type: None,
+                },
+            ]
+        );
+        assert_eq!(function.outputs, vec![Param::with_type(ParamType::Bool)]);
+        assert_eq!(function.selector().to_string(), "a9059cbb");
+    }
+
+    #[test]
+    fn test_parse_function_with_tuples() {
+        let function = parse_function(
+            "fill((address maker, uint256[2] amounts)[] orders, bytes calldata signature) payable",
+        )
+        .unwrap();
+        assert_eq!(function.name, "fill");
+        assert!(function.outputs.is_empty());
+
+        let order = ParamType::Tuple {
+            params: vec![
+                Param {
+                    name: Some("maker".to_string()),
+                    kind: ParamType::Address,
+                    internal_type: None,
+                },
+                Param {
+                    name: Some("amounts".to_string()),
+                    kind: ParamType::fixed_array(2, ParamType::u256()).unwrap(),
+                    internal_type: None,
+                },
+            ],
+        };
+        assert_eq!(function.inputs[0].name.as_deref(), Some("orders"));
+        assert_eq!(function.inputs[0].kind, ParamType::array(order));
+        assert_eq!(function.inputs[1].kind, ParamType::Bytes);
+        assert_eq!(function.signature(), "fill((address,uint256[2])[],bytes)");
+    }
+
+    #[test]
+    fn test_parse_event() {
+        let event =
+            parse_event("event Transfer(address indexed from, address indexed to, uint256 value)")
+                .unwrap();
+        assert_eq!(event.name, "Transfer");
+        assert!(!event.anonymous);
+        let indexed: Vec<_> = event.inputs.iter().map(|input| input.indexed).collect();
+        assert_eq!(indexed, vec![true, true, false]);
+        assert_eq!(
+            event.topic0().to_string(),
+            "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+
+        let event = parse_event("event Ping(address indexed) anonymous").unwrap();
+        assert!(event.anonymous);
+        assert_eq!(event.inputs[0].param.name, None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error =
+            parse_error("error InsufficientBalance(uint256 available, uint256 required)").unwrap();
+        assert_eq!(error.signature(), "InsufficientBalance(uint256,uint256)");
+        assert_eq!(error.selector().to_string(), "cf479181");
+    }
+
+    #[test]
+    fn test_parse_contract() {
+        let contract = parse_contract([
+            "constructor(string name)",
+            "function balanceOf(address owner) view returns (uint256)",
+            "event Approval(address indexed owner, address indexed spender, uint256 value)",
+            "error Unauthorized()",
+        ])
+        .unwrap();
+        assert_eq!(contract.function("balanceOf").unwrap().outputs.len(), 1);
+        assert_eq!(contract.event("Approval").unwrap().inputs.len(), 3);
+        assert!(contract.errors["Unauthorized"][0].inputs.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let invalid_functions = [
+            "function transfer(address to, uint256 amount",
+            "function transfer(address to, uint256 amount) returns bool",
+            "function transfer(address to, uint256 amount) unknown",
+            "function (address to)",
+            "function transfer(address indexed to)",
+            "function transfer(address to from)",
+            "function transfer(uint7 amount)",
+            "function transfer(tuple amount)",
+        ];
+        for function in invalid_functions {
+            parse_function(function).expect_err(function);
+        }
+
+        let uint7 = parse_param("uint7").unwrap_err();
+        assert_eq!(*uint7.error_type(), AbiErrorKind::Error_invalid_uint_value);
+
+        parse_contract(["struct Order(address maker)"]).unwrap_err();
+        parse_param("(uint256, address)[0]").unwrap_err();
+        assert_eq!(
+            parse_param("uint").unwrap().kind,
+            ParamType::Uint {
+                bits: UintBits::default()
+            }
+        );
+    }
+}
@@ -4,9 +4,10 @@
 
 use crate::abi::contract::Contract;
 use crate::abi::token::Token;
-use crate::abi::AbiResult;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use crate::address::Address;
 use lazy_static::lazy_static;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
 
@@ -18,6 +19,14 @@ lazy_static! {
     static ref ERC1155: Contract = serde_json::from_str(ERC1155_ABI).unwrap();
 }
 
+pub struct SafeTransferFromArgs {
+    pub from: Address,
+    pub to: Address,
+    pub token_id: U256,
+    pub value: U256,
+    pub data: Data,
+}
+
 pub struct Erc1155;
 
 impl Erc1155 {
@@ -37,4 +46,26 @@ impl Erc1155 {
             Token::Bytes(data),
         ])
     }
+
+    /// Decodes an ERC1155 `safeTransferFrom` call.
+    /// Returns `Ok(None)` if the call data is not a `safeTransferFrom` call.
+    pub fn decode_safe_transfer_from(data: &[u8]) -> AbiResult<Option<SafeTransferFromArgs>> {
+        let func = ERC1155.function("safeTransferFrom")?;
+        let Some(tokens) = func.decode_call(data)? else {
+            return Ok(None);
+        };
+        match tokens.as_slice() {
+            [Token::Address(from), Token::Address(to), Token::Uint { uint: token_id, .. }, Token::Uint { uint: value, .. }, Token::Bytes(data)] => {
+                Ok(Some(SafeTransferFromArgs {
+                    from: *from,
+                    to: *to,
+                    token_id: *token_id,
+                    value: *value,
+                    data: data.clone(),
+                }))
+            },
+            _ => AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("Unexpected ERC1155 'safeTransferFrom' arguments"),
+        }
+    }
 }
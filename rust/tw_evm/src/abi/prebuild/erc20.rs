@@ -4,9 +4,10 @@
 
 use crate::abi::contract::Contract;
 use crate::abi::token::Token;
-use crate::abi::AbiResult;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use crate::address::Address;
 use lazy_static::lazy_static;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
 
@@ -18,6 +19,16 @@ lazy_static! {
     static ref ERC20: Contract = serde_json::from_str(ERC20_ABI).unwrap();
 }
 
+pub struct TransferArgs {
+    pub recipient: Address,
+    pub amount: U256,
+}
+
+pub struct ApproveArgs {
+    pub spender: Address,
+    pub amount: U256,
+}
+
 pub struct Erc20;
 
 impl Erc20 {
@@ -30,4 +41,40 @@ impl Erc20 {
         let func = ERC20.function("approve")?;
         func.encode_input(&[Token::Address(spender), Token::u256(amount)])
     }
+
+    /// Decodes an ERC20 `transfer` call.
+    /// Returns `Ok(None)` if the call data is not a `transfer` call.
+    pub fn decode_transfer(data: &[u8]) -> AbiResult<Option<TransferArgs>> {
+        let func = ERC20.function("transfer")?;
+        let Some(tokens) = func.decode_call(data)? else {
+            return Ok(None);
+        };
+        match tokens.as_slice() {
+            [Token::Address(recipient), Token::Uint { uint: amount, .. }] => {
+                Ok(Some(TransferArgs {
+                    recipient: *recipient,
+                    amount: *amount,
+                }))
+            },
+            _ => AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("Unexpected ERC20 'transfer' arguments"),
+        }
+    }
+
+    /// Decodes an ERC20 `approve` call.
+    /// Returns `Ok(None)` if the call data is not an `approve` call.
+    pub fn decode_approve(data: &[u8]) -> AbiResult<Option<ApproveArgs>> {
+        let func = ERC20.function("approve")?;
+        let Some(tokens) = func.decode_call(data)? else {
+            return Ok(None);
+        };
+        match tokens.as_slice() {
+            [Token::Address(spender), Token::Uint { uint: amount, .. }] => Ok(Some(ApproveArgs {
+                spender: *spender,
+                amount: *amount,
+            })),
+            _ => AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("Unexpected ERC20 'approve' arguments"),
+        }
+    }
 }
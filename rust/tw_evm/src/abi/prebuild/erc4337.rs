@@ -5,9 +5,10 @@
 use crate::abi::contract::Contract;
 use crate::abi::param_type::ParamType;
 use crate::abi::token::Token;
-use crate::abi::AbiResult;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use crate::address::Address;
 use lazy_static::lazy_static;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
 
@@ -66,4 +67,62 @@ impl Erc4337SimpleAccount {
             Token::array(ParamType::Bytes, datas),
         ])
     }
+
+    /// Decodes an `execute` call.
+    /// Returns `Ok(None)` if the call data is not an `execute` call.
+    pub fn decode_execute(data: &[u8]) -> AbiResult<Option<ExecuteArgs>> {
+        let func = ERC4337_SIMPLE_ACCOUNT.function("execute")?;
+        let Some(tokens) = func.decode_call(data)? else {
+            return Ok(None);
+        };
+        match tokens.as_slice() {
+            [Token::Address(to), Token::Uint { uint: value, .. }, Token::Bytes(data)] => {
+                Ok(Some(ExecuteArgs {
+                    to: *to,
+                    value: *value,
+                    data: data.clone(),
+                }))
+            },
+            _ => AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("Unexpected 'execute' arguments"),
+        }
+    }
+
+    /// Decodes an `executeBatch` call.
+    /// Returns `Ok(None)` if the call data is not an `executeBatch` call.
+    pub fn decode_execute_batch(data: &[u8]) -> AbiResult<Option<Vec<ExecuteArgs>>> {
+        let func = ERC4337_SIMPLE_ACCOUNT.function("executeBatch")?;
+        let Some(tokens) = func.decode_call(data)? else {
+            return Ok(None);
+        };
+        let [Token::Array { arr: addresses, .. }, Token::Array { arr: values, .. }, Token::Array { arr: datas, .. }] =
+            tokens.as_slice()
+        else {
+            return AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("Unexpected 'executeBatch' arguments");
+        };
+
+        if addresses.len() != values.len() || addresses.len() != datas.len() {
+            return AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("'executeBatch' arguments must have the same length");
+        }
+
+        addresses
+            .iter()
+            .zip(values.iter())
+            .zip(datas.iter())
+            .map(|((to, value), data)| match (to, value, data) {
+                (Token::Address(to), Token::Uint { uint: value, .. }, Token::Bytes(data)) => {
+                    Ok(ExecuteArgs {
+                        to: *to,
+                        value: *value,
+                        data: data.clone(),
+                    })
+                },
+                _ => AbiError::err(AbiErrorKind::Error_decoding_data)
+                    .context("Unexpected 'executeBatch' call arguments"),
+            })
+            .collect::<AbiResult<Vec<_>>>()
+            .map(Some)
+    }
 }
@@ -4,9 +4,10 @@
 
 use crate::abi::contract::Contract;
 use crate::abi::token::Token;
-use crate::abi::AbiResult;
+use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use crate::address::Address;
 use lazy_static::lazy_static;
+use tw_coin_entry::error::prelude::*;
 use tw_memory::Data;
 use tw_number::U256;
 
@@ -18,6 +19,12 @@ lazy_static! {
     static ref ERC721: Contract = serde_json::from_str(ERC721_ABI).unwrap();
 }
 
+pub struct TransferFromArgs {
+    pub from: Address,
+    pub to: Address,
+    pub token_id: U256,
+}
+
 pub struct Erc721;
 
 impl Erc721 {
@@ -29,4 +36,24 @@ impl Erc721 {
             Token::u256(token_id),
         ])
     }
+
+    /// Decodes an ERC721 `transferFrom` call.
+    /// Returns `Ok(None)` if the call data is not a `transferFrom` call.
+    pub fn decode_transfer_from(data: &[u8]) -> AbiResult<Option<TransferFromArgs>> {
+        let func = ERC721.function("transferFrom")?;
+        let Some(tokens) = func.decode_call(data)? else {
+            return Ok(None);
+        };
+        match tokens.as_slice() {
+            [Token::Address(from), Token::Address(to), Token::Uint { uint: token_id, .. }] => {
+                Ok(Some(TransferFromArgs {
+                    from: *from,
+                    to: *to,
+                    token_id: *token_id,
+                }))
+            },
+            _ => AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("Unexpected ERC721 'transferFrom' arguments"),
+        }
+    }
 }
@@ -14,7 +14,9 @@ use tw_hash::{sha3::keccak256, H160, H256};
 use tw_keypair::ecdsa::secp256k1;
 use tw_memory::Data;
 
-pub trait EvmAddress: FromStr<Err = AddressError> + Into<Address> {
+pub trait EvmAddress:
+    FromStr<Err = AddressError> + From<Address> + Into<Address> + Display
+{
     /// Tries to parse an address from the string representation.
     /// Returns `Ok(None)` if the given `s` string is empty.
     #[inline]
@@ -36,6 +36,22 @@ pub trait EvmEntry {
         AbiEncoder::<Self::Context>::decode_params(input)
     }
 
+    /// Decodes an event log according to a given smart contract ABI.
+    #[inline]
+    fn decode_abi_event_log(
+        input: AbiProto::EventLogDecodingInput<'_>,
+    ) -> AbiProto::EventLogDecodingOutput<'static> {
+        AbiEncoder::<Self::Context>::decode_event_log(input)
+    }
+
+    /// Decodes revert data as `Error(string)`, `Panic(uint256)` or a custom error of a given ABI.
+    #[inline]
+    fn decode_abi_revert(
+        input: AbiProto::RevertDecodingInput<'_>,
+    ) -> AbiProto::RevertDecodingOutput<'static> {
+        AbiEncoder::<Self::Context>::decode_revert(input)
+    }
+
     /// Decodes an Eth ABI value according to a given type.
     #[inline]
     fn decode_abi_value(
@@ -70,6 +86,12 @@ pub trait EvmEntryExt {
     /// Decodes a function input or output data according to a given ABI.
     fn decode_abi_params(&self, input: &[u8]) -> ProtoResult<Data>;
 
+    /// Decodes an event log according to a given smart contract ABI.
+    fn decode_abi_event_log(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Decodes revert data as `Error(string)`, `Panic(uint256)` or a custom error of a given ABI.
+    fn decode_abi_revert(&self, input: &[u8]) -> ProtoResult<Data>;
+
     /// Returns the function type signature, of the form "baz(int32,uint256)".
     fn get_abi_function_signature(&self, input: &[u8]) -> ProtoResult<String>;
 
@@ -102,6 +124,18 @@ where
         serialize(&output)
     }
 
+    fn decode_abi_event_log(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::decode_abi_event_log(input);
+        serialize(&output)
+    }
+
+    fn decode_abi_revert(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::decode_abi_revert(input);
+        serialize(&output)
+    }
+
     fn get_abi_function_signature(&self, input: &[u8]) -> ProtoResult<String> {
         let input = deserialize(input)?;
         Ok(<Self as EvmEntry>::get_abi_function_signature(input))
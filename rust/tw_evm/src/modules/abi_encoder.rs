@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::abi::contract::Contract;
 use crate::abi::decode::{decode_params, decode_value};
 use crate::abi::function::Function;
 use crate::abi::param::Param;
@@ -19,7 +20,7 @@ use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use tw_encoding::hex::as_hex;
-use tw_hash::H32;
+use tw_hash::{H256, H32};
 use tw_misc::traits::ToBytesVec;
 use tw_number::{I256, U256};
 use tw_proto::EthereumAbi::Proto;
@@ -52,6 +53,22 @@ impl<Context: EvmContext> AbiEncoder<Context> {
             .unwrap_or_else(|err| abi_output_error!(Proto::ParamsDecodingOutput, err))
     }
 
+    #[inline]
+    pub fn decode_event_log(
+        input: Proto::EventLogDecodingInput<'_>,
+    ) -> Proto::EventLogDecodingOutput<'static> {
+        Self::decode_event_log_impl(input)
+            .unwrap_or_else(|err| abi_output_error!(Proto::EventLogDecodingOutput, err))
+    }
+
+    #[inline]
+    pub fn decode_revert(
+        input: Proto::RevertDecodingInput<'_>,
+    ) -> Proto::RevertDecodingOutput<'static> {
+        Self::decode_revert_impl(input)
+            .unwrap_or_else(|err| abi_output_error!(Proto::RevertDecodingOutput, err))
+    }
+
     #[inline]
     pub fn decode_value(
         input: Proto::ValueDecodingInput<'_>,
@@ -159,6 +176,94 @@ impl<Context: EvmContext> AbiEncoder<Context> {
         })
     }
 
+    fn decode_event_log_impl(
+        input: Proto::EventLogDecodingInput<'_>,
+    ) -> AbiResult<Proto::EventLogDecodingOutput<'static>> {
+        let contract: Contract = serde_json::from_str(&input.smart_contract_abi_json)
+            .tw_err(|_| AbiErrorKind::Error_invalid_abi)
+            .context("Error deserializing Smart Contract ABI as JSON")?;
+
+        let topics = input
+            .topics
+            .iter()
+            .map(|topic| {
+                H256::try_from(topic.as_ref())
+                    .tw_err(|_| AbiErrorKind::Error_decoding_data)
+                    .context("Each log topic must be 32 bytes long")
+            })
+            .collect::<AbiResult<Vec<_>>>()?;
+
+        let (event, decoded_tokens) = contract.decode_log(&topics, &input.data)?;
+        let event_signature = event.signature();
+
+        // Serialize the `decoded_json` result.
+        let decoded_res = EventLogDecodedJson {
+            event: event_signature.clone(),
+            inputs: &decoded_tokens,
+        };
+        let decoded_json = serde_json::to_string(&decoded_res)
+            .tw_err(|_| AbiErrorKind::Error_internal)
+            .context("Error serializing Event Log as JSON")?;
+
+        // Serialize the Proto parameters.
+        let decoded_protos = decoded_tokens
+            .into_iter()
+            .map(Self::named_token_to_proto)
+            .collect();
+
+        Ok(Proto::EventLogDecodingOutput {
+            event_signature: Cow::Owned(event_signature),
+            decoded_json: Cow::Owned(decoded_json),
+            tokens: decoded_protos,
+            ..Proto::EventLogDecodingOutput::default()
+        })
+    }
+
+    fn decode_revert_impl(
+        input: Proto::RevertDecodingInput<'_>,
+    ) -> AbiResult<Proto::RevertDecodingOutput<'static>> {
+        if input.encoded.len() < H32::len() {
+            return AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("Encoded revert data too short");
+        }
+
+        let contract: Contract = if input.smart_contract_abi_json.is_empty() {
+            Contract::default()
+        } else {
+            serde_json::from_str(&input.smart_contract_abi_json)
+                .tw_err(|_| AbiErrorKind::Error_invalid_abi)
+                .context("Error deserializing Smart Contract ABI as JSON")?
+        };
+
+        let decoded = contract.decode_revert(&input.encoded)?;
+        let error_signature = decoded.error.signature();
+        let reason = decoded.reason().unwrap_or_default();
+
+        // Serialize the `decoded_json` result.
+        let decoded_res = RevertDecodedJson {
+            error: error_signature.clone(),
+            inputs: &decoded.inputs,
+        };
+        let decoded_json = serde_json::to_string(&decoded_res)
+            .tw_err(|_| AbiErrorKind::Error_internal)
+            .context("Error serializing Revert Error as JSON")?;
+
+        // Serialize the Proto parameters.
+        let decoded_protos = decoded
+            .inputs
+            .into_iter()
+            .map(Self::named_token_to_proto)
+            .collect();
+
+        Ok(Proto::RevertDecodingOutput {
+            error_signature: Cow::Owned(error_signature),
+            decoded_json: Cow::Owned(decoded_json),
+            tokens: decoded_protos,
+            reason: Cow::Owned(reason),
+            ..Proto::RevertDecodingOutput::default()
+        })
+    }
+
     fn decode_value_impl(
         input: Proto::ValueDecodingInput<'_>,
     ) -> AbiResult<Proto::ValueDecodingOutput<'static>> {
@@ -483,6 +588,18 @@ struct SmartContractCallDecodedInputJson<'a> {
     inputs: &'a [NamedToken],
 }
 
+#[derive(Serialize)]
+struct EventLogDecodedJson<'a> {
+    event: String,
+    inputs: &'a [NamedToken],
+}
+
+#[derive(Serialize)]
+struct RevertDecodedJson<'a> {
+    error: String,
+    inputs: &'a [NamedToken],
+}
+
 /// A value type used on [`AbiEncoder::decode_value`].
 /// Please note [`AbiEncoder::decode_value`] doesn't support `ParamType::Tuple` for decoding.
 struct DecodingValueType(ParamType);
@@ -2,10 +2,12 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::address::Address;
 use crate::message::eip191::Eip191Message;
 use crate::message::eip712::eip712_message::Eip712Message;
 use crate::message::signature::{MessageSignature, SignatureType};
 use crate::message::{to_signing, EthMessage, EthMessageBoxed};
+use crate::transaction::authorization_list::Authorization;
 use std::borrow::Cow;
 use std::str::FromStr;
 use tw_coin_entry::coin_context::CoinContext;
@@ -111,6 +113,13 @@ impl EthMessageSigner {
             | Proto::MessageType::MessageType_immutable_x => {
                 Ok(Eip191Message::new(input.message).into_boxed())
             },
+            Proto::MessageType::MessageType_eip7702_authorization => {
+                let Some(ref authorization) = input.authorization else {
+                    return SigningError::err(SigningErrorType::Error_invalid_params)
+                        .context("No authorization specified");
+                };
+                Ok(Self::authorization_from_proto(authorization)?.into_boxed())
+            },
             Proto::MessageType::MessageType_typed
             | Proto::MessageType::MessageType_typed_eip155 => match input.chain_id {
                 Some(expected_chain_id) => {
@@ -126,6 +135,22 @@ impl EthMessageSigner {
         }
     }
 
+    fn authorization_from_proto(
+        authorization: &Proto::Authorization<'_>,
+    ) -> SigningResult<Authorization> {
+        Ok(Authorization {
+            chain_id: U256::from_big_endian_slice(&authorization.chain_id)
+                .into_tw()
+                .context("Invalid authorization chain ID")?,
+            address: Address::from_str(&authorization.address)
+                .into_tw()
+                .context("Invalid authorization address")?,
+            nonce: U256::from_big_endian_slice(&authorization.nonce)
+                .into_tw()
+                .context("Invalid authorization nonce")?,
+        })
+    }
+
     fn message_from_str(user_message: &str) -> SigningResult<EthMessageBoxed> {
         match Eip712Message::new(user_message) {
             Ok(typed_data) => Ok(typed_data.into_boxed()),
@@ -138,7 +163,8 @@ impl EthMessageSigner {
         maybe_chain_id: Option<Proto::MaybeChainId>,
     ) -> SignatureType {
         match msg_type {
-            Proto::MessageType::MessageType_immutable_x => SignatureType::Standard,
+            Proto::MessageType::MessageType_immutable_x
+            | Proto::MessageType::MessageType_eip7702_authorization => SignatureType::Standard,
             Proto::MessageType::MessageType_legacy | Proto::MessageType::MessageType_typed => {
                 SignatureType::Legacy
             },
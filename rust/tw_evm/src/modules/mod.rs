@@ -7,5 +7,6 @@ pub mod compiler;
 pub mod message_signer;
 pub mod rlp_encoder;
 pub mod signer;
+pub mod transaction_decoder;
 pub mod transaction_util;
 pub mod tx_builder;
@@ -0,0 +1,307 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::prebuild::erc1155::Erc1155;
+use crate::abi::prebuild::erc20::Erc20;
+use crate::abi::prebuild::erc4337::{Erc4337SimpleAccount, ExecuteArgs};
+use crate::abi::prebuild::erc721::Erc721;
+use crate::address::Address;
+use crate::evm_context::EvmContext;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::decoded_transaction::DecodedTransaction;
+use crate::transaction::signature::{EthSignature, Signature, SignatureEip155};
+use crate::transaction::transaction_eip1559::TransactionEip1559;
+use crate::transaction::transaction_eip2930::TransactionEip2930;
+use crate::transaction::transaction_non_typed::TransactionNonTyped;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_decoder::TransactionDecoder;
+use tw_coin_entry::signing_output_error;
+use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+
+const SIGNATURE_V_MIN_LEN: usize = 1;
+
+/// Legacy transactions are RLP lists, and RLP list headers are always greater than `0xbf`.
+/// Typed transactions start with a transaction type in the `[0x00, 0x7f]` range.
+/// https://eips.ethereum.org/EIPS/eip-2718
+const MAX_TX_TYPE: u8 = 0x7f;
+const EIP2930_TX_TYPE: u8 = 0x01;
+const EIP1559_TX_TYPE: u8 = 0x02;
+
+/// Common fields of the supported transaction types.
+struct CommonFields<'a> {
+    nonce: U256,
+    gas_limit: U256,
+    to: Option<Address>,
+    amount: U256,
+    payload: &'a [u8],
+}
+
+pub struct EvmTransactionDecoder<Context: EvmContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: EvmContext> Default for EvmTransactionDecoder<Context> {
+    fn default() -> Self {
+        EvmTransactionDecoder {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Context: EvmContext> TransactionDecoder for EvmTransactionDecoder<Context> {
+    type Output = Proto::DecodingTransactionOutput<'static>;
+
+    fn decode_transaction(&self, _coin: &dyn CoinContext, tx: &[u8]) -> Self::Output {
+        Self::decode_transaction_impl(tx)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingTransactionOutput, e))
+    }
+}
+
+impl<Context: EvmContext> EvmTransactionDecoder<Context> {
+    /// Decodes a raw legacy, EIP2930 or EIP1559 transaction, either signed or unsigned.
+    pub fn decode_transaction_impl(
+        tx: &[u8],
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        match tx.first() {
+            Some(&EIP2930_TX_TYPE) => Self::decode_eip2930(tx),
+            Some(&EIP1559_TX_TYPE) => Self::decode_eip1559(tx),
+            Some(tx_type) if *tx_type <= MAX_TX_TYPE => {
+                SigningError::err(SigningErrorType::Error_not_supported)
+                    .with_context(|| format!("Transaction type '{tx_type:#04x}' is not supported"))
+            },
+            Some(_) => Self::decode_legacy(tx),
+            None => {
+                SigningError::err(SigningErrorType::Error_input_parse).context("Empty transaction")
+            },
+        }
+    }
+
+    fn decode_legacy(tx: &[u8]) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let decoded = TransactionNonTyped::decode(tx)?;
+        let unsigned = &decoded.unsigned;
+
+        let common = CommonFields {
+            nonce: unsigned.nonce,
+            gas_limit: unsigned.gas_limit,
+            to: unsigned.to,
+            amount: unsigned.amount,
+            payload: &unsigned.payload,
+        };
+        let input = Proto::SigningInput {
+            tx_mode: Proto::TransactionMode::Legacy,
+            gas_price: u256_to_proto(&unsigned.gas_price),
+            ..Self::signing_input_from_common(decoded.chain_id, common)
+        };
+
+        let signature = decoded
+            .signature
+            .clone()
+            .map(|signature| SignatureEip155::new(signature, decoded.chain_id))
+            .transpose()?;
+        Self::decoding_output(input, &decoded, signature)
+    }
+
+    fn decode_eip2930(tx: &[u8]) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let decoded = TransactionEip2930::decode(tx)?;
+        let unsigned = &decoded.unsigned;
+
+        let common = CommonFields {
+            nonce: unsigned.nonce,
+            gas_limit: unsigned.gas_limit,
+            to: unsigned.to,
+            amount: unsigned.amount,
+            payload: &unsigned.payload,
+        };
+        let input = Proto::SigningInput {
+            tx_mode: Proto::TransactionMode::Eip2930,
+            gas_price: u256_to_proto(&unsigned.gas_price),
+            access_list: Self::access_list_to_proto(&unsigned.access_list),
+            ..Self::signing_input_from_common(decoded.chain_id, common)
+        };
+
+        let signature = decoded.signature.clone().map(Signature::new);
+        Self::decoding_output(input, &decoded, signature)
+    }
+
+    fn decode_eip1559(tx: &[u8]) -> SigningResult<Proto::DecodingTransactionOutput<'static>> {
+        let decoded = TransactionEip1559::decode(tx)?;
+        let unsigned = &decoded.unsigned;
+
+        let common = CommonFields {
+            nonce: unsigned.nonce,
+            gas_limit: unsigned.gas_limit,
+            to: unsigned.to,
+            amount: unsigned.amount,
+            payload: &unsigned.payload,
+        };
+        let input = Proto::SigningInput {
+            tx_mode: Proto::TransactionMode::Enveloped,
+            max_inclusion_fee_per_gas: u256_to_proto(&unsigned.max_inclusion_fee_per_gas),
+            max_fee_per_gas: u256_to_proto(&unsigned.max_fee_per_gas),
+            access_list: Self::access_list_to_proto(&unsigned.access_list),
+            ..Self::signing_input_from_common(decoded.chain_id, common)
+        };
+
+        let signature = decoded.signature.clone().map(Signature::new);
+        Self::decoding_output(input, &decoded, signature)
+    }
+
+    fn decoding_output<Tx, Sign>(
+        input: Proto::SigningInput<'static>,
+        decoded: &DecodedTransaction<Tx>,
+        signature: Option<Sign>,
+    ) -> SigningResult<Proto::DecodingTransactionOutput<'static>>
+    where
+        Sign: EthSignature,
+    {
+        let mut output = Proto::DecodingTransactionOutput {
+            transaction: Some(input),
+            pre_hash: Cow::from(decoded.pre_hash.to_vec()),
+            ..Proto::DecodingTransactionOutput::default()
+        };
+
+        let (Some(signature), Some(public_key)) = (signature, decoded.recover_public_key()?) else {
+            return Ok(output);
+        };
+
+        output.is_signed = true;
+        output.from_address = Cow::from(Self::address_to_string(Address::with_secp256k1_pubkey(
+            &public_key,
+        )));
+        output.v = Cow::from(
+            signature
+                .v()
+                .to_big_endian_compact_min_len(SIGNATURE_V_MIN_LEN),
+        );
+        output.r = Cow::from(signature.r().to_big_endian().to_vec());
+        output.s = Cow::from(signature.s().to_big_endian().to_vec());
+        Ok(output)
+    }
+
+    fn signing_input_from_common(
+        chain_id: U256,
+        common: CommonFields<'_>,
+    ) -> Proto::SigningInput<'static> {
+        let to_address = common.to.map(Self::address_to_string).unwrap_or_default();
+        let transaction = Self::transaction_to_proto(common.to, common.amount, common.payload);
+
+        Proto::SigningInput {
+            chain_id: u256_to_proto(&chain_id),
+            nonce: u256_to_proto(&common.nonce),
+            gas_limit: u256_to_proto(&common.gas_limit),
+            to_address: Cow::from(to_address),
+            transaction: Some(transaction),
+            ..Proto::SigningInput::default()
+        }
+    }
+
+    /// Recognizes the transaction payload as one of the known contract calls.
+    /// Token calls are recognized only if there is no native coin amount attached.
+    fn transaction_to_proto(
+        to: Option<Address>,
+        amount: U256,
+        payload: &[u8],
+    ) -> Proto::Transaction<'static> {
+        use Proto::mod_Transaction::OneOftransaction_oneof as Tx;
+
+        let contract_generic = || {
+            Tx::contract_generic(Proto::mod_Transaction::ContractGeneric {
+                amount: u256_to_proto(&amount),
+                data: Cow::from(payload.to_vec()),
+            })
+        };
+
+        let transaction_oneof = if to.is_none() {
+            // Contract deployment.
+            contract_generic()
+        } else if payload.is_empty() {
+            Tx::transfer(Proto::mod_Transaction::Transfer {
+                amount: u256_to_proto(&amount),
+                data: Cow::default(),
+            })
+        } else if let Some(execute) = Erc4337SimpleAccount::decode_execute(payload).ok().flatten() {
+            Self::batch_to_proto(vec![execute])
+        } else if let Some(calls) = Erc4337SimpleAccount::decode_execute_batch(payload)
+            .ok()
+            .flatten()
+        {
+            Self::batch_to_proto(calls)
+        } else if !amount.is_zero() {
+            contract_generic()
+        } else if let Some(transfer) = Erc20::decode_transfer(payload).ok().flatten() {
+            Tx::erc20_transfer(Proto::mod_Transaction::ERC20Transfer {
+                to: Cow::from(Self::address_to_string(transfer.recipient)),
+                amount: u256_to_proto(&transfer.amount),
+            })
+        } else if let Some(approve) = Erc20::decode_approve(payload).ok().flatten() {
+            Tx::erc20_approve(Proto::mod_Transaction::ERC20Approve {
+                spender: Cow::from(Self::address_to_string(approve.spender)),
+                amount: u256_to_proto(&approve.amount),
+            })
+        } else if let Some(transfer) = Erc721::decode_transfer_from(payload).ok().flatten() {
+            // Please note ERC721 `transferFrom` has the same selector as ERC20 `transferFrom`.
+            Tx::erc721_transfer(Proto::mod_Transaction::ERC721Transfer {
+                from: Cow::from(Self::address_to_string(transfer.from)),
+                to: Cow::from(Self::address_to_string(transfer.to)),
+                token_id: u256_to_proto(&transfer.token_id),
+            })
+        } else if let Some(transfer) = Erc1155::decode_safe_transfer_from(payload).ok().flatten() {
+            Tx::erc1155_transfer(Proto::mod_Transaction::ERC1155Transfer {
+                from: Cow::from(Self::address_to_string(transfer.from)),
+                to: Cow::from(Self::address_to_string(transfer.to)),
+                token_id: u256_to_proto(&transfer.token_id),
+                value: u256_to_proto(&transfer.value),
+                data: Cow::from(transfer.data),
+            })
+        } else {
+            contract_generic()
+        };
+
+        Proto::Transaction { transaction_oneof }
+    }
+
+    fn batch_to_proto(
+        calls: Vec<ExecuteArgs>,
+    ) -> Proto::mod_Transaction::OneOftransaction_oneof<'static> {
+        let calls = calls
+            .into_iter()
+            .map(|call| Proto::mod_Transaction::mod_Batch::BatchedCall {
+                address: Cow::from(Self::address_to_string(call.to)),
+                amount: u256_to_proto(&call.value),
+                payload: Cow::from(call.data),
+            })
+            .collect();
+        Proto::mod_Transaction::OneOftransaction_oneof::batch(Proto::mod_Transaction::Batch {
+            calls,
+        })
+    }
+
+    fn access_list_to_proto(access_list: &AccessList) -> Vec<Proto::Access<'static>> {
+        access_list
+            .iter()
+            .map(|access| Proto::Access {
+                address: Cow::from(Self::address_to_string(access.address)),
+                stored_keys: access
+                    .storage_keys
+                    .iter()
+                    .map(|key| Cow::from(key.to_vec()))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn address_to_string(addr: Address) -> String {
+        Context::Address::from(addr).to_string()
+    }
+}
+
+fn u256_to_proto(num: &U256) -> Cow<'static, [u8]> {
+    let data: Data = num.to_big_endian_compact();
+    Cow::from(data)
+}
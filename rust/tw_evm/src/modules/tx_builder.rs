@@ -10,14 +10,26 @@ use crate::abi::prebuild::erc721::Erc721;
 use crate::address::{Address, EvmAddress};
 use crate::evm_context::EvmContext;
 use crate::transaction::access_list::{Access, AccessList};
+use crate::transaction::authorization_list::{
+    Authorization, AuthorizationList, SignedAuthorization,
+};
+use crate::transaction::signature::Signature;
 use crate::transaction::transaction_eip1559::TransactionEip1559;
+use crate::transaction::transaction_eip2930::TransactionEip2930;
+use crate::transaction::transaction_eip4844::{
+    BlobSidecar, TransactionEip4844, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_PROOF,
+};
+use crate::transaction::transaction_eip7702::TransactionEip7702;
 use crate::transaction::transaction_non_typed::TransactionNonTyped;
 use crate::transaction::user_operation::UserOperation;
+use crate::transaction::user_operation_v0_7::UserOperationV0_7;
 use crate::transaction::UnsignedTransactionBox;
+use std::borrow::Cow;
 use std::marker::PhantomData;
 use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
 use tw_memory::Data;
 use tw_number::U256;
 use tw_proto::Common::Proto::SigningError as CommonError;
@@ -148,8 +160,7 @@ impl<Context: EvmContext> TxBuilder<Context> {
                 let payload = Erc4337SimpleAccount::encode_execute_batch(calls)
                     .map_err(abi_to_signing_error)?;
 
-                return Self::user_operation_from_proto(input, payload)
-                    .map(UserOperation::into_boxed);
+                return Self::user_operation_from_proto(input, payload);
             },
             Tx::None => {
                 return SigningError::err(SigningErrorType::Error_invalid_params)
@@ -164,6 +175,21 @@ impl<Context: EvmContext> TxBuilder<Context> {
             TxMode::Enveloped => {
                 Self::transaction_eip1559_from_proto(input, eth_amount, payload, to)?.into_boxed()
             },
+            TxMode::Eip2930 => {
+                Self::transaction_eip2930_from_proto(input, eth_amount, payload, to)?.into_boxed()
+            },
+            TxMode::Eip4844 => {
+                let to = to
+                    .or_tw_err(SigningErrorType::Error_invalid_address)
+                    .context("Blob transaction cannot create a contract, destination address must be specified")?;
+                Self::transaction_eip4844_from_proto(input, eth_amount, payload, to)?.into_boxed()
+            },
+            TxMode::Eip7702 => {
+                let to = to
+                    .or_tw_err(SigningErrorType::Error_invalid_address)
+                    .context("Set-code transaction cannot create a contract, destination address must be specified")?;
+                Self::transaction_eip7702_from_proto(input, eth_amount, payload, to)?.into_boxed()
+            },
             TxMode::UserOp => {
                 let to = to
                     .or_tw_err(SigningErrorType::Error_invalid_address)
@@ -176,7 +202,7 @@ impl<Context: EvmContext> TxBuilder<Context> {
                 })
                 .map_err(abi_to_signing_error)?;
 
-                Self::user_operation_from_proto(input, payload)?.into_boxed()
+                Self::user_operation_from_proto(input, payload)?
             },
         };
         Ok(tx)
@@ -266,9 +292,199 @@ impl<Context: EvmContext> TxBuilder<Context> {
         })
     }
 
+    #[inline]
+    fn transaction_eip2930_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Option<Address>,
+    ) -> SigningResult<TransactionEip2930> {
+        let nonce = U256::from_big_endian_slice(&input.nonce)
+            .into_tw()
+            .context("Invalid nonce")?;
+
+        let gas_price = U256::from_big_endian_slice(&input.gas_price)
+            .into_tw()
+            .context("Invalid gas price")?;
+
+        let gas_limit = U256::from_big_endian_slice(&input.gas_limit)
+            .into_tw()
+            .context("Invalid gas limit")?;
+
+        let access_list =
+            Self::parse_access_list(&input.access_list).context("Invalid access list")?;
+
+        Ok(TransactionEip2930 {
+            nonce,
+            gas_price,
+            gas_limit,
+            to: to_address,
+            amount: eth_amount,
+            payload,
+            access_list,
+        })
+    }
+
+    fn transaction_eip4844_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Address,
+    ) -> SigningResult<TransactionEip4844> {
+        let TransactionEip1559 {
+            nonce,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            amount,
+            payload,
+            access_list,
+            ..
+        } = Self::transaction_eip1559_from_proto(input, eth_amount, payload, Some(to_address))?;
+
+        let max_fee_per_blob_gas = U256::from_big_endian_slice(&input.max_fee_per_blob_gas)
+            .into_tw()
+            .context("Invalid max fee per blob gas")?;
+
+        let sidecar = input
+            .blob_sidecar
+            .as_ref()
+            .map(Self::parse_blob_sidecar)
+            .transpose()?;
+
+        let blob_versioned_hashes = input
+            .blob_versioned_hashes
+            .iter()
+            .map(|hash| {
+                H256::try_from(hash.as_ref())
+                    .tw_err(|_| SigningErrorType::Error_invalid_params)
+                    .context("Invalid blob versioned hash")
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let blob_versioned_hashes = match sidecar {
+            Some(ref sidecar) if blob_versioned_hashes.is_empty() => sidecar.versioned_hashes(),
+            Some(ref sidecar) => {
+                if sidecar.versioned_hashes() != blob_versioned_hashes {
+                    return SigningError::err(SigningErrorType::Error_invalid_params)
+                        .context("Blob versioned hashes do not match the KZG commitments");
+                }
+                blob_versioned_hashes
+            },
+            None => blob_versioned_hashes,
+        };
+
+        if blob_versioned_hashes.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Blob transaction must contain at least one blob");
+        }
+
+        Ok(TransactionEip4844 {
+            nonce,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            to: to_address,
+            amount,
+            payload,
+            access_list,
+            max_fee_per_blob_gas,
+            blob_versioned_hashes,
+            sidecar,
+        })
+    }
+
+    fn transaction_eip7702_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Address,
+    ) -> SigningResult<TransactionEip7702> {
+        let TransactionEip1559 {
+            nonce,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            amount,
+            payload,
+            access_list,
+            ..
+        } = Self::transaction_eip1559_from_proto(input, eth_amount, payload, Some(to_address))?;
+
+        let authorization_list = Self::parse_authorization_list(input)?;
+        if authorization_list.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Set-code transaction must contain at least one authorization");
+        }
+
+        Ok(TransactionEip7702 {
+            nonce,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            to: to_address,
+            amount,
+            payload,
+            access_list,
+            authorization_list,
+        })
+    }
+
+    fn parse_blob_sidecar(sidecar: &Proto::BlobSidecar) -> SigningResult<BlobSidecar> {
+        fn parse_items(
+            items: &[Cow<[u8]>],
+            expected_len: usize,
+            name: &str,
+        ) -> SigningResult<Vec<Data>> {
+            items
+                .iter()
+                .map(|item| {
+                    if item.len() != expected_len {
+                        return SigningError::err(SigningErrorType::Error_invalid_params)
+                            .with_context(|| {
+                                format!("Invalid blob {name} length, expected {expected_len} bytes")
+                            });
+                    }
+                    Ok(item.to_vec())
+                })
+                .collect()
+        }
+
+        let blobs = parse_items(&sidecar.blobs, BYTES_PER_BLOB, "data")?;
+        let commitments = parse_items(&sidecar.commitments, BYTES_PER_COMMITMENT, "commitment")?;
+        let proofs = parse_items(&sidecar.proofs, BYTES_PER_PROOF, "proof")?;
+
+        if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Expected the same number of blobs, KZG commitments and proofs");
+        }
+
+        Ok(BlobSidecar {
+            blobs,
+            commitments,
+            proofs,
+        })
+    }
+
     fn user_operation_from_proto(
         input: &Proto::SigningInput,
         erc4337_payload: Data,
+    ) -> SigningResult<Box<dyn UnsignedTransactionBox>> {
+        match (&input.user_operation, &input.user_operation_v0_7) {
+            (Some(_), Some(_)) => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Either v0.6 or v0.7 User Operation should be specified"),
+            (_, Some(ref user_op_v0_7)) => {
+                Self::user_operation_v0_7_from_proto(input, user_op_v0_7, erc4337_payload)
+                    .map(UserOperationV0_7::into_boxed)
+            },
+            _ => Self::user_operation_v0_6_from_proto(input, erc4337_payload)
+                .map(UserOperation::into_boxed),
+        }
+    }
+
+    fn user_operation_v0_6_from_proto(
+        input: &Proto::SigningInput,
+        erc4337_payload: Data,
     ) -> SigningResult<UserOperation> {
         let Some(ref user_op) = input.user_operation else {
             return SigningError::err(CommonError::Error_invalid_params)
@@ -321,6 +537,75 @@ impl<Context: EvmContext> TxBuilder<Context> {
         })
     }
 
+    fn user_operation_v0_7_from_proto(
+        input: &Proto::SigningInput,
+        user_op: &Proto::UserOperationV0_7,
+        erc4337_payload: Data,
+    ) -> SigningResult<UserOperationV0_7> {
+        let nonce = U256::from_big_endian_slice(&input.nonce)
+            .into_tw()
+            .context("Invalid nonce")?;
+
+        let call_gas_limit = Self::parse_u128(&input.gas_limit).context("Invalid gas limit")?;
+
+        let max_inclusion_fee_per_gas = Self::parse_u128(&input.max_inclusion_fee_per_gas)
+            .context("Invalid max inclusion fee per gas")?;
+
+        let max_fee_per_gas =
+            Self::parse_u128(&input.max_fee_per_gas).context("Invalid max fee per gas")?;
+
+        let entry_point =
+            Self::parse_address(user_op.entry_point.as_ref()).context("Invalid entry point")?;
+
+        let sender = Self::parse_address(user_op.sender.as_ref())
+            .context("Invalid User Operation sender")?;
+
+        let factory = Self::parse_address_optional(user_op.factory.as_ref())
+            .context("Invalid User Operation factory")?;
+
+        let verification_gas_limit = Self::parse_u128(&user_op.verification_gas_limit)
+            .context("Invalid verification gas limit")?;
+
+        let pre_verification_gas = U256::from_big_endian_slice(&user_op.pre_verification_gas)
+            .into_tw()
+            .context("Invalid pre-verification gas")?;
+
+        let paymaster = Self::parse_address_optional(user_op.paymaster.as_ref())
+            .context("Invalid paymaster")?;
+
+        let paymaster_verification_gas_limit =
+            Self::parse_u128(&user_op.paymaster_verification_gas_limit)
+                .context("Invalid paymaster verification gas limit")?;
+
+        let paymaster_post_op_gas_limit = Self::parse_u128(&user_op.paymaster_post_op_gas_limit)
+            .context("Invalid paymaster post-op gas limit")?;
+
+        Ok(UserOperationV0_7 {
+            nonce,
+            entry_point,
+            sender,
+            factory,
+            factory_data: user_op.factory_data.to_vec(),
+            call_gas_limit,
+            verification_gas_limit,
+            pre_verification_gas,
+            max_fee_per_gas,
+            max_inclusion_fee_per_gas,
+            paymaster,
+            paymaster_verification_gas_limit,
+            paymaster_post_op_gas_limit,
+            paymaster_data: user_op.paymaster_data.to_vec(),
+            payload: erc4337_payload,
+        })
+    }
+
+    fn parse_u128(data: &[u8]) -> SigningResult<u128> {
+        let num = U256::from_big_endian_slice(data).into_tw()?;
+        u128::try_from(num)
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Expected a uint128 value")
+    }
+
     fn parse_address(addr: &str) -> SigningResult<Address> {
         Context::Address::from_str(addr)
             .map(Context::Address::into)
@@ -343,6 +628,64 @@ impl<Context: EvmContext> TxBuilder<Context> {
         Ok(access_list)
     }
 
+    fn parse_authorization_list(input: &Proto::SigningInput) -> SigningResult<AuthorizationList> {
+        let mut authorization_list = AuthorizationList::default();
+        for signed_proto in input.authorization_list.iter() {
+            let authorization = Self::parse_signed_authorization(signed_proto, &input.private_key)
+                .context("Invalid authorization")?;
+            authorization_list.add_authorization(authorization);
+        }
+        Ok(authorization_list)
+    }
+
+    fn parse_signed_authorization(
+        signed_proto: &Proto::SignedAuthorization,
+        private_key: &[u8],
+    ) -> SigningResult<SignedAuthorization> {
+        let Some(ref authorization_proto) = signed_proto.authorization else {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No authorization specified");
+        };
+
+        let authorization = Authorization {
+            chain_id: U256::from_big_endian_slice(&authorization_proto.chain_id)
+                .into_tw()
+                .context("Invalid authorization chain ID")?,
+            address: Self::parse_address(&authorization_proto.address)
+                .context("Invalid authorization address")?,
+            nonce: U256::from_big_endian_slice(&authorization_proto.nonce)
+                .into_tw()
+                .context("Invalid authorization nonce")?,
+        };
+
+        // Sign the authorization with the transaction signer key if the signature is not provided.
+        if signed_proto.r.is_empty() && signed_proto.s.is_empty() {
+            let private_key = secp256k1::PrivateKey::try_from(private_key)
+                .into_tw()
+                .context("Authorization is not signed, expected a valid private key")?;
+            return authorization.sign(&private_key);
+        }
+
+        let y_parity = u8::try_from(signed_proto.y_parity)
+            .tw_err(|_| SigningErrorType::Error_invalid_params)
+            .context("Invalid authorization 'y_parity'")?;
+        let r = U256::from_big_endian_slice(&signed_proto.r)
+            .into_tw()
+            .context("Invalid authorization 'r'")?;
+        let s = U256::from_big_endian_slice(&signed_proto.s)
+            .into_tw()
+            .context("Invalid authorization 's'")?;
+        let signature =
+            secp256k1::Signature::try_from_parts(r.to_big_endian(), s.to_big_endian(), y_parity)
+                .into_tw()
+                .context("Invalid authorization signature")?;
+
+        Ok(SignedAuthorization {
+            authorization,
+            signature: Signature::new(signature),
+        })
+    }
+
     fn parse_access(access_proto: &Proto::Access) -> SigningResult<Access> {
         let addr =
             Self::parse_address(access_proto.address.as_ref()).context("Invalid access address")?;
@@ -4,8 +4,11 @@
 
 use crate::address::Address;
 use crate::rlp::buffer::RlpBuffer;
-use crate::rlp::RlpEncode;
+use crate::rlp::item::RlpItem;
+use crate::rlp::{RlpDecode, RlpEncode};
+use tw_coin_entry::error::prelude::*;
 use tw_hash::H256;
+use tw_memory::Data;
 use tw_number::U256;
 
 impl RlpEncode for U256 {
@@ -46,3 +49,47 @@ impl RlpEncode for str {
         buf.append_data(self.as_bytes())
     }
 }
+
+impl RlpDecode for U256 {
+    fn rlp_decode(item: &RlpItem<'_>) -> SigningResult<Self> {
+        let bytes = item.as_data()?;
+        if bytes.first() == Some(&0) {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("RLP integer must not have leading zeros");
+        }
+        U256::from_big_endian_slice(bytes)
+            .into_tw()
+            .context("Invalid RLP integer")
+    }
+}
+
+impl RlpDecode for H256 {
+    fn rlp_decode(item: &RlpItem<'_>) -> SigningResult<Self> {
+        H256::try_from(item.as_data()?)
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Expected a 32 byte RLP string")
+    }
+}
+
+impl RlpDecode for Address {
+    fn rlp_decode(item: &RlpItem<'_>) -> SigningResult<Self> {
+        Address::try_from(item.as_data()?)
+            .into_tw()
+            .context("Invalid RLP address")
+    }
+}
+
+impl RlpDecode for Option<Address> {
+    fn rlp_decode(item: &RlpItem<'_>) -> SigningResult<Self> {
+        if item.as_data()?.is_empty() {
+            return Ok(None);
+        }
+        Address::rlp_decode(item).map(Some)
+    }
+}
+
+impl RlpDecode for Data {
+    fn rlp_decode(item: &RlpItem<'_>) -> SigningResult<Self> {
+        item.as_data().map(<[u8]>::to_vec)
+    }
+}
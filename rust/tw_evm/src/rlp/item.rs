@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::rlp::RlpDecode;
+use tw_coin_entry::error::prelude::*;
+
+/// A decoded RLP item, either a byte string or a list of items.
+#[derive(Clone)]
+pub struct RlpItem<'a> {
+    rlp: rlp::Rlp<'a>,
+}
+
+impl<'a> RlpItem<'a> {
+    /// Parses an RLP item.
+    /// The given `bytes` must contain exactly one item with no trailing bytes.
+    pub fn new(bytes: &'a [u8]) -> SigningResult<RlpItem<'a>> {
+        let rlp = rlp::Rlp::new(bytes);
+        let payload_info = rlp
+            .payload_info()
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid RLP item header")?;
+        if payload_info.total() != bytes.len() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("RLP item contains trailing bytes");
+        }
+        Ok(RlpItem { rlp })
+    }
+
+    #[inline]
+    pub fn is_list(&self) -> bool {
+        self.rlp.is_list()
+    }
+
+    /// Returns the item's byte string.
+    pub fn as_data(&self) -> SigningResult<&'a [u8]> {
+        if self.rlp.is_list() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Expected an RLP byte string, found a list");
+        }
+        self.rlp
+            .data()
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid RLP byte string")
+    }
+
+    /// Returns the items of the list.
+    pub fn as_list(&self) -> SigningResult<Vec<RlpItem<'a>>> {
+        if !self.rlp.is_list() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Expected an RLP list, found a byte string");
+        }
+
+        let count = self
+            .rlp
+            .item_count()
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Invalid RLP list")?;
+        (0..count)
+            .map(|idx| {
+                self.rlp
+                    .at(idx)
+                    .map(|rlp| RlpItem { rlp })
+                    .tw_err(|_| SigningErrorType::Error_input_parse)
+                    .with_context(|| format!("Invalid RLP list item at {idx}"))
+            })
+            .collect()
+    }
+
+    /// Decodes the item as `T`.
+    #[inline]
+    pub fn decode<T>(&self) -> SigningResult<T>
+    where
+        T: RlpDecode,
+    {
+        T::rlp_decode(self)
+    }
+}
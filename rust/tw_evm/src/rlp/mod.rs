@@ -3,12 +3,20 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::rlp::buffer::RlpBuffer;
+use crate::rlp::item::RlpItem;
+use tw_coin_entry::error::prelude::*;
 
 pub mod buffer;
 pub mod impls;
+pub mod item;
 pub mod list;
 
 /// The trait should be implemented for all types that need to be encoded in RLP.
 pub trait RlpEncode {
     fn rlp_append(&self, buf: &mut RlpBuffer);
 }
+
+/// The trait should be implemented for all types that need to be decoded from RLP.
+pub trait RlpDecode: Sized {
+    fn rlp_decode(item: &RlpItem<'_>) -> SigningResult<Self>;
+}
@@ -44,3 +44,21 @@ pub fn remove_replay_protection(v: u8) -> u8 {
     }
     v
 }
+
+/// Extracts `chain_id` and the recovery ID from `v` param, legacy or EIP155.
+/// Returns zero `chain_id` if `v` has the legacy form, i.e. 27+v.
+pub fn extract_replay_protection(v: U256) -> NumberResult<(U256, u8)> {
+    const EIP155_SIGNATURE_V_OFFSET: u8 = 35;
+    const BIT_MASK: u8 = 0x01;
+
+    let legacy_v = v.checked_sub(ETHEREUM_SIGNATURE_V_OFFSET)?;
+    if let Ok(recovery_id @ 0..=BIT_MASK) = u8::try_from(legacy_v) {
+        return Ok((U256::zero(), recovery_id));
+    }
+
+    // v = chain_id + chain_id + 35u8 + recovery_id
+    let eip155_v = v.checked_sub(EIP155_SIGNATURE_V_OFFSET)?;
+    let recovery_id = eip155_v.low_u8() & BIT_MASK;
+    let chain_id = eip155_v.checked_div(2_u64)?;
+    Ok((chain_id, recovery_id))
+}
@@ -4,7 +4,9 @@
 
 use crate::address::Address;
 use crate::rlp::buffer::RlpBuffer;
-use crate::rlp::RlpEncode;
+use crate::rlp::item::RlpItem;
+use crate::rlp::{RlpDecode, RlpEncode};
+use tw_coin_entry::error::prelude::*;
 use tw_hash::H256;
 
 /// A list of addresses and storage keys that the transaction plans to access.
@@ -47,6 +49,26 @@ impl RlpEncode for Access {
     }
 }
 
+impl RlpDecode for Access {
+    fn rlp_decode(item: &RlpItem<'_>) -> SigningResult<Self> {
+        let [address, storage_keys]: [RlpItem; 2] = item
+            .as_list()?
+            .try_into()
+            .tw_err(|_| SigningErrorType::Error_input_parse)
+            .context("Access list item must contain an address and storage keys")?;
+
+        let storage_keys = storage_keys
+            .as_list()?
+            .iter()
+            .map(RlpItem::decode)
+            .collect::<SigningResult<Vec<H256>>>()?;
+        Ok(Access {
+            address: address.decode()?,
+            storage_keys,
+        })
+    }
+}
+
 /// [EIP-2930](https://eips.ethereum.org/EIPS/eip-2930) access list.
 #[derive(Default)]
 pub struct AccessList(Vec<Access>);
@@ -57,6 +79,11 @@ impl AccessList {
         self.0.push(access);
         self
     }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Access> {
+        self.0.iter()
+    }
 }
 
 impl RlpEncode for AccessList {
@@ -70,3 +97,13 @@ impl RlpEncode for AccessList {
         buf.finalize_list();
     }
 }
+
+impl RlpDecode for AccessList {
+    fn rlp_decode(item: &RlpItem<'_>) -> SigningResult<Self> {
+        item.as_list()?
+            .iter()
+            .map(RlpItem::decode)
+            .collect::<SigningResult<Vec<Access>>>()
+            .map(AccessList)
+    }
+}
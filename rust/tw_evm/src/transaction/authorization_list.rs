@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::message::{EthMessage, MessageSigningResult};
+use crate::rlp::buffer::RlpBuffer;
+use crate::rlp::list::RlpList;
+use crate::rlp::RlpEncode;
+use crate::transaction::signature::{EthSignature, Signature};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::sha3::keccak256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_number::U256;
+
+/// [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702) authorization magic prefix.
+const EIP7702_MAGIC_PREFIX: u8 = 0x05;
+
+/// An authorization to set the code of an EOA to the code of the `address` contract.
+pub struct Authorization {
+    /// Chain ID the authorization is valid on, or `0` if valid on any chain.
+    pub chain_id: U256,
+    pub address: Address,
+    pub nonce: U256,
+}
+
+impl Authorization {
+    /// Returns `keccak256(0x05 || rlp([chain_id, address, nonce]))`.
+    pub fn pre_hash(&self) -> H256 {
+        let mut list = RlpList::new();
+        list.append(&self.chain_id)
+            .append(&self.address)
+            .append(&self.nonce);
+        let encoded = list.finish();
+
+        let mut preimage = Vec::with_capacity(encoded.len() + 1);
+        preimage.push(EIP7702_MAGIC_PREFIX);
+        preimage.extend_from_slice(&encoded);
+
+        let hash = keccak256(&preimage);
+        H256::try_from(hash.as_slice()).expect("keccak256 returns 32 bytes")
+    }
+
+    /// Signs the authorization tuple with the EOA private key.
+    pub fn sign(self, private_key: &secp256k1::PrivateKey) -> SigningResult<SignedAuthorization> {
+        let signature = private_key.sign(self.pre_hash())?;
+        Ok(SignedAuthorization {
+            authorization: self,
+            signature: Signature::new(signature),
+        })
+    }
+}
+
+impl EthMessage for Authorization {
+    fn hash(&self) -> MessageSigningResult<H256> {
+        Ok(self.pre_hash())
+    }
+}
+
+/// An authorization signed by the EOA.
+pub struct SignedAuthorization {
+    pub authorization: Authorization,
+    /// Signature with the `y_parity` value as `v`.
+    pub signature: Signature,
+}
+
+impl RlpEncode for SignedAuthorization {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        buf.begin_list();
+        self.authorization.chain_id.rlp_append(buf);
+        self.authorization.address.rlp_append(buf);
+        self.authorization.nonce.rlp_append(buf);
+        self.signature.v().rlp_append(buf);
+        self.signature.r().rlp_append(buf);
+        self.signature.s().rlp_append(buf);
+        buf.finalize_list();
+    }
+}
+
+/// [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702) authorization list.
+#[derive(Default)]
+pub struct AuthorizationList(Vec<SignedAuthorization>);
+
+impl AuthorizationList {
+    #[inline]
+    pub fn add_authorization(&mut self, authorization: SignedAuthorization) -> &mut Self {
+        self.0.push(authorization);
+        self
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl RlpEncode for AuthorizationList {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        buf.begin_list();
+
+        for authorization in self.0.iter() {
+            authorization.rlp_append(buf);
+        }
+
+        buf.finalize_list();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_sign() {
+        let private_key = secp256k1::PrivateKey::try_from(
+            "4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904",
+        )
+        .unwrap();
+        let authorization = Authorization {
+            chain_id: U256::from(1_u64),
+            address: Address::from("0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B"),
+            nonce: U256::from(2_u64),
+        };
+        assert_eq!(
+            authorization.pre_hash(),
+            H256::from("2a903b051321d635a0dc5ff0a1c62bbce029951312b4ca920e849cad7e0c977a")
+        );
+
+        let signed = authorization.sign(&private_key).unwrap();
+        assert_eq!(signed.signature.v(), U256::zero());
+        assert_eq!(
+            signed.signature.r(),
+            U256::from_big_endian(H256::from(
+                "cc369b8b01b6097c1f1053c548353c28ec24ab0235593e3e7e0ef00e3c3ced7e"
+            ))
+        );
+        assert_eq!(
+            signed.signature.s(),
+            U256::from_big_endian(H256::from(
+                "33e4fa6126eed1d2da20ec72449728c26b1c2631204bf215b424c40f489266da"
+            ))
+        );
+    }
+}
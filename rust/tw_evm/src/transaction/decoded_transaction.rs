@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::rlp::item::RlpItem;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_number::U256;
+
+/// Number of the signature `[v, r, s]` items at the end of a signed transaction RLP list.
+pub const SIGNATURE_ITEMS_NUM: usize = 3;
+
+/// A transaction decoded from its raw binary representation.
+pub struct DecodedTransaction<Tx> {
+    pub unsigned: Tx,
+    /// Chain ID the transaction is signed for, or zero if there is no replay protection.
+    pub chain_id: U256,
+    /// The signature with `v` being the recovery ID, if the transaction is signed.
+    pub signature: Option<secp256k1::Signature>,
+    /// Hash of the transaction that has to be signed.
+    pub pre_hash: H256,
+}
+
+impl<Tx> DecodedTransaction<Tx> {
+    /// Recovers the public key of the account that signed the transaction.
+    /// Returns `Ok(None)` if the transaction is not signed.
+    pub fn recover_public_key(&self) -> SigningResult<Option<secp256k1::PublicKey>> {
+        let Some(ref signature) = self.signature else {
+            return Ok(None);
+        };
+        secp256k1::PublicKey::recover(signature.clone(), self.pre_hash)
+            .map(Some)
+            .tw_err(|_| SigningErrorType::Error_signing)
+            .context("Error recovering a public key from the transaction signature")
+    }
+}
+
+/// Splits the RLP list items into `N` transaction fields and optional `[v, r, s]` signature items.
+pub fn split_signature_items<'a, const N: usize>(
+    mut items: Vec<RlpItem<'a>>,
+) -> SigningResult<([RlpItem<'a>; N], Option<[RlpItem<'a>; SIGNATURE_ITEMS_NUM]>)> {
+    let signature_items = match items.len() {
+        len if len == N => None,
+        len if len == N + SIGNATURE_ITEMS_NUM => {
+            let signature_items: [RlpItem; SIGNATURE_ITEMS_NUM] = items
+                .split_off(N)
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("Expected exactly 3 signature items"));
+            Some(signature_items)
+        },
+        len => {
+            return SigningError::err(SigningErrorType::Error_input_parse).with_context(|| {
+                format!(
+                    "Expected {} or {} transaction fields, found {len}",
+                    N,
+                    N + SIGNATURE_ITEMS_NUM
+                )
+            })
+        },
+    };
+
+    let fields = items
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("Expected exactly {} transaction fields", N));
+    Ok((fields, signature_items))
+}
+
+/// Creates a recoverable signature from `r`, `s` values and the recovery ID.
+pub fn signature_from_parts(
+    r: U256,
+    s: U256,
+    recovery_id: u8,
+) -> SigningResult<secp256k1::Signature> {
+    secp256k1::Signature::try_from_parts(r.to_big_endian(), s.to_big_endian(), recovery_id)
+        .tw_err(|_| SigningErrorType::Error_input_parse)
+        .context("Invalid transaction signature")
+}
+
+/// Decodes `[y_parity, r, s]` signature items of a typed transaction.
+pub fn decode_typed_signature(
+    items: &[RlpItem<'_>; SIGNATURE_ITEMS_NUM],
+) -> SigningResult<secp256k1::Signature> {
+    let [y_parity, r, s] = items;
+    let y_parity = u8::try_from(y_parity.decode::<U256>()?)
+        .ok()
+        .filter(|y_parity| *y_parity <= 1)
+        .or_tw_err(SigningErrorType::Error_input_parse)
+        .context("Signature 'y_parity' must be either 0 or 1")?;
+    signature_from_parts(r.decode()?, s.decode()?, y_parity)
+}
@@ -6,8 +6,12 @@
 //! - Non-typed (legacy, pre-EIP2718) transactions:
 //!   - simple ETH transfer
 //!   - others with payload, function call, e.g. ERC20 transfer
-//! - Typed transactions (enveloped, EIP2718), with specific type and transaction payload
-//! - User operations (EIP4337)
+//! - Typed transactions (enveloped, EIP2718), with specific type and transaction payload:
+//!   - EIP2930 transactions with an access list (type 0x1)
+//!   - EIP1559 transactions (type 0x2)
+//!   - EIP4844 blob transactions (type 0x3)
+//!   - EIP7702 set-code transactions (type 0x4)
+//! - User operations (EIP4337), both EntryPoint v0.6 and v0.7 layouts
 
 use crate::transaction::signature::EthSignature;
 use tw_coin_entry::error::prelude::*;
@@ -17,10 +21,16 @@ use tw_memory::Data;
 use tw_number::U256;
 
 pub mod access_list;
+pub mod authorization_list;
+pub mod decoded_transaction;
 pub mod signature;
 pub mod transaction_eip1559;
+pub mod transaction_eip2930;
+pub mod transaction_eip4844;
+pub mod transaction_eip7702;
 pub mod transaction_non_typed;
 pub mod user_operation;
+pub mod user_operation_v0_7;
 
 pub trait TransactionCommon {
     fn payload(&self) -> Data;
@@ -3,8 +3,12 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::Address;
+use crate::rlp::item::RlpItem;
 use crate::rlp::list::RlpList;
 use crate::transaction::access_list::AccessList;
+use crate::transaction::decoded_transaction::{
+    decode_typed_signature, split_signature_items, DecodedTransaction,
+};
 use crate::transaction::signature::{EthSignature, Signature};
 use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
 use tw_coin_entry::error::prelude::*;
@@ -26,6 +30,46 @@ pub struct TransactionEip1559 {
     pub access_list: AccessList,
 }
 
+impl TransactionEip1559 {
+    /// Decodes a raw `EIP1559` transaction, either signed or unsigned.
+    pub fn decode(encoded: &[u8]) -> SigningResult<DecodedTransaction<Self>> {
+        let rlp_encoded = encoded
+            .strip_prefix(&[EIP1559_TX_TYPE])
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Expected an EIP1559 transaction type")?;
+
+        let items = RlpItem::new(rlp_encoded)?.as_list()?;
+        let (
+            [chain_id, nonce, max_inclusion_fee_per_gas, max_fee_per_gas, gas_limit, to, amount, payload, access_list],
+            signature_items,
+        ) = split_signature_items::<9>(items)?;
+
+        let chain_id = chain_id.decode()?;
+        let unsigned = TransactionEip1559 {
+            nonce: nonce.decode()?,
+            max_inclusion_fee_per_gas: max_inclusion_fee_per_gas.decode()?,
+            max_fee_per_gas: max_fee_per_gas.decode()?,
+            gas_limit: gas_limit.decode()?,
+            to: to.decode()?,
+            amount: amount.decode()?,
+            payload: payload.decode()?,
+            access_list: access_list.decode()?,
+        };
+        let signature = signature_items
+            .as_ref()
+            .map(decode_typed_signature)
+            .transpose()?;
+        let pre_hash = unsigned.pre_hash(chain_id);
+
+        Ok(DecodedTransaction {
+            unsigned,
+            chain_id,
+            signature,
+            pre_hash,
+        })
+    }
+}
+
 impl TransactionCommon for TransactionEip1559 {
     #[inline]
     fn payload(&self) -> Data {
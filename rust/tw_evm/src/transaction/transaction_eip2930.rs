@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::rlp::item::RlpItem;
+use crate::rlp::list::RlpList;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::decoded_transaction::{
+    decode_typed_signature, split_signature_items, DecodedTransaction,
+};
+use crate::transaction::signature::{EthSignature, Signature};
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use tw_coin_entry::error::prelude::*;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const EIP2930_TX_TYPE: u8 = 0x01;
+
+/// EIP2930 transaction with an access list, fee is according to `gasPrice`.
+pub struct TransactionEip2930 {
+    pub nonce: U256,
+    pub gas_price: U256,
+    pub gas_limit: U256,
+    pub to: Option<Address>,
+    pub amount: U256,
+    pub payload: Data,
+    pub access_list: AccessList,
+}
+
+impl TransactionEip2930 {
+    /// Decodes a raw `EIP2930` transaction, either signed or unsigned.
+    pub fn decode(encoded: &[u8]) -> SigningResult<DecodedTransaction<Self>> {
+        let rlp_encoded = encoded
+            .strip_prefix(&[EIP2930_TX_TYPE])
+            .or_tw_err(SigningErrorType::Error_input_parse)
+            .context("Expected an EIP2930 transaction type")?;
+
+        let items = RlpItem::new(rlp_encoded)?.as_list()?;
+        let (
+            [chain_id, nonce, gas_price, gas_limit, to, amount, payload, access_list],
+            signature_items,
+        ) = split_signature_items::<8>(items)?;
+
+        let chain_id = chain_id.decode()?;
+        let unsigned = TransactionEip2930 {
+            nonce: nonce.decode()?,
+            gas_price: gas_price.decode()?,
+            gas_limit: gas_limit.decode()?,
+            to: to.decode()?,
+            amount: amount.decode()?,
+            payload: payload.decode()?,
+            access_list: access_list.decode()?,
+        };
+        let signature = signature_items
+            .as_ref()
+            .map(decode_typed_signature)
+            .transpose()?;
+        let pre_hash = unsigned.pre_hash(chain_id);
+
+        Ok(DecodedTransaction {
+            unsigned,
+            chain_id,
+            signature,
+            pre_hash,
+        })
+    }
+}
+
+impl TransactionCommon for TransactionEip2930 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for TransactionEip2930 {
+    type SignedTransaction = SignedTransactionEip2930;
+
+    #[inline]
+    fn encode(&self, chain_id: U256) -> Data {
+        encode_transaction(self, chain_id, None)
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: secp256k1::Signature,
+        chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedTransactionEip2930 {
+            unsigned: self,
+            signature: Signature::new(signature),
+            chain_id,
+        })
+    }
+}
+
+pub struct SignedTransactionEip2930 {
+    unsigned: TransactionEip2930,
+    signature: Signature,
+    chain_id: U256,
+}
+
+impl TransactionCommon for SignedTransactionEip2930 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedTransactionEip2930 {
+    type Signature = Signature;
+
+    #[inline]
+    fn encode(&self) -> Data {
+        encode_transaction(&self.unsigned, self.chain_id, Some(&self.signature))
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+fn encode_transaction(
+    tx: &TransactionEip2930,
+    chain_id: U256,
+    signature: Option<&Signature>,
+) -> Data {
+    let mut list = RlpList::new();
+    list.append(&chain_id)
+        .append(&tx.nonce)
+        .append(&tx.gas_price)
+        .append(&tx.gas_limit)
+        .append(&tx.to)
+        .append(&tx.amount)
+        .append(tx.payload.as_slice())
+        .append(&tx.access_list);
+
+    if let Some(signature) = signature {
+        list.append(&signature.v());
+        list.append(&signature.r());
+        list.append(&signature.s());
+    }
+
+    let tx_encoded = list.finish();
+
+    let mut envelope = Vec::with_capacity(tx_encoded.len() + 1);
+    envelope.push(EIP2930_TX_TYPE);
+    envelope.extend_from_slice(tx_encoded.as_slice());
+    envelope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::access_list::Access;
+    use tw_encoding::hex;
+    use tw_hash::H256;
+
+    #[test]
+    fn test_encode_transaction_eip2930_with_access_list() {
+        let mut access = Access::new(Address::from("0xdAC17F958D2ee523a2206206994597C13D831ec7"));
+        access.add_storage_key(H256::from(
+            "0x76c8f33bcdf467e4f1313522c10a40512a867cdcd34f2b898232ad4669200764",
+        ));
+
+        let mut access_list = AccessList::default();
+        access_list.add_access(access);
+
+        let tx = TransactionEip2930 {
+            nonce: U256::from(6_u64),
+            gas_price: U256::from(20_000_000_000_u64),
+            gas_limit: U256::from(21100_u64),
+            to: Some(Address::from("0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7")),
+            amount: U256::from(543_210_987_654_321_u64),
+            payload: Data::default(),
+            access_list,
+        };
+        let chain_id = U256::from(3_u64);
+        let actual = tx.encode(chain_id);
+
+        let expected = "01f86303068504a817c80082526c94b9f5771c27664bf2282d98e09d7f50cec7cb01a78701ee0c29f50cb180f838f794dac17f958d2ee523a2206206994597c13d831ec7e1a076c8f33bcdf467e4f1313522c10a40512a867cdcd34f2b898232ad4669200764";
+        assert_eq!(hex::encode(actual, false), expected);
+    }
+
+    #[test]
+    fn test_decode_unsigned_transaction_eip2930() {
+        let encoded = hex::decode("01f86303068504a817c80082526c94b9f5771c27664bf2282d98e09d7f50cec7cb01a78701ee0c29f50cb180f838f794dac17f958d2ee523a2206206994597c13d831ec7e1a076c8f33bcdf467e4f1313522c10a40512a867cdcd34f2b898232ad4669200764").unwrap();
+        let decoded = TransactionEip2930::decode(&encoded).unwrap();
+
+        assert_eq!(decoded.chain_id, U256::from(3_u64));
+        assert!(decoded.signature.is_none());
+
+        let tx = decoded.unsigned;
+        assert_eq!(tx.nonce, U256::from(6_u64));
+        assert_eq!(tx.gas_price, U256::from(20_000_000_000_u64));
+        assert_eq!(tx.gas_limit, U256::from(21100_u64));
+        assert_eq!(
+            tx.to,
+            Some(Address::from("0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7"))
+        );
+        assert_eq!(tx.amount, U256::from(543_210_987_654_321_u64));
+        assert!(tx.payload.is_empty());
+        assert_eq!(tx.encode(decoded.chain_id), encoded);
+    }
+}
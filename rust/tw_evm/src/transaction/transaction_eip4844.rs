@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::rlp::list::RlpList;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::signature::{EthSignature, Signature};
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const EIP4844_TX_TYPE: u8 = 0x03;
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+pub const BYTES_PER_BLOB: usize = 131_072;
+pub const BYTES_PER_COMMITMENT: usize = 48;
+pub const BYTES_PER_PROOF: usize = 48;
+
+/// Blobs with the corresponding KZG commitments and proofs.
+/// Required to broadcast an EIP4844 transaction, but not included into the transaction hash.
+pub struct BlobSidecar {
+    pub blobs: Vec<Data>,
+    pub commitments: Vec<Data>,
+    pub proofs: Vec<Data>,
+}
+
+impl BlobSidecar {
+    /// Returns `0x01 || sha256(commitment)[1..]` hashes of the commitments.
+    pub fn versioned_hashes(&self) -> Vec<H256> {
+        self.commitments
+            .iter()
+            .map(|commitment| kzg_to_versioned_hash(commitment))
+            .collect()
+    }
+}
+
+/// EIP4844 blob transaction, fee is according to EIP1559, blob fee is paid separately.
+pub struct TransactionEip4844 {
+    pub nonce: U256,
+    pub max_inclusion_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas_limit: U256,
+    /// Blob transactions can't create contracts.
+    pub to: Address,
+    pub amount: U256,
+    pub payload: Data,
+    pub access_list: AccessList,
+    pub max_fee_per_blob_gas: U256,
+    pub blob_versioned_hashes: Vec<H256>,
+    /// If set, the signed transaction is encoded in the network wrapper form.
+    pub sidecar: Option<BlobSidecar>,
+}
+
+impl TransactionCommon for TransactionEip4844 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for TransactionEip4844 {
+    type SignedTransaction = SignedTransactionEip4844;
+
+    #[inline]
+    fn encode(&self, chain_id: U256) -> Data {
+        envelope(&encode_transaction(self, chain_id, None))
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: secp256k1::Signature,
+        chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedTransactionEip4844 {
+            unsigned: self,
+            signature: Signature::new(signature),
+            chain_id,
+        })
+    }
+}
+
+pub struct SignedTransactionEip4844 {
+    unsigned: TransactionEip4844,
+    signature: Signature,
+    chain_id: U256,
+}
+
+impl TransactionCommon for SignedTransactionEip4844 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedTransactionEip4844 {
+    type Signature = Signature;
+
+    /// Returns the network wrapper `0x03 || rlp([tx_payload_body, blobs, commitments, proofs])`
+    /// if the blob sidecar is set, otherwise the canonical `0x03 || rlp(tx_payload_body)` form.
+    fn encode(&self) -> Data {
+        let tx_encoded = encode_transaction(&self.unsigned, self.chain_id, Some(&self.signature));
+
+        let Some(ref sidecar) = self.unsigned.sidecar else {
+            return envelope(&tx_encoded);
+        };
+
+        let mut wrapper = RlpList::new();
+        wrapper
+            .append_raw_encoded(&tx_encoded)
+            .append_raw_encoded(&bytes_list(&sidecar.blobs))
+            .append_raw_encoded(&bytes_list(&sidecar.commitments))
+            .append_raw_encoded(&bytes_list(&sidecar.proofs));
+        envelope(&wrapper.finish())
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+/// Computes a versioned hash of the KZG commitment.
+pub fn kzg_to_versioned_hash(commitment: &[u8]) -> H256 {
+    let mut hash = H256::try_from(sha256(commitment).as_slice()).expect("sha256 returns 32 bytes");
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+    hash
+}
+
+fn encode_transaction(
+    tx: &TransactionEip4844,
+    chain_id: U256,
+    signature: Option<&Signature>,
+) -> Data {
+    let mut versioned_hashes = RlpList::new();
+    for hash in tx.blob_versioned_hashes.iter() {
+        versioned_hashes.append(hash);
+    }
+
+    let mut list = RlpList::new();
+    list.append(&chain_id)
+        .append(&tx.nonce)
+        .append(&tx.max_inclusion_fee_per_gas)
+        .append(&tx.max_fee_per_gas)
+        .append(&tx.gas_limit)
+        .append(&tx.to)
+        .append(&tx.amount)
+        .append(tx.payload.as_slice())
+        .append(&tx.access_list)
+        .append(&tx.max_fee_per_blob_gas)
+        .append_raw_encoded(&versioned_hashes.finish());
+
+    if let Some(signature) = signature {
+        list.append(&signature.v());
+        list.append(&signature.r());
+        list.append(&signature.s());
+    }
+
+    list.finish()
+}
+
+fn bytes_list(items: &[Data]) -> Data {
+    let mut list = RlpList::new();
+    for item in items.iter() {
+        list.append(item.as_slice());
+    }
+    list.finish()
+}
+
+fn envelope(tx_encoded: &[u8]) -> Data {
+    let mut envelope = Vec::with_capacity(tx_encoded.len() + 1);
+    envelope.push(EIP4844_TX_TYPE);
+    envelope.extend_from_slice(tx_encoded);
+    envelope
+}
@@ -3,10 +3,17 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::Address;
+use crate::rlp::item::RlpItem;
 use crate::rlp::list::RlpList;
+use crate::signature::extract_replay_protection;
+use crate::transaction::decoded_transaction::{
+    signature_from_parts, split_signature_items, DecodedTransaction,
+};
 use crate::transaction::signature::{EthSignature, SignatureEip155};
 use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
 use tw_coin_entry::error::prelude::*;
+use tw_hash::sha3::keccak256;
+use tw_hash::H256;
 use tw_keypair::ecdsa::secp256k1;
 use tw_memory::Data;
 use tw_number::U256;
@@ -21,6 +28,65 @@ pub struct TransactionNonTyped {
     pub payload: Data,
 }
 
+impl TransactionNonTyped {
+    /// Decodes a raw legacy transaction, either signed or unsigned.
+    /// Both EIP155 and pre-EIP155 (no replay protection) transactions are supported.
+    pub fn decode(encoded: &[u8]) -> SigningResult<DecodedTransaction<Self>> {
+        let items = RlpItem::new(encoded)?.as_list()?;
+        let ([nonce, gas_price, gas_limit, to, amount, payload], signature_items) =
+            split_signature_items::<6>(items)?;
+
+        let unsigned = TransactionNonTyped {
+            nonce: nonce.decode()?,
+            gas_price: gas_price.decode()?,
+            gas_limit: gas_limit.decode()?,
+            to: to.decode()?,
+            amount: amount.decode()?,
+            payload: payload.decode()?,
+        };
+
+        let Some([v, r, s]) = signature_items else {
+            // Unsigned pre-EIP155 transaction.
+            let pre_hash = pre_eip155_hash(&unsigned);
+            return Ok(DecodedTransaction {
+                unsigned,
+                chain_id: U256::zero(),
+                signature: None,
+                pre_hash,
+            });
+        };
+
+        let (v, r, s): (U256, U256, U256) = (v.decode()?, r.decode()?, s.decode()?);
+        if r.is_zero() && s.is_zero() {
+            // Unsigned EIP155 transaction, `v` is the chain ID.
+            let pre_hash = unsigned.pre_hash(v);
+            return Ok(DecodedTransaction {
+                unsigned,
+                chain_id: v,
+                signature: None,
+                pre_hash,
+            });
+        }
+
+        let (chain_id, recovery_id) = extract_replay_protection(v)
+            .into_tw()
+            .context("Invalid signature 'v' value")?;
+        let signature = signature_from_parts(r, s, recovery_id)?;
+        let pre_hash = if chain_id.is_zero() {
+            pre_eip155_hash(&unsigned)
+        } else {
+            unsigned.pre_hash(chain_id)
+        };
+
+        Ok(DecodedTransaction {
+            unsigned,
+            chain_id,
+            signature: Some(signature),
+            pre_hash,
+        })
+    }
+}
+
 impl TransactionCommon for TransactionNonTyped {
     #[inline]
     fn payload(&self) -> Data {
@@ -98,6 +164,21 @@ fn encode_transaction(
     list.finish()
 }
 
+/// Returns the hash of the transaction with no replay protection,
+/// i.e. `keccak256(rlp([nonce, gasPrice, gasLimit, to, value, data]))`.
+fn pre_eip155_hash(tx: &TransactionNonTyped) -> H256 {
+    let mut list = RlpList::new();
+    list.append(&tx.nonce)
+        .append(&tx.gas_price)
+        .append(&tx.gas_limit)
+        .append(&tx.to)
+        .append(&tx.amount)
+        .append(tx.payload.as_slice());
+
+    let hash = keccak256(&list.finish());
+    H256::try_from(hash.as_slice()).expect("keccak256 returns 32 bytes")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::encode::encode_tokens;
+use crate::abi::non_empty_array::NonEmptyBytes;
+use crate::abi::token::Token;
+use crate::address::Address;
+use crate::transaction::signature::Signature;
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use serde::Serialize;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex;
+use tw_hash::sha3::keccak256;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// EIP4337 PackedUserOperation, supported by EntryPoint v0.7.
+/// https://github.com/eth-infinitism/account-abstraction/blob/v0.7.0/contracts/interfaces/PackedUserOperation.sol
+pub struct UserOperationV0_7 {
+    pub nonce: U256,
+    pub entry_point: Address,
+    pub sender: Address,
+    pub factory: Option<Address>,
+    pub factory_data: Data,
+    pub call_gas_limit: u128,
+    pub verification_gas_limit: u128,
+    pub pre_verification_gas: U256,
+    pub max_fee_per_gas: u128,
+    pub max_inclusion_fee_per_gas: u128,
+    pub paymaster: Option<Address>,
+    pub paymaster_verification_gas_limit: u128,
+    pub paymaster_post_op_gas_limit: u128,
+    pub paymaster_data: Data,
+    pub payload: Data,
+}
+
+impl UserOperationV0_7 {
+    /// Returns `factory || factoryData`, or an empty value if there is no factory.
+    pub fn init_code(&self) -> Data {
+        match self.factory {
+            Some(factory) => [factory.as_slice(), self.factory_data.as_slice()].concat(),
+            None => Data::default(),
+        }
+    }
+
+    /// Returns `verificationGasLimit || callGasLimit` packed as bytes32.
+    pub fn account_gas_limits(&self) -> Data {
+        pack_u128(self.verification_gas_limit, self.call_gas_limit)
+    }
+
+    /// Returns `maxPriorityFeePerGas || maxFeePerGas` packed as bytes32.
+    pub fn gas_fees(&self) -> Data {
+        pack_u128(self.max_inclusion_fee_per_gas, self.max_fee_per_gas)
+    }
+
+    /// Returns `paymaster || paymasterVerificationGasLimit || paymasterPostOpGasLimit || paymasterData`,
+    /// or an empty value if there is no paymaster.
+    pub fn paymaster_and_data(&self) -> Data {
+        match self.paymaster {
+            Some(paymaster) => [
+                paymaster.as_slice(),
+                self.paymaster_verification_gas_limit
+                    .to_be_bytes()
+                    .as_slice(),
+                self.paymaster_post_op_gas_limit.to_be_bytes().as_slice(),
+                self.paymaster_data.as_slice(),
+            ]
+            .concat(),
+            None => Data::default(),
+        }
+    }
+}
+
+impl TransactionCommon for UserOperationV0_7 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for UserOperationV0_7 {
+    type SignedTransaction = SignedUserOperationV0_7;
+
+    fn pre_hash(&self, chain_id: U256) -> H256 {
+        let encode_hash = keccak256(&self.encode(chain_id));
+        let encode_hash =
+            NonEmptyBytes::new(encode_hash).expect("keccak256 must not return an empty hash");
+
+        let tokens = [
+            Token::FixedBytes(encode_hash),
+            Token::Address(self.entry_point),
+            Token::u256(chain_id),
+        ];
+        let encoded = encode_tokens(&tokens);
+        let pre_hash = keccak256(&encoded);
+        H256::try_from(pre_hash.as_slice()).expect("keccak256 returns 32 bytes")
+    }
+
+    fn encode(&self, _chain_id: U256) -> Data {
+        let init_code_hash = keccak256(&self.init_code());
+        let init_code_hash =
+            NonEmptyBytes::new(init_code_hash).expect("keccak256 must not return an empty hash");
+
+        let payload_hash = keccak256(&self.payload);
+        let payload_hash =
+            NonEmptyBytes::new(payload_hash).expect("keccak256 must not return an empty hash");
+
+        let account_gas_limits = NonEmptyBytes::new(self.account_gas_limits())
+            .expect("'accountGasLimits' must not be empty");
+        let gas_fees = NonEmptyBytes::new(self.gas_fees()).expect("'gasFees' must not be empty");
+
+        let paymaster_and_data_hash = keccak256(&self.paymaster_and_data());
+        let paymaster_and_data_hash = NonEmptyBytes::new(paymaster_and_data_hash)
+            .expect("keccak256 must not return an empty hash");
+
+        let tokens = [
+            Token::Address(self.sender),
+            Token::u256(self.nonce),
+            Token::FixedBytes(init_code_hash),
+            Token::FixedBytes(payload_hash),
+            Token::FixedBytes(account_gas_limits),
+            Token::u256(self.pre_verification_gas),
+            Token::FixedBytes(gas_fees),
+            Token::FixedBytes(paymaster_and_data_hash),
+        ];
+
+        encode_tokens(&tokens)
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: tw_keypair::ecdsa::secp256k1::Signature,
+        _chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedUserOperationV0_7 {
+            unsigned: self,
+            signature: Signature::new(signature),
+        })
+    }
+}
+
+pub struct SignedUserOperationV0_7 {
+    unsigned: UserOperationV0_7,
+    signature: Signature,
+}
+
+impl TransactionCommon for SignedUserOperationV0_7 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedUserOperationV0_7 {
+    type Signature = Signature;
+
+    /// Serializes the UserOperation as expected by `eth_sendUserOperation` of v0.7 bundlers.
+    fn encode(&self) -> Data {
+        let mut signature = self.signature.to_rsv_bytes();
+        signature[64] += 27;
+
+        let prefix = true;
+        let unsigned = &self.unsigned;
+        let paymaster = unsigned.paymaster.as_ref();
+
+        let tx = SignedUserOperationV0_7Serde {
+            sender: unsigned.sender.to_string(),
+            nonce: unsigned.nonce.to_string(),
+            factory: unsigned.factory.as_ref().map(Address::to_string),
+            factory_data: unsigned
+                .factory
+                .map(|_| hex::encode(&unsigned.factory_data, prefix)),
+            call_data: hex::encode(&unsigned.payload, prefix),
+            call_gas_limit: unsigned.call_gas_limit.to_string(),
+            verification_gas_limit: unsigned.verification_gas_limit.to_string(),
+            pre_verification_gas: unsigned.pre_verification_gas.to_string(),
+            max_fee_per_gas: unsigned.max_fee_per_gas.to_string(),
+            max_priority_fee_per_gas: unsigned.max_inclusion_fee_per_gas.to_string(),
+            paymaster: paymaster.map(Address::to_string),
+            paymaster_verification_gas_limit: paymaster
+                .map(|_| unsigned.paymaster_verification_gas_limit.to_string()),
+            paymaster_post_op_gas_limit: paymaster
+                .map(|_| unsigned.paymaster_post_op_gas_limit.to_string()),
+            paymaster_data: paymaster.map(|_| hex::encode(&unsigned.paymaster_data, prefix)),
+            signature: hex::encode(signature.as_slice(), prefix),
+        };
+        serde_json::to_string(&tx)
+            .expect("Simple structure should never fail on serialization")
+            .into_bytes()
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SignedUserOperationV0_7Serde {
+    sender: String,
+    nonce: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    factory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    factory_data: Option<String>,
+    call_data: String,
+    call_gas_limit: String,
+    verification_gas_limit: String,
+    pre_verification_gas: String,
+    max_fee_per_gas: String,
+    max_priority_fee_per_gas: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paymaster: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paymaster_verification_gas_limit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paymaster_post_op_gas_limit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paymaster_data: Option<String>,
+    signature: String,
+}
+
+fn pack_u128(high: u128, low: u128) -> Data {
+    [high.to_be_bytes(), low.to_be_bytes()].concat()
+}
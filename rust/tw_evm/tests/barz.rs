@@ -190,3 +190,130 @@ fn test_barz_batched_account_deployed() {
         "84d0464f5a2b191e06295443970ecdcd2d18f565d0d52b5a79443192153770ab"
     );
 }
+
+#[test]
+fn test_user_operation_v0_7_transfer_account_deployed() {
+    let private_key =
+        hex::decode("0x3c90badc15c4d35733769093d3733501e92e7f16e101df284cee9a310d36c483").unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(0x23_86f2_6fc1_0000),
+        data: Cow::default(),
+    };
+    let user_op = Proto::UserOperationV0_7 {
+        entry_point: "0x0000000071727De22E5E9d8BAf0edAc6f37da032".into(),
+        sender: "0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029".into(),
+        pre_verification_gas: U256::encode_be_compact(46856),
+        verification_gas_limit: U256::encode_be_compact(100000),
+        ..Proto::UserOperationV0_7::default()
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(11155111),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: Proto::TransactionMode::UserOp,
+        gas_limit: U256::encode_be_compact(100000),
+        max_fee_per_gas: U256::encode_be_compact(7033440745),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1000000000),
+        to_address: "0x61061fCAE11fD5461535e134EfF67A98CFFF44E9".into(),
+        private_key: private_key.into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        user_operation_v0_7: Some(user_op),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = r#"{"sender":"0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029","nonce":"0","callData":"0xb61d27f600000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9000000000000000000000000000000000000000000000000002386f26fc1000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000000","callGasLimit":"100000","verificationGasLimit":"100000","preVerificationGas":"46856","maxFeePerGas":"7033440745","maxPriorityFeePerGas":"1000000000","signature":"0xa20063d54c2994813b08dd8166ec8f3b3d69231efa528e257d94e6b96a6ea28e7c27a136740fddfb601b9447dc43a110d2f6b7eebf82c9e1fd7e988851e1f8571c"}"#;
+    let actual = String::from_utf8(output.encoded.to_vec()).unwrap();
+    assert_eq!(actual, expected);
+
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "c752cf63f1c9c90410d201ba31446cc4181ef76c4aa1223077441d51144a4330"
+    );
+}
+
+#[test]
+fn test_user_operation_v0_7_transfer_with_factory_and_paymaster() {
+    let private_key =
+        hex::decode("0x3c90badc15c4d35733769093d3733501e92e7f16e101df284cee9a310d36c483").unwrap();
+
+    let factory_data = hex::decode("5fbfb9cf000000000000000000000000174a240e5147d02de4d7724d5d3e1c1bf11ce0290000000000000000000000000000000000000000000000000000000000000000").unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(0x23_86f2_6fc1_0000),
+        data: Cow::default(),
+    };
+    let user_op = Proto::UserOperationV0_7 {
+        entry_point: "0x0000000071727De22E5E9d8BAf0edAc6f37da032".into(),
+        sender: "0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029".into(),
+        factory: "0x91E60e0613810449d098b0b5Ec8b51A0FE8c8985".into(),
+        factory_data: factory_data.into(),
+        pre_verification_gas: U256::encode_be_compact(46856),
+        verification_gas_limit: U256::encode_be_compact(100000),
+        paymaster: "0x5d33e7DBdA8F5E0b15C7Ba5a9b7d3B9c8F1f3EA1".into(),
+        paymaster_verification_gas_limit: U256::encode_be_compact(50000),
+        paymaster_post_op_gas_limit: U256::encode_be_compact(20000),
+        paymaster_data: hex::decode("deadbeef").unwrap().into(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(11155111),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: Proto::TransactionMode::UserOp,
+        gas_limit: U256::encode_be_compact(100000),
+        max_fee_per_gas: U256::encode_be_compact(7033440745),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1000000000),
+        to_address: "0x61061fCAE11fD5461535e134EfF67A98CFFF44E9".into(),
+        private_key: private_key.into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        user_operation_v0_7: Some(user_op),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = r#"{"sender":"0x174a240e5147D02dE4d7724D5D3E1c1bF11cE029","nonce":"0","factory":"0x91E60e0613810449d098b0b5Ec8b51A0FE8c8985","factoryData":"0x5fbfb9cf000000000000000000000000174a240e5147d02de4d7724d5d3e1c1bf11ce0290000000000000000000000000000000000000000000000000000000000000000","callData":"0xb61d27f600000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e9000000000000000000000000000000000000000000000000002386f26fc1000000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000000","callGasLimit":"100000","verificationGasLimit":"100000","preVerificationGas":"46856","maxFeePerGas":"7033440745","maxPriorityFeePerGas":"1000000000","paymaster":"0x5d33e7DBdA8F5E0b15C7Ba5a9b7d3B9c8F1f3EA1","paymasterVerificationGasLimit":"50000","paymasterPostOpGasLimit":"20000","paymasterData":"0xdeadbeef","signature":"0x178a491037714ef28e3623e960dbab4a1e1a7d96224d63b21dfb32c9f848bb9954a23447357b46eb5afd7e825de832144ba56deeb750de858eaf3fea339350b01c"}"#;
+    let actual = String::from_utf8(output.encoded.to_vec()).unwrap();
+    assert_eq!(actual, expected);
+
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "9ba9c6b8279a5c51cba15b6ecd1a08e8a1b234b766e607b1748fe8c1f04cf259"
+    );
+}
+
+#[test]
+fn test_user_operation_v0_6_and_v0_7_conflict() {
+    let private_key =
+        hex::decode("0x3c90badc15c4d35733769093d3733501e92e7f16e101df284cee9a310d36c483").unwrap();
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(0x23_86f2_6fc1_0000),
+        data: Cow::default(),
+    };
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(11155111),
+        tx_mode: Proto::TransactionMode::UserOp,
+        to_address: "0x61061fCAE11fD5461535e134EfF67A98CFFF44E9".into(),
+        private_key: private_key.into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        user_operation: Some(Proto::UserOperation::default()),
+        user_operation_v0_7: Some(Proto::UserOperationV0_7::default()),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
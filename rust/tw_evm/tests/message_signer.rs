@@ -8,6 +8,7 @@ use tw_coin_entry::test_utils::test_context::TestCoinContext;
 use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_evm::modules::message_signer::EthMessageSigner;
 use tw_keypair::ecdsa::secp256k1;
+use tw_number::U256;
 use tw_proto::Ethereum::Proto;
 
 const EIP712_CASE_1: &str = include_str!("data/eip712_case_1.json");
@@ -312,3 +313,47 @@ fn test_message_signer_sign_verify_eip712_different_bytes() {
         signature: "48dc667cd8a53beb58ea6b1745f98c21b12e1a57587ce28bae07689dba3600d40cef2685dc8a68028d38f3e63289891868ecdf05e8affc275fee3001e51d6c581c",
     });
 }
+
+#[test]
+fn test_message_signer_sign_eip7702_authorization() {
+    let coin = TestCoinContext::default();
+
+    let authorization = Proto::Authorization {
+        chain_id: U256::encode_be_compact(1),
+        address: "0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B".into(),
+        nonce: U256::encode_be_compact(2),
+    };
+    let signing_input = Proto::MessageSigningInput {
+        private_key: "4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message_type: Proto::MessageType::MessageType_eip7702_authorization,
+        authorization: Some(authorization.clone()),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let output = EthMessageSigner.message_preimage_hashes(&coin, signing_input.clone());
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "2a903b051321d635a0dc5ff0a1c62bbce029951312b4ca920e849cad7e0c977a"
+    );
+
+    // `r || s || y_parity`, where the values can be used as `SignedAuthorization` fields.
+    let output = EthMessageSigner.sign_message(&coin, signing_input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, "cc369b8b01b6097c1f1053c548353c28ec24ab0235593e3e7e0ef00e3c3ced7e33e4fa6126eed1d2da20ec72449728c26b1c2631204bf215b424c40f489266da00");
+}
+
+#[test]
+fn test_message_signer_sign_eip7702_no_authorization() {
+    test_message_signer_sign_err(SignErrorTestInput {
+        private_key: "4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904",
+        msg: "",
+        msg_type: Proto::MessageType::MessageType_eip7702_authorization,
+        chain_id: 1,
+        error: SigningErrorType::Error_invalid_params,
+    });
+}
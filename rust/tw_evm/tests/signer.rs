@@ -7,6 +7,7 @@ use tw_coin_entry::error::prelude::*;
 use tw_encoding::hex::{self, ToHex};
 use tw_evm::evm_context::StandardEvmContext;
 use tw_evm::modules::signer::Signer;
+use tw_hash::sha3::keccak256;
 use tw_number::U256;
 use tw_proto::Ethereum::Proto;
 use tw_proto::Ethereum::Proto::TransactionMode;
@@ -643,3 +644,260 @@ fn test_sign_transaction_non_typed_erc1155_transfer() {
     let expected_data = "f242432a000000000000000000000000718046867b5b1782379a14ea4fc0c9b724da94fc0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000023c47ee50000000000000000000000000000000000000000000000001bc16d674ec8000000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000040102030400000000000000000000000000000000000000000000000000000000";
     assert_eq!(hex::encode(output.data, false), expected_data);
 }
+
+#[test]
+fn test_sign_transaction_eip2930_native_transfer() {
+    let private_key = parse_hex("4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904");
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(543_210_987_654_321),
+        data: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(3),
+        nonce: U256::encode_be_compact(6),
+        tx_mode: TransactionMode::Eip2930,
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_100),
+        to_address: "0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key,
+        access_list: vec![Proto::Access {
+            address: "0xdAC17F958D2ee523a2206206994597C13D831ec7".into(),
+            stored_keys: vec![parse_hex(
+                "0x76c8f33bcdf467e4f1313522c10a40512a867cdcd34f2b898232ad4669200764",
+            )],
+        }],
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "01f8a603068504a817c80082526c94b9f5771c27664bf2282d98e09d7f50cec7cb01a78701ee0c29f50cb180f838f794dac17f958d2ee523a2206206994597c13d831ec7e1a076c8f33bcdf467e4f1313522c10a40512a867cdcd34f2b898232ad466920076480a0e3317a0152fcc482993a5ecf36c9adc470cd253509a922774a0c882db24d9e5fa04af766a674b4f2a9220d96aa2cddcd35f9502a121f40852187ad0273f4f34178";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(output.v.to_hex(), "00");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "3f3dcc70d46e76dccd43cc63356e39af534f8743d8c0967cb92cbf99d4e90cd5"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip4844_blob_versioned_hashes() {
+    let private_key = parse_hex("4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904");
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Eip4844,
+        gas_limit: U256::encode_be_compact(21_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(10_000_000_000),
+        max_fee_per_blob_gas: U256::encode_be_compact(1_000_000_000),
+        blob_versioned_hashes: vec![parse_hex(
+            "010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014",
+        )],
+        to_address: "0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key,
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "03f8920180843b9aca008502540be40082520894b9f5771c27664bf2282d98e09d7f50cec7cb01a78080c0843b9aca00e1a0010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c44401401a0b34f2f4da49ca2969bf0ebb4ba18a5cac8832f6fab0d1a31fd7bdeadd5922b36a03027ebaee70fe238131934d54762bdddf383dbdfd982981821b8dc82a7e545da";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(output.v.to_hex(), "01");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "937f55fc15b05f828e4e619dff4a0cddb4ac065c05a582809bebf4a89165f023"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip4844_network_wrapper() {
+    let private_key = parse_hex("4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904");
+
+    // KZG commitment and proof of the zero blob is the point at infinity.
+    let mut point_at_infinity = vec![0_u8; 48];
+    point_at_infinity[0] = 0xc0;
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Eip4844,
+        gas_limit: U256::encode_be_compact(21_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(10_000_000_000),
+        max_fee_per_blob_gas: U256::encode_be_compact(1_000_000_000),
+        // Versioned hashes are computed from the commitments.
+        blob_versioned_hashes: Vec::default(),
+        blob_sidecar: Some(Proto::BlobSidecar {
+            blobs: vec![vec![0_u8; 131_072].into()],
+            commitments: vec![point_at_infinity.clone().into()],
+            proofs: vec![point_at_infinity.into()],
+        }),
+        to_address: "0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key,
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    // The transaction hash does not depend on the sidecar.
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "937f55fc15b05f828e4e619dff4a0cddb4ac065c05a582809bebf4a89165f023"
+    );
+    assert_eq!(output.encoded.len(), 131_333);
+    assert_eq!(
+        hex::encode(&output.encoded[..16], false),
+        "03fa020100f8920180843b9aca008502"
+    );
+    assert_eq!(
+        keccak256(&output.encoded).to_hex(),
+        "69049397bfdfb48ab6f89edcf013d12fbcdd93541e80ce93c4b757ced5203b69"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip4844_invalid() {
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::Eip4844,
+        gas_limit: U256::encode_be_compact(21_000),
+        to_address: "0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key: parse_hex("4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904"),
+        ..Proto::SigningInput::default()
+    };
+
+    // No blobs.
+    let output = Signer::<StandardEvmContext>::sign_proto(input.clone());
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    // Versioned hash doesn't match the commitment.
+    let mut commitment = vec![0_u8; 48];
+    commitment[0] = 0xc0;
+    let output = Signer::<StandardEvmContext>::sign_proto(Proto::SigningInput {
+        blob_versioned_hashes: vec![parse_hex(
+            "0100000000000000000000000000000000000000000000000000000000000000",
+        )],
+        blob_sidecar: Some(Proto::BlobSidecar {
+            blobs: vec![vec![0_u8; 131_072].into()],
+            commitments: vec![commitment.clone().into()],
+            proofs: vec![commitment.into()],
+        }),
+        ..input.clone()
+    });
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    // Contract creation is not allowed.
+    let output = Signer::<StandardEvmContext>::sign_proto(Proto::SigningInput {
+        to_address: "".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::contract_generic(
+                Proto::mod_Transaction::ContractGeneric::default(),
+            ),
+        }),
+        blob_versioned_hashes: vec![parse_hex(
+            "010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014",
+        )],
+        ..input
+    });
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+}
+
+fn eip7702_signing_input(
+    authorization: Proto::SignedAuthorization<'static>,
+) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::Eip7702,
+        gas_limit: U256::encode_be_compact(100_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(1_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(10_000_000_000),
+        // The account delegates to the contract and calls itself.
+        to_address: "0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(
+                Proto::mod_Transaction::Transfer::default(),
+            ),
+        }),
+        private_key: parse_hex("4f96ed80e9a7555a6f74b3d658afdd9c756b0a40d4ca30c42c2039eb449bb904"),
+        authorization_list: vec![authorization],
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_sign_transaction_eip7702() {
+    let authorization = Proto::Authorization {
+        chain_id: U256::encode_be_compact(1),
+        address: "0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B".into(),
+        nonce: U256::encode_be_compact(2),
+    };
+    let expected = "04f8ca0101843b9aca008502540be400830186a094b9f5771c27664bf2282d98e09d7f50cec7cb01a78080c0f85cf85a019463c0c19a282a1b52b07dd5a65b58948a07dae32b0280a0cc369b8b01b6097c1f1053c548353c28ec24ab0235593e3e7e0ef00e3c3ced7ea033e4fa6126eed1d2da20ec72449728c26b1c2631204bf215b424c40f489266da01a0651101d3a21d851d27c015c2c30806e66b9a96c2d60f2c4f281bb9840442b318a045212f15f7585aaa56b48618dc12d3249b31f0aca178d27dee71b21bd2d55030";
+
+    // The authorization is signed with the transaction signer key.
+    let input = eip7702_signing_input(Proto::SignedAuthorization {
+        authorization: Some(authorization.clone()),
+        ..Proto::SignedAuthorization::default()
+    });
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(hex::encode(output.encoded, false), expected);
+    assert_eq!(output.v.to_hex(), "01");
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "3ba90da5b499fab536c9fce03cfb2514a3f4f3b99131af648b9041c0cc71d3c1"
+    );
+
+    // The authorization is signed in advance.
+    let input = eip7702_signing_input(Proto::SignedAuthorization {
+        authorization: Some(authorization),
+        y_parity: 0,
+        r: parse_hex("cc369b8b01b6097c1f1053c548353c28ec24ab0235593e3e7e0ef00e3c3ced7e"),
+        s: parse_hex("33e4fa6126eed1d2da20ec72449728c26b1c2631204bf215b424c40f489266da"),
+    });
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(hex::encode(output.encoded, false), expected);
+}
+
+#[test]
+fn test_sign_transaction_eip7702_invalid() {
+    // No authorization specified.
+    let mut input = eip7702_signing_input(Proto::SignedAuthorization::default());
+    let output = Signer::<StandardEvmContext>::sign_proto(input.clone());
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    // Empty authorization list.
+    input.authorization_list.clear();
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
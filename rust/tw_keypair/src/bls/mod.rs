@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! BLS12-381 signatures in the "minimal-signature-size" variant:
+//! signatures are points on G1 and public keys are points on G2.
+
+mod keypair;
+mod private;
+mod public;
+mod signature;
+
+pub use keypair::KeyPair;
+pub use private::PrivateKey;
+pub use public::PublicKey;
+pub use signature::Signature;
+
+use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+use bls12_381::G1Projective;
+
+/// Domain separation tag of the `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_` ciphersuite.
+const DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+fn hash_to_g1(message: &[u8]) -> G1Projective {
+    <G1Projective as HashToCurve<ExpandMsgXmd<sha2_09::Sha256>>>::hash_to_curve(message, DST)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+    use crate::KeyPairError;
+    use tw_encoding::hex;
+    use tw_misc::traits::{ToBytesVec, ToBytesZeroizing};
+
+    const PRIVATE_KEY: &str = "4e51f1f3721f644ac7a193be7f5e7b8c2abaa3467871daf4eacb5d3af080e5d6";
+    const PUBLIC_KEY: &str = "86388d26e3c3d29bd5013108af40c5dc9096e17c7571c6dcda7c22e4146925c1401326267125516a14a1252f18bb7e3e18379eeef225990300d1a0c2bb809c426726c115a2c736d624e1d470f1192e946801daf504aed756a5bdef73fca0d6ee";
+    const SIGNATURE: &str = "96564f81e9bdc726ff5595c4b22a7b46d056d478676954d9555b896eebc36ad65680ad5b3aa4fae7c15f0833e65fb685";
+
+    #[test]
+    fn test_key_pair_sign_verify() {
+        let keypair = KeyPair::try_from(PRIVATE_KEY).unwrap();
+
+        let message = b"zarb\0".to_vec();
+        let actual = keypair.sign(message.clone()).unwrap();
+        assert_eq!(hex::encode(actual.to_vec(), false), SIGNATURE);
+
+        assert!(keypair.verify(actual.clone(), message));
+        assert!(!keypair.verify(actual, b"zarb".to_vec()));
+    }
+
+    #[test]
+    fn test_key_pair_get_private_public() {
+        let privkey_bytes = hex::decode(PRIVATE_KEY).unwrap();
+        let pubkey_bytes = hex::decode(PUBLIC_KEY).unwrap();
+
+        let keypair = KeyPair::try_from(privkey_bytes.as_slice()).unwrap();
+        assert_eq!(
+            keypair.private().to_zeroizing_vec().as_slice(),
+            privkey_bytes
+        );
+        assert_eq!(keypair.public().to_vec(), pubkey_bytes);
+    }
+
+    #[test]
+    fn test_private_key_invalid() {
+        // Zero and a value greater than the curve order.
+        for bytes in [[0; 32], [0xff; 32]] {
+            let res = PrivateKey::try_from(bytes.as_slice());
+            assert!(matches!(res, Err(KeyPairError::InvalidSecretKey)));
+        }
+    }
+
+    #[test]
+    fn test_public_key_verify() {
+        let public = PublicKey::try_from(PUBLIC_KEY).unwrap();
+        let signature = Signature::try_from(hex::decode(SIGNATURE).unwrap().as_slice()).unwrap();
+        assert!(public.verify(signature, b"zarb\0".to_vec()));
+    }
+
+    #[test]
+    fn test_public_key_to_from_bytes() {
+        let bytes = hex::decode(PUBLIC_KEY).unwrap();
+        let public = PublicKey::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(public.to_vec(), bytes);
+
+        // Compressed point at infinity.
+        let mut identity = [0; PublicKey::LEN];
+        identity[0] = 0xc0;
+        assert_eq!(
+            PublicKey::try_from(identity.as_slice()).unwrap_err(),
+            KeyPairError::InvalidPublicKey
+        );
+    }
+
+    #[test]
+    fn test_signature_to_from_bytes() {
+        let bytes = hex::decode(SIGNATURE).unwrap();
+        let sign = Signature::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(sign.to_vec(), bytes);
+
+        assert_eq!(
+            Signature::try_from(&bytes[1..]).unwrap_err(),
+            KeyPairError::InvalidSignature
+        );
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::bls::hash_to_g1;
+use crate::bls::public::PublicKey;
+use crate::bls::signature::Signature;
+use crate::traits::SigningKeyTrait;
+use crate::{KeyPairError, KeyPairResult};
+use bls12_381::{G1Affine, G2Affine, Scalar};
+use tw_encoding::hex;
+use tw_hash::H256;
+use tw_misc::traits::ToBytesZeroizing;
+use zeroize::Zeroizing;
+
+/// Represents a BLS12-381 private key.
+pub struct PrivateKey {
+    secret: Scalar,
+}
+
+impl PrivateKey {
+    /// Returns an associated public key.
+    pub fn public(&self) -> PublicKey {
+        PublicKey::new(G2Affine::from(G2Affine::generator() * self.secret))
+    }
+}
+
+impl SigningKeyTrait for PrivateKey {
+    type SigningMessage = Vec<u8>;
+    type Signature = Signature;
+
+    fn sign(&self, message: Self::SigningMessage) -> KeyPairResult<Self::Signature> {
+        let point = hash_to_g1(&message) * self.secret;
+        Ok(Signature::new(G1Affine::from(point)))
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PrivateKey {
+    type Error = KeyPairError;
+
+    /// Parses a big-endian encoded private key.
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let bytes = H256::try_from(bytes).map_err(|_| KeyPairError::InvalidSecretKey)?;
+
+        // `Scalar::from_bytes` expects a little-endian representation.
+        let mut le_bytes = Zeroizing::new(bytes.take());
+        le_bytes.reverse();
+
+        let secret: Option<Scalar> = Scalar::from_bytes(&le_bytes).into();
+        match secret {
+            Some(secret) if secret != Scalar::zero() => Ok(PrivateKey { secret }),
+            _ => Err(KeyPairError::InvalidSecretKey),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PrivateKey {
+    type Error = KeyPairError;
+
+    fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
+        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl ToBytesZeroizing for PrivateKey {
+    fn to_zeroizing_vec(&self) -> Zeroizing<Vec<u8>> {
+        let mut secret = Zeroizing::new(self.secret.to_bytes().to_vec());
+        secret.reverse();
+        secret
+    }
+}
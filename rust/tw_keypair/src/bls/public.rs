@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::bls::hash_to_g1;
+use crate::bls::signature::Signature;
+use crate::traits::VerifyingKeyTrait;
+use crate::KeyPairError;
+use bls12_381::{pairing, G1Affine, G2Affine};
+use tw_encoding::hex;
+use tw_misc::traits::ToBytesVec;
+
+/// Represents a BLS12-381 public key.
+#[derive(Clone, Debug)]
+pub struct PublicKey {
+    public: G2Affine,
+}
+
+/// cbindgen:ignore
+impl PublicKey {
+    /// The number of bytes in a compressed public key.
+    pub const LEN: usize = 96;
+
+    /// Creates a public key from the given [`G2Affine`] point.
+    pub(crate) fn new(public: G2Affine) -> PublicKey {
+        PublicKey { public }
+    }
+}
+
+impl VerifyingKeyTrait for PublicKey {
+    type SigningMessage = Vec<u8>;
+    type VerifySignature = Signature;
+
+    fn verify(&self, signature: Self::VerifySignature, message: Self::SigningMessage) -> bool {
+        let hash = G1Affine::from(hash_to_g1(&message));
+        pairing(signature.inner(), &G2Affine::generator()) == pairing(&hash, &self.public)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PublicKey {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let bytes: &[u8; PublicKey::LEN] = bytes
+            .try_into()
+            .map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let public: Option<G2Affine> = G2Affine::from_compressed(bytes).into();
+        match public {
+            Some(public) if !bool::from(public.is_identity()) => Ok(PublicKey::new(public)),
+            _ => Err(KeyPairError::InvalidPublicKey),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PublicKey {
+    type Error = KeyPairError;
+
+    fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
+        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl ToBytesVec for PublicKey {
+    fn to_vec(&self) -> Vec<u8> {
+        self.public.to_compressed().to_vec()
+    }
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::KeyPairError;
+use bls12_381::G1Affine;
+use tw_misc::traits::ToBytesVec;
+
+/// Represents a BLS12-381 signature.
+#[derive(Clone, Debug)]
+pub struct Signature {
+    signature: G1Affine,
+}
+
+/// cbindgen:ignore
+impl Signature {
+    /// The number of bytes in a compressed signature.
+    pub const LEN: usize = 48;
+
+    /// Creates a signature from the given [`G1Affine`] point.
+    pub(crate) fn new(signature: G1Affine) -> Signature {
+        Signature { signature }
+    }
+
+    /// Returns a reference to the inner [`G1Affine`] point.
+    pub(crate) fn inner(&self) -> &G1Affine {
+        &self.signature
+    }
+}
+
+impl ToBytesVec for Signature {
+    fn to_vec(&self) -> Vec<u8> {
+        self.signature.to_compressed().to_vec()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Signature {
+    type Error = KeyPairError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let bytes: &[u8; Signature::LEN] = bytes
+            .try_into()
+            .map_err(|_| KeyPairError::InvalidSignature)?;
+        let signature: Option<G1Affine> = G1Affine::from_compressed(bytes).into();
+        signature
+            .map(Signature::new)
+            .ok_or(KeyPairError::InvalidSignature)
+    }
+}
@@ -156,6 +156,15 @@ impl<C: EcdsaCurve> VerifySignature<C> {
 
         (r_bytes, s_bytes)
     }
+
+    /// Normalizes the signature into "low S" form as described in
+    /// [BIP 0062: Dealing with Malleability][1].
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    pub fn normalize_s(self) -> Self {
+        let signature = self.signature.normalize_s().unwrap_or(self.signature);
+        VerifySignature { signature }
+    }
 }
 
 impl<C: EcdsaCurve> ToBytesVec for VerifySignature<C> {
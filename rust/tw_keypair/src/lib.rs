@@ -42,6 +42,7 @@
 //! assert_eq(eth_signature.v, H256::from(EXPECTED_V));
 //! ```
 
+pub mod bls;
 pub mod ecdsa;
 pub mod ed25519;
 pub mod ffi;
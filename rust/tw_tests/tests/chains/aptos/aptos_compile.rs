@@ -2,6 +2,10 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::chains::aptos::test_cases::cosigners::{
+    self, fee_payer, multi_ed25519, SECOND_ADDRESS, SECOND_PRIVATE_KEY, SECOND_PUBLIC_KEY,
+    THIRD_PRIVATE_KEY, THIRD_PUBLIC_KEY,
+};
 use crate::chains::aptos::test_cases::transfer_b4d62afd::{
     aptos_sign_transfer_input, expected_json, DATA_TO_SIGN, ENCODED, PRIVATE_KEY, RAW_TXN,
     SIGNATURE,
@@ -10,8 +14,10 @@ use crate::chains::aptos::APTOS_COIN_TYPE;
 use tw_any_coin::ffi::tw_transaction_compiler::{
     tw_transaction_compiler_compile, tw_transaction_compiler_pre_image_hashes,
 };
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, CompilerHelper, PreImageHelper};
 use tw_coin_entry::error::prelude::*;
-use tw_encoding::hex::ToHex;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_keypair::ed25519;
 use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
@@ -82,3 +88,123 @@ fn test_any_signer_compile_aptos() {
 
     assert_eq_json!(output.json, expected_json());
 }
+
+#[test]
+fn test_aptos_compile_fee_payer() {
+    let input = Proto::SigningInput {
+        fee_payer: Some(Proto::AccountSigner {
+            address: SECOND_ADDRESS.into(),
+            ..Proto::AccountSigner::default()
+        }),
+        ..aptos_sign_transfer_input()
+    };
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage = pre_imager.pre_image_hashes(CoinType::Aptos, &input);
+
+    assert_eq!(preimage.error, SigningErrorType::OK);
+    assert_eq!(preimage.data.to_hex(), fee_payer::DATA_TO_SIGN);
+
+    // The sender signature goes first, then the fee payer one.
+    let signatures = vec![
+        fee_payer::SENDER_SIGNATURE.decode_hex().unwrap(),
+        fee_payer::FEE_PAYER_SIGNATURE.decode_hex().unwrap(),
+    ];
+    let public_keys = vec![
+        cosigners::FIRST_PUBLIC_KEY.decode_hex().unwrap(),
+        SECOND_PUBLIC_KEY.decode_hex().unwrap(),
+    ];
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Aptos, &input, signatures, public_keys);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), fee_payer::ENCODED);
+}
+
+#[test]
+fn test_aptos_compile_multi_ed25519() {
+    let input = Proto::SigningInput {
+        sender: cosigners::MULTI_ED25519_ADDRESS.into(),
+        multi_ed25519_public_key: Some(cosigners::multi_ed25519_public_key()),
+        ..aptos_sign_transfer_input()
+    };
+
+    // The member signatures can be passed in any order.
+    let signatures = vec![
+        multi_ed25519::THIRD_SIGNATURE.decode_hex().unwrap(),
+        multi_ed25519::FIRST_SIGNATURE.decode_hex().unwrap(),
+    ];
+    let public_keys = vec![
+        THIRD_PUBLIC_KEY.decode_hex().unwrap(),
+        cosigners::FIRST_PUBLIC_KEY.decode_hex().unwrap(),
+    ];
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Aptos, &input, signatures, public_keys);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.raw_txn.to_hex(), multi_ed25519::RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), multi_ed25519::ENCODED);
+}
+
+#[test]
+fn test_aptos_compile_fee_payer_missing_signature() {
+    let input = Proto::SigningInput {
+        fee_payer: Some(Proto::AccountSigner {
+            address: SECOND_ADDRESS.into(),
+            ..Proto::AccountSigner::default()
+        }),
+        ..aptos_sign_transfer_input()
+    };
+
+    let signatures = vec![fee_payer::SENDER_SIGNATURE.decode_hex().unwrap()];
+    let public_keys = vec![cosigners::FIRST_PUBLIC_KEY.decode_hex().unwrap()];
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Aptos, &input, signatures, public_keys);
+
+    assert_eq!(output.error, SigningErrorType::Error_signatures_count);
+}
+
+#[test]
+fn test_aptos_compile_multi_ed25519_member_fee_payer() {
+    // The fee payer is also a member of the MultiEd25519 sender account.
+    let fee_payer = Proto::AccountSigner {
+        address: SECOND_ADDRESS.into(),
+        private_keys: vec![SECOND_PRIVATE_KEY.decode_hex().unwrap().into()],
+        ..Proto::AccountSigner::default()
+    };
+    let input = Proto::SigningInput {
+        sender: cosigners::MULTI_ED25519_ADDRESS.into(),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        multi_ed25519_public_key: Some(cosigners::multi_ed25519_public_key()),
+        additional_private_keys: vec![THIRD_PRIVATE_KEY.decode_hex().unwrap().into()],
+        fee_payer: Some(fee_payer),
+        ..aptos_sign_transfer_input()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let signed = signer.sign(CoinType::Aptos, input.clone());
+    assert_eq!(signed.error, SigningErrorType::OK);
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage = pre_imager.pre_image_hashes(CoinType::Aptos, &input);
+    assert_eq!(preimage.error, SigningErrorType::OK);
+
+    // Two signatures of the sender account members, then the fee payer one.
+    let mut signatures = Vec::new();
+    let mut public_keys = Vec::new();
+    for private_key in [PRIVATE_KEY, THIRD_PRIVATE_KEY, SECOND_PRIVATE_KEY] {
+        let key_pair = ed25519::sha512::KeyPair::try_from(private_key).unwrap();
+        signatures.push(key_pair.sign(preimage.data.to_vec()).unwrap().to_vec());
+        public_keys.push(key_pair.public().to_vec());
+    }
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Aptos, &input, signatures, public_keys);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.encoded, signed.encoded);
+}
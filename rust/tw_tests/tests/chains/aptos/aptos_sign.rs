@@ -2,16 +2,25 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::chains::aptos::test_cases::cosigners::{
+    self, fee_payer, multi_agent, multi_ed25519, SECOND_ADDRESS, SECOND_PRIVATE_KEY,
+    THIRD_PRIVATE_KEY,
+};
 use crate::chains::aptos::test_cases::transfer_b4d62afd::{
     aptos_sign_transfer_input, expected_json, ENCODED, PRIVATE_KEY, RAW_TXN, SIGNATURE,
 };
 use crate::chains::aptos::APTOS_COIN_TYPE;
+use serde_json::{json, Value as Json};
 use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
 use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
 use tw_misc::assert_eq_json;
 use tw_proto::Aptos::Proto;
+use tw_proto::Aptos::Proto::mod_FunctionArgument::OneOfvalue as ArgumentValue;
+use tw_proto::Aptos::Proto::mod_SigningInput::OneOftransaction_payload as TransactionPayloadEnum;
 use tw_proto::{deserialize, serialize};
 
 #[test]
@@ -39,3 +48,182 @@ fn test_any_signer_sign_aptos() {
 
     assert_eq_json!(output.json, expected_json());
 }
+
+#[test]
+fn test_aptos_sign_fee_payer() {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        fee_payer: Some(Proto::AccountSigner {
+            address: SECOND_ADDRESS.into(),
+            private_keys: vec![SECOND_PRIVATE_KEY.decode_hex().unwrap().into()],
+            ..Proto::AccountSigner::default()
+        }),
+        ..aptos_sign_transfer_input()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), fee_payer::ENCODED);
+
+    let authenticator = output.authenticator.unwrap();
+    assert_eq!(
+        authenticator.signature.to_hex(),
+        fee_payer::SENDER_SIGNATURE
+    );
+
+    let json: Json = serde_json::from_str(&output.json).unwrap();
+    let expected_signature = json!({
+        "fee_payer_address": SECOND_ADDRESS,
+        "fee_payer_signer": {
+            "public_key": "0x6a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e",
+            "signature": "0x329406691e6ee8284d5bc54456def8625779c93c54c34a6976660947cf31f86df89cc8ab62d2997604d44aac3634bbe21fb76e1e55987ca6122d5f61108a8401",
+            "type": "ed25519_signature"
+        },
+        "secondary_signer_addresses": [],
+        "secondary_signers": [],
+        "sender": {
+            "public_key": "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+            "signature": "0xd2afb7c9e3f3e8483a80c1ffd98d97963bda3664fd1bd9d0052928177fd5503953ec903c5364ec0acf83a9b3af83c33986ae631f2abb1fc90481afb5e7cdbb0b",
+            "type": "ed25519_signature"
+        },
+        "type": "fee_payer_signature"
+    });
+    assert_eq!(json["signature"], expected_signature);
+}
+
+#[test]
+fn test_aptos_sign_multi_agent() {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        secondary_signers: vec![Proto::AccountSigner {
+            address: SECOND_ADDRESS.into(),
+            private_keys: vec![SECOND_PRIVATE_KEY.decode_hex().unwrap().into()],
+            ..Proto::AccountSigner::default()
+        }],
+        ..aptos_sign_transfer_input()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), multi_agent::ENCODED);
+
+    let json: Json = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"]["type"], "multi_agent_signature");
+    assert_eq!(
+        json["signature"]["secondary_signer_addresses"],
+        json!([SECOND_ADDRESS])
+    );
+}
+
+#[test]
+fn test_aptos_sign_multi_ed25519() {
+    let input = Proto::SigningInput {
+        sender: cosigners::MULTI_ED25519_ADDRESS.into(),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        multi_ed25519_public_key: Some(cosigners::multi_ed25519_public_key()),
+        additional_private_keys: vec![THIRD_PRIVATE_KEY.decode_hex().unwrap().into()],
+        ..aptos_sign_transfer_input()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.raw_txn.to_hex(), multi_ed25519::RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), multi_ed25519::ENCODED);
+
+    let json: Json = serde_json::from_str(&output.json).unwrap();
+    let expected_signature = json!({
+        "bitmap": "0xa0000000",
+        "public_keys": [
+            "0xea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c",
+            "0x6a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e",
+            "0x85ebd1441fe4f954fbe5dc6077bf008e119a5e269297c6f7083d001d2ac876fe"
+        ],
+        "signatures": [
+            "0xfeb4647125c4acdc7bb46a13afc7d0add31724d4d5904797d87d2df1ff5a55bfb7f7569c0ca2fe5b4d17a0bb3f597fffc0e7150d865e6eaac7501017a630ef02",
+            "0x945182166d3f2039c9bd7c0cb970096865207ce4a18f94ee3f1d22beeedc52f59b868b42af78d018e368fc7237518bf3aa65a751c74454a535a6130f12549008"
+        ],
+        "threshold": 2,
+        "type": "multi_ed25519_signature"
+    });
+    assert_eq!(json["signature"], expected_signature);
+}
+
+#[test]
+fn test_aptos_sign_multi_ed25519_insufficient_signatures() {
+    let input = Proto::SigningInput {
+        sender: cosigners::MULTI_ED25519_ADDRESS.into(),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        multi_ed25519_public_key: Some(cosigners::multi_ed25519_public_key()),
+        ..aptos_sign_transfer_input()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningErrorType::Error_signatures_count);
+}
+
+#[test]
+fn test_aptos_sign_entry_function() {
+    let entry_function = Proto::EntryFunctionMessage {
+        function: "0x1::aptos_account::transfer".into(),
+        type_arguments: Vec::default(),
+        arguments: vec![
+            Proto::FunctionArgument {
+                value: ArgumentValue::address(
+                    "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30".into(),
+                ),
+            },
+            Proto::FunctionArgument {
+                value: ArgumentValue::u64_pb(1000),
+            },
+        ],
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionPayloadEnum::entry_function(entry_function),
+        ..aptos_sign_transfer_input()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    // The typed arguments must be encoded the same way as the `aptos_account::transfer` payload.
+    assert_eq!(output.raw_txn.to_hex(), RAW_TXN);
+    assert_eq!(output.encoded.to_hex(), ENCODED);
+    assert_eq!(output.authenticator.unwrap().signature.to_hex(), SIGNATURE);
+    assert_eq_json!(output.json, expected_json());
+}
+
+#[test]
+fn test_aptos_sign_script_unsupported_argument() {
+    let script = Proto::ScriptMessage {
+        bytecode: "a11ceb0b060000000601000203020605080707"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        type_arguments: Vec::default(),
+        arguments: vec![Proto::FunctionArgument {
+            value: ArgumentValue::string_value("Aptos".into()),
+        }],
+    };
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        transaction_payload: TransactionPayloadEnum::script(script),
+        ..aptos_sign_transfer_input()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Aptos, input);
+
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
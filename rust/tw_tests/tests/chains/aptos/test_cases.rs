@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use serde_json::{json, Value as Json};
+use tw_encoding::hex::DecodeHex;
 use tw_proto::Aptos::Proto;
 use tw_proto::Aptos::Proto::mod_SigningInput::OneOftransaction_payload as TransactionPayloadEnum;
 
@@ -60,3 +61,61 @@ pub(super) mod transfer_b4d62afd {
         })
     }
 }
+
+/// Additional signers of the `transfer_b4d62afd` transaction.
+pub(super) mod cosigners {
+    use super::*;
+
+    /// Public key of `transfer_b4d62afd::PRIVATE_KEY`.
+    pub const FIRST_PUBLIC_KEY: &str =
+        "ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c";
+
+    pub const SECOND_PRIVATE_KEY: &str =
+        "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266";
+    pub const SECOND_PUBLIC_KEY: &str =
+        "6a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e";
+    pub const SECOND_ADDRESS: &str =
+        "0x5efeb515053af564e3f077b20698c1936df51d76e31744b328236e72caa04ecd";
+
+    pub const THIRD_PRIVATE_KEY: &str =
+        "7e6682f7bf479ef0f627823cffd4e1a940a7af33e5fb39d9e0f631d2ecc5daff";
+    pub const THIRD_PUBLIC_KEY: &str =
+        "85ebd1441fe4f954fbe5dc6077bf008e119a5e269297c6f7083d001d2ac876fe";
+
+    /// 2-of-3 MultiEd25519 account of the first, second and third public keys.
+    pub const MULTI_ED25519_ADDRESS: &str =
+        "0x45251811452ea257c6615d497f782cab2fb9808044310c00e5e9372dd23fff79";
+
+    pub fn multi_ed25519_public_key() -> Proto::MultiEd25519PublicKey<'static> {
+        Proto::MultiEd25519PublicKey {
+            public_keys: vec![
+                FIRST_PUBLIC_KEY.decode_hex().unwrap().into(),
+                SECOND_PUBLIC_KEY.decode_hex().unwrap().into(),
+                THIRD_PUBLIC_KEY.decode_hex().unwrap().into(),
+            ],
+            threshold: 2,
+        }
+    }
+
+    /// `transfer_b4d62afd` transaction which gas fee is paid by the second account.
+    pub mod fee_payer {
+        pub const DATA_TO_SIGN: &str = "5efa3c4f02f83a0f4b2d69fc95c607cc02825cc4e7be536ef0992df050d9e67c0107968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021005efeb515053af564e3f077b20698c1936df51d76e31744b328236e72caa04ecd";
+        pub const SENDER_SIGNATURE: &str = "d2afb7c9e3f3e8483a80c1ffd98d97963bda3664fd1bd9d0052928177fd5503953ec903c5364ec0acf83a9b3af83c33986ae631f2abb1fc90481afb5e7cdbb0b";
+        pub const FEE_PAYER_SIGNATURE: &str = "329406691e6ee8284d5bc54456def8625779c93c54c34a6976660947cf31f86df89cc8ab62d2997604d44aac3634bbe21fb76e1e55987ca6122d5f61108a8401";
+        pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021030020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c40d2afb7c9e3f3e8483a80c1ffd98d97963bda3664fd1bd9d0052928177fd5503953ec903c5364ec0acf83a9b3af83c33986ae631f2abb1fc90481afb5e7cdbb0b00005efeb515053af564e3f077b20698c1936df51d76e31744b328236e72caa04ecd00206a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e40329406691e6ee8284d5bc54456def8625779c93c54c34a6976660947cf31f86df89cc8ab62d2997604d44aac3634bbe21fb76e1e55987ca6122d5f61108a8401";
+    }
+
+    /// `transfer_b4d62afd` transaction signed by the second account as a secondary signer.
+    pub mod multi_agent {
+        pub const ENCODED: &str = "07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3063000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021020020ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c403bb387ec2264fbf21d90bb6960544f7c3d84b38287cb5da50248d00eeec37dbb9d01228dfd3be5e3c4230b212eb7b2eb9d82f3e881eb2d2808707b4bbe9cae0e015efeb515053af564e3f077b20698c1936df51d76e31744b328236e72caa04ecd0100206a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e40c518212413df931727a627e1964175f78abc6a9440fa65ecf0deb398fd9cbb839c07fa9858f7aa41e558f4a53f5d63ad9a20353bb8c75fa25a16aaf91be39a01";
+    }
+
+    /// `transfer_b4d62afd` transaction sent from the MultiEd25519 account,
+    /// signed by the first and third account members.
+    pub mod multi_ed25519 {
+        pub const RAW_TXN: &str = "45251811452ea257c6615d497f782cab2fb9808044310c00e5e9372dd23fff7963000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021";
+        pub const FIRST_SIGNATURE: &str = "feb4647125c4acdc7bb46a13afc7d0add31724d4d5904797d87d2df1ff5a55bfb7f7569c0ca2fe5b4d17a0bb3f597fffc0e7150d865e6eaac7501017a630ef02";
+        pub const THIRD_SIGNATURE: &str = "945182166d3f2039c9bd7c0cb970096865207ce4a18f94ee3f1d22beeedc52f59b868b42af78d018e368fc7237518bf3aa65a751c74454a535a6130f12549008";
+        pub const ENCODED: &str = "45251811452ea257c6615d497f782cab2fb9808044310c00e5e9372dd23fff7963000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada00000000210161ea526ba1710343d953461ff68641f1b7df5f23b9042ffa2d2a798d3adb3f3d6c6a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e85ebd1441fe4f954fbe5dc6077bf008e119a5e269297c6f7083d001d2ac876fe028401feb4647125c4acdc7bb46a13afc7d0add31724d4d5904797d87d2df1ff5a55bfb7f7569c0ca2fe5b4d17a0bb3f597fffc0e7150d865e6eaac7501017a630ef02945182166d3f2039c9bd7c0cb970096865207ce4a18f94ee3f1d22beeedc52f59b868b42af78d018e368fc7237518bf3aa65a751c74454a535a6130f12549008a0000000";
+    }
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{
+    btc_info, compile, dust_threshold, input, output, preimage, TransactionOneof, DUST, MINER_FEE,
+    ONE_BTC, SIGHASH_ALL,
+};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_keypair::ecdsa;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::BitcoinV2::Proto;
+use tw_utxo::script::standard_script::conditions;
+
+const ALICE_PRIVATE_KEY: &str = "56429688a1a6b00b90ccd22a0de0a376b6569d8684022ae92229a28478bfb657";
+const BOB_PRIVATE_KEY: &str = "b7da1ec42b19085fe09fec54b9d9eacd998ae4e6d2ad472be38d8393391b9ead";
+const CAROL_PRIVATE_KEY: &str = "7fa638b0df495b2968ae6dc7011c4db08c86df16c91aa71a77ee6a222954e5bb";
+
+/// 2-of-3 multisig P2SH input.
+/// Alice has already signed the input on the previous signing round, Bob signs it now, and Carol doesn't sign it at all.
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_bitcoin_compile_p2sh_multisig_partially_signed() {
+    let alice_pubkey = ecdsa::secp256k1::PrivateKey::try_from(ALICE_PRIVATE_KEY)
+        .unwrap()
+        .public()
+        .compressed();
+    let bob_private_key = ecdsa::secp256k1::PrivateKey::try_from(BOB_PRIVATE_KEY).unwrap();
+    let bob_pubkey = bob_private_key.public().compressed();
+    let carol_pubkey = ecdsa::secp256k1::PrivateKey::try_from(CAROL_PRIVATE_KEY)
+        .unwrap()
+        .public()
+        .compressed();
+
+    let redeem_script =
+        conditions::new_multisig(2, &[alice_pubkey, bob_pubkey, carol_pubkey]).unwrap();
+
+    // ASN.1 DER encoded signature of Alice collected on the previous signing round.
+    let alice_signature = "3045022100b63aff21ac041abdf47125fe26c99d3ef5e3480d688df28168d460ecc5bd60e70220089d83698fbd87bd16396a99a22c9ed80ce6898f6ad1225d1e4ee0ff52e61ecb"
+        .decode_hex()
+        .unwrap();
+
+    let txid = "c19f410bf1d70864220e93bca20f836aaaf8cdde84a46692616e9f4480d54885";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: 50 * ONE_BTC,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2sh_with_partial_signatures(
+            redeem_script.to_vec(),
+            vec![(alice_pubkey.to_vec(), alice_signature)],
+        ),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: 50 * ONE_BTC - 2 * MINER_FEE,
+        to_recipient: output::p2pkh(alice_pubkey.to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    let signing = Proto::SigningInput {
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    // The sighash is returned for every signer that hasn't signed yet.
+    let sighash = "2360b6dc8e1f4ad643036089907df39be21c44e89ce304a9ef3a3a728fd85267"
+        .decode_hex()
+        .unwrap();
+    preimage::BitcoinPreImageHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .add_expected_legacy(bob_pubkey.to_vec(), sighash.clone())
+        .add_expected_legacy(carol_pubkey.to_vec(), sighash.clone())
+        .pre_image();
+
+    let bob_signature = bob_private_key
+        .sign(sighash.as_slice().try_into().unwrap())
+        .unwrap();
+
+    compile::BitcoinCompileHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .add_pubkey_sig(bob_pubkey.to_vec(), bob_signature.to_vec())
+        // Carol doesn't sign the input.
+        .add_pubkey_sig(carol_pubkey.to_vec(), Vec::default())
+        .compile(compile::Expected {
+            encoded: "02000000018548d580449f6e619266a484decdf8aa6a830fa2bc930e226408d7f10b419fc100000000fdfd0000483045022100b63aff21ac041abdf47125fe26c99d3ef5e3480d688df28168d460ecc5bd60e70220089d83698fbd87bd16396a99a22c9ed80ce6898f6ad1225d1e4ee0ff52e61ecb014730440220470187c98392b4bbd6d518c6a5961010f07c6d5307f3404f74845c85322b73fb02205a6c0dacaf007aacfb748cebfa2fe99a615400e3fd8b3031f720dd78267afd83014c695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53aeffffffff01806de729010000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac00000000",
+            txid: "21c431f1d266287c7669e5c2eb734ddb355bb97cf6ed1dceac351b82e110bf09",
+            inputs: vec![50 * ONE_BTC],
+            outputs: vec![50 * ONE_BTC - 2 * MINER_FEE],
+            vsize: 340,
+            weight: 1360,
+            fee: 2 * MINER_FEE,
+        });
+}
@@ -0,0 +1,301 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const P2WPKH_PRIVATE_KEY: &str = "bb051cd0dda0246f33c5a9e133ebd8e7bc02a92af6c41adc131ccd7826c5b004";
+const P2WPKH_PUBLIC_KEY: &str =
+    "02c7f12003196442943d8588e01aee840423cc54fc1521526a3b85c2b0cbd58872";
+const P2WPKH_ADDRESS: &str = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l";
+const P2TR_ADDRESS: &str = "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3";
+const P2PKH_PRIVATE_KEY: &str = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+const P2PKH_ADDRESS: &str = "19cAJn4Ms8jodBBGtroBNNpCZiHAWGAq7X";
+
+fn sign_message(
+    private_key: &str,
+    address: &str,
+    message: &str,
+    signing_type: Proto::MessageSigningType,
+) -> Proto::MessageSigningOutput<'static> {
+    let input = Proto::MessageSigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        message: message.into(),
+        address: address.into(),
+        signing_type,
+        dangerous_use_fixed_schnorr_rng: true,
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(CoinType::Bitcoin as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    Proto::MessageSigningOutput {
+        signature: output.signature.into_owned().into(),
+        error: output.error,
+        error_message: output.error_message.into_owned().into(),
+    }
+}
+
+fn verify_message(address: &str, message: &str, signature: &str) -> bool {
+    let input = Proto::MessageVerifyingInput {
+        message: message.into(),
+        address: address.into(),
+        signature: signature.into(),
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(CoinType::Bitcoin as u32, input_data.ptr()) }
+}
+
+fn assert_signed(
+    private_key: &str,
+    address: &str,
+    message: &str,
+    signing_type: Proto::MessageSigningType,
+    expected: &str,
+) {
+    let output = sign_message(private_key, address, message, signing_type);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, expected);
+    assert!(verify_message(address, message, expected));
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_simple_p2wpkh() {
+    use Proto::MessageSigningType::MessageSigningType_bip322_simple as Simple;
+
+    assert_signed(
+        P2WPKH_PRIVATE_KEY,
+        P2WPKH_ADDRESS,
+        "",
+        Simple,
+        "AkgwRQIhAPkJ1Q4oYS0htvyuSFHLxRQpFAY56b70UvE7Dxazen0ZAiAtZfFz1S6T6I23MWI2lK/pcNTWncuyL8UL+oMdydVgzAEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy",
+    );
+    // Matches the BIP322 test vector.
+    assert_signed(
+        P2WPKH_PRIVATE_KEY,
+        P2WPKH_ADDRESS,
+        "Hello World",
+        Simple,
+        "AkgwRQIhAOzyynlqt93lOKJr+wmmxIens//zPzl9tqIOua93wO6MAiBi5n5EyAcPScOjf1lAqIUIQtr3zKNeavYabHyR8eGhowEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy",
+    );
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_full_p2wpkh() {
+    use Proto::MessageSigningType::MessageSigningType_bip322_full as Full;
+
+    assert_signed(
+        P2WPKH_PRIVATE_KEY,
+        P2WPKH_ADDRESS,
+        "",
+        Full,
+        "AAAAAAABAaeZWlQ8KGa1G6ll540B3l21BDXN6dSCv2DYuJumCmjFAAAAAAAAAAAAAQAAAAAAAAAAAWoCSDBFAiEA+QnVDihhLSG2/K5IUcvFFCkUBjnpvvRS8TsPFrN6fRkCIC1l8XPVLpPojbcxYjaUr+lw1Nady7IvxQv6gx3J1WDMASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHIAAAAA",
+    );
+    assert_signed(
+        P2WPKH_PRIVATE_KEY,
+        P2WPKH_ADDRESS,
+        "Hello World",
+        Full,
+        "AAAAAAABASs1A9aiYU3q8XFsIzJcU+BRS0r8mBAcdxdSrUBnGZ23AAAAAAAAAAAAAQAAAAAAAAAAAWoCSDBFAiEA7PLKeWq33eU4omv7CabEh6ez//M/OX22og65r3fA7owCIGLmfkTIBw9Jw6N/WUCohQhC2vfMo15q9hpsfJHx4aGjASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHIAAAAA",
+    );
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_p2tr() {
+    assert_signed(
+        P2WPKH_PRIVATE_KEY,
+        P2TR_ADDRESS,
+        "Hello World",
+        Proto::MessageSigningType::MessageSigningType_bip322_simple,
+        "AUDjpClYFHngjnqQ3F0/3dyrLsOHFNEm4rKaaAc9GsfhC5+DngPJmXTeAmz+yfsVRa61PD2k9/CEQnLDvNUn9Qug",
+    );
+    assert_signed(
+        P2WPKH_PRIVATE_KEY,
+        P2TR_ADDRESS,
+        "Hello World",
+        Proto::MessageSigningType::MessageSigningType_bip322_full,
+        "AAAAAAABAQZ52yMWanylo3mYung2wzGYu6l1UmV7Eo2xCNKfbmYhAAAAAAAAAAAAAQAAAAAAAAAAAWoBQOOkKVgUeeCOepDcXT/d3Ksuw4cU0SbisppoBz0ax+ELn4OeA8mZdN4CbP7J+xVFrrU8PaT38IRCcsO81Sf1C6AAAAAA",
+    );
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_full_p2pkh() {
+    assert_signed(
+        P2PKH_PRIVATE_KEY,
+        P2PKH_ADDRESS,
+        "Hello World",
+        Proto::MessageSigningType::MessageSigningType_bip322_full,
+        "AAAAAAGhC80o1uwXeJcph44Xb6nRIQ7G6xPf+hMRBpCtEKpnFgAAAABrSDBFAiEAstJ2A/ngI9+7c5U3R0RWyZa14cN63Zf/pOa3a8ue074CIDdhXASgwkyFoPUZuUXzUOJw9llZczyjFoW/vZYOrDcVASEDmcb1Gtb5jJxYP46Su3dYqyypoEEQwKESbsQ+VFPRlsEAAAAAAQAAAAAAAAAAAWoAAAAA",
+    );
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322_simple_p2pkh_not_supported() {
+    let output = sign_message(
+        P2PKH_PRIVATE_KEY,
+        P2PKH_ADDRESS,
+        "Hello World",
+        Proto::MessageSigningType::MessageSigningType_bip322_simple,
+    );
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+    assert!(output.signature.is_empty());
+}
+
+#[test]
+fn test_bitcoin_message_sign_address_mismatch() {
+    let output = sign_message(
+        P2PKH_PRIVATE_KEY,
+        P2WPKH_ADDRESS,
+        "Hello World",
+        Proto::MessageSigningType::MessageSigningType_bip322_simple,
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+
+    let output = sign_message(
+        P2WPKH_PRIVATE_KEY,
+        P2PKH_ADDRESS,
+        "Hello World",
+        Proto::MessageSigningType::MessageSigningType_legacy,
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+}
+
+#[test]
+fn test_bitcoin_message_sign_legacy() {
+    use Proto::MessageSigningType::MessageSigningType_legacy as Legacy;
+
+    assert_signed(
+        P2PKH_PRIVATE_KEY,
+        P2PKH_ADDRESS,
+        "test signature",
+        Legacy,
+        "ILH5K7JQLaRGaKGXXH5mYM6FIIy9IWyY4JUPI+PHYY4WaupxUbg+zy0bhBCrDuehy9x4WidwjkRR1GSLnWvOXBo=",
+    );
+    assert_signed(
+        P2PKH_PRIVATE_KEY,
+        P2PKH_ADDRESS,
+        "another text",
+        Legacy,
+        "H7vrF2C+TlFiHyegAw3QLv6SK0myuEEXUOgfx0+Qio1YVDuSa6p/OHpoQVlUt3F8QJdbdZN9M1h/fYEAnEz16V0=",
+    );
+}
+
+#[test]
+fn test_bitcoin_message_verify_bip322_vectors() {
+    assert!(verify_message(
+        P2WPKH_ADDRESS,
+        "",
+        "AkcwRAIgM2gBAQqvZX15ZiysmKmQpDrG83avLIT492QBzLnQIxYCIBaTpOaD20qRlEylyxFSeEA2ba9YOixpX8z46TSDtS40ASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=",
+    ));
+    assert!(verify_message(
+        P2WPKH_ADDRESS,
+        "Hello World",
+        "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=",
+    ));
+    // SIGHASH_ALL Taproot signature.
+    assert!(verify_message(
+        P2TR_ADDRESS,
+        "Hello World",
+        "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAQ==",
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_verify_legacy() {
+    assert!(verify_message(
+        "1B8Qea79tsxmn4dTiKKRVvsJpHwL2fMQnr",
+        "test signature",
+        "H+3L5IbSVcejp4S2VwLXCxLEMQAWDvKbE8lQyq0ocdvyM1aoEudkzN/S/qLI3vnNOFY6V13BXWSFrPr3OjGa5Dk=",
+    ));
+    assert!(verify_message(
+        "1H8X4u6CVZRTLLNbUQTKAnc5vCkqWMpwfF",
+        "compressed key",
+        "IKUI9v2xbHogJe8HKXI2M5KEhMKaW6fjNxtyEy27Mf+3/e1ht4jZoc85e4F8stPsxt4Xcg8Yr42S28O6L/Qx9fE=",
+    ));
+    // Uncompressed public key.
+    assert!(verify_message(
+        "1E4T9JZ3mq6cdgiRJEWzHqDXb9t322fE6d",
+        "test signature",
+        "HLH5K7JQLaRGaKGXXH5mYM6FIIy9IWyY4JUPI+PHYY4WaupxUbg+zy0bhBCrDuehy9x4WidwjkRR1GSLnWvOXBo=",
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_verify_invalid() {
+    // Another message.
+    assert!(!verify_message(
+        P2WPKH_ADDRESS,
+        "Hello World!",
+        "AkgwRQIhAOzyynlqt93lOKJr+wmmxIens//zPzl9tqIOua93wO6MAiBi5n5EyAcPScOjf1lAqIUIQtr3zKNeavYabHyR8eGhowEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy",
+    ));
+    // Another address.
+    assert!(!verify_message(
+        P2TR_ADDRESS,
+        "Hello World",
+        "AkgwRQIhAOzyynlqt93lOKJr+wmmxIens//zPzl9tqIOua93wO6MAiBi5n5EyAcPScOjf1lAqIUIQtr3zKNeavYabHyR8eGhowEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy",
+    ));
+    // SIGHASH_NONE Taproot signature.
+    assert!(!verify_message(
+        P2TR_ADDRESS,
+        "Hello World",
+        "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAg==",
+    ));
+    // Compressed flag is not set.
+    assert!(!verify_message(
+        P2PKH_ADDRESS,
+        "test signature",
+        "HLH5K7JQLaRGaKGXXH5mYM6FIIy9IWyY4JUPI+PHYY4WaupxUbg+zy0bhBCrDuehy9x4WidwjkRR1GSLnWvOXBo=",
+    ));
+    // Not a Base64 string.
+    assert!(!verify_message(
+        P2WPKH_ADDRESS,
+        "Hello World",
+        "not base64!"
+    ));
+}
+
+#[test]
+fn test_bitcoin_message_signer_pre_image_hashes() {
+    let input = Proto::MessageSigningInput {
+        message: "Hello World".into(),
+        address: P2WPKH_ADDRESS.into(),
+        signing_type: Proto::MessageSigningType::MessageSigningType_bip322_simple,
+        public_key: P2WPKH_PUBLIC_KEY.decode_hex().unwrap().into(),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(CoinType::Bitcoin as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.data.to_hex(),
+        "00000000012b3503d6a2614deaf1716c23325c53e0514b4afc98101c771752ad4067199db7000000000000000000010000000000000000016a00000000"
+    );
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "af8a0cd31d9b0976e2aab2b82974c4388c4a3532b2ef828b96f14039ca372c14"
+    );
+}
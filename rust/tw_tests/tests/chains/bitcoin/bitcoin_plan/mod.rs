@@ -2,8 +2,10 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+mod plan_bnb;
 mod plan_exact;
 mod plan_exact_error;
+mod plan_fee_bump;
 mod plan_max;
 mod plan_max_error;
 mod plan_psbt;
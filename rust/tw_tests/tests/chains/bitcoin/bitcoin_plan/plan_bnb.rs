@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{plan, Amount, TransactionOneof, DUST};
+use tw_any_coin::test_utils::plan_utils::AnyPlannerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto as CommonProto;
+
+fn make_bnb_planning_input(
+    inputs: Vec<Amount>,
+    outputs: Vec<Amount>,
+    fee_per_vb: Amount,
+    long_term_fee_per_vb: Amount,
+) -> Proto::SigningInput<'static> {
+    let mut input = plan::make_planning_input(plan::PlanArgs {
+        inputs,
+        outputs,
+        change: true,
+        max: false,
+        dust_threshold: DUST,
+        order: Proto::InputSelector::SelectBranchAndBound,
+        fee_per_vb,
+    });
+    if let TransactionOneof::builder(ref mut builder) = input.transaction {
+        builder.long_term_fee_per_vb = long_term_fee_per_vb;
+    }
+    input
+}
+
+#[test]
+fn test_bnb_selector_changeless() {
+    // Each P2PKH input costs 149 vbytes, i.e. 298 sats at the current fee rate.
+    // The cost of change is 68 (the change output) + 1490 (spending it later at the long-term fee rate).
+    // `3_000 + 4_000` covers `5_000` output and fee with the excess less than the cost of change.
+    let input = make_bnb_planning_input(vec![1_000, 3_000, 4_000, 8_000], vec![5_000], 2, 10);
+    plan::BitcoinPlanHelper::new(&input)
+        .coin(CoinType::Bitcoin)
+        .plan(plan::Expected {
+            inputs: vec![3_000, 4_000],
+            // No change output.
+            outputs: vec![5_000],
+            vsize_estimate: 342,
+            // The excess goes to the fee.
+            fee_estimate: 2_000,
+            change: 0,
+        });
+}
+
+#[test]
+fn test_bnb_selector_knapsack_fallback() {
+    // There is no changeless selection, so a change output is created.
+    let input = make_bnb_planning_input(vec![1_000, 3_000, 4_000, 8_000], vec![1_500], 2, 2);
+    plan::BitcoinPlanHelper::new(&input)
+        .coin(CoinType::Bitcoin)
+        .plan(plan::Expected {
+            inputs: vec![3_000],
+            outputs: vec![1_500, 1_046],
+            vsize_estimate: 227,
+            // vsize * fee_rate
+            fee_estimate: 454,
+            change: 1_046,
+        });
+}
+
+#[test]
+fn test_bnb_selector_default_long_term_fee_rate() {
+    // The long-term fee rate defaults to 10 sat/vB, so spending a change output later is expensive
+    // and a changeless selection is preferred over `test_bnb_selector_knapsack_fallback`.
+    let input = make_bnb_planning_input(vec![1_000, 3_000, 4_000, 8_000], vec![1_500], 2, 0);
+    plan::BitcoinPlanHelper::new(&input)
+        .coin(CoinType::Bitcoin)
+        .plan(plan::Expected {
+            inputs: vec![3_000],
+            outputs: vec![1_500],
+            vsize_estimate: 193,
+            // The excess goes to the fee.
+            fee_estimate: 1_500,
+            change: 0,
+        });
+}
+
+#[test]
+fn test_bnb_selector_insufficient() {
+    let input = make_bnb_planning_input(vec![4_000, 4_000, 4_000, 4_000], vec![15_000], 2, 10);
+
+    let mut planner = AnyPlannerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Bitcoin, input);
+    assert_eq!(
+        plan.error,
+        CommonProto::SigningError::Error_not_enough_utxos
+    );
+}
@@ -2,7 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::chains::common::bitcoin::{plan, Amount, DUST};
+use crate::chains::common::bitcoin::{plan, Amount, TransactionOneof, DUST};
 use tw_coin_registry::coin_type::CoinType;
 use tw_proto::BitcoinV2::Proto;
 
@@ -270,3 +270,33 @@ fn test_exact_selector_filter_utxo_dust_without_change() {
             change: 0,
         });
 }
+
+#[test]
+fn test_exact_selector_dynamic_dust_policy() {
+    let mut input = plan::make_planning_input(plan::PlanArgs {
+        inputs: vec![1_000, 3_000, 4_000],
+        outputs: vec![1_000, 1_900],
+        change: true,
+        max: false,
+        dust_threshold: DUST,
+        order: Proto::InputSelector::SelectAscending,
+        fee_per_vb: 2,
+    });
+    // Bitcoin Core's default dust relay fee is 3000 sat/kvB, which gives the well-known 546 sats P2PKH threshold.
+    // With a lower 1000 sat/kvB rate the P2PKH threshold is 182 sats,
+    // so the change output is not considered as dust unlike with the fixed 546 threshold.
+    if let TransactionOneof::builder(ref mut builder) = input.transaction {
+        builder.dust_policy =
+            Proto::mod_TransactionBuilder::OneOfdust_policy::dust_relay_fee_per_kvb(1_000);
+    }
+
+    plan::BitcoinPlanHelper::new(&input)
+        .coin(CoinType::Bitcoin)
+        .plan(plan::Expected {
+            inputs: vec![1_000, 3_000],
+            outputs: vec![1_000, 1_900, 280],
+            vsize_estimate: 410,
+            fee_estimate: 820,
+            change: 280,
+        });
+}
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{btc_info, input, plan, TransactionOneof, DUST, SIGHASH_ALL};
+use tw_any_coin::test_utils::plan_utils::AnyPlannerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto as CommonProto;
+
+const MY_PUBLIC_KEY: &str = "03a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c";
+/// Sends 20_000 sats to "bc1q2dsdlq3343vk29runkgv4yc292hmq53jedfjmp" and 29_000 sats back as a change,
+/// spending a single 50_000 sats P2WPKH UTXO. Fee is 1_000 sats, vsize is 141, i.e. ~7 sat/vB.
+const ORIGINAL_TX: &str = "020000000001018d0e94ed369601d13f32bd653071844dfe7139508d2e71d9c105d1faa58230b30100000000fdffffff02204e0000000000001600145360df8231ac5965147c9d90ca930a2aafb05232487100000000000016001460d7ee599766db323fb1916c7f9e5d818aaf8c1b02483045022100ae2b551832f20bd883fb67b535906dd2dd6b86cd52159876c228a9b35db2a4ae022062bc2ffdf6a8695b6a3d2f84703e9b6f828cc2d2e2e6ac69b138178370e5470d012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c00000000";
+const CHANGE_OUTPUT_INDEX: u32 = 1;
+
+fn p2wpkh_utxo(txid: &str, vout: u32, value: i64) -> Proto::Input<'static> {
+    Proto::Input {
+        out_point: input::out_point(txid, vout),
+        value,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wpkh(MY_PUBLIC_KEY.decode_hex().unwrap()),
+        ..Default::default()
+    }
+}
+
+fn original_utxo() -> Proto::Input<'static> {
+    let txid = "b33082a5fad105c1d9712e8d503971fe4d84713065bd323fd1019636ed940e8d";
+    p2wpkh_utxo(txid, 1, 50_000)
+}
+
+fn extra_utxo() -> Proto::Input<'static> {
+    let txid = "1f62c18bfc5f8293a2b7b061587c427bf830fb224289f9a806e6ad48de6a4c7d";
+    p2wpkh_utxo(txid, 1, 40_000)
+}
+
+fn fee_bump_input(
+    method: Proto::mod_FeeBump::OneOfmethod<'static>,
+    extra_inputs: Vec<Proto::Input<'static>>,
+    fee_per_vb: i64,
+) -> Proto::SigningInput<'static> {
+    let fee_bump = Proto::FeeBump {
+        original_tx: ORIGINAL_TX.decode_hex().unwrap().into(),
+        original_inputs: vec![original_utxo()],
+        extra_inputs,
+        fee_per_vb,
+        change_output_index: CHANGE_OUTPUT_INDEX,
+        dust_policy: Proto::mod_FeeBump::OneOfdust_policy::fixed_dust_threshold(DUST),
+        method,
+    };
+
+    Proto::SigningInput {
+        public_keys: vec![MY_PUBLIC_KEY.decode_hex().unwrap().into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::fee_bump(fee_bump),
+        ..Default::default()
+    }
+}
+
+fn rbf() -> Proto::mod_FeeBump::OneOfmethod<'static> {
+    Proto::mod_FeeBump::OneOfmethod::replace_by_fee(Proto::mod_FeeBump::ReplaceByFee::default())
+}
+
+fn cpfp() -> Proto::mod_FeeBump::OneOfmethod<'static> {
+    Proto::mod_FeeBump::OneOfmethod::child_pays_for_parent(Proto::mod_FeeBump::ChildPaysForParent {
+        change_utxo: Some(Proto::Input {
+            sighash_type: SIGHASH_ALL,
+            claiming_script: input::p2wpkh(MY_PUBLIC_KEY.decode_hex().unwrap()),
+            ..Default::default()
+        }),
+        // Send the change back to the same address.
+        destination: None,
+    })
+}
+
+#[test]
+fn test_plan_fee_bump_rbf() {
+    let input = fee_bump_input(rbf(), vec![extra_utxo()], 20);
+    plan::BitcoinPlanHelper::new(&input)
+        .coin(CoinType::Bitcoin)
+        .plan(plan::Expected {
+            // The original input is enough to pay the target fee.
+            inputs: vec![50_000],
+            // The change output shrinks.
+            outputs: vec![20_000, 27_180],
+            vsize_estimate: 141,
+            fee_estimate: 141 * 20,
+            change: 27_180,
+        });
+}
+
+#[test]
+fn test_plan_fee_bump_rbf_extra_inputs() {
+    let input = fee_bump_input(rbf(), vec![extra_utxo()], 250);
+    plan::BitcoinPlanHelper::new(&input)
+        .coin(CoinType::Bitcoin)
+        .plan(plan::Expected {
+            inputs: vec![50_000, 40_000],
+            outputs: vec![20_000, 17_750],
+            vsize_estimate: 209,
+            fee_estimate: 209 * 250,
+            change: 17_750,
+        });
+}
+
+#[test]
+fn test_plan_fee_bump_rbf_insufficient() {
+    let input = fee_bump_input(rbf(), Vec::default(), 250);
+
+    let mut planner = AnyPlannerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Bitcoin, input);
+    assert_eq!(
+        plan.error,
+        CommonProto::SigningError::Error_not_enough_utxos
+    );
+}
+
+#[test]
+fn test_plan_fee_bump_rbf_not_signalled() {
+    let mut input = fee_bump_input(rbf(), Vec::default(), 20);
+    if let TransactionOneof::fee_bump(ref mut fee_bump) = input.transaction {
+        // Set the original input sequence to `0xffffffff`.
+        let original_tx = ORIGINAL_TX.replace("00000000fdffffff02", "00000000ffffffff02");
+        fee_bump.original_tx = original_tx.as_str().decode_hex().unwrap().into();
+    }
+
+    let mut planner = AnyPlannerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Bitcoin, input);
+    assert_eq!(plan.error, CommonProto::SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_plan_fee_bump_cpfp() {
+    let input = fee_bump_input(cpfp(), Vec::default(), 20);
+    plan::BitcoinPlanHelper::new(&input)
+        .coin(CoinType::Bitcoin)
+        .plan(plan::Expected {
+            // The change output of the original transaction.
+            inputs: vec![29_000],
+            outputs: vec![24_930],
+            vsize_estimate: 110,
+            // The package fee is `(141 + 110) * 20`, and the original transaction pays 1_000 sats.
+            // The fee rate is raised to 37 sat/vB to cover the rest.
+            fee_estimate: 110 * 37,
+            // The whole amount is sent to the destination.
+            change: 0,
+        });
+}
+
+#[test]
+fn test_plan_fee_bump_low_fee_rate() {
+    // The original transaction already pays ~7 sat/vB.
+    let input = fee_bump_input(rbf(), Vec::default(), 7);
+
+    let mut planner = AnyPlannerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Bitcoin, input);
+    assert_eq!(plan.error, CommonProto::SigningError::Error_wrong_fee);
+}
+
+#[test]
+fn test_plan_fee_bump_unknown_original_input() {
+    let mut input = fee_bump_input(rbf(), Vec::default(), 20);
+    if let TransactionOneof::fee_bump(ref mut fee_bump) = input.transaction {
+        fee_bump.original_inputs = vec![extra_utxo()];
+    }
+
+    let mut planner = AnyPlannerHelper::<Proto::TransactionPlan>::default();
+    let plan = planner.plan(CoinType::Bitcoin, input);
+    assert_eq!(plan.error, CommonProto::SigningError::Error_invalid_utxo);
+}
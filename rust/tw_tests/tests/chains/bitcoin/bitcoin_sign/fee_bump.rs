@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{btc_info, input, TransactionOneof, DUST, SIGHASH_ALL};
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::ecdsa;
+use tw_misc::traits::{ToBytesVec, ToBytesZeroizing};
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+const MY_PRIVATE_KEY: &str = "9ea2172511ed73ae0096be8e593c3b75631700edaf729f1abbae607314a20e35";
+/// Sends 20_000 sats to "bc1q2dsdlq3343vk29runkgv4yc292hmq53jedfjmp" and 29_000 sats back as a change,
+/// spending a single 50_000 sats P2WPKH UTXO. Fee is 1_000 sats.
+const ORIGINAL_TX: &str = "020000000001018d0e94ed369601d13f32bd653071844dfe7139508d2e71d9c105d1faa58230b30100000000fdffffff02204e0000000000001600145360df8231ac5965147c9d90ca930a2aafb05232487100000000000016001460d7ee599766db323fb1916c7f9e5d818aaf8c1b02483045022100ae2b551832f20bd883fb67b535906dd2dd6b86cd52159876c228a9b35db2a4ae022062bc2ffdf6a8695b6a3d2f84703e9b6f828cc2d2e2e6ac69b138178370e5470d012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c00000000";
+
+fn fee_bump_input(
+    method: Proto::mod_FeeBump::OneOfmethod<'static>,
+) -> Proto::SigningInput<'static> {
+    let my_private_key = ecdsa::secp256k1::PrivateKey::try_from(MY_PRIVATE_KEY).unwrap();
+
+    let original_utxo = Proto::Input {
+        out_point: input::out_point(
+            "b33082a5fad105c1d9712e8d503971fe4d84713065bd323fd1019636ed940e8d",
+            1,
+        ),
+        value: 50_000,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wpkh(my_private_key.public().to_vec()),
+        ..Default::default()
+    };
+
+    let fee_bump = Proto::FeeBump {
+        original_tx: ORIGINAL_TX.decode_hex().unwrap().into(),
+        original_inputs: vec![original_utxo],
+        fee_per_vb: 20,
+        change_output_index: 1,
+        dust_policy: Proto::mod_FeeBump::OneOfdust_policy::fixed_dust_threshold(DUST),
+        method,
+        ..Default::default()
+    };
+
+    Proto::SigningInput {
+        private_keys: vec![my_private_key.to_zeroizing_vec().to_vec().into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::fee_bump(fee_bump),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_bitcoin_sign_fee_bump_rbf() {
+    let input = fee_bump_input(Proto::mod_FeeBump::OneOfmethod::replace_by_fee(
+        Proto::mod_FeeBump::ReplaceByFee::default(),
+    ));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Bitcoin, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // Spends the same UTXO with the same sequence, the change output is decreased by 1_820 sats.
+    assert_eq!(
+        output.encoded.to_hex(),
+        "020000000001018d0e94ed369601d13f32bd653071844dfe7139508d2e71d9c105d1faa58230b30100000000fdffffff02204e0000000000001600145360df8231ac5965147c9d90ca930a2aafb052322c6a00000000000016001460d7ee599766db323fb1916c7f9e5d818aaf8c1b02483045022100fbcd5f9ab540738fcfe2d277f6807eabcb7ac513c100e60347bab5c4de63a06102200cd448dd869925336cddd79246bc98352d672e566c776813db10ab496759f522012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c00000000"
+    );
+    assert_eq!(
+        output.txid.to_hex(),
+        "8a03aaac3ad0df4bb7fb7e8c57de58e272ca0430537386859b3513077fd4b0bd"
+    );
+    assert_eq!(output.vsize, 141);
+    assert_eq!(output.weight, 562);
+    assert_eq!(output.fee, 2_820);
+}
+
+#[test]
+fn test_bitcoin_sign_fee_bump_cpfp() {
+    let my_private_key = ecdsa::secp256k1::PrivateKey::try_from(MY_PRIVATE_KEY).unwrap();
+    let input = fee_bump_input(Proto::mod_FeeBump::OneOfmethod::child_pays_for_parent(
+        Proto::mod_FeeBump::ChildPaysForParent {
+            change_utxo: Some(Proto::Input {
+                sighash_type: SIGHASH_ALL,
+                claiming_script: input::p2wpkh(my_private_key.public().to_vec()),
+                ..Default::default()
+            }),
+            destination: None,
+        },
+    ));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Bitcoin, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // Spends the change output of "ab6969e65fa15e42e46b8cf48d2cd3c9684c408846aab0671f0e27bdaf8c5196" original transaction.
+    assert_eq!(
+        output.encoded.to_hex(),
+        "0200000000010196518cafbd270e1f67b0aa4688404c68c9d32c8df48c6be4425ea15fe66969ab0100000000ffffffff01626100000000000016001460d7ee599766db323fb1916c7f9e5d818aaf8c1b02483045022100adf3f6d19368f51a8a0997c03333c13505962ee381638045c0b0e901e5daa9da0220766b59b0be49cf90123fa0dca808a520ac746c4abaa1b9d62d5dd39e6db8399e012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c00000000"
+    );
+    assert_eq!(
+        output.txid.to_hex(),
+        "ef46c3c7a9a95aab5eabbadff1bd8b6ea406f0a0eabb56b9451edefe41c07d2c"
+    );
+    assert_eq!(output.vsize, 110);
+    assert_eq!(output.weight, 438);
+    // `(141 + 110) * 20 - 1_000` rounded up to 37 sat/vB.
+    assert_eq!(output.fee, 4_070);
+}
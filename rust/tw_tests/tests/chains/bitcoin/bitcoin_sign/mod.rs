@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 mod brc20;
+mod fee_bump;
 mod op_return;
 mod ordinal_nft;
 mod p2pkh;
@@ -12,5 +13,6 @@ mod p2tr_script_path;
 mod p2wpkh;
 mod p2wsh;
 mod psbt;
+mod psbt_roles;
 mod send_to_address;
 mod sighash_single;
@@ -108,84 +108,65 @@ fn test_bitcoin_sign_output_p2sh_with_address() {
     test_bitcoin_sign_input_p2pkh_output_p2sh(P2SHRecipientType::P2SHAddress);
 }
 
-// TODO uncomment when P2SH spend is ready
-// #[test]
-// fn test_bitcoin_sign_input_p2sh_output_p2pkh() {
-//     // Create transaction with P2SH as input (claim).
-//
-//     let txid: Vec<u8> = hex("5d99b77a411a879fb6fa5b442f0d121965346d8e5ab61e0d189967fd5f49bd82")
-//         .into_iter()
-//         .rev()
-//         .collect();
-//
-//     let tx1 = Proto::Input {
-//         txid: txid.as_slice().into(),
-//         vout: 0,
-//         value: 50 * ONE_BTC - MINER_FEE,
-//         sighash_type: UtxoProto::SighashType::All,
-//         to_recipient: ProtoInputRecipient::builder(Proto::mod_Input::InputBuilder {
-//             // The way P2SH is signed in Bitcoin, we first place the redeem script directly here.
-//             variant: ProtoInputBuilder::p2sh(redeem_script.as_bytes().into()),
-//         }),
-//         ..Default::default()
-//     };
-//
-//     let out1 = Proto::Output {
-//         value: 50 * ONE_BTC - MINER_FEE - MINER_FEE,
-//         to_recipient: ProtoOutputRecipient::builder(Proto::mod_Output::OutputBuilder {
-//             variant: ProtoOutputBuilder::p2pkh(Proto::ToPublicKeyOrHash {
-//                 to_address: ProtoPubkeyOrHash::pubkey(alice_pubkey.as_slice().into()),
-//             }),
-//         }),
-//     };
-//
-//     let mut signing = Proto::SigningInput {
-//         inputs: vec![tx1],
-//         outputs: vec![out1],
-//         input_selector: UtxoProto::InputSelector::UseAll,
-//         disable_change_output: true,
-//         ..Default::default()
-//     };
-//
-//     // Generate the sighashes.
-//     let sighashes = BitcoinEntry.preimage_hashes(&coin, signing.clone());
-//     assert_eq!(sighashes.error, Proto::Error::OK);
-//
-//     // Sign the sighashes.
-//     let signatures = Signer::signatures_from_proto(
-//         &sighashes,
-//         bob_private_key.to_vec(),
-//         Default::default(),
-//         false,
-//     )
-//     .unwrap();
-//
-//     let sig = &signatures[0];
-//
-//     // Construc the final redeem scrip with the necessary stack items (signature + pubkey).
-//     let mut sig_buf = PushBytesBuf::new();
-//     sig_buf.extend_from_slice(sig).unwrap();
-//
-//     let mut redeem_buf = PushBytesBuf::new();
-//     redeem_buf
-//         .extend_from_slice(redeem_script.as_bytes())
-//         .unwrap();
-//
-//     let finalized = ScriptBuf::builder()
-//         .push_slice(sig_buf)
-//         .push_key(&bob_native_pubkey)
-//         .push_slice(redeem_buf.as_push_bytes())
-//         .into_script();
-//
-//     // Now that we've signed the input, we update the input with the complete,
-//     // finalized redeem script.
-//     signing.inputs[0].to_recipient = ProtoInputRecipient::builder(Proto::mod_Input::InputBuilder {
-//         variant: ProtoInputBuilder::p2sh(finalized.as_bytes().into()),
-//     });
-//
-//     // Compile the final transaction.
-//     let signed = BitcoinEntry.compile(&coin, signing, signatures, vec![]);
-//     let encoded = tw_encoding::hex::encode(signed.encoded, false);
-//     assert_eq!(signed.error, Proto::Error::OK);
-//     assert_eq!(&encoded, "020000000182bd495ffd6799180d1eb65a8e6d346519120d2f445bfab69f871a417ab7995d000000008447304402207aad4b72c6d78c81a1e795325bd5ddb449f0a1363205903f5e37950e6b89054102202aaf4dd919700d21fe2431352df99c434378bd0d46b778b445079579300effdf0121037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf1976a9145eaaa4f458f9158f86afcba08dd7448d27045e3d88acffffffff01806de729010000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac00000000");
-// }
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_bitcoin_sign_input_p2sh_output_p2pkh() {
+    let alice_private_key = "56429688a1a6b00b90ccd22a0de0a376b6569d8684022ae92229a28478bfb657";
+    let alice_private_key = ecdsa::secp256k1::PrivateKey::try_from(alice_private_key).unwrap();
+    let alice_public_key = alice_private_key.public();
+
+    let bob_privkey_data = "b7da1ec42b19085fe09fec54b9d9eacd998ae4e6d2ad472be38d8393391b9ead"
+        .decode_hex()
+        .unwrap();
+    let bob_private_key =
+        ecdsa::secp256k1::PrivateKey::try_from(bob_privkey_data.as_slice()).unwrap();
+    let bob_public_key = bob_private_key.public();
+
+    // The redeem script is a simple P2PKH (ie. P2PKH embedded inside P2SH).
+    let bob_pubkey_hash = sha256_ripemd(bob_public_key.compressed().as_slice());
+    let bob_pubkey_hash = H160::try_from(bob_pubkey_hash.as_slice()).unwrap();
+    let redeem_script = conditions::new_p2pkh(&bob_pubkey_hash);
+
+    // Create transaction with P2SH as input (claim).
+    let txid = "5d99b77a411a879fb6fa5b442f0d121965346d8e5ab61e0d189967fd5f49bd82";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: 50 * ONE_BTC - MINER_FEE,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2sh(redeem_script.to_vec()),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: 50 * ONE_BTC - 2 * MINER_FEE,
+        to_recipient: output::p2pkh(alice_public_key.compressed().to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    let input = Proto::SigningInput {
+        private_keys: vec![bob_privkey_data.into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    sign::BitcoinSignHelper::new(&input)
+        .coin(CoinType::Bitcoin)
+        .sign(sign::Expected {
+            encoded: "020000000182bd495ffd6799180d1eb65a8e6d346519120d2f445bfab69f871a417ab7995d000000008447304402207aad4b72c6d78c81a1e795325bd5ddb449f0a1363205903f5e37950e6b89054102202aaf4dd919700d21fe2431352df99c434378bd0d46b778b445079579300effdf0121037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf1976a9145eaaa4f458f9158f86afcba08dd7448d27045e3d88acffffffff01806de729010000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac00000000",
+            txid: "c01007bb55bde4e70278e1154c34db72f34a833687d3f37443bd5c49137ee5fe",
+            inputs: vec![50 * ONE_BTC - MINER_FEE],
+            outputs: vec![50 * ONE_BTC - 2 * MINER_FEE],
+            vsize: 217,
+            weight: 868,
+            fee: MINER_FEE,
+        });
+}
@@ -1,14 +1,24 @@
 use crate::chains::common::bitcoin::{
-    btc_info, dust_threshold, input, output, sign, TransactionOneof, DUST, SIGHASH_ALL,
+    btc_info, dust_threshold, input, output, sign, TaprootSpendInfoType, TransactionOneof, DUST,
+    SIGHASH_ALL,
 };
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::DecodeHex;
 use tw_keypair::schnorr;
 use tw_misc::traits::ToBytesVec;
 use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto as CommonProto;
 use tw_utxo::transaction::asset::brc20::{BRC20TransferInscription, Brc20Ticker};
 
 const ALICE_PRIVATE_KEY: &str = "e253373989199da27c48680e3a3fc0f648d50f9a727ef17a7fe6a4dc3b159129";
+const BOB_PRIVATE_KEY: &str = "b7da1ec42b19085fe09fec54b9d9eacd998ae4e6d2ad472be38d8393391b9ead";
+
+/// `<144> OP_CHECKSEQUENCEVERIFY OP_DROP <bob_pubkey> OP_CHECKSIG`
+const BOB_TIMELOCK_LEAF: &str =
+    "029000b275207ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bfac";
+/// `<alice_pubkey> OP_CHECKSIG`
+const ALICE_LEAF: &str = "200f209b6ada5edb42c77fd2bc64ad650ae38314c8f451f3e36d80bc8e26f132cbac";
 
 /// A test for the custom P2TR script-path builders. This test essentially
 /// reconstruct the BRC20 transfer tests, but without using the convenience
@@ -78,73 +88,158 @@ fn test_bitcoin_sign_output_p2tr_custom_script_path() {
         });
 }
 
-// TODO uncomment when input P2TR script path
-// #[test]
-// fn test_bitcoin_sign_input_p2tr_custom_script_path() {
-//     // https://www.blockchain.com/explorer/transactions/btc/797d17d47ae66e598341f9dfdea020b04d4017dcf9cc33f0e51f7a6082171fb1
-//     let txid: Vec<u8> = hex("797d17d47ae66e598341f9dfdea020b04d4017dcf9cc33f0e51f7a6082171fb1")
-//         .into_iter()
-//         .rev()
-//         .collect();
-//
-//     // Prepare the BRC20 payload and control block.
-//     let payload = &transfer.script;
-//     let control_block = transfer
-//         .spend_info
-//         .control_block(&(payload.to_owned(), LeafVersion::TapScript))
-//         .unwrap();
-//
-//     // Provide the payload and control block directly to the builder.
-//     let tx1 = Proto::Input {
-//         txid: txid.as_slice().into(),
-//         vout: 0,
-//         value: 7_000,
-//         sighash_type: UtxoProto::SighashType::UseDefault,
-//         to_recipient: ProtoInputRecipient::builder(Proto::mod_Input::InputBuilder {
-//             variant: ProtoInputBuilder::p2tr_script_path(
-//                 Proto::mod_Input::InputTaprootScriptPath {
-//                     one_prevout: false,
-//                     payload: payload.to_vec().into(),
-//                     control_block: control_block.serialize().into(),
-//                 },
-//             ),
-//         }),
-//         ..Default::default()
-//     };
-//
-//     let out1 = Proto::Output {
-//         value: 546,
-//         to_recipient: ProtoOutputRecipient::builder(Proto::mod_Output::OutputBuilder {
-//             variant: ProtoOutputBuilder::p2wpkh(Proto::ToPublicKeyOrHash {
-//                 to_address: ProtoPubkeyOrHash::pubkey(alice_pubkey.as_slice().into()),
-//             }),
-//         }),
-//     };
-//
-//     let signing = Proto::SigningInput {
-//         private_key: alice_private_key.as_slice().into(),
-//         inputs: vec![tx1],
-//         outputs: vec![out1],
-//         input_selector: UtxoProto::InputSelector::UseAll,
-//         disable_change_output: true,
-//         // We enable deterministic Schnorr signatures here
-//         dangerous_use_fixed_schnorr_rng: true,
-//         ..Default::default()
-//     };
-//
-//     let signed = BitcoinEntry.sign(&coin, signing);
-//     assert_eq!(signed.error, Proto::Error::OK);
-//
-//     // https://www.blockchain.com/explorer/transactions/btc/7046dc2689a27e143ea2ad1039710885147e9485ab6453fa7e87464aa7dd3eca
-//     assert_eq!(
-//         signed.txid,
-//         hex("7046dc2689a27e143ea2ad1039710885147e9485ab6453fa7e87464aa7dd3eca")
-//     );
-//
-//     let encoded = tw_encoding::hex::encode(signed.encoded, false);
-//     let transaction = signed.transaction.unwrap();
-//
-//     assert_eq!(encoded, "02000000000101b11f1782607a1fe5f033ccf9dc17404db020a0dedff94183596ee67ad4177d790000000000ffffffff012202000000000000160014e311b8d6ddff856ce8e9a4e03bc6d4fe5050a83d03406a35548b8fa4620028e021a944c1d3dc6e947243a7bfc901bf63fefae0d2460efa149a6440cab51966aa4f09faef2d1e5efcba23ab4ca6e669da598022dbcfe35b0063036f7264010118746578742f706c61696e3b636861727365743d7574662d3800377b2270223a226272632d3230222c226f70223a227472616e73666572222c227469636b223a226f616466222c22616d74223a223230227d6821c00f209b6ada5edb42c77fd2bc64ad650ae38314c8f451f3e36d80bc8e26f132cb00000000");
-//     assert_eq!(transaction.inputs.len(), 1);
-//     assert_eq!(transaction.outputs.len(), 1);
-// }
+/// Spends a P2TR output committing to a tree of two tapscripts:
+/// * `<144> OP_CHECKSEQUENCEVERIFY OP_DROP <bob_pubkey> OP_CHECKSIG` - Bob can spend the output after 144 blocks.
+/// * `<alice_pubkey> OP_CHECKSIG` - Alice can spend the output at any time.
+///
+/// Alice's key is also used as the internal key. Bob spends the output through the timelocked leaf.
+/// Note this test contains a sample transaction that has never been broadcasted.
+fn bitcoin_sign_input_p2tr_timelock_script_path(spend_info: TaprootSpendInfoType<'static>) {
+    let bob_private_key = schnorr::PrivateKey::try_from(BOB_PRIVATE_KEY).unwrap();
+    let bob_pubkey = bob_private_key.public();
+
+    let txid = "d9a8a2ef2ad7bf6f9f5d9b0a2fb0e6a8d59b9b3e4c5b4a7cb08c0b0a5e7c1d01";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: 10_000,
+        // The relative timelock of the leaf script.
+        sequence: input::sequence(144),
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2tr_script_path(
+            bob_pubkey.to_vec(),
+            BOB_TIMELOCK_LEAF.decode_hex().unwrap(),
+            spend_info,
+        ),
+    };
+
+    let out1 = Proto::Output {
+        value: 9_000,
+        to_recipient: output::p2wpkh(bob_pubkey.to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    let signing = Proto::SigningInput {
+        private_keys: vec![BOB_PRIVATE_KEY.decode_hex().unwrap().into()],
+        chain_info: btc_info(),
+        // We enable deterministic Schnorr signatures here
+        dangerous_use_fixed_schnorr_rng: true,
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    sign::BitcoinSignHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .sign(sign::Expected {
+            encoded: "02000000000101011d7c5e0a0b8cb07c4a5b4c3e9b9bd5a8e6b02f0a9b5d9f6fbfd72aefa2a8d90000000000900000000128230000000000001600145eaaa4f458f9158f86afcba08dd7448d27045e3d0340c6ec6b606e533acaac3cdf1493eab265cc45dd614c544daaf1eb7e2a037dfb72dac510c3f549780f1a94ee94846f23fe5f1fc5239c38093d39d30df6fb536c7227029000b275207ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bfac41c00f209b6ada5edb42c77fd2bc64ad650ae38314c8f451f3e36d80bc8e26f132cb988ab94d6395d6cf058d0d2f7a0811b7dc5b3c371e7a2f70b9030c647a8f05e200000000",
+            txid: "9d754c951fbfd6dbee853186879c5b1a40a9663a7b63a362cdbf94868dbe3320",
+            inputs: vec![10_000],
+            outputs: vec![9_000],
+            vsize: 126,
+            weight: 502,
+            fee: 1_000,
+        });
+}
+
+#[test]
+fn test_bitcoin_sign_input_p2tr_script_path_with_script_tree() {
+    let alice_pubkey = schnorr::PrivateKey::try_from(ALICE_PRIVATE_KEY)
+        .unwrap()
+        .public();
+
+    let leaves = vec![
+        (1, BOB_TIMELOCK_LEAF.decode_hex().unwrap()),
+        (1, ALICE_LEAF.decode_hex().unwrap()),
+    ];
+    bitcoin_sign_input_p2tr_timelock_script_path(input::tr_script_tree(
+        alice_pubkey.to_vec(),
+        leaves,
+    ));
+}
+
+#[test]
+fn test_bitcoin_sign_input_p2tr_script_path_with_merkle_branch() {
+    let alice_pubkey = schnorr::PrivateKey::try_from(ALICE_PRIVATE_KEY)
+        .unwrap()
+        .public();
+
+    // The only sibling of Bob's leaf is Alice's leaf.
+    let alice_leaf_hash = "988ab94d6395d6cf058d0d2f7a0811b7dc5b3c371e7a2f70b9030c647a8f05e2"
+        .decode_hex()
+        .unwrap();
+    bitcoin_sign_input_p2tr_timelock_script_path(input::tr_merkle_branch(
+        // Pass the x-only internal key.
+        alice_pubkey.x_only().bytes().to_vec(),
+        vec![alice_leaf_hash],
+    ));
+}
+
+#[test]
+fn test_bitcoin_sign_input_p2tr_script_path_with_control_block() {
+    let control_block = "c00f209b6ada5edb42c77fd2bc64ad650ae38314c8f451f3e36d80bc8e26f132cb988ab94d6395d6cf058d0d2f7a0811b7dc5b3c371e7a2f70b9030c647a8f05e2";
+    bitcoin_sign_input_p2tr_timelock_script_path(input::tr_control_block(
+        control_block.decode_hex().unwrap(),
+    ));
+}
+
+#[test]
+fn test_bitcoin_sign_input_p2tr_script_path_leaf_not_in_tree() {
+    let alice_pubkey = schnorr::PrivateKey::try_from(ALICE_PRIVATE_KEY)
+        .unwrap()
+        .public();
+    let bob_pubkey = schnorr::PrivateKey::try_from(BOB_PRIVATE_KEY)
+        .unwrap()
+        .public();
+
+    let txid = "d9a8a2ef2ad7bf6f9f5d9b0a2fb0e6a8d59b9b3e4c5b4a7cb08c0b0a5e7c1d01";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: 10_000,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2tr_script_path(
+            bob_pubkey.to_vec(),
+            BOB_TIMELOCK_LEAF.decode_hex().unwrap(),
+            // Bob's leaf is not a part of the script tree.
+            input::tr_script_tree(
+                alice_pubkey.to_vec(),
+                vec![(0, ALICE_LEAF.decode_hex().unwrap())],
+            ),
+        ),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: 9_000,
+        to_recipient: output::p2wpkh(bob_pubkey.to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    let signing = Proto::SigningInput {
+        private_keys: vec![BOB_PRIVATE_KEY.decode_hex().unwrap().into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Bitcoin, signing);
+    assert_eq!(
+        output.error,
+        CommonProto::SigningError::Error_invalid_params
+    );
+}
@@ -16,6 +16,7 @@ use tw_utxo::script::Script;
 
 const ALICE_PRIVATE_KEY: &str = "56429688a1a6b00b90ccd22a0de0a376b6569d8684022ae92229a28478bfb657";
 const BOB_PRIVATE_KEY: &str = "b7da1ec42b19085fe09fec54b9d9eacd998ae4e6d2ad472be38d8393391b9ead";
+const CAROL_PRIVATE_KEY: &str = "7fa638b0df495b2968ae6dc7011c4db08c86df16c91aa71a77ee6a222954e5bb";
 
 fn p2pkh_redeem_script(public_key: &ecdsa::secp256k1::PublicKey) -> Script {
     let pubkey_hash = sha256_ripemd(public_key.compressed().as_slice());
@@ -111,72 +112,124 @@ fn test_bitcoin_sign_output_p2wsh_address() {
     test_bitcoin_sign_output_p2wsh(P2WSHRecipientType::P2WSHAddress);
 }
 
-// TODO uncomment when P2WSH is ready
-// #[test]
-// fn test_bitcoin_sign_input_p2wsh() {
-//     // Create transaction with P2WSH as input (claim).
-//     let txid: Vec<u8> = hex("dd9d4ca23532f5c89d016e1aacef1210ab5b9d00527c633969841daca7dd17c7")
-//         .into_iter()
-//         .rev()
-//         .collect();
-//
-//     let tx1 = Proto::Input {
-//         txid: txid.as_slice().into(),
-//         vout: 0,
-//         value: 50 * ONE_BTC - 3 * MINER_FEE,
-//         sighash_type: UtxoProto::SighashType::All,
-//         to_recipient: ProtoInputRecipient::builder(Proto::mod_Input::InputBuilder {
-//             // The way P2WSH is signed in Bitcoin, we first place the redeem script directly here.
-//             variant: ProtoInputBuilder::p2wsh(redeem_script.to_bytes().into()),
-//         }),
-//         ..Default::default()
-//     };
-//
-//     let out1 = Proto::Output {
-//         value: 50 * ONE_BTC - 4 * MINER_FEE,
-//         to_recipient: ProtoOutputRecipient::builder(Proto::mod_Output::OutputBuilder {
-//             variant: ProtoOutputBuilder::p2pkh(Proto::ToPublicKeyOrHash {
-//                 to_address: ProtoPubkeyOrHash::pubkey(alice_pubkey.as_slice().into()),
-//             }),
-//         }),
-//     };
-//
-//     let mut signing = Proto::SigningInput {
-//         inputs: vec![tx1],
-//         outputs: vec![out1],
-//         input_selector: UtxoProto::InputSelector::UseAll,
-//         disable_change_output: true,
-//         ..Default::default()
-//     };
-//
-//     // Generate the sighashes.
-//     let sighashes = BitcoinEntry.preimage_hashes(&coin, signing.clone());
-//     let sighash = sighashes.sighashes.iter().next().unwrap().sighash.as_ref();
-//     let sighash = H256::try_from(sighash).unwrap();
-//
-//     // Sign the sighash externally.
-//     let private_key = ecdsa::secp256k1::PrivateKey::try_from(bob_private_key.as_slice()).unwrap();
-//     let signature = private_key.sign(sighash).unwrap();
-//
-//     // Construct the final redeem witness stack with the necessary witness stack items (signature + pubkey).
-//     let mut finalized = Witness::new();
-//     finalized.push(sig);
-//     finalized.push(bob_native_pubkey.to_bytes());
-//     finalized.push(redeem_script);
-//
-//     // The witness stack must be encoded correctly.
-//     let mut encoded = vec![];
-//     let _ = finalized.consensus_encode(&mut encoded).unwrap();
-//
-//     // Now that we've signed the input, we update the input with the complete,
-//     // finalized redeem witness stack..
-//     signing.inputs[0].to_recipient = ProtoInputRecipient::builder(Proto::mod_Input::InputBuilder {
-//         variant: ProtoInputBuilder::p2wsh(encoded.into()),
-//     });
-//
-//     // Compile the final transaction.
-//     let signed = BitcoinEntry.compile(&coin, signing, signatures, vec![]);
-//     let encoded = tw_encoding::hex::encode(signed.encoded, false);
-//     assert_eq!(signed.error, Proto::Error::OK);
-//     assert_eq!(&encoded, "02000000000101c717dda7ac1d846939637c52009d5bab1012efac1a6e019dc8f53235a24c9ddd0000000000ffffffff0100e9c829010000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac0347304402201d22810b5580a49a2e73d7c4ea90754b5d70d36adb9a8f0c9cb7393da1d1d28f02207683b2e3d31a5c7e74126681f1f2a7249b7a3a918d5890ef69b94bd3bb4fb9300121037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf1976a9145eaaa4f458f9158f86afcba08dd7448d27045e3d88ac00000000");
-// }
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_bitcoin_sign_input_p2wsh() {
+    let alice_private_key = ecdsa::secp256k1::PrivateKey::try_from(ALICE_PRIVATE_KEY).unwrap();
+    let alice_pubkey = alice_private_key.public();
+
+    let bob_private_key = ecdsa::secp256k1::PrivateKey::try_from(BOB_PRIVATE_KEY).unwrap();
+    let bob_pubkey = bob_private_key.public();
+
+    // Create transaction with P2WSH as input (claim).
+    let txid = "dd9d4ca23532f5c89d016e1aacef1210ab5b9d00527c633969841daca7dd17c7";
+    let redeem_script = p2pkh_redeem_script(&bob_pubkey);
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: 50 * ONE_BTC - 3 * MINER_FEE,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wsh(redeem_script.to_vec()),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: 50 * ONE_BTC - 4 * MINER_FEE,
+        to_recipient: output::p2pkh(alice_pubkey.compressed().to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    let signing = Proto::SigningInput {
+        private_keys: vec![BOB_PRIVATE_KEY.decode_hex().unwrap().into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    sign::BitcoinSignHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .sign(sign::Expected {
+            encoded: "02000000000101c717dda7ac1d846939637c52009d5bab1012efac1a6e019dc8f53235a24c9ddd0000000000ffffffff0100e9c829010000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac0347304402201d22810b5580a49a2e73d7c4ea90754b5d70d36adb9a8f0c9cb7393da1d1d28f02207683b2e3d31a5c7e74126681f1f2a7249b7a3a918d5890ef69b94bd3bb4fb9300121037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf1976a9145eaaa4f458f9158f86afcba08dd7448d27045e3d88ac00000000",
+            txid: "64a05a69415b2d49c601ee42293207bebb7c5a0c93c2b5fb1527aa5ba9616e76",
+            inputs: vec![50 * ONE_BTC - 3 * MINER_FEE],
+            outputs: vec![50 * ONE_BTC - 4 * MINER_FEE],
+            vsize: 119,
+            weight: 475,
+            fee: MINER_FEE,
+        });
+}
+
+/// 2-of-3 multisig P2WSH input signed by Alice and Carol.
+/// Note this test contains a sample transaction that has never been broadcasted.
+#[test]
+fn test_bitcoin_sign_input_p2wsh_multisig() {
+    let alice_private_key = ecdsa::secp256k1::PrivateKey::try_from(ALICE_PRIVATE_KEY).unwrap();
+    let alice_pubkey = alice_private_key.public();
+    let bob_private_key = ecdsa::secp256k1::PrivateKey::try_from(BOB_PRIVATE_KEY).unwrap();
+    let bob_pubkey = bob_private_key.public();
+    let carol_private_key = ecdsa::secp256k1::PrivateKey::try_from(CAROL_PRIVATE_KEY).unwrap();
+    let carol_pubkey = carol_private_key.public();
+
+    let witness_script = conditions::new_multisig(
+        2,
+        &[
+            alice_pubkey.compressed(),
+            bob_pubkey.compressed(),
+            carol_pubkey.compressed(),
+        ],
+    )
+    .unwrap();
+
+    let txid = "64a05a69415b2d49c601ee42293207bebb7c5a0c93c2b5fb1527aa5ba9616e76";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: ONE_BTC,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wsh(witness_script.to_vec()),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: ONE_BTC - MINER_FEE,
+        to_recipient: output::p2pkh(alice_pubkey.compressed().to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    // Bob doesn't sign the transaction.
+    let signing = Proto::SigningInput {
+        private_keys: vec![
+            ALICE_PRIVATE_KEY.decode_hex().unwrap().into(),
+            CAROL_PRIVATE_KEY.decode_hex().unwrap().into(),
+        ],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    sign::BitcoinSignHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .sign(sign::Expected {
+            encoded: "02000000000101766e61a95baa2715fbb5c2930c5a7cbbbe07322942ee01c6492d5b41695aa0640000000000ffffffff01c09ee605000000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac040048304502210089e9b136528b7d0b1b8211305cdd40ac6f1d55224d10d0507a77b270a722231a02205fa378ed3c0fd9256f612053b264c3bb87f13b6bf8b4ceffd3c729dc098bf8ea01483045022100d1ca5466c1d10c3665aef030db9bb6835ef5ca3e0d6d8b9fba198c4c7c42255b02206bf7fe456b869323b1b155150cf7b8e65eb8e1eff977bde3e0907156de0e9fe501695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53ae00000000",
+            txid: "e47d23c95f5e2051aa0bad047ae425d258d16405c6387e44fd413fbb105569b7",
+            inputs: vec![ONE_BTC],
+            outputs: vec![ONE_BTC - MINER_FEE],
+            vsize: 149,
+            weight: 596,
+            fee: MINER_FEE,
+        });
+}
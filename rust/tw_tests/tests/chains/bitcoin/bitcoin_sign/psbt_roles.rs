@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{
+    btc_info, dust_threshold, input, output, TransactionOneof, DUST, MINER_FEE, ONE_BTC,
+    SIGHASH_ALL,
+};
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::ecdsa;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+use tw_utxo::script::standard_script::conditions;
+
+const ALICE_PRIVATE_KEY: &str = "56429688a1a6b00b90ccd22a0de0a376b6569d8684022ae92229a28478bfb657";
+const BOB_PRIVATE_KEY: &str = "b7da1ec42b19085fe09fec54b9d9eacd998ae4e6d2ad472be38d8393391b9ead";
+const CAROL_PRIVATE_KEY: &str = "7fa638b0df495b2968ae6dc7011c4db08c86df16c91aa71a77ee6a222954e5bb";
+
+/// 2-of-3 multisig P2WSH input, the same transaction as in `test_bitcoin_sign_input_p2wsh_multisig`.
+const CREATED_PSBT: &str = "70736274ff0100550200000001766e61a95baa2715fbb5c2930c5a7cbbbe07322942ee01c6492d5b41695aa0640000000000ffffffff01c09ee605000000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac000000000001012b00e1f50500000000220020e46dde69d6633ccedb97106c9f81384c66624d158c028f4b15624f93906b9958010304010000000105695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53ae0000";
+const ALICE_SIGNED_PSBT: &str = "70736274ff0100550200000001766e61a95baa2715fbb5c2930c5a7cbbbe07322942ee01c6492d5b41695aa0640000000000ffffffff01c09ee605000000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac000000000001012b00e1f50500000000220020e46dde69d6633ccedb97106c9f81384c66624d158c028f4b15624f93906b99582202036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953648304502210089e9b136528b7d0b1b8211305cdd40ac6f1d55224d10d0507a77b270a722231a02205fa378ed3c0fd9256f612053b264c3bb87f13b6bf8b4ceffd3c729dc098bf8ea01010304010000000105695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53ae0000";
+const CAROL_SIGNED_PSBT: &str = "70736274ff0100550200000001766e61a95baa2715fbb5c2930c5a7cbbbe07322942ee01c6492d5b41695aa0640000000000ffffffff01c09ee605000000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac000000000001012b00e1f50500000000220020e46dde69d6633ccedb97106c9f81384c66624d158c028f4b15624f93906b995822020372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f483045022100d1ca5466c1d10c3665aef030db9bb6835ef5ca3e0d6d8b9fba198c4c7c42255b02206bf7fe456b869323b1b155150cf7b8e65eb8e1eff977bde3e0907156de0e9fe501010304010000000105695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53ae0000";
+const COMBINED_PSBT: &str = "70736274ff0100550200000001766e61a95baa2715fbb5c2930c5a7cbbbe07322942ee01c6492d5b41695aa0640000000000ffffffff01c09ee605000000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac000000000001012b00e1f50500000000220020e46dde69d6633ccedb97106c9f81384c66624d158c028f4b15624f93906b99582202036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953648304502210089e9b136528b7d0b1b8211305cdd40ac6f1d55224d10d0507a77b270a722231a02205fa378ed3c0fd9256f612053b264c3bb87f13b6bf8b4ceffd3c729dc098bf8ea0122020372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f483045022100d1ca5466c1d10c3665aef030db9bb6835ef5ca3e0d6d8b9fba198c4c7c42255b02206bf7fe456b869323b1b155150cf7b8e65eb8e1eff977bde3e0907156de0e9fe501010304010000000105695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53ae0000";
+
+fn multisig_tx_builder() -> Proto::TransactionBuilder<'static> {
+    let pubkey = |private: &str| {
+        ecdsa::secp256k1::PrivateKey::try_from(private)
+            .unwrap()
+            .public()
+            .compressed()
+    };
+
+    let witness_script = conditions::new_multisig(
+        2,
+        &[
+            pubkey(ALICE_PRIVATE_KEY),
+            pubkey(BOB_PRIVATE_KEY),
+            pubkey(CAROL_PRIVATE_KEY),
+        ],
+    )
+    .unwrap();
+
+    let txid = "64a05a69415b2d49c601ee42293207bebb7c5a0c93c2b5fb1527aa5ba9616e76";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 0),
+        value: ONE_BTC,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wsh(witness_script.to_vec()),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: ONE_BTC - MINER_FEE,
+        to_recipient: output::p2pkh(pubkey(ALICE_PRIVATE_KEY).to_vec()),
+    };
+
+    Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    }
+}
+
+fn psbt(hex: &str) -> Proto::Psbt<'static> {
+    Proto::Psbt {
+        psbt: hex.decode_hex().unwrap().into(),
+        ..Proto::Psbt::default()
+    }
+}
+
+fn signing_input(
+    private_keys: &[&str],
+    transaction: TransactionOneof<'static>,
+) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        private_keys: private_keys
+            .iter()
+            .map(|private| private.decode_hex().unwrap().into())
+            .collect(),
+        chain_info: btc_info(),
+        transaction,
+        ..Default::default()
+    }
+}
+
+fn output_psbt(output: &Proto::SigningOutput) -> String {
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    output
+        .psbt
+        .as_ref()
+        .expect("No PSBT in the SigningOutput")
+        .psbt
+        .to_hex()
+}
+
+#[test]
+fn test_bitcoin_psbt_create() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(&[], TransactionOneof::psbt_create(multisig_tx_builder())),
+    );
+
+    assert_eq!(output_psbt(&output), CREATED_PSBT);
+    assert_eq!(
+        output.psbt.unwrap().psbt_v2.to_hex(),
+        "70736274ff0102040200000001030400000000010401010105010101fb04020000000001012b00e1f50500000000220020e46dde69d6633ccedb97106c9f81384c66624d158c028f4b15624f93906b9958010304010000000105695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53ae010e20766e61a95baa2715fbb5c2930c5a7cbbbe07322942ee01c6492d5b41695aa064010f0400000000011004ffffffff00010308c09ee6050000000001041976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac00"
+    );
+    assert_eq!(output.fee, MINER_FEE);
+    // The transaction is not finalized yet.
+    assert!(output.encoded.is_empty());
+}
+
+#[test]
+fn test_bitcoin_psbt_create_non_segwit_utxo() {
+    let alice_pubkey = ecdsa::secp256k1::PrivateKey::try_from(ALICE_PRIVATE_KEY)
+        .unwrap()
+        .public()
+        .compressed();
+
+    let mut tx_builder = multisig_tx_builder();
+    tx_builder.inputs[0].claiming_script = input::p2pkh(alice_pubkey.to_vec());
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(&[], TransactionOneof::psbt_create(tx_builder)),
+    );
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
+
+#[test]
+fn test_bitcoin_psbt_create_partially_signed() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(
+            &[ALICE_PRIVATE_KEY],
+            TransactionOneof::psbt_create(multisig_tx_builder()),
+        ),
+    );
+    assert_eq!(output_psbt(&output), ALICE_SIGNED_PSBT);
+}
+
+#[test]
+fn test_bitcoin_psbt_sign_partially() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(
+            &[ALICE_PRIVATE_KEY],
+            TransactionOneof::psbt_sign(psbt(CREATED_PSBT)),
+        ),
+    );
+    assert_eq!(output_psbt(&output), ALICE_SIGNED_PSBT);
+    assert_eq!(output.fee, MINER_FEE);
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(
+            &[CAROL_PRIVATE_KEY],
+            TransactionOneof::psbt_sign(psbt(CREATED_PSBT)),
+        ),
+    );
+    assert_eq!(output_psbt(&output), CAROL_SIGNED_PSBT);
+}
+
+#[test]
+fn test_bitcoin_psbt_sign_unknown_private_key() {
+    let unknown_private_key = "9ea2172511ed73ae0096be8e593c3b75631700edaf729f1abbae607314a20e35";
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(
+            &[unknown_private_key],
+            TransactionOneof::psbt_sign(psbt(CREATED_PSBT)),
+        ),
+    );
+    assert_eq!(output.error, SigningError::Error_missing_private_key);
+}
+
+#[test]
+fn test_bitcoin_psbt_combine() {
+    let combine = Proto::PsbtCombine {
+        psbts: vec![
+            ALICE_SIGNED_PSBT.decode_hex().unwrap().into(),
+            CAROL_SIGNED_PSBT.decode_hex().unwrap().into(),
+        ],
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(&[], TransactionOneof::psbt_combine(combine)),
+    );
+    assert_eq!(output_psbt(&output), COMBINED_PSBT);
+}
+
+#[test]
+fn test_bitcoin_psbt_combine_different_transactions() {
+    let other_psbt = "70736274ff0100bc0200000001147010db5fbcf619067c1090fec65c131443fbc80fb4aaeebe940e44206098c60000000000ffffffff0360ea000000000000160014f22a703617035ef7f490743d50f26ae08c30d0a70000000000000000426a403d3a474149412e41544f4d3a636f736d6f7331737377797a666d743675396a373437773537753438746778646575393573757a666c6d7175753a303a743a35303e12000000000000160014b139199ec796f36fc42e637f42da8e3e6720aa9d000000000001011f6603010000000000160014b139199ec796f36fc42e637f42da8e3e6720aa9d00000000";
+    let combine = Proto::PsbtCombine {
+        psbts: vec![
+            ALICE_SIGNED_PSBT.decode_hex().unwrap().into(),
+            other_psbt.decode_hex().unwrap().into(),
+        ],
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(&[], TransactionOneof::psbt_combine(combine)),
+    );
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_bitcoin_psbt_finalize() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(&[], TransactionOneof::psbt_finalize(psbt(COMBINED_PSBT))),
+    );
+
+    // Partial signatures and the witness script are replaced with the final witness.
+    assert_eq!(
+        output_psbt(&output),
+        "70736274ff0100550200000001766e61a95baa2715fbb5c2930c5a7cbbbe07322942ee01c6492d5b41695aa0640000000000ffffffff01c09ee605000000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac000000000001012b00e1f50500000000220020e46dde69d6633ccedb97106c9f81384c66624d158c028f4b15624f93906b99580108fdfe00040048304502210089e9b136528b7d0b1b8211305cdd40ac6f1d55224d10d0507a77b270a722231a02205fa378ed3c0fd9256f612053b264c3bb87f13b6bf8b4ceffd3c729dc098bf8ea01483045022100d1ca5466c1d10c3665aef030db9bb6835ef5ca3e0d6d8b9fba198c4c7c42255b02206bf7fe456b869323b1b155150cf7b8e65eb8e1eff977bde3e0907156de0e9fe501695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53ae0000"
+    );
+    assert_eq!(
+        output.encoded.to_hex(),
+        "02000000000101766e61a95baa2715fbb5c2930c5a7cbbbe07322942ee01c6492d5b41695aa0640000000000ffffffff01c09ee605000000001976a914e4c1ea86373d554b8f4efff2cfb0001ea19124d288ac040048304502210089e9b136528b7d0b1b8211305cdd40ac6f1d55224d10d0507a77b270a722231a02205fa378ed3c0fd9256f612053b264c3bb87f13b6bf8b4ceffd3c729dc098bf8ea01483045022100d1ca5466c1d10c3665aef030db9bb6835ef5ca3e0d6d8b9fba198c4c7c42255b02206bf7fe456b869323b1b155150cf7b8e65eb8e1eff977bde3e0907156de0e9fe501695221036666dd712e05a487916384bfcd5973eb53e8038eccbbf97f7eed775b8738953621037ed9a436e11ec4947ac4b7823787e24ba73180f1edd2857bff19c9f4d62b65bf210372ffe9ba28d6f24e22f25d70c180be6cce20a14629bb9318ba279f85c221f24f53ae00000000"
+    );
+    assert_eq!(
+        output.txid.to_hex(),
+        "e47d23c95f5e2051aa0bad047ae425d258d16405c6387e44fd413fbb105569b7"
+    );
+    assert_eq!(output.vsize, 149);
+    assert_eq!(output.weight, 596);
+    assert_eq!(output.fee, MINER_FEE);
+}
+
+#[test]
+fn test_bitcoin_psbt_finalize_not_enough_signatures() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Bitcoin,
+        signing_input(
+            &[],
+            TransactionOneof::psbt_finalize(psbt(ALICE_SIGNED_PSBT)),
+        ),
+    );
+    assert_eq!(output.error, SigningError::Error_signatures_count);
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+/// https://mempool.space/tx/2b871b6c1112ad0a777f6db1f7a7709154c4d9af8e771ba4eca148915f830e9d
+#[test]
+fn test_bitcoin_decode_segwit_transaction() {
+    let encoded_tx = "01000000000101200e3119012b1ccff35c011523050038bbdd1ca38aa56774c8331edbb5ada6170000000000ffffffff031027000000000000160014daaae0d3de9d8fdee31661e61aea828b59be78640000000000000000166a14a8491d40d4f71a752ca41da0516aed80c33a1b56fc1c000000000000160014540371330ae036602f2a715adaa044ac0856312c02483045022100e29731f7474f9103c6df3434c8c62a540a21ad0e10e23df343b1e81e4b26110602202d37fb4fee5341a41f9e4e65ba2d3e0d2309425ea9806d94eb268efe6f21007001210369cdaf80b4a5fdad91e9face90e848225512884ec2e3ed572ca11dc68e75054700000000"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Bitcoin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(
+        output.txid.to_hex(),
+        "2b871b6c1112ad0a777f6db1f7a7709154c4d9af8e771ba4eca148915f830e9d"
+    );
+    assert_eq!(output.vsize, 172);
+    assert_eq!(output.weight, 686);
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.version, 1);
+    assert_eq!(tx.lock_time, 0);
+
+    assert_eq!(tx.inputs.len(), 1);
+    let out_point = tx.inputs[0].out_point.as_ref().unwrap();
+    assert_eq!(
+        out_point.hash.to_hex(),
+        "200e3119012b1ccff35c011523050038bbdd1ca38aa56774c8331edbb5ada617"
+    );
+    assert_eq!(out_point.vout, 0);
+    assert_eq!(tx.inputs[0].sequence, u32::MAX);
+    assert!(tx.inputs[0].script_sig.is_empty());
+    assert_eq!(tx.inputs[0].witness_items.len(), 2);
+    assert_eq!(
+        tx.inputs[0].witness_items[1].to_hex(),
+        "0369cdaf80b4a5fdad91e9face90e848225512884ec2e3ed572ca11dc68e750547"
+    );
+
+    let values: Vec<_> = tx.outputs.iter().map(|out| out.value).collect();
+    assert_eq!(values, vec![10_000, 0, 7_420]);
+    assert_eq!(
+        tx.outputs[1].script_pubkey.to_hex(),
+        "6a14a8491d40d4f71a752ca41da0516aed80c33a1b56"
+    );
+    assert_eq!(
+        output.output_addresses,
+        vec![
+            "bc1qm24wp577nk8aacckv8np465z3dvmu7ry45el6y",
+            // OP_RETURN output has no address.
+            "",
+            "bc1q2sphzvc2uqmxqte2w9dd4gzy4sy9vvfv0me9ke",
+        ]
+    );
+}
+
+#[test]
+fn test_bitcoin_decode_taproot_output() {
+    let encoded_tx = "02000000000101089098890d2653567b9e8df2d1fbe5c3c8bf1910ca7184e301db0ad3b495c88e0100000000ffffffff02581b000000000000225120e8b706a97732e705e22ae7710703e7f589ed13c636324461afa443016134cc051040000000000000160014e311b8d6ddff856ce8e9a4e03bc6d4fe5050a83d02483045022100a44aa28446a9a886b378a4a65e32ad9a3108870bd725dc6105160bed4f317097022069e9de36422e4ce2e42b39884aa5f626f8f94194d1013007d5a1ea9220a06dce0121030f209b6ada5edb42c77fd2bc64ad650ae38314c8f451f3e36d80bc8e26f132cb00000000"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Bitcoin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(
+        output.txid.to_hex(),
+        "797d17d47ae66e598341f9dfdea020b04d4017dcf9cc33f0e51f7a6082171fb1"
+    );
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.version, 2);
+    let values: Vec<_> = tx.outputs.iter().map(|out| out.value).collect();
+    assert_eq!(values, vec![7_000, 16_400]);
+    assert_eq!(
+        output.output_addresses,
+        vec![
+            "bc1pazmsd2thxtnstc32uacswql87ky76y7xxceygcd053pszcf5eszslw78re",
+            "bc1quvgm34kal7zke68f5nsrh3k5leg9p2pa2nlgsp",
+        ]
+    );
+}
+
+#[test]
+fn test_bitcoin_decode_invalid_transaction() {
+    let encoded_tx = "0100000001".decode_hex().unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Bitcoin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
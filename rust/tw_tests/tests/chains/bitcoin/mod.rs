@@ -4,6 +4,8 @@
 
 mod bitcoin_address;
 mod bitcoin_compile;
+mod bitcoin_message_signer;
 mod bitcoin_plan;
 mod bitcoin_sign;
+mod bitcoin_transaction_decoder;
 mod bitcoin_transaction_util;
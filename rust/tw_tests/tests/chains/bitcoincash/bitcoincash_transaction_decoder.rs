@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::bitcoincash::test_cases::transfer_96ee20;
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+#[test]
+fn test_bitcoincash_decode_transaction() {
+    let encoded_tx = transfer_96ee20::ENCODED_TX.decode_hex().unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::BitcoinCash, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.txid.to_hex(), transfer_96ee20::TX_ID);
+    assert_eq!(output.vsize, 226);
+    assert_eq!(output.weight, 904);
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.version, 1);
+    assert_eq!(tx.inputs.len(), 1);
+    let out_point = tx.inputs[0].out_point.as_ref().unwrap();
+    assert_eq!(
+        out_point.hash.to_hex(),
+        "e28c2b955293159898e34c6840d99bf4d390e2ee1c6f606939f18ee1e2000d05"
+    );
+    assert_eq!(out_point.vout, 2);
+    assert!(tx.inputs[0].witness_items.is_empty());
+
+    let values: Vec<_> = tx.outputs.iter().map(|out| out.value).collect();
+    assert_eq!(values, vec![600, 4325]);
+    // Outputs are displayed as Cash addresses, even if the transaction was built with a legacy address.
+    assert_eq!(
+        output.output_addresses,
+        vec![
+            "bitcoincash:qpmfhhledgp0jy66r5vmwjwmdfu0up7ujqcp07ha9v",
+            "bitcoincash:qz0q3xmg38sr94rw8wg45vujah7kzma3cskxymnw06",
+        ]
+    );
+}
@@ -5,6 +5,7 @@
 mod bitcoincash_address;
 mod bitcoincash_compile;
 mod bitcoincash_sign;
+mod bitcoincash_transaction_decoder;
 mod test_cases;
 
 pub const BCH_SIGHASH_FORK: u32 = 0x40;
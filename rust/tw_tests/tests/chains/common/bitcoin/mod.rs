@@ -31,6 +31,7 @@ pub const BITCOIN_HRP: &str = "bc";
 pub type Amount = i64;
 
 pub use tw_proto::BitcoinV2::Proto::mod_Input::mod_InputBuilder::OneOfvariant as InputBuilderType;
+pub use tw_proto::BitcoinV2::Proto::mod_Input::mod_InputTaprootScriptPath::OneOfspend_info as TaprootSpendInfoType;
 pub use tw_proto::BitcoinV2::Proto::mod_Input::{
     InputBuilder, OneOfclaiming_script as ClaimingScriptType,
 };
@@ -58,6 +59,7 @@ pub fn dust_threshold(threshold: i64) -> Proto::mod_TransactionBuilder::OneOfdus
 pub fn transaction_psbt(hex: &str) -> TransactionOneof {
     TransactionOneof::psbt(Proto::Psbt {
         psbt: hex.decode_hex().unwrap().into(),
+        ..Proto::Psbt::default()
     })
 }
 
@@ -111,6 +113,95 @@ pub mod input {
         claiming_script_builder(InputBuilderType::p2tr_key_path(pubkey.into()))
     }
 
+    pub fn p2tr_script_path(
+        signer: Data,
+        payload: Data,
+        spend_info: TaprootSpendInfoType<'static>,
+    ) -> ClaimingScriptType<'static> {
+        claiming_script_builder(InputBuilderType::p2tr_script_path(
+            Proto::mod_Input::InputTaprootScriptPath {
+                public_key: signer.into(),
+                payload: payload.into(),
+                spend_info,
+            },
+        ))
+    }
+
+    pub fn tr_control_block(control_block: Data) -> TaprootSpendInfoType<'static> {
+        TaprootSpendInfoType::control_block(control_block.into())
+    }
+
+    pub fn tr_merkle_branch(
+        internal_key: Data,
+        hashes: Vec<Data>,
+    ) -> TaprootSpendInfoType<'static> {
+        TaprootSpendInfoType::merkle_branch(Proto::mod_Input::TaprootMerkleBranch {
+            internal_key: internal_key.into(),
+            hashes: hashes.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// `leaves` is a list of `(depth, script)` pairs.
+    pub fn tr_script_tree(
+        internal_key: Data,
+        leaves: Vec<(u32, Data)>,
+    ) -> TaprootSpendInfoType<'static> {
+        let leaves = leaves
+            .into_iter()
+            .map(|(depth, script)| Proto::mod_Input::TapLeaf {
+                depth,
+                script: script.into(),
+            })
+            .collect();
+        TaprootSpendInfoType::script_tree(Proto::mod_Input::TaprootScriptTree {
+            internal_key: internal_key.into(),
+            leaves,
+        })
+    }
+
+    /// `partial_signatures` is a list of `(public_key, signature)` pairs.
+    pub fn script_hash(
+        redeem_script: Data,
+        partial_signatures: Vec<(Data, Data)>,
+    ) -> Proto::mod_Input::InputScriptHash<'static> {
+        let partial_signatures = partial_signatures
+            .into_iter()
+            .map(
+                |(public_key, signature)| Proto::mod_Input::PartialSignature {
+                    public_key: public_key.into(),
+                    signature: signature.into(),
+                },
+            )
+            .collect();
+
+        Proto::mod_Input::InputScriptHash {
+            redeem_script: redeem_script.into(),
+            signers: Vec::default(),
+            partial_signatures,
+        }
+    }
+
+    pub fn p2sh(redeem_script: Data) -> ClaimingScriptType<'static> {
+        p2sh_with_partial_signatures(redeem_script, Vec::default())
+    }
+
+    pub fn p2sh_with_partial_signatures(
+        redeem_script: Data,
+        partial_signatures: Vec<(Data, Data)>,
+    ) -> ClaimingScriptType<'static> {
+        claiming_script_builder(InputBuilderType::p2sh(script_hash(
+            redeem_script,
+            partial_signatures,
+        )))
+    }
+
+    pub fn p2wsh(witness_script: Data) -> ClaimingScriptType<'static> {
+        claiming_script_builder(InputBuilderType::p2wsh(script_hash(
+            witness_script,
+            Vec::default(),
+        )))
+    }
+
     pub fn brc20_inscribe(
         inscribe_to: Data,
         ticker: &str,
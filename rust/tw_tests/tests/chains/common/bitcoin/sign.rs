@@ -108,6 +108,17 @@ impl<'a> BitcoinSignHelper<'a> {
             TransactionOneof::psbt(_) => panic!(
                 "`BitcoinSignHelper` doesn't support PSBT. Consider using `BitcoinPsbtSignHelper`"
             ),
+            TransactionOneof::fee_bump(_) => {
+                panic!(
+                    "`BitcoinSignHelper` doesn't support FeeBump. Consider using `AnySignerHelper`"
+                )
+            },
+            TransactionOneof::psbt_create(_)
+            | TransactionOneof::psbt_sign(_)
+            | TransactionOneof::psbt_combine(_)
+            | TransactionOneof::psbt_finalize(_) => panic!(
+                "`BitcoinSignHelper` doesn't support PSBT roles. Consider using `AnySignerHelper`"
+            ),
             TransactionOneof::None => unreachable!(),
         }
     }
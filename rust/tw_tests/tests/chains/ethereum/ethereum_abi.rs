@@ -10,9 +10,9 @@ use tw_number::U256;
 use tw_proto::EthereumAbi::{Proto as AbiProto, Proto};
 use tw_proto::{deserialize, serialize};
 use wallet_core_rs::ffi::ethereum::abi::{
-    tw_ethereum_abi_decode_contract_call, tw_ethereum_abi_decode_params,
-    tw_ethereum_abi_decode_value, tw_ethereum_abi_encode_function,
-    tw_ethereum_abi_function_get_signature,
+    tw_ethereum_abi_decode_contract_call, tw_ethereum_abi_decode_event_log,
+    tw_ethereum_abi_decode_params, tw_ethereum_abi_decode_revert, tw_ethereum_abi_decode_value,
+    tw_ethereum_abi_encode_function, tw_ethereum_abi_function_get_signature,
 };
 
 use tw_coin_registry::coin_type::CoinType;
@@ -191,3 +191,251 @@ fn test_ethereum_abi_decode_value() {
     assert!(output.error_message.is_empty());
     assert_eq!(output.param_str, "42");
 }
+
+fn decode_event_log(topics: &[&str], data: &str, abi_json: &Json) -> Vec<u8> {
+    let input = AbiProto::EventLogDecodingInput {
+        topics: topics
+            .iter()
+            .map(|topic| topic.decode_hex().unwrap().into())
+            .collect(),
+        data: data.decode_hex().unwrap().into(),
+        smart_contract_abi_json: serde_json::to_string(abi_json).unwrap().into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    TWDataHelper::wrap(unsafe {
+        tw_ethereum_abi_decode_event_log(CoinType::Ethereum as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_abi_decode_event_log returned nullptr")
+}
+
+fn decode_revert(encoded: &str, abi_json: &str) -> Vec<u8> {
+    let input = AbiProto::RevertDecodingInput {
+        encoded: encoded.decode_hex().unwrap().into(),
+        smart_contract_abi_json: abi_json.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    TWDataHelper::wrap(unsafe {
+        tw_ethereum_abi_decode_revert(CoinType::Ethereum as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_abi_decode_revert returned nullptr")
+}
+
+#[test]
+fn test_ethereum_abi_decode_event_log_erc20_transfer() {
+    let abi_json = json!([
+        {
+            "anonymous": false,
+            "inputs": [
+                { "indexed": true, "internalType": "address", "name": "from", "type": "address" },
+                { "indexed": true, "internalType": "address", "name": "to", "type": "address" },
+                { "indexed": false, "internalType": "uint256", "name": "value", "type": "uint256" }
+            ],
+            "name": "Transfer",
+            "type": "event"
+        }
+    ]);
+
+    let output_data = decode_event_log(
+        &[
+            "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            "00000000000000000000000088341d1a8f672d2780c8dc725902aae72f143b0c",
+            "0000000000000000000000007d8bf18c7ce84b3e175b339c4ca93aed1dd166f1",
+        ],
+        "00000000000000000000000000000000000000000000000000000000000f4240",
+        &abi_json,
+    );
+    let output: AbiProto::EventLogDecodingOutput = deserialize(&output_data).unwrap();
+
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.event_signature, "Transfer(address,address,uint256)");
+
+    let expected_tokens = vec![
+        named_token(
+            "from",
+            TokenEnum::address("0x88341d1a8F672D2780C8dC725902AAe72F143B0c".into()),
+        ),
+        named_token(
+            "to",
+            TokenEnum::address("0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1".into()),
+        ),
+        named_token("value", TokenEnum::number_uint(number_n::<256>(1_000_000))),
+    ];
+    assert_eq!(output.tokens, expected_tokens);
+
+    let actual: Json = serde_json::from_str(&output.decoded_json).unwrap();
+    let expected = json!({
+        "event": "Transfer(address,address,uint256)",
+        "inputs": [
+            { "name": "from", "type": "address", "value": "0x88341d1a8F672D2780C8dC725902AAe72F143B0c" },
+            { "name": "to", "type": "address", "value": "0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1" },
+            { "name": "value", "type": "uint256", "value": "1000000" }
+        ]
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_ethereum_abi_decode_event_log_hashed_and_anonymous() {
+    let abi_json = json!([
+        {
+            "anonymous": false,
+            "inputs": [
+                { "indexed": true, "name": "name", "type": "string" },
+                { "indexed": true, "name": "sender", "type": "address" },
+                { "indexed": false, "name": "amount", "type": "uint256" }
+            ],
+            "name": "Deposit",
+            "type": "event"
+        },
+        {
+            "anonymous": true,
+            "inputs": [
+                { "indexed": true, "name": "from", "type": "address" },
+                { "indexed": false, "name": "value", "type": "uint256" }
+            ],
+            "name": "Ping",
+            "type": "event"
+        }
+    ]);
+
+    // An indexed `string` parameter is stored as a Keccak-256 hash of its value, i.e. keccak256("alice").
+    let output_data = decode_event_log(
+        &[
+            "d327b35e36b3981157588978d60961f5c09dc2926008abb81dd77b1197a416ed",
+            "9c0257114eb9399a2985f8e75dad7600c5d89fe3824ffa99ec1c3eb8bf3b0501",
+            "00000000000000000000000088341d1a8f672d2780c8dc725902aae72f143b0c",
+        ],
+        "000000000000000000000000000000000000000000000000000000000000002a",
+        &abi_json,
+    );
+    let output: AbiProto::EventLogDecodingOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(output.event_signature, "Deposit(string,address,uint256)");
+    let expected_tokens = vec![
+        named_token(
+            "name",
+            TokenEnum::byte_array_fix(
+                "9c0257114eb9399a2985f8e75dad7600c5d89fe3824ffa99ec1c3eb8bf3b0501"
+                    .decode_hex()
+                    .unwrap()
+                    .into(),
+            ),
+        ),
+        named_token(
+            "sender",
+            TokenEnum::address("0x88341d1a8F672D2780C8dC725902AAe72F143B0c".into()),
+        ),
+        named_token("amount", TokenEnum::number_uint(number_n::<256>(42))),
+    ];
+    assert_eq!(output.tokens, expected_tokens);
+
+    // An anonymous event doesn't have the event selector topic.
+    let output_data = decode_event_log(
+        &["0000000000000000000000007d8bf18c7ce84b3e175b339c4ca93aed1dd166f1"],
+        "0000000000000000000000000000000000000000000000000000000000000007",
+        &abi_json,
+    );
+    let output: AbiProto::EventLogDecodingOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(output.event_signature, "Ping(address,uint256)");
+    let expected_tokens = vec![
+        named_token(
+            "from",
+            TokenEnum::address("0x7d8bf18C7cE84b3E175b339c4Ca93aEd1dD166F1".into()),
+        ),
+        named_token("value", TokenEnum::number_uint(number_n::<256>(7))),
+    ];
+    assert_eq!(output.tokens, expected_tokens);
+
+    // Unknown event selector and unexpected number of topics.
+    let output_data = decode_event_log(
+        &[
+            "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+            "00000000000000000000000088341d1a8f672d2780c8dc725902aae72f143b0c",
+        ],
+        "",
+        &abi_json,
+    );
+    let output: AbiProto::EventLogDecodingOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, AbiErrorKind::Error_abi_mismatch);
+    assert!(output.tokens.is_empty());
+}
+
+#[test]
+fn test_ethereum_abi_decode_revert_builtin() {
+    // revert("Insufficient balance")
+    let encoded = "08c379a000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000014496e73756666696369656e742062616c616e6365000000000000000000000000";
+    let output_data = decode_revert(encoded, "");
+    let output: AbiProto::RevertDecodingOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(output.error_signature, "Error(string)");
+    assert_eq!(output.reason, "Insufficient balance");
+    assert_eq!(
+        output.tokens,
+        vec![named_token(
+            "message",
+            TokenEnum::string_value("Insufficient balance".into())
+        )]
+    );
+
+    // Arithmetic overflow.
+    let output_data = decode_revert(
+        "4e487b710000000000000000000000000000000000000000000000000000000000000011",
+        "",
+    );
+    let output: AbiProto::RevertDecodingOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(output.error_signature, "Panic(uint256)");
+    assert_eq!(
+        output.reason,
+        "Panic(0x11): arithmetic operation overflowed or underflowed"
+    );
+    assert_eq!(
+        output.tokens,
+        vec![named_token(
+            "code",
+            TokenEnum::number_uint(number_n::<256>(0x11))
+        )]
+    );
+}
+
+#[test]
+fn test_ethereum_abi_decode_revert_custom_error() {
+    let abi_json = json!([
+        {
+            "inputs": [
+                { "internalType": "uint256", "name": "available", "type": "uint256" },
+                { "internalType": "uint256", "name": "required", "type": "uint256" }
+            ],
+            "name": "InsufficientBalance",
+            "type": "error"
+        }
+    ]);
+    let abi_json = serde_json::to_string(&abi_json).unwrap();
+    let encoded = "cf479181000000000000000000000000000000000000000000000000000000000000006400000000000000000000000000000000000000000000000000000000000000fa";
+
+    let output_data = decode_revert(encoded, &abi_json);
+    let output: AbiProto::RevertDecodingOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(
+        output.error_signature,
+        "InsufficientBalance(uint256,uint256)"
+    );
+    assert!(output.reason.is_empty());
+    let expected_tokens = vec![
+        named_token("available", TokenEnum::number_uint(number_n::<256>(100))),
+        named_token("required", TokenEnum::number_uint(number_n::<256>(250))),
+    ];
+    assert_eq!(output.tokens, expected_tokens);
+
+    // The custom error cannot be decoded without ABI.
+    let output_data = decode_revert(encoded, "");
+    let output: AbiProto::RevertDecodingOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, AbiErrorKind::Error_abi_mismatch);
+    assert!(output.error_signature.is_empty());
+}
@@ -21,6 +21,7 @@ fn test_tw_message_signer_sign() {
         message: "Foo".into(),
         chain_id: None,
         message_type: Ethereum::Proto::MessageType::MessageType_legacy,
+        ..Ethereum::Proto::MessageSigningInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
@@ -72,6 +73,7 @@ fn test_tw_message_signer_pre_image_hashes() {
         message: "Foo".into(),
         chain_id: None,
         message_type: Ethereum::Proto::MessageType::MessageType_legacy,
+        ..Ethereum::Proto::MessageSigningInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
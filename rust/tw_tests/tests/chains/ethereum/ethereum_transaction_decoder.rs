@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_number::U256;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Ethereum::Proto;
+use tw_proto::Ethereum::Proto::mod_Transaction::OneOftransaction_oneof as TransactionType;
+
+#[test]
+fn test_ethereum_decode_legacy_eip155_transaction() {
+    // https://eips.ethereum.org/EIPS/eip-155#example
+    let encoded_tx = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.is_signed);
+    assert_eq!(
+        output.from_address,
+        "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"
+    );
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53"
+    );
+    assert_eq!(output.v.to_hex(), "25");
+    assert_eq!(
+        output.r.to_hex(),
+        "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276"
+    );
+    assert_eq!(
+        output.s.to_hex(),
+        "67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+    );
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.tx_mode, Proto::TransactionMode::Legacy);
+    assert_eq!(tx.chain_id, U256::encode_be_compact(1));
+    assert_eq!(tx.nonce, U256::encode_be_compact(9));
+    assert_eq!(tx.gas_price, U256::encode_be_compact(20_000_000_000));
+    assert_eq!(tx.gas_limit, U256::encode_be_compact(21_000));
+    assert_eq!(tx.to_address, "0x3535353535353535353535353535353535353535");
+    assert!(tx.private_key.is_empty());
+
+    let TransactionType::transfer(transfer) = tx.transaction.unwrap().transaction_oneof else {
+        panic!("Expected a native transfer");
+    };
+    assert_eq!(
+        transfer.amount,
+        U256::encode_be_compact(1_000_000_000_000_000_000)
+    );
+    assert!(transfer.data.is_empty());
+}
+
+#[test]
+fn test_ethereum_decode_legacy_pre_eip155_transaction() {
+    let encoded_tx = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000801ba08383adc8b8ae116f918fb44ca7ff9dfd8012596a5c130c6246a2cc717ba41cdaa053ddfacf5bd4aa7e46d1575acf52636ea659b91f29e2fb91c75567a279738f38"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.is_signed);
+    assert_eq!(
+        output.from_address,
+        "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"
+    );
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "f9e36c28c8cb35adba138005c02ab7aa7fbcd891f3139cb2eeed052a51cd2713"
+    );
+    assert_eq!(output.v.to_hex(), "1b");
+
+    let tx = output.transaction.unwrap();
+    assert!(tx.chain_id.is_empty());
+}
+
+#[test]
+fn test_ethereum_decode_eip1559_erc20_transfer() {
+    let encoded_tx = "02f8b1018084773594008509c7652400830130b9946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec80000c080a041a98b73ac4bcb023164474a01e3fdcb0ae19586abcff3107557c7cd6cb36098a05c743b37f88fa11bd4dc39dffe3020832f4bcc5612482771eb9df5e212d3d760"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(output.is_signed);
+    assert_eq!(
+        output.from_address,
+        "0xB9F5771C27664bF2282D98E09D7F50cEc7cB01a7"
+    );
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "3b7c5d5d8d340539a1f9627c6f0ad1a06e77183ebcf7049f938590c202fe847c"
+    );
+    assert_eq!(output.v.to_hex(), "00");
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.tx_mode, Proto::TransactionMode::Enveloped);
+    assert_eq!(tx.chain_id, U256::encode_be_compact(1));
+    assert!(tx.nonce.is_empty());
+    assert_eq!(
+        tx.max_inclusion_fee_per_gas,
+        U256::encode_be_compact(2_000_000_000)
+    );
+    assert_eq!(tx.max_fee_per_gas, U256::encode_be_compact(42_000_000_000));
+    assert_eq!(tx.gas_limit, U256::encode_be_compact(78_009));
+    // Token contract address.
+    assert_eq!(tx.to_address, "0x6B175474E89094C44Da98b954EedeAC495271d0F");
+    assert!(tx.access_list.is_empty());
+
+    let TransactionType::erc20_transfer(transfer) = tx.transaction.unwrap().transaction_oneof
+    else {
+        panic!("Expected an ERC20 transfer");
+    };
+    assert_eq!(transfer.to, "0x5322B34c88Ed0691971Bf52A7047448f0F4eFC84");
+    assert_eq!(
+        transfer.amount,
+        U256::encode_be_compact(2_000_000_000_000_000_000)
+    );
+}
+
+#[test]
+fn test_ronin_decode_unsigned_eip2930_execute_batch() {
+    let encoded_tx = "01f902458207e4038504a817c8008301d4c094174a240e5147d02de4d7724d5d3e1c1bf11ce02980b901e447e1da2a000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000120000000000000000000000000000000000000000000000000000000000000000200000000000000000000000061061fcae11fd5461535e134eff67a98cfff44e90000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000002386f26fc10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004deadbeef00000000000000000000000000000000000000000000000000000000f838f794dac17f958d2ee523a2206206994597c13d831ec7e1a076c8f33bcdf467e4f1313522c10a40512a867cdcd34f2b898232ad4669200764"
+        .decode_hex()
+        .unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ronin, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert!(!output.is_signed);
+    assert!(output.from_address.is_empty());
+    assert!(output.v.is_empty());
+    assert_eq!(
+        output.pre_hash.to_hex(),
+        "1d42ea85350d0344ec22a5d46710f1fff0c30f24ddd6af936a67744d4d564f70"
+    );
+
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.tx_mode, Proto::TransactionMode::Eip2930);
+    assert_eq!(tx.chain_id, U256::encode_be_compact(2020));
+    assert_eq!(tx.nonce, U256::encode_be_compact(3));
+    assert_eq!(tx.gas_price, U256::encode_be_compact(20_000_000_000));
+    assert_eq!(tx.gas_limit, U256::encode_be_compact(120_000));
+    assert_eq!(
+        tx.to_address,
+        "ronin:174a240e5147D02dE4d7724D5D3E1c1bF11cE029"
+    );
+
+    assert_eq!(tx.access_list.len(), 1);
+    assert_eq!(
+        tx.access_list[0].address,
+        "ronin:dAC17F958D2ee523a2206206994597C13D831ec7"
+    );
+    assert_eq!(
+        tx.access_list[0].stored_keys[0].to_hex(),
+        "76c8f33bcdf467e4f1313522c10a40512a867cdcd34f2b898232ad4669200764"
+    );
+
+    let TransactionType::batch(batch) = tx.transaction.unwrap().transaction_oneof else {
+        panic!("Expected an ERC-4337 batch");
+    };
+    assert_eq!(batch.calls.len(), 2);
+    assert_eq!(
+        batch.calls[0].address,
+        "ronin:61061FCaE11fD5461535E134EFf67A98cFff44E9"
+    );
+    assert_eq!(
+        batch.calls[0].amount,
+        U256::encode_be_compact(10_000_000_000_000_000)
+    );
+    assert!(batch.calls[0].payload.is_empty());
+    assert_eq!(
+        batch.calls[1].address,
+        "ronin:5322B34c88Ed0691971Bf52A7047448f0F4eFC84"
+    );
+    assert!(batch.calls[1].amount.is_empty());
+    assert_eq!(batch.calls[1].payload.to_hex(), "deadbeef");
+}
+
+#[test]
+fn test_ethereum_decode_transaction_error() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+
+    // EIP1559 transaction with a missing field.
+    let encoded_tx = "02f8b0018084773594008509c7652400830130b9946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec8000080a041a98b73ac4bcb023164474a01e3fdcb0ae19586abcff3107557c7cd6cb36098a05c743b37f88fa11bd4dc39dffe3020832f4bcc5612482771eb9df5e212d3d760"
+        .decode_hex()
+        .unwrap();
+    let output = decoder.decode(CoinType::Ethereum, encoded_tx);
+    assert_eq!(output.error, SigningError::Error_input_parse);
+
+    // EIP4844 transactions are not supported yet.
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::Ethereum, vec![0x03, 0xc0]);
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
@@ -8,4 +8,5 @@ mod ethereum_compile;
 mod ethereum_message_sign;
 mod ethereum_rlp;
 mod ethereum_sign;
+mod ethereum_transaction_decoder;
 mod ethereum_transaction_util;
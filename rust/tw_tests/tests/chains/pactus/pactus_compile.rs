@@ -10,8 +10,8 @@ use tw_any_coin::ffi::tw_transaction_compiler::{
 use tw_coin_entry::error::prelude::*;
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::ToHex;
-use tw_keypair::ed25519;
 use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_keypair::{bls, ed25519};
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
 use tw_memory::test_utils::tw_data_vector_helper::TWDataVectorHelper;
 use tw_misc::traits::ToBytesVec;
@@ -41,13 +41,19 @@ fn test_pactus_transaction_compile() {
         assert_eq!(preimage.data.to_hex(), case.data_to_sign);
 
         // Step 3: Sign the data "externally"
-        let private_key = ed25519::sha512::KeyPair::try_from(PRIVATE_KEY).unwrap();
-        let public_key = private_key.public().to_vec();
-
-        let signature = private_key
-            .sign(preimage.data.to_vec())
-            .expect("Error signing data")
-            .to_vec();
+        let (public_key, signature) = if case.bls_signer {
+            let private_key = bls::KeyPair::try_from(PRIVATE_KEY).unwrap();
+            let signature = private_key
+                .sign(preimage.data.to_vec())
+                .expect("Error signing data");
+            (private_key.public().to_vec(), signature.to_vec())
+        } else {
+            let private_key = ed25519::sha512::KeyPair::try_from(PRIVATE_KEY).unwrap();
+            let signature = private_key
+                .sign(preimage.data.to_vec())
+                .expect("Error signing data");
+            (private_key.public().to_vec(), signature.to_vec())
+        };
         assert_eq!(signature.to_hex(), case.signature);
 
         // Step 4: Compile transaction info
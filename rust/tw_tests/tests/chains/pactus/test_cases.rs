@@ -173,21 +173,159 @@ pub mod bond_without_public_key_test_case {
     }
 }
 
+// Validator payloads are signed with the BLS key of the validator.
+// The validator address `pc1pfe0usujevyledkjj2jzkr2fqnj90gwgk0sp6u4` is derived from
+// the BLS public key of `PRIVATE_KEY`.
+pub mod sortition_test_case {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    define_test_data!(
+        "4017d88c286fc622e08ea1dba550c568f5f0d4251a8a4663960907b9d4b97d8b", // transaction ID
+        "91a0a8a02405464a9829b743d4553d385ca5e295ab742272\
+         b1c88002673aae4ac2490bb2336195e8383c63723cd8cbf7", // BLS Signature
+        "86388d26e3c3d29bd5013108af40c5dc9096e17c7571c6dcda7c22e4146925c1\
+         401326267125516a14a1252f18bb7e3e18379eeef225990300d1a0c2bb809c42\
+         6726c115a2c736d624e1d470f1192e946801daf504aed756a5bdef73fca0d6ee", // BLS PublicKey
+        "01",                                                               // Version
+        "24a32300",                                                         // LockTime
+        "00",                                                               // Fee
+        "00",                                                               // Memo
+        "03",                                                               // PayloadType
+        "014e5fc87259613f96da52548561a9209c8af43916",                       // Validator
+        "8bd2b1c9e8eb0f5ab7e9e4cb2e0a6e5d2a4c0a0c6b5e9a3d1f7c2b8e4a6d0c3f\
+         9e1b7a5c3d2f8e6b4a0c9d7e5f3a1b8c", // Proof
+    );
+
+    pub fn sign_input() -> Proto::SigningInput<'static> {
+        let sortition_payload = Proto::SortitionPayload {
+            validator: "pc1pfe0usujevyledkjj2jzkr2fqnj90gwgk0sp6u4".into(),
+            proof: "8bd2b1c9e8eb0f5ab7e9e4cb2e0a6e5d2a4c0a0c6b5e9a3d1f7c2b8e4a6d0c3f9e1b7a5c3d2f8e6b4a0c9d7e5f3a1b8c"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        };
+
+        let transaction = Proto::TransactionMessage {
+            lock_time: 2335524,
+            fee: 0,
+            memo: Default::default(),
+            payload: Proto::mod_TransactionMessage::OneOfpayload::sortition(sortition_payload),
+        };
+
+        let private_key_bytes = PRIVATE_KEY.decode_hex().unwrap();
+
+        Proto::SigningInput {
+            transaction: Some(transaction),
+            private_key: private_key_bytes.into(),
+        }
+    }
+}
+
+// Unbonding the stake of a validator.
+pub mod unbond_test_case {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    define_test_data!(
+        "2146b8577f93ad3afb444a9026d781ddf624908cdada1e618831296b757ce475", // transaction ID
+        "b0a91b3f2c65c130349121ccd6dd3e545fa3f12d37ffda28\
+         1a3a716f39f7102436191811120c2dddbb0d76d903fb4651", // BLS Signature
+        "86388d26e3c3d29bd5013108af40c5dc9096e17c7571c6dcda7c22e4146925c1\
+         401326267125516a14a1252f18bb7e3e18379eeef225990300d1a0c2bb809c42\
+         6726c115a2c736d624e1d470f1192e946801daf504aed756a5bdef73fca0d6ee", // BLS PublicKey
+        "01",                                                               // Version
+        "24a32300",                                                         // LockTime
+        "80ade204",                                                         // Fee
+        "0b77616c6c65742d636f7265",                                         // Memo
+        "04",                                                               // PayloadType
+        "014e5fc87259613f96da52548561a9209c8af43916",                       // Validator
+    );
+
+    pub fn sign_input() -> Proto::SigningInput<'static> {
+        let unbond_payload = Proto::UnbondPayload {
+            validator: "pc1pfe0usujevyledkjj2jzkr2fqnj90gwgk0sp6u4".into(),
+        };
+
+        let transaction = Proto::TransactionMessage {
+            lock_time: 2335524,
+            fee: 10000000,
+            memo: "wallet-core".into(),
+            payload: Proto::mod_TransactionMessage::OneOfpayload::unbond(unbond_payload),
+        };
+
+        let private_key_bytes = PRIVATE_KEY.decode_hex().unwrap();
+
+        Proto::SigningInput {
+            transaction: Some(transaction),
+            private_key: private_key_bytes.into(),
+        }
+    }
+}
+
+// Withdrawing the unbonded stake of a validator to an account.
+pub mod withdraw_test_case {
+    use super::*;
+    use tw_encoding::hex::DecodeHex;
+
+    define_test_data!(
+        "b41511356fbd063e3c6f00fd0cda98d0ee5de75d4691248fb2ecc50417c45a8c", // transaction ID
+        "80b0a8d30e93358f1fdd46f200f25bed05e8ac9c6e15cf4f\
+         46aa69f4e4fc2d4bbf831d7ab66443c91be069cc9a22532b", // BLS Signature
+        "86388d26e3c3d29bd5013108af40c5dc9096e17c7571c6dcda7c22e4146925c1\
+         401326267125516a14a1252f18bb7e3e18379eeef225990300d1a0c2bb809c42\
+         6726c115a2c736d624e1d470f1192e946801daf504aed756a5bdef73fca0d6ee", // BLS PublicKey
+        "01",                                                               // Version
+        "24a32300",                                                         // LockTime
+        "80ade204",                                                         // Fee
+        "0b77616c6c65742d636f7265",                                         // Memo
+        "05",                                                               // PayloadType
+        "014e5fc87259613f96da52548561a9209c8af43916",                       // Validator
+        "037098338e0b6808119dfd4457ab806b9c2059b89b",                       // Account
+        "8094ebdc03",                                                       // Amount
+    );
+
+    pub fn sign_input() -> Proto::SigningInput<'static> {
+        let withdraw_payload = Proto::WithdrawPayload {
+            validator_address: "pc1pfe0usujevyledkjj2jzkr2fqnj90gwgk0sp6u4".into(),
+            account_address: "pc1rwzvr8rstdqypr80ag3t6hqrtnss9nwymcxy3lr".into(),
+            amount: 1000000000,
+        };
+
+        let transaction = Proto::TransactionMessage {
+            lock_time: 2335524,
+            fee: 10000000,
+            memo: "wallet-core".into(),
+            payload: Proto::mod_TransactionMessage::OneOfpayload::withdraw(withdraw_payload),
+        };
+
+        let private_key_bytes = PRIVATE_KEY.decode_hex().unwrap();
+
+        Proto::SigningInput {
+            transaction: Some(transaction),
+            private_key: private_key_bytes.into(),
+        }
+    }
+}
+
 pub(crate) struct TestCase {
     pub sign_input_fn: fn() -> Proto::SigningInput<'static>,
     pub transaction_id: &'static str,
     pub signature: &'static str,
     pub data_to_sign: &'static str,
     pub signed_data: &'static str,
+    /// Whether the transaction is signed with a BLS key rather than an Ed25519 one.
+    pub bls_signer: bool,
 }
 
-pub(crate) const TEST_CASES: &[TestCase; 3] = &[
+pub(crate) const TEST_CASES: &[TestCase; 6] = &[
     TestCase {
         sign_input_fn: transfer_test_case::sign_input,
         transaction_id: transfer_test_case::TX_ID,
         signature: transfer_test_case::SIGNATURE,
         data_to_sign: transfer_test_case::DATA_TO_SIGN,
         signed_data: transfer_test_case::SIGNED_DATA,
+        bls_signer: false,
     },
     TestCase {
         sign_input_fn: bond_with_public_key_test_case::sign_input,
@@ -195,6 +333,7 @@ pub(crate) const TEST_CASES: &[TestCase; 3] = &[
         signature: bond_with_public_key_test_case::SIGNATURE,
         data_to_sign: bond_with_public_key_test_case::DATA_TO_SIGN,
         signed_data: bond_with_public_key_test_case::SIGNED_DATA,
+        bls_signer: false,
     },
     TestCase {
         sign_input_fn: bond_without_public_key_test_case::sign_input,
@@ -202,5 +341,30 @@ pub(crate) const TEST_CASES: &[TestCase; 3] = &[
         signature: bond_without_public_key_test_case::SIGNATURE,
         data_to_sign: bond_without_public_key_test_case::DATA_TO_SIGN,
         signed_data: bond_without_public_key_test_case::SIGNED_DATA,
+        bls_signer: false,
+    },
+    TestCase {
+        sign_input_fn: sortition_test_case::sign_input,
+        transaction_id: sortition_test_case::TX_ID,
+        signature: sortition_test_case::SIGNATURE,
+        data_to_sign: sortition_test_case::DATA_TO_SIGN,
+        signed_data: sortition_test_case::SIGNED_DATA,
+        bls_signer: true,
+    },
+    TestCase {
+        sign_input_fn: unbond_test_case::sign_input,
+        transaction_id: unbond_test_case::TX_ID,
+        signature: unbond_test_case::SIGNATURE,
+        data_to_sign: unbond_test_case::DATA_TO_SIGN,
+        signed_data: unbond_test_case::SIGNED_DATA,
+        bls_signer: true,
+    },
+    TestCase {
+        sign_input_fn: withdraw_test_case::sign_input,
+        transaction_id: withdraw_test_case::TX_ID,
+        signature: withdraw_test_case::SIGNATURE,
+        data_to_sign: withdraw_test_case::DATA_TO_SIGN,
+        signed_data: withdraw_test_case::SIGNED_DATA,
+        bls_signer: true,
     },
 ];
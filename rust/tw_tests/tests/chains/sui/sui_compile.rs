@@ -2,7 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::chains::sui::test_cases::{transfer_d4ay9tdb, PRIVATE_KEY_54E80D76, SENDER_54E80D76};
+use crate::chains::sui::test_cases::{
+    multisig_2e562f1e, transfer_d4ay9tdb, PRIVATE_KEY_54E80D76, SENDER_54E80D76,
+};
 use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::{DecodeHex, ToHex};
@@ -90,3 +92,49 @@ fn test_sui_compile_direct_transfer() {
         signature: transfer_d4ay9tdb::SIGNATURE,
     });
 }
+
+#[test]
+fn test_sui_compile_direct_transfer_multisig() {
+    let unsigned_tx = "AAACAAgQJwAAAAAAAAAgJZ/4B0q0Jcu0ifI24Y4I8D8aeFa998eih3vWT3OLUBUCAgABAQAAAQEDAAAAAAEBANV1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1QAQbywsjB2JZN8QGdZhbpcFcZvrq9kx2idVy5SM635olk7AIAAAAAAAAgYEVuxmf1zRBGdoDr+VDtMpIFF12s2Ua7I2ru1XyGF8/Vda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAEAAAAAAAAA0AcAAAAAAAAA";
+    let direct = Proto::SignDirect {
+        unsigned_tx_msg: unsigned_tx.into(),
+    };
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::sign_direct_message(direct),
+        multisig: Some(multisig_2e562f1e::multisig()),
+        ..Proto::SigningInput::default()
+    };
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Sui, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "a2e2c096666756eacd5052292285df09b4ad92f4f6c14f91e1040b9c29564986"
+    );
+
+    // Step 3: Compile transaction info
+
+    // Secp256r1 and Secp256k1 signatures of `sha256(data_hash)`, the order doesn't matter.
+    let signatures = vec![
+        "2943bda795720947e31345992ab24703f9dcf7e7a10d5c1a081d1d69cc7279007425f19c4227cbb1c490dcccc22bc5eebf42ad30c6923efd1e553252d04e5e20".decode_hex().unwrap(),
+        "b4f081a7ebcb2a5a98dbde2e9a2e6f9250781b9300c56500ce825cdc7647813b3fdcbf0703a139dc65e56c34989240ea28f38e8fe3a0581e6c04277ac51bb9c6".decode_hex().unwrap(),
+    ];
+    let public_keys = vec![
+        multisig_2e562f1e::SECP256R1_PUBLIC_KEY
+            .decode_hex()
+            .unwrap(),
+        multisig_2e562f1e::SECP256K1_PUBLIC_KEY
+            .decode_hex()
+            .unwrap(),
+    ];
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Sui, &input, signatures, public_keys);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.unsigned_tx, unsigned_tx);
+    assert_eq!(output.signature, "AwIBtPCBp+vLKlqY294umi5vklB4G5MAxWUAzoJc3HZHgTs/3L8HA6E53GXlbDSYkkDqKPOOj+OgWB5sBCd6xRu5xgIpQ72nlXIJR+MTRZkqskcD+dz356ENXBoIHR1pzHJ5AHQl8ZxCJ8uxxJDczMIrxe6/Qq0wxpI+/R5VMlLQTl4gBgADAGp83uwWp1wP9nh7wjVhCUaQMwIrsQ6CbJ1EOp8fwL2OAgEDLsN0GPJ/tPqWgL/t4ILK2xL5UNGNBGsLLhJuFzczZhcBAgMEUaf6+6vE9V0cGEVaMLXiyFA83+Q1cGkdt/ggWsFdzAECAA==");
+}
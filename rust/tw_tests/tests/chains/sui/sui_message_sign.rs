@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::SigningErrorType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Sui::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PRIVATE_KEY: &str = "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266";
+const MESSAGE: &str = "Hello, World!";
+const ED25519_SIGNATURE: &str = "AFk4nOuwQ5CAHdQ1iz2NcQJSa791N6MUOEBzeg8O0vnyHLASKOEjGR/MRbdMCWw3PS2xddVGE2j+AvVVR5f04ARqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jg==";
+const SECP256K1_SIGNATURE: &str = "AUoj4wyVYya1BUfyms0HhHPLFST5nez2XilYHA6TLKe2Q1UvOGTNM4UxAoMAOrEf3FRLePdy4osloyRnERVsclsC+AuT6/SoStEKkvToOriKCjbegBwLWrJJK0eP5IVIq3Y=";
+
+fn sign_message(input: Proto::MessageSigningInput<'_>) -> Vec<u8> {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    TWDataHelper::wrap(unsafe { tw_message_signer_sign(CoinType::Sui as u32, input_data.ptr()) })
+        .to_vec()
+        .expect("!tw_message_signer_sign returned nullptr")
+}
+
+fn verify_message(input: Proto::MessageVerifyingInput<'_>) -> bool {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(CoinType::Sui as u32, input_data.ptr()) }
+}
+
+#[test]
+fn test_sui_message_signer_sign_ed25519() {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.as_bytes().into(),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let output = sign_message(input);
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, ED25519_SIGNATURE);
+}
+
+#[test]
+fn test_sui_message_signer_sign_secp256k1() {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        signature_scheme: Proto::SignatureScheme::SECP256K1,
+        message: MESSAGE.as_bytes().into(),
+    };
+
+    let output = sign_message(input);
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, SECP256K1_SIGNATURE);
+}
+
+#[test]
+fn test_sui_message_signer_verify() {
+    for signature in [ED25519_SIGNATURE, SECP256K1_SIGNATURE] {
+        let input = Proto::MessageVerifyingInput {
+            message: MESSAGE.as_bytes().into(),
+            signature: signature.into(),
+            ..Proto::MessageVerifyingInput::default()
+        };
+        assert!(verify_message(input));
+    }
+}
+
+#[test]
+fn test_sui_message_signer_verify_address() {
+    let input = Proto::MessageVerifyingInput {
+        message: MESSAGE.as_bytes().into(),
+        signature: ED25519_SIGNATURE.into(),
+        address: "0xd575ad7f18e948462a5cf698f564ef394a752a71fec62493af8a055c012c0d50".into(),
+    };
+    assert!(verify_message(input));
+
+    // The signature is valid, but it belongs to another address.
+    let input = Proto::MessageVerifyingInput {
+        message: MESSAGE.as_bytes().into(),
+        signature: ED25519_SIGNATURE.into(),
+        address: "0xbc6d9c914ecd51dee12657847cda0bea5459604a151b2d577108aa4cd2787871".into(),
+    };
+    assert!(!verify_message(input));
+}
+
+#[test]
+fn test_sui_message_signer_verify_invalid() {
+    let input = Proto::MessageVerifyingInput {
+        message: "Hello, World?".as_bytes().into(),
+        signature: ED25519_SIGNATURE.into(),
+        ..Proto::MessageVerifyingInput::default()
+    };
+    assert!(!verify_message(input));
+}
+
+#[test]
+fn test_sui_message_signer_pre_image_hashes() {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: MESSAGE.as_bytes().into(),
+        ..Proto::MessageSigningInput::default()
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(CoinType::Sui as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    // PersonalMessage intent followed by the `bcs` encoded `vector<u8>`.
+    assert_eq!(output.data.to_hex(), "0300000d48656c6c6f2c20576f726c6421");
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "fd2bec7342ee90818640d2dd5d5de615ab2a2f6b0bf281947ac86e3ab93cd96f"
+    );
+}
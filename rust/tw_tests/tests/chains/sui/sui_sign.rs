@@ -3,13 +3,20 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::chains::sui::object_ref;
-use crate::chains::sui::test_cases::{transfer_d4ay9tdb, PRIVATE_KEY_54E80D76, SENDER_54E80D76};
+use crate::chains::sui::test_cases::{
+    multisig_2e562f1e, transfer_d4ay9tdb, PRIVATE_KEY_54E80D76, SENDER_54E80D76,
+};
 use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
 use tw_coin_registry::coin_type::CoinType;
 use tw_encoding::hex::DecodeHex;
 use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto::mod_Argument::OneOfkind as ArgumentType;
+use tw_proto::Sui::Proto::mod_CallArg::OneOfarg as CallArgType;
+use tw_proto::Sui::Proto::mod_Command::OneOfcommand as CommandType;
 use tw_proto::Sui::Proto::{self, mod_SigningInput::OneOftransaction_payload as TransactionType};
 
+const DIRECT_TRANSFER_UNSIGNED_TX: &str = "AAACAAgQJwAAAAAAAAAgJZ/4B0q0Jcu0ifI24Y4I8D8aeFa998eih3vWT3OLUBUCAgABAQAAAQEDAAAAAAEBANV1rX8Y6UhGKlz2mPVk7zlKdSpx/sYkk6+KBVwBLA1QAQbywsjB2JZN8QGdZhbpcFcZvrq9kx2idVy5SM635olk7AIAAAAAAAAgYEVuxmf1zRBGdoDr+VDtMpIFF12s2Ua7I2ru1XyGF8/Vda1/GOlIRipc9pj1ZO85SnUqcf7GJJOvigVcASwNUAEAAAAAAAAA0AcAAAAAAAAA";
+
 fn test_sign_direct_impl(unsigned_tx: &str, private_key: &str, expected_signature: &str) {
     let direct = Proto::SignDirect {
         unsigned_tx_msg: unsigned_tx.into(),
@@ -65,6 +72,99 @@ fn test_sui_sign_direct_add_delegation() {
     test_sign_direct_impl(unsigned_tx, private_key, expected_signature);
 }
 
+#[test]
+fn test_sui_sign_direct_transfer_secp256k1() {
+    let direct = Proto::SignDirect {
+        unsigned_tx_msg: DIRECT_TRANSFER_UNSIGNED_TX.into(),
+    };
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::sign_direct_message(direct),
+        private_key: "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        signature_scheme: Proto::SignatureScheme::SECP256K1,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.unsigned_tx, DIRECT_TRANSFER_UNSIGNED_TX);
+    assert_eq!(output.signature, "AUwzfQWIURM8RRcE+qUGhgFMK5ZEnKUBzCD8A4ohhc87WmgJYnTJsmqGNEr+phSjcUhEDWOCo9d0Fn4R3KcGpj0C+AuT6/SoStEKkvToOriKCjbegBwLWrJJK0eP5IVIq3Y=");
+}
+
+#[test]
+fn test_sui_sign_direct_transfer_secp256r1() {
+    let direct = Proto::SignDirect {
+        unsigned_tx_msg: DIRECT_TRANSFER_UNSIGNED_TX.into(),
+    };
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::sign_direct_message(direct),
+        private_key: "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        signature_scheme: Proto::SignatureScheme::SECP256R1,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.unsigned_tx, DIRECT_TRANSFER_UNSIGNED_TX);
+    assert_eq!(output.signature, "Al5Z5FQMDe3WTsV8vmoocBYpj4H9ZjqFaTTZARvPJMosa0IMZ3ZggCzmyVEaVgZMx4n23CK5K9fmlgQcOsOz5SAD7BXkqEYf/YAwgxvaNDZ6Cyhe9McOMrEPgdFicucf2Qk=");
+}
+
+#[test]
+fn test_sui_sign_direct_transfer_multisig() {
+    let direct = Proto::SignDirect {
+        unsigned_tx_msg: DIRECT_TRANSFER_UNSIGNED_TX.into(),
+    };
+    // Ed25519 key weight is enough to reach the threshold.
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::sign_direct_message(direct),
+        private_key: multisig_2e562f1e::ED25519_PRIVATE_KEY
+            .decode_hex()
+            .unwrap()
+            .into(),
+        multisig: Some(multisig_2e562f1e::multisig()),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.unsigned_tx, DIRECT_TRANSFER_UNSIGNED_TX);
+    assert_eq!(output.signature, "AwEA/E9241W8ePYJxFwc62I/ZoFH2o/e87YJvSDXY8jrhCh8MHqSzfeip7+L+IJMtyinasGuRzPCtrJm4k52LuC/AwEAAwBqfN7sFqdcD/Z4e8I1YQlGkDMCK7EOgmydRDqfH8C9jgIBAy7DdBjyf7T6loC/7eCCytsS+VDRjQRrCy4Sbhc3M2YXAQIDBFGn+vurxPVdHBhFWjC14shQPN/kNXBpHbf4IFrBXcwBAgA=");
+}
+
+#[test]
+fn test_sui_sign_direct_transfer_multisig_insufficient_weight() {
+    let direct = Proto::SignDirect {
+        unsigned_tx_msg: DIRECT_TRANSFER_UNSIGNED_TX.into(),
+    };
+    // Secp256k1 key weight is 1, but the threshold is 2.
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::sign_direct_message(direct),
+        private_key: "088baa019f081d6eab8dff5c447f9ce2f83c1babf3d03686299eaf6a1e89156e"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        signature_scheme: Proto::SignatureScheme::SECP256K1,
+        multisig: Some(multisig_2e562f1e::multisig()),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::Error_signing);
+}
+
 #[test]
 fn test_sui_sign_transfer_sui() {
     let input = Proto::SigningInput {
@@ -466,3 +566,186 @@ fn test_sui_sign_transfer_nft() {
     assert_eq!(output.unsigned_tx, "AAACACD4h+cHcBdVRRHnNtQ0JDY9qUbYqnSCJfawVGMKCxwK5QEALiNVp+X4V6Z8I30n5aIYT5xoP0J11Uv5Dcxw9hF/SgOxcSAFAAAAACAWqN6yiNss1A1yjjz0hYuYwWdS3Dui2QSHjdKsQz08ZgEBAQEBAAEAAFToDXbXkMJ39aRPPOkvU9JvWJSJK/OV3uY3WYiHa+ayARAgVLdnakaxuuckE03JYttynzOJrPedPW88J7oBigQEhlwxBQAAAAAgRQo1hDoAiMbl2lgicyjy67PmKIWT5wccUlQMAfu84LxU6A1215DCd/WkTzzpL1PSb1iUiSvzld7mN1mIh2vmsu4CAAAAAAAAAAk9AAAAAAAA");
     assert_eq!(output.signature, "AIbNoo74XJ9EvfVCBVwM2YMht5qsPHSu4Cb61uzKq6g2tgh4dlhKpY9Shhw/hHjlNGcg590+PvXm4nlj/IWy6wGF69FEH+T5VPvl3GB3vwCOEZpeJpKXxvcIPQAdKsh2/g==");
 }
+
+fn argument(kind: ArgumentType) -> Proto::Argument {
+    Proto::Argument { kind }
+}
+
+fn pure_input(value: &str) -> Proto::CallArg<'static> {
+    Proto::CallArg {
+        arg: CallArgType::pure_pb(value.decode_hex().unwrap().into()),
+    }
+}
+
+fn command(command: CommandType<'static>) -> Proto::Command<'static> {
+    Proto::Command { command }
+}
+
+/// Replicates the `transfer_d4ay9tdb` PaySui transaction via a programmable transaction.
+#[test]
+fn test_sui_sign_programmable_pay_sui() {
+    let nested_result = |result, index| {
+        argument(ArgumentType::nested_result(Proto::NestedResult {
+            result,
+            index,
+        }))
+    };
+
+    let pt = Proto::ProgrammableTransaction {
+        inputs: vec![
+            // 1000 u64
+            pure_input("e803000000000000"),
+            // 50000 u64
+            pure_input("50c3000000000000"),
+            pure_input("a7175abdd5ed92ebe3ad390db366c6a706478cdf517cde6cf98630065cda377a"),
+            pure_input(SENDER_54E80D76),
+        ],
+        commands: vec![
+            command(CommandType::split_coins(Proto::SplitCoins {
+                coin: Some(argument(ArgumentType::gas_coin(true))),
+                amounts: vec![
+                    argument(ArgumentType::input(0)),
+                    argument(ArgumentType::input(1)),
+                ],
+            })),
+            command(CommandType::transfer_objects(Proto::TransferObjects {
+                objects: vec![nested_result(0, 0)],
+                recipient: Some(argument(ArgumentType::input(2))),
+            })),
+            command(CommandType::transfer_objects(Proto::TransferObjects {
+                objects: vec![nested_result(0, 1)],
+                recipient: Some(argument(ArgumentType::input(3))),
+            })),
+        ],
+        gas_coins: vec![object_ref(
+            "0x636020b3a7dc7b11c3aa6f419b17f8a9c12e7f79a31d1bdd2de670b4edd63005",
+            85619064,
+            "2eKuWbZSVfpFVfg8FXY9wP6W5AFXnTchSoUdp7obyYZ5",
+        )],
+    };
+
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::programmable_transaction(pt),
+        private_key: PRIVATE_KEY_54E80D76.decode_hex().unwrap().into(),
+        // 0.003 SUI
+        gas_budget: 3000000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.unsigned_tx, transfer_d4ay9tdb::UNSIGNED_TX);
+    assert_eq!(output.signature, transfer_d4ay9tdb::SIGNATURE);
+}
+
+/// Replicates the `test_sui_sign_delegate_sui` transaction via a programmable transaction.
+#[test]
+fn test_sui_sign_programmable_move_call() {
+    let pt = Proto::ProgrammableTransaction {
+        inputs: vec![
+            Proto::CallArg {
+                arg: CallArgType::shared_object(Proto::SharedObjectRef {
+                    object_id: "0x0000000000000000000000000000000000000000000000000000000000000005"
+                        .into(),
+                    initial_shared_version: 1,
+                    is_mutable: true,
+                }),
+            },
+            Proto::CallArg {
+                arg: CallArgType::object(object_ref(
+                    "0xff1af62d35654956964437882b33d3256aad20214f18a234c62b5e258ca163ee",
+                    83160977,
+                    "FAugxdfWPQrMu57mMc9FmgNSjkt613pixR6V5M9nashw",
+                )),
+            },
+            Proto::CallArg {
+                arg: CallArgType::object(object_ref(
+                    "0x5ef77d20c7d6745d3d9b5f69e7825aae733fa5c8a3f82f7192749e3169791c8c",
+                    85887695,
+                    "F3JgSqdQJgzBsNnzJiYkr2XkjTEXmq7NEybixjEYrSf4",
+                )),
+            },
+            // Option<u64>: Some(1000950000)
+            pure_input("01f048a93b00000000"),
+            pure_input("61953ea72709eed72f4441dd944eec49a11b4acabfc8e04015e89c63be81b6ab"),
+        ],
+        commands: vec![
+            command(CommandType::make_move_vec(Proto::MakeMoveVec {
+                type_tag: "".into(),
+                elements: vec![
+                    argument(ArgumentType::input(1)),
+                    argument(ArgumentType::input(2)),
+                ],
+            })),
+            command(CommandType::move_call(Proto::MoveCall {
+                package: "0x0000000000000000000000000000000000000000000000000000000000000003"
+                    .into(),
+                module: "sui_system".into(),
+                function: "request_add_stake_mul_coin".into(),
+                type_arguments: Vec::default(),
+                arguments: vec![
+                    argument(ArgumentType::input(0)),
+                    argument(ArgumentType::result(0)),
+                    argument(ArgumentType::input(3)),
+                    argument(ArgumentType::input(4)),
+                ],
+            })),
+        ],
+        gas_coins: vec![object_ref(
+            "0x102054b7676a46b1bae724134dc962db729f3389acf79d3d6f3c27ba018a0404",
+            85989207,
+            "GXGhEVNJGNBsvaTiLi85bGask5PbVXTZUKyN6CLR3N7D",
+        )],
+    };
+
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::programmable_transaction(pt),
+        private_key: PRIVATE_KEY_54E80D76.decode_hex().unwrap().into(),
+        // 0.009 SUI
+        gas_budget: 9000000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::OK);
+    assert_eq!(output.unsigned_tx, "AAAFAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQEAAAAAAAAAAQEA/xr2LTVlSVaWRDeIKzPTJWqtICFPGKI0xiteJYyhY+6R7/QEAAAAACDSjWt6fM4gT8LU9OmUKUD0oeVAN3195wXyRgLAAkj/RgEAXvd9IMfWdF09m19p54JarnM/pcij+C9xknSeMWl5HIzPih4FAAAAACDQmsUAK2qhMxauQja6zUchci2O+VpXNpKHQPa5uzG92wAJAfBIqTsAAAAAACBhlT6nJwnu1y9EQd2UTuxJoRtKyr/I4EAV6JxjvoG2qwIFAAIBAQABAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMKc3VpX3N5c3RlbRpyZXF1ZXN0X2FkZF9zdGFrZV9tdWxfY29pbgAEAQAAAgAAAQMAAQQAVOgNdteQwnf1pE886S9T0m9YlIkr85Xe5jdZiIdr5rIBECBUt2dqRrG65yQTTcli23KfM4ms9509bzwnugGKBARXFyAFAAAAACDmoHkZ4Q2u0tMpkkJOmnK9WHxAXfwVxtKnoGoU3ZecTFToDXbXkMJ39aRPPOkvU9JvWJSJK/OV3uY3WYiHa+ay7gIAAAAAAABAVIkAAAAAAAA=");
+    assert_eq!(output.signature, "AF7oDeTkRQT23xGuW1WsILvm2FQIycaP6bvbTA8oQ8QJU75VQcJDTgEscfxfg8GAN60uzSLKVAJKXKOu8O6vugmF69FEH+T5VPvl3GB3vwCOEZpeJpKXxvcIPQAdKsh2/g==");
+}
+
+#[test]
+fn test_sui_sign_programmable_invalid_argument() {
+    let pt = Proto::ProgrammableTransaction {
+        inputs: vec![pure_input(SENDER_54E80D76)],
+        commands: vec![command(CommandType::transfer_objects(
+            Proto::TransferObjects {
+                // Refers to the result of the command itself.
+                objects: vec![argument(ArgumentType::result(0))],
+                recipient: Some(argument(ArgumentType::input(0))),
+            },
+        ))],
+        gas_coins: vec![object_ref(
+            "0x636020b3a7dc7b11c3aa6f419b17f8a9c12e7f79a31d1bdd2de670b4edd63005",
+            85619064,
+            "2eKuWbZSVfpFVfg8FXY9wP6W5AFXnTchSoUdp7obyYZ5",
+        )],
+    };
+
+    let input = Proto::SigningInput {
+        transaction_payload: TransactionType::programmable_transaction(pt),
+        private_key: PRIVATE_KEY_54E80D76.decode_hex().unwrap().into(),
+        gas_budget: 3000000,
+        reference_gas_price: 750,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Sui, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::chains::sui::object_ref;
+use tw_encoding::hex::DecodeHex;
 use tw_proto::Sui::Proto::{self, mod_SigningInput::OneOftransaction_payload as TransactionType};
 
 pub(super) const PRIVATE_KEY_54E80D76: &str =
@@ -42,3 +43,35 @@ pub(super) mod transfer_d4ay9tdb {
         }
     }
 }
+
+/// 2-of-3 MultiSig account that consists of an Ed25519 public key with weight 2,
+/// Secp256k1 and Secp256r1 public keys with weight 1.
+pub(super) mod multisig_2e562f1e {
+    use super::*;
+
+    pub const ED25519_PRIVATE_KEY: &str =
+        "3823dce5288ab55dd1c00d97e91933c613417fdb282a0b8b01a7f5f5a533b266";
+    pub const ED25519_PUBLIC_KEY: &str =
+        "6a7cdeec16a75c0ff6787bc2356109469033022bb10e826c9d443a9f1fc0bd8e";
+    pub const SECP256K1_PUBLIC_KEY: &str =
+        "032ec37418f27fb4fa9680bfede082cadb12f950d18d046b0b2e126e1737336617";
+    pub const SECP256R1_PUBLIC_KEY: &str =
+        "030451a7fafbabc4f55d1c18455a30b5e2c8503cdfe43570691db7f8205ac15dcc";
+
+    pub fn multisig() -> Proto::MultiSig<'static> {
+        let public_key = |scheme, public_key: &str, weight| Proto::MultiSigPublicKey {
+            scheme,
+            public_key: public_key.decode_hex().unwrap().into(),
+            weight,
+        };
+
+        Proto::MultiSig {
+            public_keys: vec![
+                public_key(Proto::SignatureScheme::ED25519, ED25519_PUBLIC_KEY, 2),
+                public_key(Proto::SignatureScheme::SECP256K1, SECP256K1_PUBLIC_KEY, 1),
+                public_key(Proto::SignatureScheme::SECP256R1, SECP256R1_PUBLIC_KEY, 1),
+            ],
+            threshold: 2,
+        }
+    }
+}
@@ -9,5 +9,6 @@ mod ton_compile;
 mod ton_message_signer;
 mod ton_sign;
 mod ton_sign_wallet_v5r1;
+mod ton_transaction_decoder;
 mod ton_transaction_util;
 mod ton_wallet;
@@ -265,11 +265,11 @@ fn test_ton_sign_transfer_with_utf8_comment() {
 }
 
 #[test]
-fn test_ton_sign_transfer_invalid_wallet_version() {
+fn test_ton_sign_transfer_and_deploy_v3r2() {
     let private_key = "63474e5fe9511f1526a50567ce142befc343e71a49b865ac3908f58667319cb8";
 
     let transfer = Proto::Transfer {
-        dest: "EQBm--PFwDv1yCeS-QTJ-L8oiUpqo9IT1BwgVptlSq3ts90Q".into(),
+        dest: "EQDYW_1eScJVxtitoBRksvoV9cCYo4uKGWLVNIHB1JqRR3n0".into(),
         amount: 10,
         mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
             | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
@@ -288,7 +288,13 @@ fn test_ton_sign_transfer_invalid_wallet_version() {
     let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
     let output = signer.sign(CoinType::TON, input);
 
-    assert_eq!(output.error, SigningError::Error_not_supported);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // The wallet contract address is EQCspB93tcxmTGVunU6axO056IRcItBUJLQWuoRQjSDY5YS_
+    assert_eq_boc(&output.encoded, "te6ccgECBwEAAU0AAkWIAVlIPu9rmMyYyt06nTWJ2nPRCLhFoKhJaC11CKEaQbHKHgECAgE0AwQBmhATzsnTY7gvcPZXDMOu6RdG+1AWLHvEJY+s3MKrXlEIOrJYRK9Jr9F46PN/G2+nl4wOSUgcH92djVy3Z4QXTQ0pqaMX/////wAAAAADBQDe/wAg3SCCAUyXuiGCATOcurGfcbDtRNDTH9MfMdcL/+ME4KTyYIMI1xgg0x/TH9Mf+CMTu/Jj7UTQ0x/TH9P/0VEyuvKhUUS68qIE+QFUEFX5EPKj+ACTINdKltMH1AL7AOjRAaTIyx/LH8v/ye1UAFAAAAAAKamjF/Qsd/kxvqIOxdAVBzEna7suKGCUdmEkWyMZ74Ez7o1BAWJiAGwt/q8k4SrjbFbQCjJZfQr64ExRxcUMsWqaQODqTUijiFAAAAAAAAAAAAAAAAABBgAA");
+    assert_eq!(
+        output.hash.to_hex(),
+        "c1fbe75eca9c811cb94976e2ff41e2251c95c832497e0c901a41e23dda9dce92"
+    );
 }
 
 #[test]
@@ -384,6 +390,207 @@ fn test_ton_sign_transfer_jettons_with_comment() {
     );
 }
 
+#[test]
+fn test_ton_sign_transfer_jettons_with_forward_payload() {
+    let private_key = "c054900a527538c1b4325688a421c0469b171c29f23a62da216e90b0df2412ee";
+
+    let jetton_transfer = Proto::JettonTransfer {
+        query_id: 0,
+        // Transfer 0.5 testtwt (decimal precision is 9).
+        jetton_amount: 500 * 1000 * 1000,
+        to_owner: "EQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnDx8".into(),
+        // Send unused toncoins back to sender.
+        response_address: "EQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llTXk".into(),
+        forward_amount: 1,
+        forward_payload: "te6cckEBAQEADgAAGHNi0JwAAAAAAAAAKnVj1yc=".into(),
+        ..Proto::JettonTransfer::default()
+    };
+
+    let transfer = Proto::Transfer {
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 100 * 1000 * 1000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        payload: PayloadType::jetton_transfer(jetton_transfer),
+        ..Proto::Transfer::default()
+    };
+
+    let input = Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        messages: vec![transfer],
+        sequence_number: 1,
+        expire_at: 1787693046,
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq_boc(&output.encoded, "te6ccgECBQEAARQAAUWIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsqDAEBnDeCe8Hx3OAWOt8uthsnHarrbPP+M30vbMFRanCLoxjTxaJ/FLJbuRoZDDhrOIVScQxrwnzJZPaKkMeV9/pRSQ8pqaMXao4H9gAAAAEAAwIBaGIAMTQfh52puD7eKUmDbhqfta4cdUMRF662Uxp3zzqug/MgL68IAAAAAAAAAAAAAAAAAAEDAaoPin6lAAAAAAAAAABB3NZQCAALgZZzC14dAz6ZxChX5pn6bIJ3WNipSJrCELO7Ex0TOQAWiiDKuQJtqf630dSBU8LDVh8QZDqS05sFGop+RY+JZUIDBAAYc2LQnAAAAAAAAAAq");
+    assert_eq!(
+        output.hash.to_hex(),
+        "08d12181ce574baff54b3be97c07b3b335149b1139ef822306b623af22413d08"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_nft() {
+    let private_key = "c054900a527538c1b4325688a421c0469b171c29f23a62da216e90b0df2412ee";
+
+    let nft_transfer = Proto::NftTransfer {
+        query_id: 1,
+        new_owner: "EQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnDx8".into(),
+        // Send unused toncoins back to sender.
+        response_destination: "EQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llTXk".into(),
+        forward_amount: 1,
+        ..Proto::NftTransfer::default()
+    };
+
+    let transfer = Proto::Transfer {
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 50 * 1000 * 1000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        // The comment is forwarded to the new owner.
+        comment: "nft gift".into(),
+        payload: PayloadType::nft_transfer(nft_transfer),
+        ..Proto::Transfer::default()
+    };
+
+    let input = Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        messages: vec![transfer],
+        sequence_number: 1,
+        expire_at: 1787693046,
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq_boc(&output.encoded, "te6ccgECBAEAAQ0AAUWIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsqDAEBnHjJNecXeVOEV/y2dMkwIL5Mqm+YZtl7qwhjk6VMpthj76xDGPM/CoPi/7cRHlQC0EE5LZu8vI/dUxkPnD8hygspqaMXao4H9gAAAAEAAwIBaGIAMTQfh52puD7eKUmDbhqfta4cdUMRF662Uxp3zzqug/MgF9eEAAAAAAAAAAAAAAAAAAEDALlfzD0UAAAAAAAAAAGAALgZZzC14dAz6ZxChX5pn6bIJ3WNipSJrCELO7Ex0TOQAWiiDKuQJtqf630dSBU8LDVh8QZDqS05sFGop+RY+JZUICAAAAAG5mdCBnaWZ0g=");
+    assert_eq!(
+        output.hash.to_hex(),
+        "a3b540e28fc15bf55e09c68b6c12421b091a8a2c54449be8ad8640bc68a755d7"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_jetton_burn() {
+    let private_key = "c054900a527538c1b4325688a421c0469b171c29f23a62da216e90b0df2412ee";
+
+    let jetton_burn = Proto::JettonBurn {
+        query_id: 2,
+        // Burn 1 testtwt (decimal precision is 9).
+        jetton_amount: 1000 * 1000 * 1000,
+        // Send unused toncoins back to sender.
+        response_address: "EQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llTXk".into(),
+        ..Proto::JettonBurn::default()
+    };
+
+    let transfer = Proto::Transfer {
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 100 * 1000 * 1000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        payload: PayloadType::jetton_burn(jetton_burn),
+        ..Proto::Transfer::default()
+    };
+
+    let input = Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        messages: vec![transfer],
+        sequence_number: 1,
+        expire_at: 1787693046,
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq_boc(&output.encoded, "te6ccgEBBAEA4gABRYgAtFEGVcgTbU/1vo6kCp4WGrD4gyHUlpzYKNRT8ix8SyoMAQGcYi9+2HGUrui3R2aVHGhWo/GDauIIADm0o+FOr0LtMRKn1twE6luwZFFDdCwz5if87YM7faJHxhgtBbYW3TSBBimpoxdqjgf2AAAAAQADAgFoYgAxNB+Hnam4Pt4pSYNuGp+1rhx1QxEXrrZTGnfPOq6D8yAvrwgAAAAAAAAAAAAAAAAAAQMAZFlfB7wAAAAAAAAAAkO5rKAIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsq");
+    assert_eq!(
+        output.hash.to_hex(),
+        "67d596938b4a35cf4cb381470e828b31d468a4eb4751594a507fb3b4132cda7b"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_encrypted_comment() {
+    let private_key = "c054900a527538c1b4325688a421c0469b171c29f23a62da216e90b0df2412ee";
+
+    // `pub_xor || msg_key || encrypted data` doesn't fit the first cell and continues in a child cell.
+    let encrypted_comment: Vec<u8> = (0..128).collect();
+
+    let transfer = Proto::Transfer {
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 10,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        payload: PayloadType::encrypted_comment(encrypted_comment.into()),
+        ..Proto::Transfer::default()
+    };
+
+    let input = Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        messages: vec![transfer],
+        sequence_number: 1,
+        expire_at: 1787693046,
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq_boc(&output.encoded, "te6ccgECBQEAATQAAUWIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsqDAEBnL3J2XCK60/vY9Dipy69ZBmecuNdRZ2hzPJzeLBnd33SKHF59PeKX31WXpeWeOUxxwpdd3ZZIjm4xOdONxxJjwYpqaMXao4H9gAAAAEAAwIBYmIAMTQfh52puD7eKUmDbhqfta4cdUMRF662Uxp3zzqug/MIUAAAAAAAAAAAAAAAAAEDAf4hZ9pLAAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4vMDEyMzQ1Njc4OTo7PD0+P0BBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWltcXV5fYGFiY2RlZmdoaWprbG1ub3BxcnN0dXZ3eHl6BAAKe3x9fn8=");
+    assert_eq!(
+        output.hash.to_hex(),
+        "2f0e3045dd574f5775a4317e19584f234655fc887d3d42e630d3877a59ee753a"
+    );
+}
+
+#[test]
+fn test_ton_sign_transfer_encrypted_comment_too_short() {
+    let private_key = "c054900a527538c1b4325688a421c0469b171c29f23a62da216e90b0df2412ee";
+
+    let transfer = Proto::Transfer {
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 10,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        // `pub_xor` and `msg_key` without encrypted data.
+        payload: PayloadType::encrypted_comment(vec![0; 48].into()),
+        ..Proto::Transfer::default()
+    };
+
+    let input = Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        messages: vec![transfer],
+        sequence_number: 1,
+        expire_at: 1787693046,
+        wallet_version: Proto::WalletVersion::WALLET_V4_R2,
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::TON, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
 #[test]
 fn test_ton_sign_transfer_custom_payload() {
     // UQApdOSfRTScoB9bMNGeo3bn-DGpQD8gywA27UaZVvAQsrHg
@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::ton::ton_sign::assert_eq_boc;
+use tw_any_coin::test_utils::transaction_decode_utils::TransactionDecoderHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base64::{self, STANDARD};
+use tw_encoding::hex::ToHex;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TheOpenNetwork::Proto;
+use tw_proto::TheOpenNetwork::Proto::mod_Transfer::OneOfpayload as PayloadType;
+
+#[test]
+fn test_ton_decode_transaction_v4r2_jetton_transfer_with_comment() {
+    // Signed in `test_ton_sign_transfer_jettons_with_comment`.
+    let encoded_tx = base64::decode("te6ccgICAAQAAQAAARgAAAFFiAC0UQZVyBNtT/W+jqQKnhYasPiDIdSWnNgo1FPyLHxLKgwAAQGcaIWVosi1XnveAmoG9y0/mPeNUqUu7GY76mdbRAaVeNeDOPDlh5M3BEb26kkc6XoYDekV60o2iOobN+TGS76jBSmpoxdqjgf2AAAAAQADAAIBaGIAMTQfh52puD7eKUmDbhqfta4cdUMRF662Uxp3zzqug/MgL68IAAAAAAAAAAAAAAAAAAEAAwDKD4p+pQAAAAAAAAAAQdzWUAgAC4GWcwteHQM+mcQoV+aZ+myCd1jYqUiawhCzuxMdEzkAFoogyrkCban+t9HUgVPCw1YfEGQ6ktObBRqKfkWPiWVCAgAAAAB0ZXN0IGNvbW1lbnQ=", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V4_R2);
+    assert_eq!(
+        tx.wallet_address,
+        "UQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llWgh"
+    );
+    assert!(tx.state_init.is_empty());
+    assert_eq!(tx.sequence_number, 1);
+    assert_eq!(tx.expire_at, 1787693046);
+    assert_eq!(tx.signature.to_hex(), "688595a2c8b55e7bde026a06f72d3f98f78d52a52eec663bea675b44069578d78338f0e58793370446f6ea491ce97a180de915eb4a3688ea1b37e4c64bbea305");
+
+    let expected_jetton_transfer = Proto::JettonTransfer {
+        query_id: 0,
+        jetton_amount: 500 * 1000 * 1000,
+        to_owner: "UQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnGG5".into(),
+        response_address: "UQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llWgh".into(),
+        forward_amount: 1,
+        ..Proto::JettonTransfer::default()
+    };
+    let expected_transfer = Proto::Transfer {
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 100 * 1000 * 1000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        comment: "test comment".into(),
+        payload: PayloadType::jetton_transfer(expected_jetton_transfer),
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected_transfer]);
+}
+
+#[test]
+fn test_ton_decode_transaction_v4r2_nft_transfer() {
+    // Signed in `test_ton_sign_transfer_nft`.
+    let encoded_tx = base64::decode("te6ccgECBAEAAQ0AAUWIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsqDAEBnHjJNecXeVOEV/y2dMkwIL5Mqm+YZtl7qwhjk6VMpthj76xDGPM/CoPi/7cRHlQC0EE5LZu8vI/dUxkPnD8hygspqaMXao4H9gAAAAEAAwIBaGIAMTQfh52puD7eKUmDbhqfta4cdUMRF662Uxp3zzqug/MgF9eEAAAAAAAAAAAAAAAAAAEDALlfzD0UAAAAAAAAAAGAALgZZzC14dAz6ZxChX5pn6bIJ3WNipSJrCELO7Ex0TOQAWiiDKuQJtqf630dSBU8LDVh8QZDqS05sFGop+RY+JZUICAAAAAG5mdCBnaWZ0g=", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V4_R2);
+    assert_eq!(tx.sequence_number, 1);
+    assert_eq!(tx.expire_at, 1787693046);
+
+    let expected_nft_transfer = Proto::NftTransfer {
+        query_id: 1,
+        new_owner: "UQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnGG5".into(),
+        response_destination: "UQBaKIMq5Am2p_rfR1IFTwsNWHxBkOpLTmwUain5Fj4llWgh".into(),
+        forward_amount: 1,
+        ..Proto::NftTransfer::default()
+    };
+    let expected_transfer = Proto::Transfer {
+        dest: "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja".into(),
+        amount: 50 * 1000 * 1000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        comment: "nft gift".into(),
+        payload: PayloadType::nft_transfer(expected_nft_transfer),
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected_transfer]);
+}
+
+#[test]
+fn test_ton_decode_transaction_v4r2_comment() {
+    // Signed in `test_ton_sign_transfer_custom_payload`.
+    // The custom payload is a comment cell, so it's decoded as a comment.
+    let encoded_tx = base64::decode("te6cckEBBAEAvwABRYgAUunJPoppOUA+tmGjPUbtz/BjUoB+QZYAbdqNMq3gIWQMAQGc981GQ9a8Yr4m2YeIeuuNIWlzdHliyW6MRq3RDs5kgvXJP+iNhdZU7o79DJnm/OKuzWI5FbiNy3SF0fGGBObDDCmpoxdmojQrAAAAAgADAgFmYgAdYQm/0Kcw+xnyxzyhpLIsIGhnyFtKVVNCLKFmg/s0ZRgehIAAAAAAAAAAAAAAAAABAwAgAAAAAEhpIHRoZXJlIHNpcoAlI8E=", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(
+        tx.wallet_address,
+        "UQApdOSfRTScoB9bMNGeo3bn-DGpQD8gywA27UaZVvAQsrHg"
+    );
+    assert_eq!(tx.sequence_number, 2);
+    assert_eq!(tx.expire_at, 1721906219);
+
+    let expected_transfer = Proto::Transfer {
+        dest: "EQA6whN_oU5h9jPljnlDSWRYQNDPkLaUqqaEWULNB_ZoyhZr".into(),
+        amount: 250_000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        comment: "Hi there sir".into(),
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected_transfer]);
+}
+
+#[test]
+fn test_ton_decode_transaction_v4r2_unknown_payload() {
+    // Signed in `test_ton_sign_transfer_jetton_burn`.
+    // Jetton burn payload is not recognized by the decoder, so the raw cell tree is returned.
+    let encoded_tx = base64::decode("te6ccgEBBAEA4gABRYgAtFEGVcgTbU/1vo6kCp4WGrD4gyHUlpzYKNRT8ix8SyoMAQGcYi9+2HGUrui3R2aVHGhWo/GDauIIADm0o+FOr0LtMRKn1twE6luwZFFDdCwz5if87YM7faJHxhgtBbYW3TSBBimpoxdqjgf2AAAAAQADAgFoYgAxNB+Hnam4Pt4pSYNuGp+1rhx1QxEXrrZTGnfPOq6D8yAvrwgAAAAAAAAAAAAAAAAAAQMAZFlfB7wAAAAAAAAAAkO5rKAIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsq", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.messages.len(), 1);
+
+    let transfer = &tx.messages[0];
+    assert_eq!(
+        transfer.dest,
+        "EQBiaD8PO1NwfbxSkwbcNT9rXDjqhiIvXWymNO-edV0H5lja"
+    );
+    assert_eq!(transfer.amount, 100 * 1000 * 1000);
+    assert!(transfer.comment.is_empty());
+    match transfer.payload {
+        PayloadType::custom_payload(ref payload) => assert_eq_boc(
+            payload,
+            "te6cckEBAQEANAAAZFlfB7wAAAAAAAAAAkO5rKAIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsqM7Wqqw==",
+        ),
+        ref other => panic!("Expected a custom payload, found: {other:?}"),
+    }
+}
+
+#[test]
+fn test_ton_decode_transaction_v5r1_transfer_and_deploy() {
+    // Signed in `test_ton_sign_wallet_v5r1_transfer_and_deploy`.
+    let encoded_tx = base64::decode("te6cckECGwEAA2sAAkWIACm9HPyVOpjCNOG6nbf+EwCONRHHpeMQsIlCoWNKhUaaHgECAgE0AwQBoXNpZ25///8R/////wAAAACACOfqY7L3l3aKc58eNxuJTaeH/fgBw2aG0coM+hjDpjWhJKbYKmsAD8v054HYSuO6vN3bQnV5U19BhsGfe1MDoAUBFP8A9KQT9LzyyAsGAFGAAAAAP///iKrcG+d35KaMMtuxik4jqNofFL51Mu1f8Qf19onqsDlyIAIKDsPIbQMWBwIBIAgJAWJiAC90HaFSSy94Zxb85WYu97uTKIxAlLvolycpcYkWc+8giFAAAAAAAAAAAAAAAAABFgIBSAoLAQLyDALc0CDXScEgkVuPYyDXCx8gghBleHRuvSGCEHNpbnS9sJJfA+CCEGV4dG66jrSAINchAdB01yH6QDD6RPgo+kQwWL2RW+DtRNCBAUHXIfQFgwf0Dm+hMZEw4YBA1yFwf9s84DEg10mBAoC5kTDgcOIXDQIBIA4PAR4g1wsfghBzaWduuvLgin8NAeaO8O2i7fshgwjXIgKDCNcjIIAg1yHTH9Mf0x/tRNDSANMfINMf0//XCgAK+QFAzPkQmiiUXwrbMeHywIffArNQB7Dy0IRRJbry4IVQNrry4Ib4I7vy0IgikvgA3gGkf8jKAMsfAc8Wye1UIJL4D95w2zzYFwIBIBARABm+Xw9qJoQICg65D6AsAgFuEhMCAUgUFQAZrc52omhAIOuQ64X/wAAZrx32omhAEOuQ64WPwAAXsyX7UTQcdch1wsfgABGyYvtRNDXCgCAAAAP27aLt+wL0BCFukmwhjkwCIdc5MHCUIccAs44tAdcoIHYeQ2wg10nACPLgkyDXSsAC8uCTINcdBscSwgBSMLDy0InXTNc5MAGk6GwShAe78uCT10rAAPLgk+1V4tIAAcAAkVvg69csCBQgkXCWAdcsCBwS4lIQseMPINdKGBkaAJYB+kAB+kT4KPpEMFi68uCR7UTQgQFB1xj0BQSdf8jKAEAEgwf0U/Lgi44UA4MH9Fvy4Iwi1woAIW4Bs7Dy0JDiyFADzxYS9ADJ7VQAcjDXLAgkji0h8uCS0gDtRNDSAFETuvLQj1RQMJExnAGBAUDXIdcKAPLgjuLIygBYzxbJ7VST8sCN4gAQk1vbMeHXTNB8Ui06", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V5_R1);
+    assert_eq!(
+        tx.wallet_address,
+        "UQAU3o5-Sp1MYRpw3U7b_wmARxqI49LxiFhEoVCxpUKjTYXk"
+    );
+    assert_eq_boc(&tx.state_init, "te6cckECFgEAArEAAgE0AQIBFP8A9KQT9LzyyAsDAFGAAAAAP///iKrcG+d35KaMMtuxik4jqNofFL51Mu1f8Qf19onqsDlyIAIBIAQFAgFIBgcBAvIIAtzQINdJwSCRW49jINcLHyCCEGV4dG69IYIQc2ludL2wkl8D4IIQZXh0brqOtIAg1yEB0HTXIfpAMPpE+Cj6RDBYvZFb4O1E0IEBQdch9AWDB/QOb6ExkTDhgEDXIXB/2zzgMSDXSYECgLmRMOBw4hIJAgEgCgsBHiDXCx+CEHNpZ2668uCKfwkB5o7w7aLt+yGDCNciAoMI1yMggCDXIdMf0x/TH+1E0NIA0x8g0x/T/9cKAAr5AUDM+RCaKJRfCtsx4fLAh98Cs1AHsPLQhFEluvLghVA2uvLghvgju/LQiCKS+ADeAaR/yMoAyx8BzxbJ7VQgkvgP3nDbPNgSAgEgDA0AGb5fD2omhAgKDrkPoCwCAW4ODwIBSBARABmtznaiaEAg65Drhf/AABmvHfaiaEAQ65DrhY/AABezJftRNBx1yHXCx+AAEbJi+1E0NcKAIAP27aLt+wL0BCFukmwhjkwCIdc5MHCUIccAs44tAdcoIHYeQ2wg10nACPLgkyDXSsAC8uCTINcdBscSwgBSMLDy0InXTNc5MAGk6GwShAe78uCT10rAAPLgk+1V4tIAAcAAkVvg69csCBQgkXCWAdcsCBwS4lIQseMPINdKExQVAJYB+kAB+kT4KPpEMFi68uCR7UTQgQFB1xj0BQSdf8jKAEAEgwf0U/Lgi44UA4MH9Fvy4Iwi1woAIW4Bs7Dy0JDiyFADzxYS9ADJ7VQAcjDXLAgkji0h8uCS0gDtRNDSAFETuvLQj1RQMJExnAGBAUDXIdcKAPLgjuLIygBYzxbJ7VST8sCN4gAQk1vbMeHXTNCmVaPr");
+    assert_eq!(tx.sequence_number, 0);
+    assert_eq!(tx.expire_at, 0xffffffff);
+    assert_eq!(tx.signature.to_hex(), "00239fa98ecbde5dda29ce7c78dc6e25369e1ff7e0070d9a1b472833e8630e98d684929b60a9ac003f2fd39e07612b8eeaf3776d09d5e54d7d061b067ded4c0e");
+
+    let expected_transfer = Proto::Transfer {
+        dest: "EQBe6DtCpJZe8M4t-crMXe93JlEYgSl30S5OUuMSLOfeQfBu".into(),
+        amount: 10,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected_transfer]);
+}
+
+#[test]
+fn test_ton_decode_transaction_v5r1_jetton_transfer_with_comment() {
+    // Signed in `test_ton_sign_wallet_v5r1_transfer_jettons_with_comment`.
+    let encoded_tx = base64::decode("te6cckECBgEAASIAAUWIAUPGsCB+oHBGks4E/WQD8VoYBDFK43SgpC9TyWeqauJoDAEBoXNpZ25///8RZro7egAAAAGYUu8+ZHZK/wWb9ojB++h5tz2ie7e4GktWZfr475zJcnpRLZADd9wbyuuV7GXr8QzKeqvQbSDVJlgv4bcdnjwDYAICCg7DyG0DAwQAAAFoYgBwcARvtYoAq2KC3PPTyFSjlwwsLVuA3hCZtiQP1p6T4yAvrwgAAAAAAAAAAAAAAAAAAQUAyA+KfqUAAAAAAAAAADAa2wgAKb0c/JU6mMI04bqdt/4TAI41Ecel4xCwiUKhY0qFRpsAKHjWBA/UDgjSWcCfrIB+K0MAhilcbpQUhep5LPVNXE0CAgAAAAB0ZXN0IGNvbW1lbnQIl4du", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V5_R1);
+    assert!(tx.state_init.is_empty());
+    assert_eq!(tx.sequence_number, 1);
+    assert_eq!(tx.expire_at, 1723480954);
+
+    let expected_jetton_transfer = Proto::JettonTransfer {
+        query_id: 0,
+        jetton_amount: 110000,
+        to_owner: "UQAU3o5-Sp1MYRpw3U7b_wmARxqI49LxiFhEoVCxpUKjTYXk".into(),
+        response_address: "UQCh41gQP1A4I0lnAn6yAfitDAIYpXG6UFIXqeSz1TVxNOJ_".into(),
+        forward_amount: 1,
+        ..Proto::JettonTransfer::default()
+    };
+    let expected_transfer = Proto::Transfer {
+        dest: "EQDg4AjfaxQBVsUFueenkKlHLhhYWrcBvCEzbEgfrT0nxuGC".into(),
+        amount: 100 * 1000 * 1000,
+        mode: Proto::SendMode::PAY_FEES_SEPARATELY as u32
+            | Proto::SendMode::IGNORE_ACTION_PHASE_ERRORS as u32,
+        bounceable: true,
+        comment: "test comment".into(),
+        payload: PayloadType::jetton_transfer(expected_jetton_transfer),
+        ..Proto::Transfer::default()
+    };
+    assert_eq!(tx.messages, vec![expected_transfer]);
+}
+
+#[test]
+fn test_ton_decode_transaction_v4r2_jetton_transfer_with_forward_payload() {
+    // Signed in `test_ton_sign_transfer_jettons_with_forward_payload`.
+    let encoded_tx = base64::decode("te6ccgECBQEAARQAAUWIALRRBlXIE21P9b6OpAqeFhqw+IMh1Jac2CjUU/IsfEsqDAEBnDeCe8Hx3OAWOt8uthsnHarrbPP+M30vbMFRanCLoxjTxaJ/FLJbuRoZDDhrOIVScQxrwnzJZPaKkMeV9/pRSQ8pqaMXao4H9gAAAAEAAwIBaGIAMTQfh52puD7eKUmDbhqfta4cdUMRF662Uxp3zzqug/MgL68IAAAAAAAAAAAAAAAAAAEDAaoPin6lAAAAAAAAAABB3NZQCAALgZZzC14dAz6ZxChX5pn6bIJ3WNipSJrCELO7Ex0TOQAWiiDKuQJtqf630dSBU8LDVh8QZDqS05sFGop+RY+JZUIDBAAYc2LQnAAAAAAAAAAq", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.messages.len(), 1);
+    let transfer = &tx.messages[0];
+    assert!(transfer.comment.is_empty());
+
+    // The forward payload is not a text comment, so it's returned as a raw BoC.
+    match transfer.payload {
+        PayloadType::jetton_transfer(ref jetton_transfer) => {
+            assert_eq!(jetton_transfer.jetton_amount, 500 * 1000 * 1000);
+            assert_eq!(
+                jetton_transfer.to_owner,
+                "UQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnGG5"
+            );
+            assert_eq!(jetton_transfer.forward_amount, 1);
+            assert!(jetton_transfer.custom_payload.is_empty());
+            assert_eq_boc(
+                &jetton_transfer.forward_payload,
+                "te6cckEBAQEADgAAGHNi0JwAAAAAAAAAKnVj1yc=",
+            );
+        },
+        ref other => panic!("Expected a jetton transfer, found: {other:?}"),
+    }
+}
+
+#[test]
+fn test_ton_decode_transaction_v5r1_inline_state_init() {
+    // The same transaction as in `test_ton_decode_transaction_v5r1_transfer_and_deploy`,
+    // but the StateInit is stored inline as serialized by `@ton/core`.
+    let encoded_tx = base64::decode("te6cckECGgEAA2gAA0eIACm9HPyVOpjCNOG6nbf+EwCONRHHpeMQsIlCoWNKhUaaEbABAgMBFP8A9KQT9LzyyAsEAFGAAAAAP///iKrcG+d35KaMMtuxik4jqNofFL51Mu1f8Qf19onqsDlyIAGhc2lnbn///xH/////AAAAAIAI5+pjsveXdopznx43G4lNp4f9+AHDZobRygz6GMOmNaEkptgqawAPy/TngdhK47q83dtCdXlTX0GGwZ97UwOgBQIBIAYHAgoOw8htAxUIAgFICQoBAvILAWJiAC90HaFSSy94Zxb85WYu97uTKIxAlLvolycpcYkWc+8giFAAAAAAAAAAAAAAAAABFQLc0CDXScEgkVuPYyDXCx8gghBleHRuvSGCEHNpbnS9sJJfA+CCEGV4dG66jrSAINchAdB01yH6QDD6RPgo+kQwWL2RW+DtRNCBAUHXIfQFgwf0Dm+hMZEw4YBA1yFwf9s84DEg10mBAoC5kTDgcOIWDAIBIA0OAR4g1wsfghBzaWduuvLgin8MAeaO8O2i7fshgwjXIgKDCNcjIIAg1yHTH9Mf0x/tRNDSANMfINMf0//XCgAK+QFAzPkQmiiUXwrbMeHywIffArNQB7Dy0IRRJbry4IVQNrry4Ib4I7vy0IgikvgA3gGkf8jKAMsfAc8Wye1UIJL4D95w2zzYFgIBIA8QABm+Xw9qJoQICg65D6AsAgFuERICAUgTFAAZrc52omhAIOuQ64X/wAAZrx32omhAEOuQ64WPwAAXsyX7UTQcdch1wsfgABGyYvtRNDXCgCAAAAP27aLt+wL0BCFukmwhjkwCIdc5MHCUIccAs44tAdcoIHYeQ2wg10nACPLgkyDXSsAC8uCTINcdBscSwgBSMLDy0InXTNc5MAGk6GwShAe78uCT10rAAPLgk+1V4tIAAcAAkVvg69csCBQgkXCWAdcsCBwS4lIQseMPINdKFxgZAJYB+kAB+kT4KPpEMFi68uCR7UTQgQFB1xj0BQSdf8jKAEAEgwf0U/Lgi44UA4MH9Fvy4Iwi1woAIW4Bs7Dy0JDiyFADzxYS9ADJ7VQAcjDXLAgkji0h8uCS0gDtRNDSAFETuvLQj1RQMJExnAGBAUDXIdcKAPLgjuLIygBYzxbJ7VST8sCN4gAQk1vbMeHXTNAf0oaf", STANDARD).unwrap();
+
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, encoded_tx);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    let tx = output.transaction.unwrap();
+    assert_eq!(tx.wallet_version, Proto::WalletVersion::WALLET_V5_R1);
+    assert_eq!(
+        tx.wallet_address,
+        "UQAU3o5-Sp1MYRpw3U7b_wmARxqI49LxiFhEoVCxpUKjTYXk"
+    );
+    // The inline StateInit is returned as a standalone cell.
+    assert_eq_boc(&tx.state_init, "te6cckECFgEAArEAAgE0AQIBFP8A9KQT9LzyyAsDAFGAAAAAP///iKrcG+d35KaMMtuxik4jqNofFL51Mu1f8Qf19onqsDlyIAIBIAQFAgFIBgcBAvIIAtzQINdJwSCRW49jINcLHyCCEGV4dG69IYIQc2ludL2wkl8D4IIQZXh0brqOtIAg1yEB0HTXIfpAMPpE+Cj6RDBYvZFb4O1E0IEBQdch9AWDB/QOb6ExkTDhgEDXIXB/2zzgMSDXSYECgLmRMOBw4hIJAgEgCgsBHiDXCx+CEHNpZ2668uCKfwkB5o7w7aLt+yGDCNciAoMI1yMggCDXIdMf0x/TH+1E0NIA0x8g0x/T/9cKAAr5AUDM+RCaKJRfCtsx4fLAh98Cs1AHsPLQhFEluvLghVA2uvLghvgju/LQiCKS+ADeAaR/yMoAyx8BzxbJ7VQgkvgP3nDbPNgSAgEgDA0AGb5fD2omhAgKDrkPoCwCAW4ODwIBSBARABmtznaiaEAg65Drhf/AABmvHfaiaEAQ65DrhY/AABezJftRNBx1yHXCx+AAEbJi+1E0NcKAIAP27aLt+wL0BCFukmwhjkwCIdc5MHCUIccAs44tAdcoIHYeQ2wg10nACPLgkyDXSsAC8uCTINcdBscSwgBSMLDy0InXTNc5MAGk6GwShAe78uCT10rAAPLgk+1V4tIAAcAAkVvg69csCBQgkXCWAdcsCBwS4lIQseMPINdKExQVAJYB+kAB+kT4KPpEMFi68uCR7UTQgQFB1xj0BQSdf8jKAEAEgwf0U/Lgi44UA4MH9Fvy4Iwi1woAIW4Bs7Dy0JDiyFADzxYS9ADJ7VQAcjDXLAgkji0h8uCS0gDtRNDSAFETuvLQj1RQMJExnAGBAUDXIdcKAPLgjuLIygBYzxbJ7VST8sCN4gAQk1vbMeHXTNCmVaPr");
+    assert_eq!(tx.sequence_number, 0);
+    assert_eq!(tx.expire_at, 0xffffffff);
+    assert_eq!(tx.messages.len(), 1);
+}
+
+#[test]
+fn test_ton_decode_transaction_invalid_boc() {
+    let mut decoder = TransactionDecoderHelper::<Proto::DecodingTransactionOutput>::default();
+    let output = decoder.decode(CoinType::TON, vec![0x01, 0x02, 0x03]);
+
+    assert_eq!(output.error, SigningError::Error_input_parse);
+    assert!(output.transaction.is_none());
+}
@@ -7,9 +7,36 @@ use tw_keypair::tw::PublicKeyType;
 use tw_memory::test_utils::tw_string_helper::TWStringHelper;
 use tw_ton::resources::WALLET_ID_V5R1_TON_MAINNET;
 use wallet_core_rs::ffi::ton::wallet::{
+    tw_ton_wallet_build_v3_r1_state_init, tw_ton_wallet_build_v3_r2_state_init,
     tw_ton_wallet_build_v4_r2_state_init, tw_ton_wallet_build_v5_r1_state_init,
 };
 
+#[test]
+fn test_ton_wallet_v3_r1_create_state_init() {
+    let public_key = TWPublicKeyHelper::with_hex(
+        "f229a9371fa7c2108b3d90ea22c9be705ff5d0cfeaee9cbb9366ff0171579357",
+        PublicKeyType::Ed25519,
+    );
+    assert!(!public_key.is_null());
+    let state_init_boc = TWStringHelper::wrap(unsafe {
+        tw_ton_wallet_build_v3_r1_state_init(public_key.ptr(), 0, 0x29a9a317)
+    });
+    assert_eq!(state_init_boc.to_string().unwrap(), "te6cckEBAwEAkQACATQBAgDA/wAg3SCCAUyXupcw7UTQ1wsf4KTyYIMI1xgg0x/TH9Mf+CMTu/Jj7UTQ0x/TH9P/0VEyuvKhUUS68qIE+QFUEFX5EPKj+ACTINdKltMH1AL7AOjRAaTIyx/LH8v/ye1UAFAAAAAAKamjF/IpqTcfp8IQiz2Q6iLJvnBf9dDP6u6cu5Nm/wFxV5NXvg7hpA==");
+}
+
+#[test]
+fn test_ton_wallet_v3_r2_create_state_init() {
+    let public_key = TWPublicKeyHelper::with_hex(
+        "f229a9371fa7c2108b3d90ea22c9be705ff5d0cfeaee9cbb9366ff0171579357",
+        PublicKeyType::Ed25519,
+    );
+    assert!(!public_key.is_null());
+    let state_init_boc = TWStringHelper::wrap(unsafe {
+        tw_ton_wallet_build_v3_r2_state_init(public_key.ptr(), 0, 0x29a9a317)
+    });
+    assert_eq!(state_init_boc.to_string().unwrap(), "te6cckEBAwEAoAACATQBAgDe/wAg3SCCAUyXuiGCATOcurGfcbDtRNDTH9MfMdcL/+ME4KTyYIMI1xgg0x/TH9Mf+CMTu/Jj7UTQ0x/TH9P/0VEyuvKhUUS68qIE+QFUEFX5EPKj+ACTINdKltMH1AL7AOjRAaTIyx/LH8v/ye1UAFAAAAAAKamjF/IpqTcfp8IQiz2Q6iLJvnBf9dDP6u6cu5Nm/wFxV5NXmPjCgg==");
+}
+
 #[test]
 fn test_ton_wallet_v4_r2_create_state_init() {
     let public_key = TWPublicKeyHelper::with_hex(
@@ -51,6 +51,46 @@ pub unsafe extern "C" fn tw_ethereum_abi_decode_params(
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
 
+/// Decode an event log according to a given smart contract ABI.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.EventLogDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.EventLogDecodingOutput` proto object.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_abi_decode_event_log(
+    coin: u32,
+    input: *const TWData,
+) -> *mut TWData {
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+
+    evm_dispatcher
+        .decode_abi_event_log(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Decode revert data as `Error(string)`, `Panic(uint256)` or a custom error of a given smart contract ABI.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.RevertDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.RevertDecodingOutput` proto object.
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_abi_decode_revert(
+    coin: u32,
+    input: *const TWData,
+) -> *mut TWData {
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+
+    evm_dispatcher
+        .decode_abi_revert(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
 /// Returns the function type signature, of the form "baz(int32,uint256)".
 ///
 /// \param coin EVM-compatible coin type.
@@ -36,6 +36,14 @@ TWData* _Nonnull TWEthereumAbiDecodeParams(enum TWCoinType coin, TWData* _Nonnul
     return ethereumAbiForwardToRust(Rust::tw_ethereum_abi_decode_params, coin, input);
 }
 
+TWData* _Nonnull TWEthereumAbiDecodeEventLog(enum TWCoinType coin, TWData* _Nonnull input) {
+    return ethereumAbiForwardToRust(Rust::tw_ethereum_abi_decode_event_log, coin, input);
+}
+
+TWData* _Nonnull TWEthereumAbiDecodeRevert(enum TWCoinType coin, TWData* _Nonnull input) {
+    return ethereumAbiForwardToRust(Rust::tw_ethereum_abi_decode_revert, coin, input);
+}
+
 TWData* _Nonnull TWEthereumAbiDecodeValue(enum TWCoinType coin, TWData* _Nonnull input) {
     return ethereumAbiForwardToRust(Rust::tw_ethereum_abi_decode_value, coin, input);
 }
@@ -9,6 +9,26 @@
 
 namespace TW::TheOpenNetwork::tests {
 
+TEST(TWTONWallet, BuildV3R1StateInit) {
+    auto publicKeyBytes = DATA("f229a9371fa7c2108b3d90ea22c9be705ff5d0cfeaee9cbb9366ff0171579357");
+    auto publicKey = WRAP(TWPublicKey, TWPublicKeyCreateWithData(publicKeyBytes.get(), TWPublicKeyTypeED25519));
+
+    const int32_t baseWorkchain = 0;
+    const int32_t defaultWalletId = 0x29a9a317;
+    const auto stateInit = WRAPS(TWTONWalletBuildV3R1StateInit(publicKey.get(), baseWorkchain, defaultWalletId));
+    assertStringsEqual(stateInit, "te6cckEBAwEAkQACATQBAgDA/wAg3SCCAUyXupcw7UTQ1wsf4KTyYIMI1xgg0x/TH9Mf+CMTu/Jj7UTQ0x/TH9P/0VEyuvKhUUS68qIE+QFUEFX5EPKj+ACTINdKltMH1AL7AOjRAaTIyx/LH8v/ye1UAFAAAAAAKamjF/IpqTcfp8IQiz2Q6iLJvnBf9dDP6u6cu5Nm/wFxV5NXvg7hpA==");
+}
+
+TEST(TWTONWallet, BuildV3R2StateInit) {
+    auto publicKeyBytes = DATA("f229a9371fa7c2108b3d90ea22c9be705ff5d0cfeaee9cbb9366ff0171579357");
+    auto publicKey = WRAP(TWPublicKey, TWPublicKeyCreateWithData(publicKeyBytes.get(), TWPublicKeyTypeED25519));
+
+    const int32_t baseWorkchain = 0;
+    const int32_t defaultWalletId = 0x29a9a317;
+    const auto stateInit = WRAPS(TWTONWalletBuildV3R2StateInit(publicKey.get(), baseWorkchain, defaultWalletId));
+    assertStringsEqual(stateInit, "te6cckEBAwEAoAACATQBAgDe/wAg3SCCAUyXuiGCATOcurGfcbDtRNDTH9MfMdcL/+ME4KTyYIMI1xgg0x/TH9Mf+CMTu/Jj7UTQ0x/TH9P/0VEyuvKhUUS68qIE+QFUEFX5EPKj+ACTINdKltMH1AL7AOjRAaTIyx/LH8v/ye1UAFAAAAAAKamjF/IpqTcfp8IQiz2Q6iLJvnBf9dDP6u6cu5Nm/wFxV5NXmPjCgg==");
+}
+
 TEST(TWTONWallet, BuildV4R2StateInit) {
     auto publicKeyBytes = DATA("f229a9371fa7c2108b3d90ea22c9be705ff5d0cfeaee9cbb9366ff0171579357");
     auto publicKey = WRAP(TWPublicKey, TWPublicKeyCreateWithData(publicKeyBytes.get(), TWPublicKeyTypeED25519));